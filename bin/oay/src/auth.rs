@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! HTTP basic and bearer auth shared by HTTP based frontends, and secret
+//! comparison shared by all frontends.
+
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use axum::http::header;
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use axum::http::HeaderMap;
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use axum::http::StatusCode;
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use axum::response::IntoResponse;
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use axum::response::Response;
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use base64::engine::general_purpose::STANDARD as BASE64;
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use base64::Engine;
+
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use crate::policy::ANONYMOUS_USER;
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+use crate::UserCredential;
+
+/// Compare secrets in constant time to avoid leaking them via timing.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Authenticate the request by HTTP basic or bearer auth, returns the user name.
+///
+/// All requests are treated as [`ANONYMOUS_USER`] if there are no users.
+/// Returns `None` if the credentials are missing or invalid.
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+pub fn authenticate(users: &[UserCredential], headers: &HeaderMap) -> Option<String> {
+    if users.is_empty() {
+        return Some(ANONYMOUS_USER.to_string());
+    }
+
+    let v = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())?;
+    let secret_eq = |expected: Option<&str>, actual: &str| {
+        expected.is_some_and(|v| constant_time_eq(v.as_bytes(), actual.as_bytes()))
+    };
+    let user = if let Some(token) = v.strip_prefix("Bearer ") {
+        users
+            .iter()
+            .find(|u| secret_eq(u.token.as_deref(), token.trim()))
+    } else {
+        let v = v.strip_prefix("Basic ")?;
+        let v = String::from_utf8(BASE64.decode(v.trim()).ok()?).ok()?;
+        let (username, password) = v.split_once(':')?;
+        users
+            .iter()
+            .find(|u| u.username == username && secret_eq(u.password.as_deref(), password))
+    };
+    user.map(|u| u.username.clone())
+}
+
+/// Build the response of unauthorized requests.
+#[cfg(any(feature = "frontends-http", feature = "frontends-webdav"))]
+pub fn unauthorized() -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        [(
+            header::WWW_AUTHENTICATE,
+            "Basic realm=\"oay\", charset=\"UTF-8\"",
+        )],
+    )
+        .into_response()
+}
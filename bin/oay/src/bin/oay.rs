@@ -20,10 +20,9 @@ use std::sync::Arc;
 
 use anyhow::Context;
 use anyhow::Result;
-use oay::services::S3Service;
-use oay::services::WebdavService;
+use futures::future::BoxFuture;
+use futures::FutureExt;
 use oay::Config;
-use opendal::services::Fs;
 use opendal::Operator;
 use opendal::Scheme;
 use tracing_subscriber::fmt;
@@ -32,11 +31,6 @@ use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let _ = s3().await;
-    webdav().await
-}
-
-async fn s3() -> Result<()> {
     tracing_subscriber::registry()
         .with(fmt::layer().pretty())
         .with(EnvFilter::from_default_env())
@@ -46,36 +40,32 @@ async fn s3() -> Result<()> {
         toml::from_str(&std::fs::read_to_string("oay.toml").context("failed to open oay.toml")?)?;
     let scheme = Scheme::from_str(&cfg.backend.typ).context("unsupported scheme")?;
     let op = Operator::via_iter(scheme, cfg.backend.map.clone())?;
+    let cfg = Arc::new(cfg);
 
-    let s3 = S3Service::new(Arc::new(cfg), op);
-
-    s3.serve().await?;
-
-    Ok(())
-}
-
-async fn webdav() -> Result<()> {
-    let cfg: Config = Config {
-        backend: oay::BackendConfig {
-            typ: "fs".to_string(),
-            ..Default::default()
-        },
-        frontends: oay::FrontendsConfig {
-            webdav: oay::WebdavConfig {
-                enable: true,
-                addr: "127.0.0.1:3000".to_string(),
-            },
-            ..Default::default()
-        },
-    };
-
-    let builder = Fs::default().root("/tmp");
-
-    let op = Operator::new(builder)?.finish();
-
-    let webdav = WebdavService::new(Arc::new(cfg), op);
+    let mut frontends: Vec<BoxFuture<'static, Result<()>>> = vec![];
+    #[cfg(feature = "frontends-s3")]
+    if cfg.frontends.s3.enable {
+        let s3 = oay::services::S3Service::new(cfg.clone(), op.clone());
+        frontends.push(async move { s3.serve().await }.boxed());
+    }
+    #[cfg(feature = "frontends-webdav")]
+    if cfg.frontends.webdav.enable {
+        let webdav = oay::services::WebdavService::new(cfg.clone(), op.clone());
+        frontends.push(async move { webdav.serve().await }.boxed());
+    }
+    #[cfg(feature = "frontends-http")]
+    if cfg.frontends.http.enable {
+        let http = oay::services::HttpService::new(cfg.clone(), op.clone());
+        frontends.push(async move { http.serve().await }.boxed());
+    }
+    #[cfg(feature = "frontends-ftp")]
+    if cfg.frontends.ftp.enable {
+        let ftp = oay::services::FtpService::new(cfg.clone(), op.clone());
+        frontends.push(async move { ftp.serve().await }.boxed());
+    }
+    anyhow::ensure!(!frontends.is_empty(), "no frontend is enabled in oay.toml");
 
-    webdav.serve().await?;
+    futures::future::try_join_all(frontends).await?;
 
     Ok(())
 }
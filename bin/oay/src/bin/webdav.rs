@@ -42,9 +42,11 @@ async fn main() -> Result<()> {
             webdav: oay::WebdavConfig {
                 enable: true,
                 addr: "127.0.0.1:3000".to_string(),
+                ..Default::default()
             },
             ..Default::default()
         },
+        ..Default::default()
     };
 
     let builder = Fs::default().root("/tmp");
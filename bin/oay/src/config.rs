@@ -20,10 +20,13 @@ use std::collections::HashMap;
 use serde::Deserialize;
 use serde::Serialize;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default)]
 pub struct Config {
     pub backend: BackendConfig,
     pub frontends: FrontendsConfig,
+    /// Access rules of users, see [`PolicyConfig`] for details.
+    #[serde(default)]
+    pub policies: Vec<PolicyConfig>,
 }
 
 #[derive(Serialize, Deserialize, Default)]
@@ -35,19 +38,158 @@ pub struct BackendConfig {
 }
 
 #[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct FrontendsConfig {
     pub s3: S3Config,
     pub webdav: WebdavConfig,
+    pub http: HttpConfig,
+    pub ftp: FtpConfig,
 }
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
 pub struct S3Config {
     pub enable: bool,
     pub addr: String,
+    /// The bucket name exposed to clients, default to `oay`.
+    pub bucket: String,
+    /// The region returned by `GetBucketLocation`, default to `us-east-1`.
+    pub region: String,
+    /// Credentials allowed to access this frontend.
+    ///
+    /// Requests must be signed with AWS Signature Version 4 by one of
+    /// those credentials. Anonymous access is allowed if it's empty.
+    ///
+    /// The access key id is used as the user name while matching policies.
+    pub credentials: Vec<S3Credential>,
+    /// Seconds to keep multipart uploads that are neither completed nor
+    /// aborted, default to 7 days.
+    pub multipart_expire_secs: u64,
+    pub tls: Option<TlsConfig>,
+}
+
+impl Default for S3Config {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            addr: String::new(),
+            bucket: "oay".to_string(),
+            region: "us-east-1".to_string(),
+            credentials: vec![],
+            multipart_expire_secs: 7 * 24 * 3600,
+            tls: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct S3Credential {
+    pub access_key_id: String,
+    pub secret_access_key: String,
 }
 
 #[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct WebdavConfig {
     pub enable: bool,
     pub addr: String,
+    /// Users allowed to access this frontend via HTTP basic or bearer auth.
+    ///
+    /// Anonymous access is allowed if it's empty.
+    pub users: Vec<UserCredential>,
+    pub tls: Option<TlsConfig>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpConfig {
+    pub enable: bool,
+    pub addr: String,
+    /// Render an HTML index for directories, default to `true`.
+    pub index: bool,
+    /// Allow uploading files via `PUT`, default to `false`.
+    pub upload: bool,
+    /// Users allowed to access this frontend via HTTP basic or bearer auth.
+    ///
+    /// Anonymous access is allowed if it's empty.
+    pub users: Vec<UserCredential>,
+    pub tls: Option<TlsConfig>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            addr: String::new(),
+            index: true,
+            upload: false,
+            users: vec![],
+            tls: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FtpConfig {
+    pub enable: bool,
+    pub addr: String,
+    /// The inclusive port range used by passive data connections, like `[50000, 50100]`.
+    ///
+    /// Use the default range of libunftp if not set.
+    pub passive_ports: Option<(u16, u16)>,
+    /// The IP address returned to clients for passive mode.
+    ///
+    /// Use the IP address of the control connection if not set.
+    pub passive_host: Option<String>,
+    /// Users allowed to login.
+    ///
+    /// Anonymous access is allowed if it's empty.
+    pub users: Vec<UserCredential>,
+    /// Enable FTPS with the given certificate.
+    pub tls: Option<TlsConfig>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct UserCredential {
+    pub username: String,
+    /// The password used by HTTP basic auth and FTP login.
+    pub password: Option<String>,
+    /// The token used by HTTP bearer auth.
+    pub token: Option<String>,
+}
+
+/// TLS certificate of a frontend.
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct TlsConfig {
+    /// Path to the PEM encoded certificate chain.
+    pub cert: String,
+    /// Path to the PEM encoded private key.
+    pub key: String,
+}
+
+/// PolicyConfig restricts a user to the given path prefixes.
+///
+/// Users are identified by the user name, or the access key id for the s3
+/// frontend. Requests without credentials belong to user `anonymous`.
+///
+/// If there are no policies, all users have full access. Otherwise, users
+/// can only access paths allowed by their own policies.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PolicyConfig {
+    pub user: String,
+    /// Path prefixes like `public/` this policy applies to, all paths if empty.
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+    #[serde(default)]
+    pub access: AccessMode,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AccessMode {
+    ReadOnly,
+    #[default]
+    ReadWrite,
 }
@@ -15,6 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
+#[cfg(any(
+    feature = "frontends-ftp",
+    feature = "frontends-http",
+    feature = "frontends-s3",
+    feature = "frontends-webdav"
+))]
+mod auth;
+pub mod policy;
+#[cfg(any(
+    feature = "frontends-http",
+    feature = "frontends-s3",
+    feature = "frontends-webdav"
+))]
+mod server;
 pub mod services;
 
 mod config;
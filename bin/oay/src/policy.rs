@@ -0,0 +1,629 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Per-user access control.
+//!
+//! Frontends resolve the [`Policy`] of the authenticated user and wrap the
+//! backend [`Operator`] with it for every request, so all operations are
+//! checked no matter which API is used.
+//!
+//! Paths inside [`INTERNAL_DIR`] are hidden from all users.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use opendal::raw::*;
+use opendal::Error;
+use opendal::ErrorKind;
+use opendal::Operator;
+use opendal::Result;
+
+use crate::AccessMode;
+use crate::PolicyConfig;
+
+/// User name of requests without credentials.
+pub const ANONYMOUS_USER: &str = "anonymous";
+
+/// Directory used by oay internally, like staging multipart uploads of s3.
+pub const INTERNAL_DIR: &str = ".oay/";
+
+/// Check if the path is [`INTERNAL_DIR`] or inside it.
+///
+/// Leading `/` will be removed by the backend, so it's ignored here.
+pub fn is_internal_path(path: &str) -> bool {
+    let path = path.trim_start_matches('/');
+    path == INTERNAL_DIR.trim_end_matches('/') || path.starts_with(INTERNAL_DIR)
+}
+
+/// Policies of all users.
+#[derive(Debug, Default)]
+pub struct Policies {
+    /// `None` means there are no policies and all users have full access.
+    users: Option<HashMap<String, Policy>>,
+}
+
+impl Policies {
+    pub fn new(cfgs: &[PolicyConfig]) -> Self {
+        if cfgs.is_empty() {
+            return Self::default();
+        }
+
+        let mut users: HashMap<String, Vec<Rule>> = HashMap::new();
+        for cfg in cfgs {
+            let rules = users.entry(cfg.user.clone()).or_default();
+            if cfg.prefixes.is_empty() {
+                rules.push(Rule {
+                    prefix: String::new(),
+                    access: cfg.access,
+                });
+            }
+            rules.extend(cfg.prefixes.iter().map(|prefix| {
+                // Prefixes are matched on segment boundaries, so `home/alice`
+                // won't allow `home/alice2`.
+                let mut prefix = prefix.trim_start_matches('/').to_string();
+                if !prefix.is_empty() && !prefix.ends_with('/') {
+                    prefix.push('/');
+                }
+                Rule {
+                    prefix,
+                    access: cfg.access,
+                }
+            }));
+        }
+
+        Self {
+            users: Some(
+                users
+                    .into_iter()
+                    .map(|(user, rules)| {
+                        let policy = Policy {
+                            rules: Some(Arc::new(rules)),
+                        };
+                        (user, policy)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Get the policy of given user, users without policies can't access anything.
+    pub fn policy(&self, user: &str) -> Policy {
+        match &self.users {
+            None => Policy::default(),
+            Some(users) => users.get(user).cloned().unwrap_or_else(|| Policy {
+                rules: Some(Arc::default()),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    /// Empty or ends with `/`.
+    prefix: String,
+    access: AccessMode,
+}
+
+impl Rule {
+    /// Check if the path is the prefix dir itself or inside it.
+    fn matches(&self, path: &str) -> bool {
+        path.starts_with(&self.prefix) || path == self.prefix.trim_end_matches('/')
+    }
+}
+
+/// Check if the path contains `.` or `..` segments.
+///
+/// Backends like fs resolve them, which could be used to escape from the
+/// allowed prefixes, so such paths are always rejected.
+fn has_dot_segment(path: &str) -> bool {
+    path.split('/').any(|v| v == "." || v == "..")
+}
+
+/// Policy of a user, the default policy allows everything.
+#[derive(Clone, Debug, Default)]
+pub struct Policy {
+    rules: Option<Arc<Vec<Rule>>>,
+}
+
+impl Policy {
+    fn allows(&self, path: &str, write: bool) -> bool {
+        let Some(rules) = &self.rules else {
+            return true;
+        };
+        let path = path.trim_start_matches('/');
+        rules
+            .iter()
+            .any(|rule| rule.matches(path) && (!write || rule.access == AccessMode::ReadWrite))
+    }
+
+    /// Check if the path is a parent directory of allowed prefixes.
+    ///
+    /// Those directories can be visited to reach allowed paths, but only
+    /// entries leading to allowed paths will be listed.
+    fn browsable(&self, path: &str) -> bool {
+        let Some(rules) = &self.rules else {
+            return true;
+        };
+        let path = path.trim_start_matches('/');
+        rules
+            .iter()
+            .any(|rule| match rule.prefix.strip_prefix(path) {
+                Some(rest) => path.is_empty() || path.ends_with('/') || rest.starts_with('/'),
+                None => false,
+            })
+    }
+
+    pub fn can_read(&self, path: &str) -> bool {
+        self.allows(path, false)
+    }
+
+    pub fn can_write(&self, path: &str) -> bool {
+        self.allows(path, true)
+    }
+
+    fn can_stat(&self, path: &str) -> bool {
+        self.allows(path, false) || self.browsable(path)
+    }
+
+    fn is_restricted(&self) -> bool {
+        self.rules.is_some()
+    }
+
+    /// Wrap the operator to enforce this policy.
+    ///
+    /// The operator is always wrapped even if the policy allows everything,
+    /// so that paths with `.` or `..` segments are rejected for all users.
+    pub fn operator(&self, op: &Operator) -> Operator {
+        op.clone().layer(PolicyLayer {
+            policy: self.clone(),
+        })
+    }
+}
+
+fn permission_denied(path: &str) -> Error {
+    Error::new(ErrorKind::PermissionDenied, "access denied by policy").with_context("path", path)
+}
+
+/// Return `PermissionDenied` if the condition is not satisfied, the path
+/// contains `.` or `..` segments or is internal.
+macro_rules! check {
+    ($cond:expr, $path:expr) => {
+        if has_dot_segment($path) || is_internal_path($path) || !$cond {
+            return Err(permission_denied($path));
+        }
+    };
+}
+
+struct PolicyLayer {
+    policy: Policy,
+}
+
+impl<A: Access> Layer<A> for PolicyLayer {
+    type LayeredAccess = PolicyAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        PolicyAccessor {
+            inner,
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PolicyAccessor<A: Access> {
+    inner: A,
+    policy: Policy,
+}
+
+impl<A: Access> LayeredAccess for PolicyAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = PolicyLister<A::Lister>;
+    type BlockingLister = PolicyLister<A::BlockingLister>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        check!(self.policy.can_write(path), path);
+        self.inner.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        check!(self.policy.can_read(path), path);
+        self.inner.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        check!(self.policy.can_write(path), path);
+        self.inner.write(path, args).await
+    }
+
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        check!(self.policy.can_read(from), from);
+        check!(self.policy.can_write(to), to);
+        self.inner.copy(from, to, args).await
+    }
+
+    async fn rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        check!(self.policy.can_write(from), from);
+        check!(self.policy.can_write(to), to);
+        self.inner.rename(from, to, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        check!(self.policy.can_stat(path), path);
+        self.inner.stat(path, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        check!(self.policy.can_write(path), path);
+        self.inner.delete(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        // Parent directories of allowed prefixes are listed with filtering.
+        let policy = if has_dot_segment(path) || is_internal_path(path) {
+            return Err(permission_denied(path));
+        } else if self.policy.can_read(path) {
+            None
+        } else if self.policy.browsable(path) {
+            Some(self.policy.clone())
+        } else {
+            return Err(permission_denied(path));
+        };
+        let (rp, lister) = self.inner.list(path, args).await?;
+        Ok((
+            rp,
+            PolicyLister {
+                inner: lister,
+                policy,
+            },
+        ))
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        for (path, op) in args.operation() {
+            match op {
+                BatchOperation::Stat(_) | BatchOperation::Read(_) => {
+                    check!(self.policy.can_read(path), path)
+                }
+                _ => check!(self.policy.can_write(path), path),
+            }
+        }
+        self.inner.batch(args).await
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        match args.operation() {
+            PresignOperation::Stat(_) | PresignOperation::Read(_) | PresignOperation::List(_) => {
+                check!(self.policy.can_read(path), path)
+            }
+            _ => check!(self.policy.can_write(path), path),
+        }
+        self.inner.presign(path, args).await
+    }
+
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        check!(self.policy.can_read(path), path);
+        self.inner.list_multipart_uploads(path, args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        check!(self.policy.can_write(path), path);
+        self.inner.abort_multipart_upload(path, args).await
+    }
+
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        check!(self.policy.can_read(path), path);
+        self.inner.summary(path, args).await
+    }
+
+    /// Symlinks could point to anywhere, so restricted users can't create them.
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        check!(!self.policy.is_restricted(), path);
+        self.inner.create_symlink(path, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        check!(self.policy.can_read(path), path);
+        self.inner.read_link(path, args).await
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        check!(self.policy.can_write(path), path);
+        self.inner.set_attr(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        check!(self.policy.can_read(path), path);
+        self.inner.get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        check!(self.policy.can_write(path), path);
+        self.inner.set_tags(path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        check!(self.policy.can_write(path), path);
+        self.inner.set_retention(path, args).await
+    }
+
+    async fn set_legal_hold(&self, path: &str, args: OpSetLegalHold) -> Result<RpSetLegalHold> {
+        check!(self.policy.can_write(path), path);
+        self.inner.set_legal_hold(path, args).await
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        check!(self.policy.can_write(path), path);
+        self.inner.restore(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        check!(self.policy.can_write(path), path);
+        self.inner.presign_post(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        check!(self.policy.can_write(path), path);
+        self.inner.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        check!(self.policy.can_read(path), path);
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        check!(self.policy.can_write(path), path);
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        check!(self.policy.can_read(from), from);
+        check!(self.policy.can_write(to), to);
+        self.inner.blocking_copy(from, to, args)
+    }
+
+    fn blocking_rename(&self, from: &str, to: &str, args: OpRename) -> Result<RpRename> {
+        check!(self.policy.can_write(from), from);
+        check!(self.policy.can_write(to), to);
+        self.inner.blocking_rename(from, to, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        check!(self.policy.can_stat(path), path);
+        self.inner.blocking_stat(path, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        check!(self.policy.can_write(path), path);
+        self.inner.blocking_delete(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        // Parent directories of allowed prefixes are listed with filtering.
+        let policy = if has_dot_segment(path) || is_internal_path(path) {
+            return Err(permission_denied(path));
+        } else if self.policy.can_read(path) {
+            None
+        } else if self.policy.browsable(path) {
+            Some(self.policy.clone())
+        } else {
+            return Err(permission_denied(path));
+        };
+        let (rp, lister) = self.inner.blocking_list(path, args)?;
+        Ok((
+            rp,
+            PolicyLister {
+                inner: lister,
+                policy,
+            },
+        ))
+    }
+}
+
+/// PolicyLister hides internal entries and entries that are not allowed while
+/// listing parent directories.
+pub struct PolicyLister<L> {
+    inner: L,
+    policy: Option<Policy>,
+}
+
+impl<L> PolicyLister<L> {
+    fn visible(&self, entry: &oio::Entry) -> bool {
+        if is_internal_path(entry.path()) {
+            return false;
+        }
+        match &self.policy {
+            None => true,
+            Some(policy) => policy.allows(entry.path(), false) || policy.browsable(entry.path()),
+        }
+    }
+}
+
+impl<L: oio::List> oio::List for PolicyLister<L> {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            match self.inner.next().await? {
+                Some(entry) if !self.visible(&entry) => continue,
+                entry => return Ok(entry),
+            }
+        }
+    }
+}
+
+impl<L: oio::BlockingList> oio::BlockingList for PolicyLister<L> {
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            match self.inner.next()? {
+                Some(entry) if !self.visible(&entry) => continue,
+                entry => return Ok(entry),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal::services::Memory;
+
+    use super::*;
+
+    fn policies() -> Policies {
+        Policies::new(&[
+            PolicyConfig {
+                user: "alice".to_string(),
+                prefixes: vec!["home/alice/".to_string()],
+                access: AccessMode::ReadWrite,
+            },
+            PolicyConfig {
+                user: "alice".to_string(),
+                prefixes: vec!["/public/".to_string()],
+                access: AccessMode::ReadOnly,
+            },
+            PolicyConfig {
+                user: "carol".to_string(),
+                prefixes: vec!["shared".to_string()],
+                access: AccessMode::ReadWrite,
+            },
+            PolicyConfig {
+                user: ANONYMOUS_USER.to_string(),
+                prefixes: vec![],
+                access: AccessMode::ReadOnly,
+            },
+        ])
+    }
+
+    #[test]
+    fn test_policy() {
+        let policies = policies();
+
+        let alice = policies.policy("alice");
+        assert!(alice.can_write("home/alice/a"));
+        assert!(alice.can_read("public/a"));
+        assert!(!alice.can_write("public/a"));
+        assert!(!alice.can_read("home/bob/a"));
+        assert!(alice.can_stat("home/"));
+        assert!(alice.can_stat("home/alice"));
+        assert!(!alice.can_stat("home/ali"));
+
+        let anonymous = policies.policy(ANONYMOUS_USER);
+        assert!(anonymous.can_read("home/bob/a"));
+        assert!(!anonymous.can_write("home/bob/a"));
+
+        // Prefixes are matched on segment boundaries.
+        let carol = policies.policy("carol");
+        assert!(carol.can_write("shared/a"));
+        assert!(carol.can_read("shared"));
+        assert!(carol.can_read("shared/"));
+        assert!(!carol.can_read("shared2/a"));
+        assert!(!carol.can_stat("shared2"));
+        assert!(!alice.can_read("home/alice2/a"));
+
+        assert!(!policies.policy("bob").can_read("public/a"));
+        assert!(Policies::default().policy("bob").can_write("a"));
+    }
+
+    #[tokio::test]
+    async fn test_policy_operator() -> anyhow::Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("home/alice/a", "a").await?;
+        op.write("home/bob/b", "b").await?;
+        op.write("public/c", "c").await?;
+
+        let alice = policies().policy("alice").operator(&op);
+        let paths: Vec<_> = alice
+            .list_with("/")
+            .recursive(true)
+            .await?
+            .into_iter()
+            .map(|e| e.path().to_string())
+            .collect();
+        assert!(paths.contains(&"home/alice/a".to_string()));
+        assert!(paths.contains(&"public/c".to_string()));
+        assert!(!paths.iter().any(|p| p.starts_with("home/bob")));
+
+        assert_eq!(alice.read("public/c").await?.to_vec(), b"c");
+        let err = alice.write("public/c", "x").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let err = alice.read("home/bob/b").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let err = alice.copy("home/bob/b", "home/alice/b").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_policy_operator_dot_segments() -> anyhow::Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("home/bob/b", "b").await?;
+
+        let alice = policies().policy("alice").operator(&op);
+        let err = alice.read("home/alice/../bob/b").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let err = alice.write("home/alice/./a", "a").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let err = alice.list("home/alice/../").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+
+        // Users without restrictions can't use them either.
+        let bob = Policies::default().policy("bob").operator(&op);
+        assert_eq!(bob.read("home/bob/b").await?.to_vec(), b"b");
+        let err = bob.read("home/../home/bob/b").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_policy_operator_internal_dir() -> anyhow::Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write(".oay/multipart/upload.json", "{}").await?;
+        op.write("a", "a").await?;
+
+        let bob = Policies::default().policy("bob").operator(&op);
+        let err = bob.read(".oay/multipart/upload.json").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let err = bob.stat("/.oay/").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        let err = bob.list(".oay/").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+
+        let paths: Vec<_> = bob
+            .list_with("/")
+            .recursive(true)
+            .await?
+            .into_iter()
+            .map(|v| v.path().to_string())
+            .collect();
+        assert_eq!(paths, vec!["a"]);
+        Ok(())
+    }
+}
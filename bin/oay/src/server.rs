@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Serve HTTP based frontends with optional TLS.
+
+use std::io::BufReader;
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::Router;
+use hyper_util::rt::TokioExecutor;
+use hyper_util::rt::TokioIo;
+use hyper_util::server::conn::auto;
+use hyper_util::service::TowerToHyperService;
+use tokio::net::TcpListener;
+use tokio_rustls::rustls;
+use tokio_rustls::TlsAcceptor;
+use tracing::debug;
+use tracing::warn;
+
+use crate::TlsConfig;
+
+/// Serve the app on given address, enable HTTPS if `tls` is set.
+pub async fn serve(addr: &str, tls: Option<&TlsConfig>, app: Router) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("failed to bind {addr}"))?;
+    let Some(tls) = tls else {
+        axum::serve(listener, app.into_make_service()).await?;
+        return Ok(());
+    };
+
+    let acceptor = TlsAcceptor::from(Arc::new(load_server_config(tls)?));
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(v) => v,
+            Err(err) => {
+                warn!("failed to accept connection: {err}");
+                continue;
+            }
+        };
+        let acceptor = acceptor.clone();
+        let service = TowerToHyperService::new(app.clone());
+        tokio::spawn(async move {
+            let stream = match acceptor.accept(stream).await {
+                Ok(stream) => stream,
+                Err(err) => {
+                    debug!("tls handshake with {peer} failed: {err}");
+                    return;
+                }
+            };
+            if let Err(err) = auto::Builder::new(TokioExecutor::new())
+                .serve_connection_with_upgrades(TokioIo::new(stream), service)
+                .await
+            {
+                debug!("failed to serve connection from {peer}: {err}");
+            }
+        });
+    }
+}
+
+/// Load the certificate chain and private key from PEM files.
+pub fn load_server_config(tls: &TlsConfig) -> anyhow::Result<rustls::ServerConfig> {
+    let mut cert = BufReader::new(
+        std::fs::File::open(&tls.cert)
+            .with_context(|| format!("failed to open certificate {}", tls.cert))?,
+    );
+    let certs = rustls_pemfile::certs(&mut cert)
+        .collect::<Result<Vec<_>, _>>()
+        .context("failed to parse certificate")?;
+    let mut key = BufReader::new(
+        std::fs::File::open(&tls.key)
+            .with_context(|| format!("failed to open private key {}", tls.key))?,
+    );
+    let key = rustls_pemfile::private_key(&mut key)
+        .context("failed to parse private key")?
+        .with_context(|| format!("no private key found in {}", tls.key))?;
+
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let mut cfg = rustls::ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+    cfg.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(cfg)
+}
@@ -0,0 +1,255 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use libunftp::auth::AuthenticationError;
+use libunftp::auth::Authenticator;
+use libunftp::auth::Credentials;
+use libunftp::auth::UserDetail;
+use libunftp::options::PassiveHost;
+use libunftp::storage;
+use libunftp::storage::StorageBackend;
+use libunftp::ServerBuilder;
+use opendal::Operator;
+use unftp_sbe_opendal::OpendalMetadata;
+use unftp_sbe_opendal::OpendalStorage;
+
+use crate::auth::constant_time_eq;
+use crate::policy::Policies;
+use crate::policy::ANONYMOUS_USER;
+use crate::Config;
+use crate::UserCredential;
+
+pub struct FtpService {
+    cfg: Arc<Config>,
+    op: Operator,
+}
+
+impl FtpService {
+    pub fn new(cfg: Arc<Config>, op: Operator) -> Self {
+        Self { cfg, op }
+    }
+
+    pub async fn serve(&self) -> anyhow::Result<()> {
+        let ftp_cfg = &self.cfg.frontends.ftp;
+
+        let storage =
+            PolicyStorage::new(self.op.clone(), Arc::new(Policies::new(&self.cfg.policies)));
+        let mut builder = ServerBuilder::with_authenticator(
+            Box::new(move || storage.clone()),
+            Arc::new(UserAuthenticator {
+                users: ftp_cfg.users.clone(),
+            }),
+        )
+        .greeting("Welcome to oay FTP server");
+        if let Some((start, end)) = ftp_cfg.passive_ports {
+            builder = builder.passive_ports(start..end.saturating_add(1));
+        }
+        builder = match &ftp_cfg.passive_host {
+            Some(host) => builder.passive_host(host.as_str()),
+            None => builder.passive_host(PassiveHost::FromConnection),
+        };
+        if let Some(tls) = &ftp_cfg.tls {
+            builder = builder.ftps(&tls.cert, &tls.key);
+        }
+
+        builder.build()?.listen(ftp_cfg.addr.clone()).await?;
+
+        Ok(())
+    }
+}
+
+/// FtpUser is the user logged in.
+#[derive(Debug)]
+pub struct FtpUser {
+    name: String,
+}
+
+impl Display for FtpUser {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl UserDetail for FtpUser {}
+
+/// UserAuthenticator authenticates users against the configured credentials.
+///
+/// Anonymous access is allowed if there are no users.
+struct UserAuthenticator {
+    users: Vec<UserCredential>,
+}
+
+impl Debug for UserAuthenticator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserAuthenticator")
+            .field("users", &self.users.len())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Authenticator<FtpUser> for UserAuthenticator {
+    async fn authenticate(
+        &self,
+        username: &str,
+        creds: &Credentials,
+    ) -> Result<FtpUser, AuthenticationError> {
+        if self.users.is_empty() {
+            return Ok(FtpUser {
+                name: ANONYMOUS_USER.to_string(),
+            });
+        }
+
+        let user = self
+            .users
+            .iter()
+            .find(|u| u.username == username)
+            .ok_or(AuthenticationError::BadUser)?;
+        let password_eq = match (&user.password, &creds.password) {
+            (Some(expected), Some(actual)) => {
+                constant_time_eq(expected.as_bytes(), actual.as_bytes())
+            }
+            _ => false,
+        };
+        if !password_eq {
+            return Err(AuthenticationError::BadPassword);
+        }
+        Ok(FtpUser {
+            name: user.username.clone(),
+        })
+    }
+}
+
+/// PolicyStorage restricts the storage by the policy of user after login.
+#[derive(Debug, Clone)]
+struct PolicyStorage {
+    op: Operator,
+    policies: Arc<Policies>,
+    storage: OpendalStorage,
+}
+
+impl PolicyStorage {
+    fn new(op: Operator, policies: Arc<Policies>) -> Self {
+        let storage = OpendalStorage::new(policies.policy(ANONYMOUS_USER).operator(&op));
+        Self {
+            op,
+            policies,
+            storage,
+        }
+    }
+}
+
+#[async_trait]
+impl StorageBackend<FtpUser> for PolicyStorage {
+    type Metadata = OpendalMetadata;
+
+    fn enter(&mut self, user: &FtpUser) -> std::io::Result<()> {
+        let op = self.policies.policy(&user.name).operator(&self.op);
+        self.storage = OpendalStorage::new(op);
+        Ok(())
+    }
+
+    async fn metadata<P: AsRef<Path> + Send + Debug>(
+        &self,
+        user: &FtpUser,
+        path: P,
+    ) -> storage::Result<Self::Metadata> {
+        self.storage.metadata(user, path).await
+    }
+
+    async fn list<P: AsRef<Path> + Send + Debug>(
+        &self,
+        user: &FtpUser,
+        path: P,
+    ) -> storage::Result<Vec<storage::Fileinfo<PathBuf, Self::Metadata>>>
+    where
+        Self::Metadata: storage::Metadata,
+    {
+        self.storage.list(user, path).await
+    }
+
+    async fn get<P: AsRef<Path> + Send + Debug>(
+        &self,
+        user: &FtpUser,
+        path: P,
+        start_pos: u64,
+    ) -> storage::Result<Box<dyn tokio::io::AsyncRead + Send + Sync + Unpin>> {
+        self.storage.get(user, path, start_pos).await
+    }
+
+    async fn put<
+        P: AsRef<Path> + Send + Debug,
+        R: tokio::io::AsyncRead + Send + Sync + Unpin + 'static,
+    >(
+        &self,
+        user: &FtpUser,
+        input: R,
+        path: P,
+        start_pos: u64,
+    ) -> storage::Result<u64> {
+        self.storage.put(user, input, path, start_pos).await
+    }
+
+    async fn del<P: AsRef<Path> + Send + Debug>(
+        &self,
+        user: &FtpUser,
+        path: P,
+    ) -> storage::Result<()> {
+        self.storage.del(user, path).await
+    }
+
+    async fn mkd<P: AsRef<Path> + Send + Debug>(
+        &self,
+        user: &FtpUser,
+        path: P,
+    ) -> storage::Result<()> {
+        self.storage.mkd(user, path).await
+    }
+
+    async fn rename<P: AsRef<Path> + Send + Debug>(
+        &self,
+        user: &FtpUser,
+        from: P,
+        to: P,
+    ) -> storage::Result<()> {
+        self.storage.rename(user, from, to).await
+    }
+
+    async fn rmd<P: AsRef<Path> + Send + Debug>(
+        &self,
+        user: &FtpUser,
+        path: P,
+    ) -> storage::Result<()> {
+        self.storage.rmd(user, path).await
+    }
+
+    async fn cwd<P: AsRef<Path> + Send + Debug>(
+        &self,
+        user: &FtpUser,
+        path: P,
+    ) -> storage::Result<()> {
+        self.storage.cwd(user, path).await
+    }
+}
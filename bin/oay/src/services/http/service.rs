@@ -0,0 +1,401 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::extract::Request;
+use axum::extract::State;
+use axum::http::header;
+use axum::http::request::Parts;
+use axum::http::Method;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::Router;
+use futures_util::StreamExt;
+use opendal::ErrorKind;
+use opendal::Metakey;
+use opendal::Operator;
+use percent_encoding::percent_decode_str;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::CONTROLS;
+
+use crate::auth::authenticate;
+use crate::auth::unauthorized;
+use crate::policy::Policies;
+use crate::server::serve;
+use crate::services::http_util::abort_writer;
+use crate::services::http_util::check_conditions;
+use crate::services::http_util::http_date;
+use crate::services::http_util::object_etag;
+use crate::services::http_util::parse_range;
+use crate::services::http_util::ByteRange;
+use crate::services::http_util::Condition;
+use crate::Config;
+use crate::UserCredential;
+
+/// Characters to be escaped in the href of index entries.
+const HREF_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'\'')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+pub struct HttpService {
+    cfg: Arc<Config>,
+    op: Operator,
+}
+
+impl HttpService {
+    pub fn new(cfg: Arc<Config>, op: Operator) -> Self {
+        Self { cfg, op }
+    }
+
+    pub async fn serve(&self) -> anyhow::Result<()> {
+        let http_cfg = &self.cfg.frontends.http;
+
+        let app = Router::new().fallback(handle).with_state(HttpState {
+            op: self.op.clone(),
+            index: http_cfg.index,
+            upload: http_cfg.upload,
+            users: Arc::new(http_cfg.users.clone()),
+            policies: Arc::new(Policies::new(&self.cfg.policies)),
+        });
+
+        serve(&http_cfg.addr, http_cfg.tls.as_ref(), app).await
+    }
+}
+
+#[derive(Clone)]
+struct HttpState {
+    op: Operator,
+    index: bool,
+    upload: bool,
+    users: Arc<Vec<UserCredential>>,
+    policies: Arc<Policies>,
+}
+
+/// HttpError is a plain text error response.
+struct HttpError {
+    status: StatusCode,
+    message: String,
+}
+
+impl HttpError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+impl IntoResponse for HttpError {
+    fn into_response(self) -> Response {
+        (self.status, format!("{}\n", self.message)).into_response()
+    }
+}
+
+impl From<opendal::Error> for HttpError {
+    fn from(err: opendal::Error) -> Self {
+        let status = match err.kind() {
+            ErrorKind::NotFound | ErrorKind::NotADirectory => StatusCode::NOT_FOUND,
+            ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+            ErrorKind::IsADirectory | ErrorKind::AlreadyExists => StatusCode::CONFLICT,
+            ErrorKind::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            ErrorKind::Unsupported => StatusCode::NOT_IMPLEMENTED,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        if status == StatusCode::INTERNAL_SERVER_ERROR {
+            tracing::error!("failed to handle request: {err}");
+        }
+        Self::new(status, status.canonical_reason().unwrap_or_default())
+    }
+}
+
+async fn handle(State(state): State<HttpState>, req: Request) -> Response {
+    let Some(user) = authenticate(&state.users, req.headers()) else {
+        return unauthorized();
+    };
+    // Operations of this request are restricted by the policy of user.
+    let state = HttpState {
+        op: state.policies.policy(&user).operator(&state.op),
+        ..state
+    };
+
+    match dispatch(&state, req).await {
+        Ok(resp) => resp,
+        Err(err) => err.into_response(),
+    }
+}
+
+async fn dispatch(state: &HttpState, req: Request) -> Result<Response, HttpError> {
+    let (parts, body) = req.into_parts();
+    let path = percent_decode_str(parts.uri.path())
+        .decode_utf8()
+        .map_err(|_| HttpError::new(StatusCode::BAD_REQUEST, "path is not valid UTF-8"))?
+        .into_owned();
+    // Don't allow escaping from the root of backend.
+    if path.split('/').any(|v| v == "." || v == "..") {
+        return Err(HttpError::new(
+            StatusCode::BAD_REQUEST,
+            "path must not contain `.` or `..`",
+        ));
+    }
+
+    match parts.method {
+        Method::GET => handle_get(state, &parts, &path, false).await,
+        Method::HEAD => handle_get(state, &parts, &path, true).await,
+        Method::PUT if state.upload => handle_put(state, &parts, body, &path).await,
+        _ => {
+            let allow = if state.upload {
+                "GET, HEAD, PUT"
+            } else {
+                "GET, HEAD"
+            };
+            Ok((StatusCode::METHOD_NOT_ALLOWED, [(header::ALLOW, allow)]).into_response())
+        }
+    }
+}
+
+async fn handle_get(
+    state: &HttpState,
+    parts: &Parts,
+    path: &str,
+    head: bool,
+) -> Result<Response, HttpError> {
+    let meta = state.op.stat(path).await?;
+    if meta.is_dir() {
+        if !path.ends_with('/') {
+            let location = format!("{}/", parts.uri.path());
+            return Ok((
+                StatusCode::MOVED_PERMANENTLY,
+                [(header::LOCATION, location)],
+            )
+                .into_response());
+        }
+        if !state.index {
+            return Err(HttpError::new(StatusCode::FORBIDDEN, "Forbidden"));
+        }
+        let html = render_index(&state.op, path).await?;
+        let mut resp = ([(header::CONTENT_TYPE, "text/html; charset=utf-8")], html).into_response();
+        if head {
+            *resp.body_mut() = Body::empty();
+        }
+        return Ok(resp);
+    }
+
+    let size = meta.content_length();
+    let mut builder = Response::builder()
+        .header(header::ETAG, object_etag(&meta))
+        .header(header::ACCEPT_RANGES, "bytes");
+    if let Some(v) = meta.last_modified() {
+        builder = builder.header(header::LAST_MODIFIED, http_date(v));
+    }
+    match check_conditions(&parts.headers, "", &meta) {
+        Condition::Matched => {}
+        Condition::NotModified => {
+            return Ok(builder
+                .status(StatusCode::NOT_MODIFIED)
+                .body(Body::empty())
+                .expect("response must be valid"));
+        }
+        Condition::PreconditionFailed => {
+            return Err(HttpError::new(
+                StatusCode::PRECONDITION_FAILED,
+                "precondition failed",
+            ));
+        }
+    }
+
+    let content_type = match meta.content_type() {
+        Some(v) => v.to_string(),
+        None => mime_guess::from_path(path)
+            .first_or_octet_stream()
+            .to_string(),
+    };
+    builder = builder.header(header::CONTENT_TYPE, content_type);
+
+    let range = match parts
+        .headers
+        .get(header::RANGE)
+        .and_then(|v| v.to_str().ok())
+    {
+        // Malformed and multiple ranges are ignored.
+        Some(v) => match parse_range(v, size) {
+            ByteRange::Satisfiable(start, end) => Some((start, end)),
+            ByteRange::Invalid => None,
+            ByteRange::Unsatisfiable => {
+                let mut resp =
+                    HttpError::new(StatusCode::RANGE_NOT_SATISFIABLE, "range not satisfiable")
+                        .into_response();
+                resp.headers_mut().insert(
+                    header::CONTENT_RANGE,
+                    format!("bytes */{size}")
+                        .parse()
+                        .expect("header must be valid"),
+                );
+                return Ok(resp);
+            }
+        },
+        None => None,
+    };
+    let (start, end) = match range {
+        Some((start, end)) => {
+            builder = builder.status(StatusCode::PARTIAL_CONTENT).header(
+                header::CONTENT_RANGE,
+                format!("bytes {start}-{}/{size}", end - 1),
+            );
+            (start, end)
+        }
+        None => (0, size),
+    };
+    builder = builder.header(header::CONTENT_LENGTH, end - start);
+
+    let body = if head || start == end {
+        Body::empty()
+    } else {
+        let stream = state
+            .op
+            .reader(path)
+            .await?
+            .into_bytes_stream(start..end)
+            .await?;
+        Body::from_stream(stream)
+    };
+    Ok(builder.body(body).expect("response must be valid"))
+}
+
+fn escape_html(v: &str) -> String {
+    let mut s = String::with_capacity(v.len());
+    for c in v.chars() {
+        match c {
+            '&' => s.push_str("&amp;"),
+            '<' => s.push_str("&lt;"),
+            '>' => s.push_str("&gt;"),
+            '"' => s.push_str("&quot;"),
+            '\'' => s.push_str("&#39;"),
+            c => s.push(c),
+        }
+    }
+    s
+}
+
+/// Render the HTML index of given directory.
+async fn render_index(op: &Operator, path: &str) -> Result<String, HttpError> {
+    let mut entries = op
+        .list_with(path)
+        .metakey(Metakey::Mode | Metakey::ContentLength | Metakey::LastModified)
+        .await?;
+    // Some services will return the directory itself.
+    let dir = path.trim_start_matches('/');
+    entries.retain(|e| e.path() != dir && e.path() != "/");
+    entries.sort_by(|a, b| {
+        (!a.metadata().is_dir(), a.name()).cmp(&(!b.metadata().is_dir(), b.name()))
+    });
+
+    let title = escape_html(path);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Index of {title}</title>\n</head>\n<body>\n<h1>Index of {title}</h1>\n<table>\n"
+    );
+    if path != "/" {
+        html.push_str("<tr><td><a href=\"../\">../</a></td><td></td><td></td></tr>\n");
+    }
+    for entry in entries {
+        let meta = entry.metadata();
+        let (size, modified) = if meta.is_dir() {
+            (String::new(), String::new())
+        } else {
+            (
+                meta.content_length().to_string(),
+                meta.last_modified().map(http_date).unwrap_or_default(),
+            )
+        };
+        let _ = writeln!(
+            html,
+            "<tr><td><a href=\"{}\">{}</a></td><td>{size}</td><td>{modified}</td></tr>",
+            utf8_percent_encode(entry.name(), HREF_ENCODE_SET),
+            escape_html(entry.name()),
+        );
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+    Ok(html)
+}
+
+async fn handle_put(
+    state: &HttpState,
+    parts: &Parts,
+    body: Body,
+    path: &str,
+) -> Result<Response, HttpError> {
+    if path.ends_with('/') {
+        state.op.create_dir(path).await?;
+        return Ok(StatusCode::CREATED.into_response());
+    }
+
+    let existed = match state.op.stat(path).await {
+        Ok(meta) if meta.is_dir() => {
+            return Err(HttpError::new(StatusCode::CONFLICT, "path is a directory"))
+        }
+        Ok(_) => true,
+        Err(err) if err.kind() == ErrorKind::NotFound => false,
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut w = state.op.writer_with(path);
+    if let Some(v) = parts
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+    {
+        if state.op.info().full_capability().write_with_content_type {
+            w = w.content_type(v);
+        }
+    }
+    let mut w = w.await?;
+
+    let mut stream = body.into_data_stream();
+    let res = async {
+        while let Some(bs) = stream.next().await {
+            let bs = bs.map_err(|err| HttpError::new(StatusCode::BAD_REQUEST, err.to_string()))?;
+            w.write(bs).await?;
+        }
+        w.close().await?;
+        Ok::<_, HttpError>(())
+    }
+    .await;
+    if let Err(err) = res {
+        abort_writer(w).await;
+        return Err(err);
+    }
+
+    if existed {
+        Ok(StatusCode::NO_CONTENT.into_response())
+    } else {
+        Ok(StatusCode::CREATED.into_response())
+    }
+}
@@ -0,0 +1,207 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers about etag, date, conditional and range headers and writers shared
+//! by the http and s3 frontends.
+
+use axum::http::HeaderMap;
+use chrono::DateTime;
+use chrono::Utc;
+use opendal::Metadata;
+use opendal::Writer;
+use tracing::debug;
+
+/// Build the ETag of an object.
+///
+/// Services like fs don't have etag, so we build a weak one from last
+/// modified time and content length which contains `-` to make sure
+/// clients won't treat it as MD5.
+pub fn object_etag(meta: &Metadata) -> String {
+    match meta.etag() {
+        Some(etag) if etag.starts_with('"') || etag.starts_with("W/") => etag.to_string(),
+        Some(etag) => format!("\"{etag}\""),
+        None => format!(
+            "\"{:x}-{:x}\"",
+            meta.last_modified()
+                .map(|v| v.timestamp_micros())
+                .unwrap_or_default(),
+            meta.content_length()
+        ),
+    }
+}
+
+fn trim_etag(v: &str) -> &str {
+    v.trim().trim_start_matches("W/").trim_matches('"')
+}
+
+fn etag_matches(header: &str, etag: &str) -> bool {
+    header
+        .split(',')
+        .any(|v| v.trim() == "*" || trim_etag(v) == trim_etag(etag))
+}
+
+pub fn http_date(v: DateTime<Utc>) -> String {
+    v.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+fn parse_http_date(v: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(v)
+        .ok()
+        .map(|v| v.with_timezone(&Utc))
+}
+
+/// The result of evaluating conditional headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    /// All conditions hold, the request should be served.
+    Matched,
+    /// `If-None-Match` or `If-Modified-Since` doesn't hold.
+    NotModified,
+    /// `If-Match` or `If-Unmodified-Since` doesn't hold.
+    PreconditionFailed,
+}
+
+/// Evaluate conditional headers against given metadata.
+///
+/// `prefix` is used to support `x-amz-copy-source-if-*` headers.
+pub fn check_conditions(headers: &HeaderMap, prefix: &str, meta: &Metadata) -> Condition {
+    let get = |name: &str| {
+        headers
+            .get(format!("{prefix}{name}"))
+            .and_then(|v| v.to_str().ok())
+    };
+    let etag = object_etag(meta);
+    let last_modified = meta.last_modified().map(|v| v.timestamp());
+
+    if let Some(v) = get("if-match") {
+        if !etag_matches(v, &etag) {
+            return Condition::PreconditionFailed;
+        }
+    } else if let Some(v) = get("if-unmodified-since").and_then(parse_http_date) {
+        if matches!(last_modified, Some(t) if t > v.timestamp()) {
+            return Condition::PreconditionFailed;
+        }
+    }
+
+    if let Some(v) = get("if-none-match") {
+        if etag_matches(v, &etag) {
+            return Condition::NotModified;
+        }
+    } else if let Some(v) = get("if-modified-since").and_then(parse_http_date) {
+        if matches!(last_modified, Some(t) if t <= v.timestamp()) {
+            return Condition::NotModified;
+        }
+    }
+
+    Condition::Matched
+}
+
+/// The result of parsing a range header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// The range `[start, end)` to be served.
+    Satisfiable(u64, u64),
+    /// The range is valid but doesn't overlap with the content.
+    Unsatisfiable,
+    /// The range is malformed or contains multiple ranges.
+    Invalid,
+}
+
+/// Parse a single range like `bytes=0-9`, `bytes=10-` or `bytes=-10`.
+pub fn parse_range(v: &str, size: u64) -> ByteRange {
+    let Some((start, end)) = v
+        .trim()
+        .strip_prefix("bytes=")
+        .filter(|v| !v.contains(','))
+        .and_then(|v| v.split_once('-'))
+    else {
+        return ByteRange::Invalid;
+    };
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", "") => return ByteRange::Invalid,
+        ("", end) => match end.parse::<u64>() {
+            Ok(n) => (size.saturating_sub(n), size),
+            Err(_) => return ByteRange::Invalid,
+        },
+        (start, "") => match start.parse::<u64>() {
+            Ok(n) => (n, size),
+            Err(_) => return ByteRange::Invalid,
+        },
+        (start, end) => match (start.parse::<u64>(), end.parse::<u64>()) {
+            (Ok(s), Ok(e)) if s <= e => (s, e.saturating_add(1).min(size)),
+            _ => return ByteRange::Invalid,
+        },
+    };
+    if start >= end {
+        return ByteRange::Unsatisfiable;
+    }
+    ByteRange::Satisfiable(start, end)
+}
+
+/// Abort the writer of a failed upload.
+///
+/// Abort is a best effort operation. The writer is never closed, which could
+/// commit partial data, and the target is never deleted, which could remove
+/// an object written by others.
+pub async fn abort_writer(mut w: Writer) {
+    if let Err(err) = w.abort().await {
+        debug!("failed to abort writer: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::header;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        use ByteRange::*;
+
+        assert_eq!(parse_range("bytes=0-9", 100), Satisfiable(0, 10));
+        assert_eq!(parse_range("bytes=90-", 100), Satisfiable(90, 100));
+        assert_eq!(parse_range("bytes=-10", 100), Satisfiable(90, 100));
+        assert_eq!(parse_range("bytes=50-1000", 100), Satisfiable(50, 100));
+        assert_eq!(parse_range("items=0-9", 100), Invalid);
+        assert_eq!(parse_range("bytes=0-1,5-9", 100), Invalid);
+        assert_eq!(parse_range("bytes=9-0", 100), Invalid);
+        assert_eq!(parse_range("bytes=100-", 100), Unsatisfiable);
+        assert_eq!(parse_range("bytes=-0", 100), Unsatisfiable);
+    }
+
+    #[test]
+    fn test_check_conditions() {
+        let meta = Metadata::new(opendal::EntryMode::FILE).with_etag("\"abc\"".to_string());
+        let check = |name: header::HeaderName, value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(name, value.parse().unwrap());
+            check_conditions(&headers, "", &meta)
+        };
+
+        assert_eq!(check(header::IF_MATCH, "\"abc\""), Condition::Matched);
+        assert_eq!(
+            check(header::IF_MATCH, "\"def\""),
+            Condition::PreconditionFailed
+        );
+        assert_eq!(
+            check(header::IF_NONE_MATCH, "W/\"abc\""),
+            Condition::NotModified
+        );
+        assert_eq!(check(header::IF_NONE_MATCH, "\"def\""), Condition::Matched);
+    }
+}
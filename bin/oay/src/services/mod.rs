@@ -15,6 +15,19 @@
 // specific language governing permissions and limitations
 // under the License.
 
+#[cfg(any(feature = "frontends-http", feature = "frontends-s3"))]
+mod http_util;
+
+#[cfg(feature = "frontends-ftp")]
+mod ftp;
+#[cfg(feature = "frontends-ftp")]
+pub use ftp::FtpService;
+
+#[cfg(feature = "frontends-http")]
+mod http;
+#[cfg(feature = "frontends-http")]
+pub use http::HttpService;
+
 #[cfg(feature = "frontends-s3")]
 mod s3;
 #[cfg(feature = "frontends-s3")]
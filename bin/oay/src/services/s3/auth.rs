@@ -0,0 +1,721 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! AWS Signature Version 4 verification.
+//!
+//! Reference: <https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html>
+
+use std::collections::HashMap;
+
+use axum::body::Body;
+use axum::body::BodyDataStream;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use bytes::Buf;
+use bytes::Bytes;
+use bytes::BytesMut;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::NaiveDateTime;
+use chrono::Utc;
+use futures_util::StreamExt;
+use hmac::Hmac;
+use hmac::Mac;
+use percent_encoding::percent_decode_str;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::NON_ALPHANUMERIC;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::error::ErrorResponse;
+use crate::auth::constant_time_eq;
+use crate::policy::ANONYMOUS_USER;
+use crate::S3Credential;
+
+const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+const STREAMING_PAYLOAD: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD";
+const STREAMING_PAYLOAD_TRAILER: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD-TRAILER";
+const STREAMING_UNSIGNED_PAYLOAD_TRAILER: &str = "STREAMING-UNSIGNED-PAYLOAD-TRAILER";
+const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+/// Max allowed clock skew between client and server.
+const MAX_SKEW_SECS: i64 = 15 * 60;
+/// Max allowed expires of presigned requests.
+const MAX_EXPIRES_SECS: i64 = 7 * 24 * 60 * 60;
+/// Max length of chunk header line in aws-chunked payload.
+const MAX_CHUNK_HEADER_SIZE: usize = 4096;
+
+/// AsciiSet for [AWS UriEncode](https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-header-based-auth.html)
+///
+/// - URI encode every byte except the unreserved characters: 'A'-'Z', 'a'-'z', '0'-'9', '-', '.', '_', and '~'.
+const AWS_URI_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// AsciiSet for AWS UriEncode of path, which keeps `/` as is.
+const AWS_PATH_ENCODE_SET: &AsciiSet = &AWS_URI_ENCODE_SET.remove(b'/');
+
+/// Percent encode the input with AWS UriEncode rules.
+pub fn uri_encode(s: &str, encode_slash: bool) -> String {
+    if encode_slash {
+        utf8_percent_encode(s, AWS_URI_ENCODE_SET).to_string()
+    } else {
+        utf8_percent_encode(s, AWS_PATH_ENCODE_SET).to_string()
+    }
+}
+
+/// Percent decode the input, `+` will be kept as is.
+pub fn uri_decode(s: &str) -> String {
+    percent_decode_str(s).decode_utf8_lossy().to_string()
+}
+
+/// Parse the raw query into decoded key value pairs.
+pub fn parse_query(query: Option<&str>) -> Vec<(String, String)> {
+    let Some(query) = query else {
+        return vec![];
+    };
+
+    query
+        .split('&')
+        .filter(|v| !v.is_empty())
+        .map(|v| match v.split_once('=') {
+            Some((k, v)) => (uri_decode(k), uri_decode(v)),
+            None => (uri_decode(v), "".to_string()),
+        })
+        .collect()
+}
+
+/// Authenticator verifies the signature of incoming requests.
+pub struct Authenticator {
+    /// Mapping from access key id to secret access key.
+    credentials: HashMap<String, String>,
+}
+
+impl Authenticator {
+    pub fn new(credentials: &[S3Credential]) -> Self {
+        Self {
+            credentials: credentials
+                .iter()
+                .map(|v| (v.access_key_id.clone(), v.secret_access_key.clone()))
+                .collect(),
+        }
+    }
+
+    /// Verify the request, returns the user (the access key id) and how to read its payload.
+    pub fn verify(&self, parts: &Parts) -> Result<(String, Payload), ErrorResponse> {
+        let content_sha256 = parts
+            .headers
+            .get("x-amz-content-sha256")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+
+        // Anonymous access is allowed if there are no credentials configured.
+        if self.credentials.is_empty() {
+            let payload = Payload::new(content_sha256.as_deref(), None)?;
+            return Ok((ANONYMOUS_USER.to_string(), payload));
+        }
+
+        let query = parse_query(parts.uri.query());
+        let presigned = query.iter().any(|(k, _)| k == "X-Amz-Signature");
+
+        let auth = if presigned {
+            let get = |name: &str| {
+                query
+                    .iter()
+                    .find(|(k, _)| k == name)
+                    .map(|(_, v)| v.as_str())
+                    .ok_or_else(|| {
+                        ErrorResponse::access_denied(format!("query {name} is required"))
+                    })
+            };
+
+            if get("X-Amz-Algorithm")? != ALGORITHM {
+                return Err(ErrorResponse::access_denied(
+                    "only AWS4-HMAC-SHA256 is supported",
+                ));
+            }
+            let expires: i64 = get("X-Amz-Expires")?
+                .parse()
+                .map_err(|_| ErrorResponse::access_denied("X-Amz-Expires is invalid"))?;
+            if !(0..=MAX_EXPIRES_SECS).contains(&expires) {
+                return Err(ErrorResponse::access_denied("X-Amz-Expires is invalid"));
+            }
+
+            SignedRequest {
+                credential: get("X-Amz-Credential")?.to_string(),
+                signed_headers: get("X-Amz-SignedHeaders")?.to_string(),
+                signature: get("X-Amz-Signature")?.to_string(),
+                amz_date: get("X-Amz-Date")?.to_string(),
+                expires: Some(expires),
+            }
+        } else {
+            let value = parts
+                .headers
+                .get("authorization")
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| {
+                    ErrorResponse::access_denied(
+                        "request is not signed with AWS Signature Version 4",
+                    )
+                })?;
+            let amz_date = parts
+                .headers
+                .get("x-amz-date")
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| ErrorResponse::access_denied("header x-amz-date is required"))?;
+            SignedRequest::parse_authorization(value, amz_date)?
+        };
+
+        let time = parse_amz_date(&auth.amz_date)?;
+        let now = Utc::now();
+        match auth.expires {
+            Some(expires) => {
+                if now > time + Duration::seconds(expires)
+                    || time - now > Duration::seconds(MAX_SKEW_SECS)
+                {
+                    return Err(ErrorResponse::access_denied("request has expired"));
+                }
+            }
+            None => {
+                if (now - time).num_seconds().abs() > MAX_SKEW_SECS {
+                    return Err(ErrorResponse::new(
+                        StatusCode::FORBIDDEN,
+                        "RequestTimeTooSkewed",
+                        "The difference between the request time and the server's time is too large.",
+                    ));
+                }
+            }
+        }
+
+        // Credential is in the format of `<key>/<date>/<region>/<service>/aws4_request`.
+        let (access_key_id, scope) = auth
+            .credential
+            .split_once('/')
+            .ok_or_else(|| ErrorResponse::access_denied("credential is malformed"))?;
+        let scope_parts: Vec<&str> = scope.split('/').collect();
+        if scope_parts.len() != 4 || scope_parts[3] != "aws4_request" {
+            return Err(ErrorResponse::access_denied("credential is malformed"));
+        }
+        let secret_access_key = self.credentials.get(access_key_id).ok_or_else(|| {
+            ErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                "InvalidAccessKeyId",
+                "The access key Id you provided does not exist in our records.",
+            )
+        })?;
+
+        let payload_hash = if presigned {
+            UNSIGNED_PAYLOAD.to_string()
+        } else {
+            content_sha256
+                .clone()
+                .ok_or_else(|| ErrorResponse::invalid_request("missing x-amz-content-sha256"))?
+        };
+
+        let canonical_request =
+            canonical_request(parts, &query, &auth.signed_headers, &payload_hash)?;
+        let string_to_sign = format!(
+            "{ALGORITHM}\n{}\n{scope}\n{}",
+            auth.amz_date,
+            hex_sha256(canonical_request.as_bytes())
+        );
+        let signing_key = signing_key(
+            secret_access_key,
+            scope_parts[0],
+            scope_parts[1],
+            scope_parts[2],
+        );
+        let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+        if !constant_time_eq(signature.as_bytes(), auth.signature.as_bytes()) {
+            return Err(ErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                "SignatureDoesNotMatch",
+                "The request signature we calculated does not match the signature you provided.",
+            ));
+        }
+
+        let signer = ChunkSigner {
+            signing_key,
+            amz_date: auth.amz_date,
+            scope: scope.to_string(),
+            prev_signature: signature,
+        };
+        let payload = Payload::new(content_sha256.as_deref(), Some(signer))?;
+        Ok((access_key_id.to_string(), payload))
+    }
+}
+
+struct SignedRequest {
+    credential: String,
+    signed_headers: String,
+    signature: String,
+    amz_date: String,
+    expires: Option<i64>,
+}
+
+impl SignedRequest {
+    /// Parse authorization header like:
+    ///
+    /// `AWS4-HMAC-SHA256 Credential=<credential>, SignedHeaders=<headers>, Signature=<signature>`
+    fn parse_authorization(value: &str, amz_date: &str) -> Result<Self, ErrorResponse> {
+        let value = value
+            .strip_prefix(ALGORITHM)
+            .ok_or_else(|| ErrorResponse::access_denied("only AWS4-HMAC-SHA256 is supported"))?;
+
+        let (mut credential, mut signed_headers, mut signature) = (None, None, None);
+        for kv in value.split(',') {
+            match kv.trim().split_once('=') {
+                Some(("Credential", v)) => credential = Some(v.to_string()),
+                Some(("SignedHeaders", v)) => signed_headers = Some(v.to_string()),
+                Some(("Signature", v)) => signature = Some(v.to_string()),
+                _ => {}
+            }
+        }
+
+        match (credential, signed_headers, signature) {
+            (Some(credential), Some(signed_headers), Some(signature)) => Ok(Self {
+                credential,
+                signed_headers,
+                signature,
+                amz_date: amz_date.to_string(),
+                expires: None,
+            }),
+            _ => Err(ErrorResponse::access_denied(
+                "authorization header is malformed",
+            )),
+        }
+    }
+}
+
+fn parse_amz_date(v: &str) -> Result<DateTime<Utc>, ErrorResponse> {
+    NaiveDateTime::parse_from_str(v, "%Y%m%dT%H%M%SZ")
+        .map(|v| v.and_utc())
+        .map_err(|_| ErrorResponse::access_denied(format!("date {v} is invalid")))
+}
+
+fn canonical_request(
+    parts: &Parts,
+    query: &[(String, String)],
+    signed_headers: &str,
+    payload_hash: &str,
+) -> Result<String, ErrorResponse> {
+    let path = uri_encode(&uri_decode(parts.uri.path()), false);
+
+    let mut query: Vec<(String, String)> = query
+        .iter()
+        .filter(|(k, _)| k != "X-Amz-Signature")
+        .map(|(k, v)| (uri_encode(k, true), uri_encode(v, true)))
+        .collect();
+    query.sort();
+    let query = query
+        .into_iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let mut headers = String::new();
+    for name in signed_headers.split(';') {
+        let values: Vec<String> = parts
+            .headers
+            .get_all(name)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .map(|v| v.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect();
+        let value = if values.is_empty() && name == "host" {
+            // HTTP/2 requests carry host in the authority instead.
+            parts
+                .uri
+                .authority()
+                .map(|v| v.to_string())
+                .ok_or_else(|| ErrorResponse::access_denied("header host is required"))?
+        } else if values.is_empty() {
+            return Err(ErrorResponse::access_denied(format!(
+                "signed header {name} is missing"
+            )));
+        } else {
+            values.join(",")
+        };
+        headers.push_str(&format!("{name}:{value}\n"));
+    }
+
+    Ok(format!(
+        "{}\n{path}\n{query}\n{headers}\n{signed_headers}\n{payload_hash}",
+        parts.method
+    ))
+}
+
+fn signing_key(secret: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let key = hmac_sha256(format!("AWS4{secret}").as_bytes(), date.as_bytes());
+    let key = hmac_sha256(&key, region.as_bytes());
+    let key = hmac_sha256(&key, service.as_bytes());
+    hmac_sha256(&key, b"aws4_request")
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut h = Hmac::<Sha256>::new_from_slice(key).expect("hmac accepts key of any size");
+    h.update(data);
+    h.finalize().into_bytes().to_vec()
+}
+
+fn hex_sha256(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// ChunkSigner verifies the signature of every chunk in aws-chunked payload.
+///
+/// Reference: <https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html>
+pub struct ChunkSigner {
+    signing_key: Vec<u8>,
+    amz_date: String,
+    scope: String,
+    prev_signature: String,
+}
+
+impl ChunkSigner {
+    fn verify(&mut self, chunk_sha256: &str, signature: &str) -> bool {
+        let string_to_sign = format!(
+            "{ALGORITHM}-PAYLOAD\n{}\n{}\n{}\n{EMPTY_SHA256}\n{chunk_sha256}",
+            self.amz_date, self.scope, self.prev_signature
+        );
+        let expected = hex::encode(hmac_sha256(&self.signing_key, string_to_sign.as_bytes()));
+        if !constant_time_eq(expected.as_bytes(), signature.as_bytes()) {
+            return false;
+        }
+        self.prev_signature = expected;
+        true
+    }
+}
+
+/// Payload describes how to read the request body.
+pub enum Payload {
+    /// Plain payload with the expected sha256 if it's signed.
+    Plain(Option<String>),
+    /// Payload in aws-chunked encoding with the chunk signer if chunks are signed.
+    Chunked(Option<ChunkSigner>),
+}
+
+impl Payload {
+    fn new(
+        content_sha256: Option<&str>,
+        signer: Option<ChunkSigner>,
+    ) -> Result<Self, ErrorResponse> {
+        match content_sha256 {
+            None | Some(UNSIGNED_PAYLOAD) => Ok(Payload::Plain(None)),
+            Some(STREAMING_PAYLOAD) | Some(STREAMING_PAYLOAD_TRAILER) => {
+                Ok(Payload::Chunked(signer))
+            }
+            Some(STREAMING_UNSIGNED_PAYLOAD_TRAILER) => Ok(Payload::Chunked(None)),
+            Some(v) if v.len() == 64 && v.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                Ok(Payload::Plain(Some(v.to_ascii_lowercase())))
+            }
+            Some(v) => Err(ErrorResponse::invalid_argument(format!(
+                "x-amz-content-sha256 {v} is invalid"
+            ))),
+        }
+    }
+}
+
+enum ChunkState {
+    /// Waiting for chunk header like `<size-in-hex>;chunk-signature=<signature>\r\n`
+    Header,
+    /// Reading chunk data.
+    Data {
+        remaining: usize,
+        signature: Option<String>,
+        hasher: Sha256,
+    },
+    /// Waiting for the `\r\n` after chunk data.
+    DataEnd {
+        signature: Option<String>,
+        chunk_sha256: String,
+    },
+    /// The last chunk has been read, trailers will be ignored.
+    Done,
+}
+
+/// PayloadReader reads the decoded request body and verifies it on the fly.
+///
+/// Errors will be returned at the end of the payload if verification failed,
+/// so callers must not commit the data before the payload has been fully read.
+pub struct PayloadReader {
+    body: BodyDataStream,
+    buf: BytesMut,
+    eof: bool,
+
+    payload: Payload,
+    hasher: Sha256,
+    state: ChunkState,
+}
+
+impl PayloadReader {
+    pub fn new(body: Body, payload: Payload) -> Self {
+        Self {
+            body: body.into_data_stream(),
+            buf: BytesMut::new(),
+            eof: false,
+
+            payload,
+            hasher: Sha256::new(),
+            state: ChunkState::Header,
+        }
+    }
+
+    /// Read all payload into memory, returns error if it's larger than `limit`.
+    pub async fn read_all(&mut self, limit: usize) -> Result<Bytes, ErrorResponse> {
+        let mut bs = BytesMut::new();
+        while let Some(v) = self.next().await? {
+            if bs.len() + v.len() > limit {
+                return Err(ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "EntityTooLarge",
+                    "Your proposed upload exceeds the maximum allowed object size.",
+                ));
+            }
+            bs.extend_from_slice(&v);
+        }
+        Ok(bs.freeze())
+    }
+
+    /// Read next bytes of the payload, returns `None` if reaches the end.
+    pub async fn next(&mut self) -> Result<Option<Bytes>, ErrorResponse> {
+        match &self.payload {
+            Payload::Plain(_) => self.next_plain().await,
+            Payload::Chunked(_) => self.next_chunked().await,
+        }
+    }
+
+    async fn fill(&mut self) -> Result<bool, ErrorResponse> {
+        if self.eof {
+            return Ok(false);
+        }
+        match self.body.next().await {
+            Some(bs) => {
+                let bs = bs.map_err(|err| {
+                    ErrorResponse::new(StatusCode::BAD_REQUEST, "IncompleteBody", err.to_string())
+                })?;
+                self.buf.extend_from_slice(&bs);
+                Ok(true)
+            }
+            None => {
+                self.eof = true;
+                Ok(false)
+            }
+        }
+    }
+
+    async fn next_plain(&mut self) -> Result<Option<Bytes>, ErrorResponse> {
+        if self.buf.is_empty() && !self.fill().await? {
+            if let Payload::Plain(Some(expected)) = &self.payload {
+                let actual = hex::encode(std::mem::take(&mut self.hasher).finalize());
+                if &actual != expected {
+                    return Err(ErrorResponse::new(
+                        StatusCode::BAD_REQUEST,
+                        "XAmzContentSHA256Mismatch",
+                        "The provided 'x-amz-content-sha256' header does not match what was computed.",
+                    ));
+                }
+            }
+            return Ok(None);
+        }
+
+        let bs = self.buf.split().freeze();
+        self.hasher.update(&bs);
+        Ok(Some(bs))
+    }
+
+    async fn next_chunked(&mut self) -> Result<Option<Bytes>, ErrorResponse> {
+        loop {
+            if matches!(self.state, ChunkState::Data { .. })
+                && self.buf.is_empty()
+                && !self.fill().await?
+            {
+                return Err(incomplete_body("unexpected end of chunked payload"));
+            }
+
+            match &mut self.state {
+                ChunkState::Header => {
+                    let Some(pos) = self.buf.windows(2).position(|v| v == b"\r\n") else {
+                        if self.buf.len() > MAX_CHUNK_HEADER_SIZE {
+                            return Err(incomplete_body("chunk header is too large"));
+                        }
+                        if !self.fill().await? {
+                            return Err(incomplete_body("unexpected end of chunked payload"));
+                        }
+                        continue;
+                    };
+                    let line = self.buf.split_to(pos + 2);
+                    let line = std::str::from_utf8(&line[..pos])
+                        .map_err(|_| incomplete_body("chunk header is invalid"))?;
+                    let (size, signature) = match line.split_once(';') {
+                        Some((size, ext)) => (
+                            size,
+                            ext.trim()
+                                .strip_prefix("chunk-signature=")
+                                .map(|v| v.to_string()),
+                        ),
+                        None => (line, None),
+                    };
+                    let size = usize::from_str_radix(size.trim(), 16)
+                        .map_err(|_| incomplete_body("chunk size is invalid"))?;
+
+                    if size == 0 {
+                        self.verify_chunk(EMPTY_SHA256, signature)?;
+                        self.state = ChunkState::Done;
+                    } else {
+                        self.state = ChunkState::Data {
+                            remaining: size,
+                            signature,
+                            hasher: Sha256::new(),
+                        };
+                    }
+                }
+                ChunkState::Data {
+                    remaining,
+                    signature,
+                    hasher,
+                } => {
+                    let n = (*remaining).min(self.buf.len());
+                    let bs = self.buf.split_to(n).freeze();
+                    hasher.update(&bs);
+                    *remaining -= n;
+                    if *remaining == 0 {
+                        self.state = ChunkState::DataEnd {
+                            signature: signature.take(),
+                            chunk_sha256: hex::encode(std::mem::take(hasher).finalize()),
+                        };
+                    }
+                    return Ok(Some(bs));
+                }
+                ChunkState::DataEnd {
+                    signature,
+                    chunk_sha256,
+                } => {
+                    if self.buf.len() < 2 {
+                        if !self.fill().await? {
+                            return Err(incomplete_body("unexpected end of chunked payload"));
+                        }
+                        continue;
+                    }
+                    if &self.buf[..2] != b"\r\n" {
+                        return Err(incomplete_body("chunk data is not ended with CRLF"));
+                    }
+                    self.buf.advance(2);
+                    let (signature, chunk_sha256) = (signature.take(), chunk_sha256.clone());
+                    self.verify_chunk(&chunk_sha256, signature)?;
+                    self.state = ChunkState::Header;
+                }
+                ChunkState::Done => return Ok(None),
+            }
+        }
+    }
+
+    fn verify_chunk(
+        &mut self,
+        chunk_sha256: &str,
+        signature: Option<String>,
+    ) -> Result<(), ErrorResponse> {
+        let Payload::Chunked(Some(signer)) = &mut self.payload else {
+            return Ok(());
+        };
+        match signature {
+            Some(signature) if signer.verify(chunk_sha256, &signature) => Ok(()),
+            _ => Err(ErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                "SignatureDoesNotMatch",
+                "The chunk signature we calculated does not match the signature you provided.",
+            )),
+        }
+    }
+}
+
+fn incomplete_body(message: &str) -> ErrorResponse {
+    ErrorResponse::new(StatusCode::BAD_REQUEST, "IncompleteBody", message)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::Request;
+
+    use super::*;
+
+    /// Example from <https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-header-based-auth.html>
+    #[test]
+    fn test_canonical_request_and_signature() {
+        let req = Request::get("/test.txt")
+            .header("host", "examplebucket.s3.amazonaws.com")
+            .header("range", "bytes=0-9")
+            .header("x-amz-content-sha256", EMPTY_SHA256)
+            .header("x-amz-date", "20130524T000000Z")
+            .body(())
+            .unwrap();
+        let (parts, _) = req.into_parts();
+
+        let canonical_request = canonical_request(
+            &parts,
+            &[],
+            "host;range;x-amz-content-sha256;x-amz-date",
+            EMPTY_SHA256,
+        )
+        .unwrap();
+        let string_to_sign = format!(
+            "{ALGORITHM}\n20130524T000000Z\n20130524/us-east-1/s3/aws4_request\n{}",
+            hex_sha256(canonical_request.as_bytes())
+        );
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY",
+            "20130524",
+            "us-east-1",
+            "s3",
+        );
+        assert_eq!(
+            hex::encode(hmac_sha256(&key, string_to_sign.as_bytes())),
+            "f0e8bdb87c964420e857bd35b5d6ed310bd44f0170aba48dd91039c6036bdb41"
+        );
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let query = parse_query(Some("uploads&prefix=a%2Fb+c&list-type=2"));
+        assert_eq!(
+            query,
+            vec![
+                ("uploads".to_string(), "".to_string()),
+                ("prefix".to_string(), "a/b+c".to_string()),
+                ("list-type".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_unsigned_chunked_payload() {
+        let body = Body::from("5\r\nhello\r\n6\r\n world\r\n0\r\nx-amz-checksum-crc32:abc\r\n\r\n");
+        let mut r = PayloadReader::new(body, Payload::Chunked(None));
+        let bs = r.read_all(1024).await.unwrap();
+        assert_eq!(bs.as_ref(), b"hello world");
+    }
+
+    #[tokio::test]
+    async fn test_read_plain_payload_with_mismatched_sha256() {
+        let body = Body::from("hello");
+        let mut r = PayloadReader::new(body, Payload::Plain(Some(EMPTY_SHA256.to_string())));
+        assert!(r.read_all(1024).await.is_err());
+    }
+}
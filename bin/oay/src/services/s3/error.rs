@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use axum::http::header;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use opendal::ErrorKind;
+use serde::Serialize;
+
+#[derive(Debug)]
+pub struct ErrorResponse {
+    code: StatusCode,
+    err: Error,
+}
+
+impl ErrorResponse {
+    pub fn new(code: StatusCode, err_code: &str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            err: Error {
+                code: err_code.to_string(),
+                message: message.into(),
+                resource: "".to_string(),
+                request_id: "".to_string(),
+            },
+        }
+    }
+
+    pub fn with_resource(mut self, resource: &str) -> Self {
+        self.err.resource = resource.to_string();
+        self
+    }
+
+    /// Consume into the error code and message, used by per key errors of `DeleteObjects`.
+    pub fn into_code_message(self) -> (String, String) {
+        (self.err.code, self.err.message)
+    }
+
+    pub fn access_denied(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::FORBIDDEN, "AccessDenied", message)
+    }
+
+    pub fn invalid_argument(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "InvalidArgument", message)
+    }
+
+    pub fn invalid_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "InvalidRequest", message)
+    }
+
+    pub fn malformed_xml(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "MalformedXML", message)
+    }
+
+    pub fn not_implemented(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_IMPLEMENTED, "NotImplemented", message)
+    }
+
+    pub fn method_not_allowed() -> Self {
+        Self::new(
+            StatusCode::METHOD_NOT_ALLOWED,
+            "MethodNotAllowed",
+            "The specified method is not allowed against this resource.",
+        )
+    }
+
+    pub fn no_such_bucket(bucket: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "NoSuchBucket",
+            "The specified bucket does not exist.",
+        )
+        .with_resource(bucket)
+    }
+
+    pub fn no_such_key(key: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "NoSuchKey",
+            "The specified key does not exist.",
+        )
+        .with_resource(key)
+    }
+
+    pub fn no_such_upload(upload_id: &str) -> Self {
+        Self::new(
+            StatusCode::NOT_FOUND,
+            "NoSuchUpload",
+            "The specified multipart upload does not exist.",
+        )
+        .with_resource(upload_id)
+    }
+
+    pub fn precondition_failed() -> Self {
+        Self::new(
+            StatusCode::PRECONDITION_FAILED,
+            "PreconditionFailed",
+            "At least one of the pre-conditions you specified did not hold.",
+        )
+    }
+
+    pub fn invalid_range() -> Self {
+        Self::new(
+            StatusCode::RANGE_NOT_SATISFIABLE,
+            "InvalidRange",
+            "The requested range is not satisfiable.",
+        )
+    }
+}
+
+impl IntoResponse for ErrorResponse {
+    fn into_response(self) -> Response {
+        (
+            self.code,
+            [(header::CONTENT_TYPE, "application/xml")],
+            quick_xml::se::to_string(&self.err).unwrap(),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(default, rename_all = "PascalCase")]
+struct Error {
+    code: String,
+    message: String,
+    resource: String,
+    request_id: String,
+}
+
+impl From<opendal::Error> for ErrorResponse {
+    fn from(err: opendal::Error) -> Self {
+        let (code, err_code) = match err.kind() {
+            ErrorKind::NotFound => (StatusCode::NOT_FOUND, "NoSuchKey"),
+            ErrorKind::PermissionDenied => (StatusCode::FORBIDDEN, "AccessDenied"),
+            ErrorKind::ObjectLocked => (StatusCode::FORBIDDEN, "AccessDenied"),
+            ErrorKind::ObjectArchived => (StatusCode::FORBIDDEN, "InvalidObjectState"),
+            ErrorKind::ConditionNotMatch => (StatusCode::PRECONDITION_FAILED, "PreconditionFailed"),
+            ErrorKind::RangeNotSatisfied => (StatusCode::RANGE_NOT_SATISFIABLE, "InvalidRange"),
+            ErrorKind::Unsupported => (StatusCode::NOT_IMPLEMENTED, "NotImplemented"),
+            ErrorKind::RateLimited => (StatusCode::SERVICE_UNAVAILABLE, "SlowDown"),
+            ErrorKind::IsADirectory | ErrorKind::NotADirectory | ErrorKind::AlreadyExists => {
+                (StatusCode::CONFLICT, "InvalidRequest")
+            }
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, "InternalError"),
+        };
+
+        ErrorResponse::new(code, err_code, err.to_string())
+    }
+}
+
+impl From<std::io::Error> for ErrorResponse {
+    fn from(err: std::io::Error) -> Self {
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "InternalError",
+            err.to_string(),
+        )
+    }
+}
+
+impl From<anyhow::Error> for ErrorResponse {
+    fn from(err: anyhow::Error) -> Self {
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "InternalError",
+            err.to_string(),
+        )
+    }
+}
@@ -15,5 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod auth;
+mod error;
+mod multipart;
+mod object;
 mod service;
 pub use service::*;
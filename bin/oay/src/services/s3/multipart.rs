@@ -0,0 +1,700 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Multipart upload support.
+//!
+//! Uploads are staged under `.oay/multipart/{upload_id}/` in the backend, so
+//! they survive restarting:
+//!
+//! - `upload.json` records the key, owner, initiated time and write options.
+//! - Every uploaded part is stored as `{part_number}-{uuid}` along with a
+//!   `{part_number}.json` record of its etag and size. The record is written
+//!   after the data, so the last finished upload of a part number wins.
+//!
+//! Parts are concatenated into the target object by a [`opendal::Writer`] while
+//! completing, which reads and writes the whole object again since native
+//! multipart uploads of services are not exposed by opendal. Uploads that are
+//! neither completed nor aborted are removed after `multipart_expire_secs`.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use axum::http::header;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use chrono::DateTime;
+use chrono::Utc;
+use futures_util::StreamExt;
+use opendal::ErrorKind;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::debug;
+use tracing::warn;
+
+use super::error::ErrorResponse;
+use super::object::*;
+use super::service::xml_response;
+use super::service::S3Request;
+use super::service::S3State;
+use super::service::XMLNS;
+use crate::policy::INTERNAL_DIR;
+use crate::services::http_util::object_etag;
+
+const MAX_PART_NUMBER: u32 = 10000;
+const MAX_COMPLETE_BODY_SIZE: usize = 4 * 1024 * 1024;
+const MULTIPART_DIR: &str = "multipart/";
+
+/// Uploads manages the states of multipart uploads stored in backend.
+pub struct Uploads {
+    /// Operator used to access [`INTERNAL_DIR`], which is not restricted by policies.
+    op: Operator,
+    expire: Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Upload {
+    key: String,
+    /// The user who initiated this upload, only the same user can access it.
+    owner: String,
+    /// Unix timestamp in seconds.
+    initiated: i64,
+    opts: WriteOptions,
+}
+
+impl Upload {
+    fn initiated(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.initiated, 0).unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Part {
+    /// The name of data file in upload dir.
+    file: String,
+    etag: String,
+    size: u64,
+    /// Unix timestamp in seconds.
+    last_modified: i64,
+}
+
+impl Part {
+    fn last_modified(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.last_modified, 0).unwrap_or_default()
+    }
+}
+
+impl Uploads {
+    pub fn new(op: Operator, expire: Duration) -> Self {
+        Self { op, expire }
+    }
+
+    fn root() -> String {
+        format!("{INTERNAL_DIR}{MULTIPART_DIR}")
+    }
+
+    fn upload_dir(upload_id: &str) -> String {
+        format!("{}{upload_id}/", Self::root())
+    }
+
+    /// Upload ids are generated by us, anything else could escape from the upload dir.
+    fn is_valid_id(upload_id: &str) -> bool {
+        upload_id.len() == 32 && upload_id.bytes().all(|v| v.is_ascii_hexdigit())
+    }
+
+    fn is_expired(&self, upload: &Upload) -> bool {
+        let expire = chrono::Duration::from_std(self.expire).unwrap_or(chrono::Duration::MAX);
+        upload.initiated() + expire < Utc::now()
+    }
+
+    async fn create(&self, upload_id: &str, upload: &Upload) -> Result<(), ErrorResponse> {
+        let data = serde_json::to_vec(upload).expect("upload must be serializable");
+        self.op
+            .write(&format!("{}upload.json", Self::upload_dir(upload_id)), data)
+            .await?;
+        Ok(())
+    }
+
+    async fn read(&self, upload_id: &str) -> Result<Option<Upload>, ErrorResponse> {
+        if !Self::is_valid_id(upload_id) {
+            return Ok(None);
+        }
+        let path = format!("{}upload.json", Self::upload_dir(upload_id));
+        let data = match self.op.read(&path).await {
+            Ok(v) => v,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        match serde_json::from_slice(&data.to_vec()) {
+            Ok(upload) => Ok(Some(upload)),
+            Err(err) => {
+                warn!("upload {upload_id} is corrupted: {err}");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Get the upload and make sure it belongs to the key and user.
+    async fn get(&self, upload_id: &str, key: &str, owner: &str) -> Result<Upload, ErrorResponse> {
+        match self.read(upload_id).await? {
+            Some(upload) if upload.key == key && upload.owner == owner => {
+                if self.is_expired(&upload) {
+                    return Err(ErrorResponse::no_such_upload(upload_id));
+                }
+                Ok(upload)
+            }
+            _ => Err(ErrorResponse::no_such_upload(upload_id)),
+        }
+    }
+
+    /// Allocate the path of a new data file of given part.
+    fn new_part_file(part_number: u32) -> String {
+        format!("{part_number:05}-{}", uuid::Uuid::new_v4().simple())
+    }
+
+    /// Record the part and remove the data files replaced by it.
+    async fn put_part(
+        &self,
+        upload_id: &str,
+        part_number: u32,
+        part: &Part,
+    ) -> Result<(), ErrorResponse> {
+        let dir = Self::upload_dir(upload_id);
+        let data = serde_json::to_vec(part).expect("part must be serializable");
+        self.op
+            .write(&format!("{dir}{part_number:05}.json"), data)
+            .await?;
+
+        let prefix = format!("{part_number:05}-");
+        for entry in self.op.list(&dir).await? {
+            if entry.name().starts_with(&prefix) && entry.name() != part.file {
+                self.op.delete(entry.path()).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn parts(&self, upload_id: &str) -> Result<BTreeMap<u32, Part>, ErrorResponse> {
+        let dir = Self::upload_dir(upload_id);
+        let mut parts = BTreeMap::new();
+        for entry in self.op.list(&dir).await? {
+            let Some(n) = entry
+                .name()
+                .strip_suffix(".json")
+                .and_then(|v| v.parse::<u32>().ok())
+            else {
+                continue;
+            };
+            let data = self.op.read(entry.path()).await?;
+            match serde_json::from_slice(&data.to_vec()) {
+                Ok(part) => {
+                    parts.insert(n, part);
+                }
+                Err(err) => warn!("part {n} of upload {upload_id} is corrupted: {err}"),
+            }
+        }
+        Ok(parts)
+    }
+
+    async fn remove(&self, upload_id: &str) -> Result<(), ErrorResponse> {
+        self.op.remove_all(&Self::upload_dir(upload_id)).await?;
+        Ok(())
+    }
+
+    /// List ids of all uploads, including the expired ones.
+    async fn list_ids(&self) -> Result<Vec<String>, ErrorResponse> {
+        let entries = match self.op.list(&Self::root()).await {
+            Ok(v) => v,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(entries
+            .into_iter()
+            .filter(|v| v.metadata().is_dir())
+            .map(|v| v.name().trim_end_matches('/').to_string())
+            .filter(|v| Self::is_valid_id(v))
+            .collect())
+    }
+
+    /// Remove expired uploads and the dirs left without upload state.
+    pub async fn cleanup(&self) -> Result<(), ErrorResponse> {
+        for upload_id in self.list_ids().await? {
+            let expired = match self.read(&upload_id).await? {
+                Some(upload) => self.is_expired(&upload),
+                // Parts uploaded while the upload is completed or aborted.
+                None => true,
+            };
+            if expired {
+                debug!("removing expired upload {upload_id}");
+                self.remove(&upload_id).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct InitiateMultipartUploadResult {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    bucket: String,
+    key: String,
+    upload_id: String,
+}
+
+pub async fn handle_create_multipart_upload(
+    state: &S3State,
+    req: S3Request,
+    key: &str,
+) -> Result<Response, ErrorResponse> {
+    // Parts are staged by the internal operator, so we check the permission
+    // before accepting any data.
+    if !state.policy.can_write(key) {
+        return Err(ErrorResponse::access_denied("Access Denied"));
+    }
+
+    let upload_id = uuid::Uuid::new_v4().simple().to_string();
+    let upload = Upload {
+        key: key.to_string(),
+        owner: state.user.clone(),
+        initiated: Utc::now().timestamp(),
+        opts: WriteOptions::from_headers(&req.parts.headers),
+    };
+    state.uploads.create(&upload_id, &upload).await?;
+
+    Ok(xml_response(&InitiateMultipartUploadResult {
+        xmlns: XMLNS,
+        bucket: state.bucket.clone(),
+        key: key.to_string(),
+        upload_id,
+    }))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    etag: String,
+    last_modified: String,
+}
+
+pub async fn handle_upload_part(
+    state: &S3State,
+    mut req: S3Request,
+    key: &str,
+) -> Result<Response, ErrorResponse> {
+    let upload_id = req.query("uploadId").unwrap_or_default().to_string();
+    let part_number: u32 = req
+        .query("partNumber")
+        .and_then(|v| v.parse().ok())
+        .filter(|v| (1..=MAX_PART_NUMBER).contains(v))
+        .ok_or_else(|| {
+            ErrorResponse::invalid_argument("Part number must be an integer between 1 and 10000.")
+        })?;
+    state.uploads.get(&upload_id, key, &state.user).await?;
+
+    let file = Uploads::new_part_file(part_number);
+    let path = format!("{}{file}", Uploads::upload_dir(&upload_id));
+    let copy_source = req.header("x-amz-copy-source").map(|v| v.to_string());
+    let (size, md5) = match &copy_source {
+        Some(source) => {
+            let src = parse_copy_source(state, source)?;
+            if !state.policy.can_read(&src) {
+                return Err(ErrorResponse::access_denied("Access Denied"));
+            }
+            let meta = stat_object(&state.op, &src).await?;
+            if !check_conditions(&req.parts.headers, "x-amz-copy-source-", &meta)? {
+                return Err(ErrorResponse::precondition_failed());
+            }
+            let range = match req.header("x-amz-copy-source-range") {
+                Some(v) => parse_range(v, meta.content_length())?,
+                None => (0, meta.content_length()),
+            };
+            let w = WriteOptions::default()
+                .writer(&state.internal, &path)
+                .await?;
+            w.write_copy(&src, range).await?
+        }
+        None => {
+            let content_md5 = req.header("content-md5").map(|v| v.to_string());
+            let w = WriteOptions::default()
+                .writer(&state.internal, &path)
+                .await?;
+            w.write_payload(&mut req.body, content_md5.as_deref())
+                .await?
+        }
+    };
+
+    let part = Part {
+        file,
+        etag: format!("\"{}\"", hex::encode(md5)),
+        size,
+        last_modified: Utc::now().timestamp(),
+    };
+    state
+        .uploads
+        .put_part(&upload_id, part_number, &part)
+        .await?;
+    // The upload could be aborted or completed while uploading, the staged
+    // part will be removed by cleanup.
+    state.uploads.get(&upload_id, key, &state.user).await?;
+
+    let (etag, last_modified) = (part.etag.clone(), part.last_modified());
+
+    if copy_source.is_some() {
+        return Ok(xml_response(&CopyPartResult {
+            etag,
+            last_modified: iso8601(last_modified),
+        }));
+    }
+    Ok((StatusCode::OK, [(header::ETAG, etag)]).into_response())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CompleteMultipartUpload {
+    #[serde(rename = "Part", default)]
+    parts: Vec<CompletePart>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CompletePart {
+    part_number: u32,
+    #[serde(rename = "ETag")]
+    etag: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct CompleteMultipartUploadResult {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    location: String,
+    bucket: String,
+    key: String,
+    #[serde(rename = "ETag")]
+    etag: String,
+}
+
+pub async fn handle_complete_multipart_upload(
+    state: &S3State,
+    mut req: S3Request,
+    key: &str,
+) -> Result<Response, ErrorResponse> {
+    let upload_id = req.query("uploadId").unwrap_or_default().to_string();
+    let opts = state.uploads.get(&upload_id, key, &state.user).await?.opts;
+    let uploaded = state.uploads.parts(&upload_id).await?;
+
+    let body = req.body.read_all(MAX_COMPLETE_BODY_SIZE).await?;
+    let complete: CompleteMultipartUpload =
+        quick_xml::de::from_reader(body.as_ref()).map_err(|err| {
+            ErrorResponse::malformed_xml(format!("complete multipart upload is invalid: {err}"))
+        })?;
+    if complete.parts.is_empty() {
+        return Err(ErrorResponse::malformed_xml(
+            "You must specify at least one part.",
+        ));
+    }
+    if complete
+        .parts
+        .windows(2)
+        .any(|v| v[0].part_number >= v[1].part_number)
+    {
+        return Err(ErrorResponse::new(
+            StatusCode::BAD_REQUEST,
+            "InvalidPartOrder",
+            "The list of parts was not in ascending order.",
+        ));
+    }
+    for part in &complete.parts {
+        match uploaded.get(&part.part_number) {
+            Some(v) if v.etag.trim_matches('"') == part.etag.trim().trim_matches('"') => {}
+            _ => {
+                return Err(ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "InvalidPart",
+                    "One or more of the specified parts could not be found.",
+                ))
+            }
+        }
+    }
+
+    let dir = Uploads::upload_dir(&upload_id);
+    let mut w = opts.writer(&state.op, key).await?;
+    let res = async {
+        for part in &complete.parts {
+            let file = &uploaded[&part.part_number].file;
+            let mut stream = state
+                .internal
+                .reader(&format!("{dir}{file}"))
+                .await?
+                .into_bytes_stream(..)
+                .await?;
+            while let Some(bs) = stream.next().await {
+                w.write(bs?).await?;
+            }
+        }
+        Ok::<(), ErrorResponse>(())
+    }
+    .await;
+    if let Err(err) = res {
+        return Err(w.abort(err).await);
+    }
+    w.close().await?;
+
+    state.uploads.remove(&upload_id).await?;
+
+    let meta = state.op.stat(key).await?;
+    Ok(xml_response(&CompleteMultipartUploadResult {
+        xmlns: XMLNS,
+        location: format!("/{}/{key}", state.bucket),
+        bucket: state.bucket.clone(),
+        key: key.to_string(),
+        etag: object_etag(&meta),
+    }))
+}
+
+pub async fn handle_abort_multipart_upload(
+    state: &S3State,
+    req: S3Request,
+    key: &str,
+) -> Result<Response, ErrorResponse> {
+    let upload_id = req.query("uploadId").unwrap_or_default().to_string();
+    state.uploads.get(&upload_id, key, &state.user).await?;
+    state.uploads.remove(&upload_id).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListPartsResult {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    bucket: String,
+    key: String,
+    upload_id: String,
+    part_number_marker: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_part_number_marker: Option<u32>,
+    max_parts: usize,
+    is_truncated: bool,
+    #[serde(rename = "Part")]
+    parts: Vec<ListPart>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListPart {
+    part_number: u32,
+    last_modified: String,
+    #[serde(rename = "ETag")]
+    etag: String,
+    size: u64,
+}
+
+pub async fn handle_list_parts(
+    state: &S3State,
+    req: S3Request,
+    key: &str,
+) -> Result<Response, ErrorResponse> {
+    let upload_id = req.query("uploadId").unwrap_or_default().to_string();
+    let marker: u32 = req
+        .query("part-number-marker")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_default();
+    let max_parts: usize = req
+        .query("max-parts")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(1000)
+        .min(1000);
+
+    state.uploads.get(&upload_id, key, &state.user).await?;
+    let parts: Vec<_> = state
+        .uploads
+        .parts(&upload_id)
+        .await?
+        .split_off(&(marker + 1))
+        .into_iter()
+        .collect();
+    let is_truncated = parts.len() > max_parts;
+    let parts: Vec<ListPart> = parts
+        .into_iter()
+        .take(max_parts)
+        .map(|(n, p)| ListPart {
+            part_number: n,
+            last_modified: iso8601(p.last_modified()),
+            etag: p.etag,
+            size: p.size,
+        })
+        .collect();
+
+    Ok(xml_response(&ListPartsResult {
+        xmlns: XMLNS,
+        bucket: state.bucket.clone(),
+        key: key.to_string(),
+        upload_id,
+        part_number_marker: marker,
+        next_part_number_marker: is_truncated
+            .then(|| parts.last().map(|v| v.part_number))
+            .flatten(),
+        max_parts,
+        is_truncated,
+        parts,
+    }))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListMultipartUploadsResult {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    bucket: String,
+    prefix: String,
+    is_truncated: bool,
+    #[serde(rename = "Upload")]
+    uploads: Vec<ListUpload>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListUpload {
+    key: String,
+    upload_id: String,
+    initiated: String,
+}
+
+pub async fn handle_list_multipart_uploads(
+    state: &S3State,
+    req: S3Request,
+) -> Result<Response, ErrorResponse> {
+    let prefix = req.query("prefix").unwrap_or_default().to_string();
+
+    let mut uploads = vec![];
+    for upload_id in state.uploads.list_ids().await? {
+        let Some(v) = state.uploads.read(&upload_id).await? else {
+            continue;
+        };
+        if v.owner != state.user || !v.key.starts_with(&prefix) || state.uploads.is_expired(&v) {
+            continue;
+        }
+        uploads.push(ListUpload {
+            key: v.key.clone(),
+            initiated: iso8601(v.initiated()),
+            upload_id,
+        });
+    }
+    uploads.sort_by(|a, b| (&a.key, &a.upload_id).cmp(&(&b.key, &b.upload_id)));
+
+    Ok(xml_response(&ListMultipartUploadsResult {
+        xmlns: XMLNS,
+        bucket: state.bucket.clone(),
+        prefix,
+        is_truncated: false,
+        uploads,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use opendal::services::Memory;
+
+    use super::*;
+
+    fn upload(initiated: i64) -> Upload {
+        Upload {
+            key: "a/b".to_string(),
+            owner: "alice".to_string(),
+            initiated,
+            opts: WriteOptions::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_uploads() -> Result<(), ErrorResponse> {
+        let op = Operator::new(Memory::default())?.finish();
+        let uploads = Uploads::new(op.clone(), Duration::from_secs(3600));
+
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        uploads.create(&id, &upload(Utc::now().timestamp())).await?;
+        assert!(uploads.get(&id, "a/b", "alice").await.is_ok());
+        assert!(uploads.get(&id, "a/b", "bob").await.is_err());
+        assert!(uploads.get("../../a", "a/b", "alice").await.is_err());
+
+        // The last recorded part wins and replaced data is removed.
+        let dir = Uploads::upload_dir(&id);
+        for etag in ["\"1\"", "\"2\""] {
+            let file = Uploads::new_part_file(1);
+            op.write(&format!("{dir}{file}"), "data").await?;
+            let part = Part {
+                file,
+                etag: etag.to_string(),
+                size: 4,
+                last_modified: Utc::now().timestamp(),
+            };
+            uploads.put_part(&id, 1, &part).await?;
+        }
+        let parts = uploads.parts(&id).await?;
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[&1].etag, "\"2\"");
+        let files = op.list(&dir).await?;
+        assert_eq!(
+            files
+                .iter()
+                .filter(|v| v.name().starts_with("00001-"))
+                .count(),
+            1
+        );
+
+        // Uploads survive restarting.
+        let uploads = Uploads::new(op.clone(), Duration::from_secs(3600));
+        assert_eq!(uploads.list_ids().await?, vec![id.clone()]);
+        uploads.cleanup().await?;
+        assert_eq!(uploads.list_ids().await?, vec![id.clone()]);
+
+        uploads.remove(&id).await?;
+        assert!(uploads.get(&id, "a/b", "alice").await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_uploads_cleanup() -> Result<(), ErrorResponse> {
+        let op = Operator::new(Memory::default())?.finish();
+        let uploads = Uploads::new(op.clone(), Duration::from_secs(3600));
+
+        let expired = uuid::Uuid::new_v4().simple().to_string();
+        uploads
+            .create(&expired, &upload(Utc::now().timestamp() - 7200))
+            .await?;
+        // Parts uploaded after the upload has been completed.
+        let orphan = uuid::Uuid::new_v4().simple().to_string();
+        op.write(&format!("{}00001-x", Uploads::upload_dir(&orphan)), "data")
+            .await?;
+        let active = uuid::Uuid::new_v4().simple().to_string();
+        uploads
+            .create(&active, &upload(Utc::now().timestamp()))
+            .await?;
+
+        assert!(uploads.get(&expired, "a/b", "alice").await.is_err());
+        uploads.cleanup().await?;
+        assert_eq!(uploads.list_ids().await?, vec![active]);
+        Ok(())
+    }
+}
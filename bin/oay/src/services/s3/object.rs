@@ -0,0 +1,533 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use axum::body::Body;
+use axum::http::header;
+use axum::http::HeaderMap;
+use axum::http::HeaderValue;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use bytes::Bytes;
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use futures_util::StreamExt;
+use md5::Digest;
+use md5::Md5;
+use opendal::ErrorKind;
+use opendal::Metadata;
+use opendal::Operator;
+use opendal::Writer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::auth::uri_decode;
+use super::auth::PayloadReader;
+use super::error::ErrorResponse;
+use super::service::xml_response;
+use super::service::S3Request;
+use super::service::S3State;
+use super::service::XMLNS;
+use crate::policy::is_internal_path;
+use crate::policy::INTERNAL_DIR;
+use crate::services::http_util;
+use crate::services::http_util::abort_writer;
+use crate::services::http_util::http_date;
+use crate::services::http_util::object_etag;
+use crate::services::http_util::ByteRange;
+use crate::services::http_util::Condition;
+
+/// Chunk size used while writing to services that support multipart write.
+const WRITE_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+pub fn iso8601(v: DateTime<Utc>) -> String {
+    v.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Evaluate conditional headers against given metadata.
+///
+/// `prefix` is used to support `x-amz-copy-source-if-*` headers. Returns
+/// `Ok(false)` if the object is not modified.
+pub fn check_conditions(
+    headers: &HeaderMap,
+    prefix: &str,
+    meta: &Metadata,
+) -> Result<bool, ErrorResponse> {
+    match http_util::check_conditions(headers, prefix, meta) {
+        Condition::Matched => Ok(true),
+        Condition::NotModified => Ok(false),
+        Condition::PreconditionFailed => Err(ErrorResponse::precondition_failed()),
+    }
+}
+
+/// Parse range like `bytes=0-9`, `bytes=10-` or `bytes=-10` into `[start, end)`.
+///
+/// Returns `InvalidRange` if the range is malformed or not satisfiable.
+pub fn parse_range(v: &str, size: u64) -> Result<(u64, u64), ErrorResponse> {
+    match http_util::parse_range(v, size) {
+        ByteRange::Satisfiable(start, end) => Ok((start, end)),
+        ByteRange::Unsatisfiable | ByteRange::Invalid => Err(ErrorResponse::invalid_range()),
+    }
+}
+
+/// Stat the object, returns `NoSuchKey` for directories accessed without trailing `/`.
+pub async fn stat_object(op: &Operator, key: &str) -> Result<Metadata, ErrorResponse> {
+    let meta = op.stat(key).await.map_err(|err| match err.kind() {
+        ErrorKind::NotFound | ErrorKind::NotADirectory => ErrorResponse::no_such_key(key),
+        _ => err.into(),
+    })?;
+    if meta.is_dir() && !key.ends_with('/') {
+        return Err(ErrorResponse::no_such_key(key));
+    }
+    Ok(meta)
+}
+
+fn object_headers(meta: &Metadata) -> Vec<(String, String)> {
+    let mut headers = vec![
+        (header::ETAG.to_string(), object_etag(meta)),
+        (header::ACCEPT_RANGES.to_string(), "bytes".to_string()),
+        (
+            header::CONTENT_TYPE.to_string(),
+            meta.content_type()
+                .unwrap_or("application/octet-stream")
+                .to_string(),
+        ),
+    ];
+    if let Some(v) = meta.last_modified() {
+        headers.push((header::LAST_MODIFIED.to_string(), http_date(v)));
+    }
+    if let Some(v) = meta.cache_control() {
+        headers.push((header::CACHE_CONTROL.to_string(), v.to_string()));
+    }
+    if let Some(v) = meta.content_disposition() {
+        headers.push((header::CONTENT_DISPOSITION.to_string(), v.to_string()));
+    }
+    if let Some(v) = meta.content_encoding() {
+        headers.push((header::CONTENT_ENCODING.to_string(), v.to_string()));
+    }
+    if let Some(v) = meta.storage_class() {
+        headers.push(("x-amz-storage-class".to_string(), v.to_string()));
+    }
+    if let Some(v) = meta.user_metadata() {
+        for (k, v) in v {
+            headers.push((format!("x-amz-meta-{k}"), v.to_string()));
+        }
+    }
+    headers
+}
+
+fn build_response(
+    code: StatusCode,
+    headers: Vec<(String, String)>,
+    body: Body,
+) -> Result<Response, ErrorResponse> {
+    let mut resp = Response::builder().status(code);
+    for (k, v) in headers {
+        let v = HeaderValue::from_str(&v).map_err(|err| {
+            ErrorResponse::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                format!("header {k} is invalid: {err}"),
+            )
+        })?;
+        resp = resp.header(k, v);
+    }
+    resp.body(body).map_err(|err| {
+        ErrorResponse::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "InternalError",
+            err.to_string(),
+        )
+    })
+}
+
+pub async fn handle_get_object(
+    state: &S3State,
+    req: S3Request,
+    key: &str,
+    head: bool,
+) -> Result<Response, ErrorResponse> {
+    let meta = stat_object(&state.op, key).await?;
+    let mut headers = object_headers(&meta);
+    if !check_conditions(&req.parts.headers, "", &meta)? {
+        return build_response(StatusCode::NOT_MODIFIED, headers, Body::empty());
+    }
+
+    // Allow presigned requests to override response headers.
+    for (name, header) in [
+        ("response-content-type", header::CONTENT_TYPE),
+        ("response-content-language", header::CONTENT_LANGUAGE),
+        ("response-expires", header::EXPIRES),
+        ("response-cache-control", header::CACHE_CONTROL),
+        ("response-content-disposition", header::CONTENT_DISPOSITION),
+        ("response-content-encoding", header::CONTENT_ENCODING),
+    ] {
+        if let Some(v) = req.query(name) {
+            headers.retain(|(k, _)| k != header.as_str());
+            headers.push((header.to_string(), v.to_string()));
+        }
+    }
+
+    let size = meta.content_length();
+    let (code, start, end) = match req.header(header::RANGE.as_str()) {
+        Some(range) if !meta.is_dir() => {
+            let (start, end) = parse_range(range, size)?;
+            headers.push((
+                header::CONTENT_RANGE.to_string(),
+                format!("bytes {start}-{}/{size}", end - 1),
+            ));
+            (StatusCode::PARTIAL_CONTENT, start, end)
+        }
+        _ => (StatusCode::OK, 0, size),
+    };
+    headers.push((
+        header::CONTENT_LENGTH.to_string(),
+        (end - start).to_string(),
+    ));
+
+    if head || meta.is_dir() || start == end {
+        return build_response(code, headers, Body::empty());
+    }
+
+    let stream = state
+        .op
+        .reader(key)
+        .await?
+        .into_bytes_stream(start..end)
+        .await?;
+    build_response(code, headers, Body::from_stream(stream))
+}
+
+/// Options used to write an object, extracted from request headers.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct WriteOptions {
+    content_type: Option<String>,
+    content_disposition: Option<String>,
+    content_encoding: Option<String>,
+    cache_control: Option<String>,
+    storage_class: Option<String>,
+    user_metadata: HashMap<String, String>,
+}
+
+impl WriteOptions {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let get = |name: header::HeaderName| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+
+        let user_metadata = headers
+            .iter()
+            .filter_map(|(k, v)| {
+                let k = k.as_str().strip_prefix("x-amz-meta-")?;
+                Some((k.to_string(), v.to_str().ok()?.to_string()))
+            })
+            .collect();
+
+        Self {
+            content_type: get(header::CONTENT_TYPE),
+            content_disposition: get(header::CONTENT_DISPOSITION),
+            // `aws-chunked` is used by the transfer and must not be stored.
+            content_encoding: get(header::CONTENT_ENCODING)
+                .map(|v| {
+                    v.split(',')
+                        .map(|v| v.trim())
+                        .filter(|v| !v.is_empty() && *v != "aws-chunked")
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .filter(|v| !v.is_empty()),
+            cache_control: get(header::CACHE_CONTROL),
+            storage_class: headers
+                .get("x-amz-storage-class")
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string()),
+            user_metadata,
+        }
+    }
+
+    fn from_metadata(meta: &Metadata) -> Self {
+        Self {
+            content_type: meta.content_type().map(|v| v.to_string()),
+            content_disposition: meta.content_disposition().map(|v| v.to_string()),
+            content_encoding: meta.content_encoding().map(|v| v.to_string()),
+            cache_control: meta.cache_control().map(|v| v.to_string()),
+            storage_class: meta.storage_class().map(|v| v.to_string()),
+            user_metadata: meta.user_metadata().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Open a writer with options supported by the service, others will be ignored.
+    pub async fn writer(&self, op: &Operator, path: &str) -> Result<ObjectWriter, ErrorResponse> {
+        let cap = op.info().full_capability();
+
+        let mut fut = op.writer_with(path);
+        if cap.write_can_multi {
+            fut = fut.chunk(WRITE_CHUNK_SIZE.max(cap.write_multi_min_size.unwrap_or_default()));
+        }
+        if let (Some(v), true) = (&self.content_type, cap.write_with_content_type) {
+            fut = fut.content_type(v);
+        }
+        if let (Some(v), true) = (
+            &self.content_disposition,
+            cap.write_with_content_disposition,
+        ) {
+            fut = fut.content_disposition(v);
+        }
+        if let (Some(v), true) = (&self.content_encoding, cap.write_with_content_encoding) {
+            fut = fut.content_encoding(v);
+        }
+        if let (Some(v), true) = (&self.cache_control, cap.write_with_cache_control) {
+            fut = fut.cache_control(v);
+        }
+        if let (Some(v), true) = (&self.storage_class, cap.write_with_storage_class) {
+            fut = fut.storage_class(v);
+        }
+        if !self.user_metadata.is_empty() && cap.write_with_user_metadata {
+            fut = fut.user_metadata(self.user_metadata.clone());
+        }
+        Ok(ObjectWriter {
+            op: op.clone(),
+            w: fut.await?,
+        })
+    }
+}
+
+/// ObjectWriter writes an object and makes sure it won't be visible if
+/// anything goes wrong.
+pub struct ObjectWriter {
+    op: Operator,
+    w: Writer,
+}
+
+impl ObjectWriter {
+    pub async fn write(&mut self, bs: Bytes) -> Result<(), ErrorResponse> {
+        Ok(self.w.write(bs).await?)
+    }
+
+    pub async fn close(&mut self) -> Result<(), ErrorResponse> {
+        Ok(self.w.close().await?)
+    }
+
+    /// Abort the writer, returns the given error.
+    ///
+    /// Services like fs without `atomic_write_dir` can't abort the writer, the
+    /// writer will be dropped without closing. Please note that those services
+    /// write to the target directly, so the existing object has been replaced
+    /// by the partial data already. Configure `atomic_write_dir` to avoid it.
+    pub async fn abort(self, err: ErrorResponse) -> ErrorResponse {
+        abort_writer(self.w).await;
+        err
+    }
+
+    /// Write the whole payload and close the writer, returns the size and MD5 of payload.
+    ///
+    /// The writer will be aborted if anything goes wrong, including the payload
+    /// doesn't match the given `content_md5`.
+    pub async fn write_payload(
+        mut self,
+        payload: &mut PayloadReader,
+        content_md5: Option<&str>,
+    ) -> Result<(u64, [u8; 16]), ErrorResponse> {
+        let (mut size, mut hasher) = (0, Md5::new());
+        loop {
+            let bs = match payload.next().await {
+                Ok(Some(bs)) => bs,
+                Ok(None) => break,
+                Err(err) => return Err(self.abort(err).await),
+            };
+            size += bs.len() as u64;
+            hasher.update(&bs);
+            if let Err(err) = self.write(bs).await {
+                return Err(self.abort(err).await);
+            }
+        }
+
+        let md5: [u8; 16] = hasher.finalize().into();
+        if matches!(content_md5, Some(v) if BASE64.encode(md5) != v.trim()) {
+            let err = ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "BadDigest",
+                "The Content-MD5 you specified did not match what we received.",
+            );
+            return Err(self.abort(err).await);
+        }
+
+        self.close().await?;
+        Ok((size, md5))
+    }
+
+    /// Copy the range of object and close the writer, returns the size and MD5 of copied data.
+    ///
+    /// The writer will be aborted if anything goes wrong.
+    pub async fn write_copy(
+        mut self,
+        src: &str,
+        range: (u64, u64),
+    ) -> Result<(u64, [u8; 16]), ErrorResponse> {
+        let (mut size, mut hasher) = (0, Md5::new());
+        let res = async {
+            let mut stream = self
+                .op
+                .reader(src)
+                .await?
+                .into_bytes_stream(range.0..range.1)
+                .await?;
+            while let Some(bs) = stream.next().await {
+                let bs = bs?;
+                size += bs.len() as u64;
+                hasher.update(&bs);
+                self.write(bs).await?;
+            }
+            Ok::<(), ErrorResponse>(())
+        }
+        .await;
+        if let Err(err) = res {
+            return Err(self.abort(err).await);
+        }
+
+        self.close().await?;
+        Ok((size, hasher.finalize().into()))
+    }
+}
+
+pub async fn handle_put_object(
+    state: &S3State,
+    mut req: S3Request,
+    key: &str,
+) -> Result<Response, ErrorResponse> {
+    if req.header("if-none-match") == Some("*") && state.op.is_exist(key).await? {
+        return Err(ErrorResponse::precondition_failed());
+    }
+
+    // Keys end with `/` are treated as directories.
+    if key.ends_with('/') {
+        if !req.body.read_all(0).await?.is_empty() {
+            return Err(ErrorResponse::invalid_request(
+                "directory object must be empty",
+            ));
+        }
+        state.op.create_dir(key).await?;
+        let meta = state.op.stat(key).await?;
+        return build_response(
+            StatusCode::OK,
+            vec![(header::ETAG.to_string(), object_etag(&meta))],
+            Body::empty(),
+        );
+    }
+
+    let opts = WriteOptions::from_headers(&req.parts.headers);
+    let w = opts.writer(&state.op, key).await?;
+    let content_md5 = req.header("content-md5").map(|v| v.to_string());
+    w.write_payload(&mut req.body, content_md5.as_deref())
+        .await?;
+
+    let meta = state.op.stat(key).await?;
+    build_response(
+        StatusCode::OK,
+        vec![(header::ETAG.to_string(), object_etag(&meta))],
+        Body::empty(),
+    )
+}
+
+/// Parse `x-amz-copy-source` like `/bucket/key` or `bucket/key?versionId=xxx`.
+pub fn parse_copy_source(state: &S3State, v: &str) -> Result<String, ErrorResponse> {
+    let v = v.split_once('?').map(|(v, _)| v).unwrap_or(v);
+    let v = uri_decode(v);
+    let (bucket, key) = v
+        .trim_start_matches('/')
+        .split_once('/')
+        .ok_or_else(|| ErrorResponse::invalid_argument("x-amz-copy-source is invalid"))?;
+    if bucket != state.bucket {
+        return Err(ErrorResponse::no_such_bucket(bucket));
+    }
+    if key.is_empty() {
+        return Err(ErrorResponse::invalid_argument(
+            "x-amz-copy-source is invalid",
+        ));
+    }
+    if is_internal_path(key) {
+        return Err(ErrorResponse::access_denied(format!(
+            "{INTERNAL_DIR} is reserved by oay"
+        )));
+    }
+    Ok(key.to_string())
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CopyObjectResult {
+    #[serde(rename = "@xmlns")]
+    pub xmlns: &'static str,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub last_modified: String,
+}
+
+pub async fn handle_copy_object(
+    state: &S3State,
+    req: S3Request,
+    key: &str,
+    source: &str,
+) -> Result<Response, ErrorResponse> {
+    let src = parse_copy_source(state, source)?;
+    let src_meta = stat_object(&state.op, &src).await?;
+    if !check_conditions(&req.parts.headers, "x-amz-copy-source-", &src_meta)? {
+        return Err(ErrorResponse::precondition_failed());
+    }
+
+    let replace = req.header("x-amz-metadata-directive") == Some("REPLACE");
+    if src == key && !replace {
+        return Err(ErrorResponse::invalid_request(
+            "This copy request is illegal because it is trying to copy an object to itself without changing the object's metadata.",
+        ));
+    }
+
+    if src_meta.is_dir() {
+        state.op.create_dir(key).await?;
+    } else if !replace && state.op.info().full_capability().copy {
+        state.op.copy(&src, key).await?;
+    } else {
+        let opts = if replace {
+            WriteOptions::from_headers(&req.parts.headers)
+        } else {
+            WriteOptions::from_metadata(&src_meta)
+        };
+        let w = opts.writer(&state.op, key).await?;
+        w.write_copy(&src, (0, src_meta.content_length())).await?;
+    }
+
+    let meta = state.op.stat(key).await?;
+    let resp = CopyObjectResult {
+        xmlns: XMLNS,
+        etag: object_etag(&meta),
+        last_modified: iso8601(meta.last_modified().unwrap_or_else(Utc::now)),
+    };
+    Ok(xml_response(&resp))
+}
+
+pub async fn handle_delete_object(state: &S3State, key: &str) -> Result<Response, ErrorResponse> {
+    state.op.delete(key).await?;
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -15,25 +15,53 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::extract::Query;
+use axum::extract::Request;
 use axum::extract::State;
+use axum::http::header;
+use axum::http::request::Parts;
+use axum::http::Method;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::response::Response;
-use axum::routing::get;
 use axum::Router;
-use chrono::SecondsFormat;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL;
+use base64::Engine;
+use chrono::Utc;
 use futures_util::StreamExt;
 use opendal::Metakey;
 use opendal::Operator;
 use serde::Deserialize;
 use serde::Serialize;
 use tracing::debug;
-
+use tracing::warn;
+
+use super::auth::parse_query;
+use super::auth::uri_decode;
+use super::auth::uri_encode;
+use super::auth::Authenticator;
+use super::auth::PayloadReader;
+use super::error::ErrorResponse;
+use super::multipart::*;
+use super::object::*;
+use crate::policy::is_internal_path;
+use crate::policy::Policies;
+use crate::policy::Policy;
+use crate::policy::INTERNAL_DIR;
+use crate::server::serve;
+use crate::services::http_util::object_etag;
 use crate::Config;
 
+/// XML namespace of S3 responses.
+pub const XMLNS: &str = "http://s3.amazonaws.com/doc/2006-03-01/";
+const MAX_KEYS: usize = 1000;
+const MAX_DELETE_BODY_SIZE: usize = 4 * 1024 * 1024;
+/// How often expired multipart uploads are removed.
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
+
 pub struct S3Service {
     cfg: Arc<Config>,
     op: Operator,
@@ -47,109 +75,280 @@ impl S3Service {
     pub async fn serve(&self) -> anyhow::Result<()> {
         let s3_cfg = &self.cfg.frontends.s3;
 
-        let app = Router::new()
-            .route("/", get(handle_list_objects))
-            .with_state(S3State {
-                op: self.op.clone(),
-            });
+        let uploads = Arc::new(Uploads::new(
+            self.op.clone(),
+            Duration::from_secs(s3_cfg.multipart_expire_secs),
+        ));
+        let cleanup = uploads.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+            loop {
+                interval.tick().await;
+                if let Err(err) = cleanup.cleanup().await {
+                    warn!("failed to clean up multipart uploads: {err:?}");
+                }
+            }
+        });
 
-        let listener = tokio::net::TcpListener::bind(&s3_cfg.addr).await.unwrap();
-        axum::serve(listener, app.into_make_service()).await?;
+        let app = Router::new().fallback(handle).with_state(S3State {
+            op: self.op.clone(),
+            internal: self.op.clone(),
+            user: String::new(),
+            policy: Policy::default(),
+            bucket: s3_cfg.bucket.clone(),
+            region: s3_cfg.region.clone(),
+            auth: Arc::new(Authenticator::new(&s3_cfg.credentials)),
+            policies: Arc::new(Policies::new(&self.cfg.policies)),
+            uploads,
+        });
 
-        Ok(())
+        serve(&s3_cfg.addr, s3_cfg.tls.as_ref(), app).await
     }
 }
 
 #[derive(Clone)]
 pub struct S3State {
-    op: Operator,
+    /// Operator restricted by the policy of current user.
+    pub(super) op: Operator,
+    /// Operator used to access [`INTERNAL_DIR`], which is not restricted by policies.
+    pub(super) internal: Operator,
+    /// The user of current request.
+    pub(super) user: String,
+    pub(super) policy: Policy,
+    pub(super) bucket: String,
+    pub(super) region: String,
+    auth: Arc<Authenticator>,
+    policies: Arc<Policies>,
+    pub(super) uploads: Arc<Uploads>,
 }
 
-/// # TODO
-///
-/// we need to support following parameters:
-///
-/// - max-keys
-/// - continuation_token
-#[derive(Deserialize, Default, Debug)]
-#[serde(default)]
-struct ListObjectsV2Params {
-    prefix: String,
-    start_after: String,
-}
-
-async fn handle_list_objects(
-    state: State<S3State>,
-    params: Query<ListObjectsV2Params>,
-) -> Result<OkResponse, ErrorResponse> {
-    debug!("got params: {:?}", params);
-
-    if !state.op.info().full_capability().list_with_start_after {
-        return Err(ErrorResponse {
-            code: StatusCode::NOT_IMPLEMENTED,
-            err: Error {
-                code: "NotImplemented".to_string(),
-                message: "list with start after is not supported".to_string(),
-                resource: "".to_string(),
-                request_id: "".to_string(),
-            },
-        });
+/// S3Request is the parsed request of S3 API.
+pub struct S3Request {
+    pub(super) parts: Parts,
+    pub(super) body: PayloadReader,
+    query: Vec<(String, String)>,
+}
+
+impl S3Request {
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.parts.headers.get(name).and_then(|v| v.to_str().ok())
     }
+}
 
-    let mut lister = state
-        .op
-        .lister_with(&params.prefix)
-        .start_after(&params.start_after)
-        .metakey(Metakey::Mode | Metakey::LastModified | Metakey::Etag | Metakey::ContentLength)
-        .await?
-        .chunks(1000);
+async fn handle(state: State<S3State>, req: Request) -> Response {
+    match dispatch(&state, req).await {
+        Ok(resp) => resp,
+        Err(err) => err.into_response(),
+    }
+}
 
-    let page = lister.next().await.unwrap_or_default();
+/// Split bucket and key from the request.
+///
+/// Both virtual hosted style (`bucket.host/key`) and path style (`host/bucket/key`)
+/// are supported.
+fn split_bucket_key(state: &S3State, parts: &Parts) -> (Option<String>, Option<String>) {
+    let path = uri_decode(parts.uri.path());
+    let path = path.strip_prefix('/').unwrap_or(&path);
+
+    let host = parts
+        .headers
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .or_else(|| parts.uri.host())
+        .unwrap_or_default();
+    if host.starts_with(&format!("{}.", state.bucket)) {
+        let key = (!path.is_empty()).then(|| path.to_string());
+        return (Some(state.bucket.clone()), key);
+    }
 
-    let is_truncated = page.len() >= 1000;
+    match path.split_once('/') {
+        None if path.is_empty() => (None, None),
+        None => (Some(path.to_string()), None),
+        Some((bucket, "")) => (Some(bucket.to_string()), None),
+        Some((bucket, key)) => (Some(bucket.to_string()), Some(key.to_string())),
+    }
+}
 
-    let (mut common_prefixes, mut contents) = (vec![], vec![]);
-    for v in page {
-        let v = v?;
-        let meta = v.metadata();
+async fn dispatch(state: &S3State, req: Request) -> Result<Response, ErrorResponse> {
+    let (parts, body) = req.into_parts();
+    debug!("got request: {} {}", parts.method, parts.uri);
+
+    let (user, payload) = state.auth.verify(&parts)?;
+    let policy = state.policies.policy(&user);
+    let state = &S3State {
+        op: policy.operator(&state.internal),
+        user,
+        policy,
+        ..state.clone()
+    };
+    let (bucket, key) = split_bucket_key(state, &parts);
+    let req = S3Request {
+        query: parse_query(parts.uri.query()),
+        body: PayloadReader::new(body, payload),
+        parts,
+    };
+    let has = |name: &str| req.query.iter().any(|(k, _)| k == name);
 
-        if meta.is_dir() {
-            common_prefixes.push(CommonPrefix {
-                prefix: v.path().to_string(),
-            });
-        } else {
-            contents.push(Object {
-                key: v.path().to_string(),
-                last_modified: meta
-                    .last_modified()
-                    .unwrap_or_default()
-                    .to_rfc3339_opts(SecondsFormat::Millis, true),
-                etag: meta.etag().unwrap_or_default().to_string(),
-                size: meta.content_length(),
-            });
-        }
+    let Some(bucket) = bucket else {
+        return match req.parts.method {
+            Method::GET => handle_list_buckets(state).await,
+            _ => Err(ErrorResponse::method_not_allowed()),
+        };
+    };
+    if bucket != state.bucket {
+        return Err(ErrorResponse::no_such_bucket(&bucket));
     }
 
-    let resp = ListBucketResult {
-        is_truncated,
-        common_prefixes,
-        contents,
-        start_after: Some(params.start_after.clone()),
+    let Some(key) = key else {
+        return match req.parts.method {
+            Method::HEAD => Ok(StatusCode::OK.into_response()),
+            Method::GET if has("location") => handle_get_bucket_location(state).await,
+            Method::GET if has("uploads") => handle_list_multipart_uploads(state, req).await,
+            Method::GET => handle_list_objects(state, req).await,
+            Method::POST if has("delete") => handle_delete_objects(state, req).await,
+            Method::PUT => Err(ErrorResponse::new(
+                StatusCode::CONFLICT,
+                "BucketAlreadyOwnedByYou",
+                "Your previous request to create the named bucket succeeded and you already own it.",
+            )
+            .with_resource(&bucket)),
+            _ => Err(ErrorResponse::not_implemented(format!(
+                "{} on bucket is not supported",
+                req.parts.method
+            ))),
+        };
     };
+    if is_internal_path(&key) {
+        return Err(ErrorResponse::access_denied(format!(
+            "{INTERNAL_DIR} is reserved by oay"
+        )));
+    }
+
+    let copy_source = req.header("x-amz-copy-source").map(|v| v.to_string());
+    match req.parts.method {
+        Method::GET if has("uploadId") => handle_list_parts(state, req, &key).await,
+        Method::GET => handle_get_object(state, req, &key, false).await,
+        Method::HEAD => handle_get_object(state, req, &key, true).await,
+        Method::PUT if has("uploadId") => handle_upload_part(state, req, &key).await,
+        Method::PUT => match copy_source {
+            Some(source) => handle_copy_object(state, req, &key, &source).await,
+            None => handle_put_object(state, req, &key).await,
+        },
+        Method::POST if has("uploads") => handle_create_multipart_upload(state, req, &key).await,
+        Method::POST if has("uploadId") => handle_complete_multipart_upload(state, req, &key).await,
+        Method::DELETE if has("uploadId") => handle_abort_multipart_upload(state, req, &key).await,
+        Method::DELETE => handle_delete_object(state, &key).await,
+        _ => Err(ErrorResponse::method_not_allowed()),
+    }
+}
+
+pub fn xml_response(v: &impl Serialize) -> Response {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/xml")],
+        quick_xml::se::to_string(v).unwrap(),
+    )
+        .into_response()
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListAllMyBucketsResult {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    owner: Owner,
+    buckets: Buckets,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Owner {
+    #[serde(rename = "ID")]
+    id: String,
+    display_name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Buckets {
+    bucket: Vec<Bucket>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Bucket {
+    name: String,
+    creation_date: String,
+}
+
+async fn handle_list_buckets(state: &S3State) -> Result<Response, ErrorResponse> {
+    Ok(xml_response(&ListAllMyBucketsResult {
+        xmlns: XMLNS,
+        owner: Owner {
+            id: "oay".to_string(),
+            display_name: "oay".to_string(),
+        },
+        buckets: Buckets {
+            bucket: vec![Bucket {
+                name: state.bucket.clone(),
+                creation_date: iso8601(Utc::now()),
+            }],
+        },
+    }))
+}
+
+#[derive(Serialize)]
+struct LocationConstraint {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    #[serde(rename = "$text")]
+    region: String,
+}
 
-    Ok(OkResponse {
-        code: StatusCode::OK,
-        content: quick_xml::se::to_string(&resp).unwrap().into_bytes(),
-    })
+async fn handle_get_bucket_location(state: &S3State) -> Result<Response, ErrorResponse> {
+    Ok(xml_response(&LocationConstraint {
+        xmlns: XMLNS,
+        region: state.region.clone(),
+    }))
 }
 
 #[derive(Serialize, Default)]
 #[serde(default, rename_all = "PascalCase")]
 struct ListBucketResult {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    name: String,
+    prefix: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delimiter: Option<String>,
+    max_keys: usize,
     is_truncated: bool,
-    common_prefixes: Vec<CommonPrefix>,
-    contents: Vec<Object>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding_type: Option<String>,
+    // Fields for ListObjects.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_marker: Option<String>,
+    // Fields for ListObjectsV2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    continuation_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_continuation_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     start_after: Option<String>,
+
+    contents: Vec<Object>,
+    common_prefixes: Vec<CommonPrefix>,
 }
 
 #[derive(Serialize, Default)]
@@ -163,69 +362,301 @@ struct CommonPrefix {
 struct Object {
     key: String,
     last_modified: String,
+    #[serde(rename = "ETag")]
     etag: String,
     size: u64,
+    storage_class: String,
 }
 
-struct OkResponse {
-    code: StatusCode,
-    content: Vec<u8>,
+/// Handle both ListObjects and ListObjectsV2.
+///
+/// OpenDAL services don't promise the order of listed entries, so every page
+/// is sorted by [`list_entries`] which only keeps `max-keys + 1` entries.
+async fn handle_list_objects(state: &S3State, req: S3Request) -> Result<Response, ErrorResponse> {
+    debug!("got params: {:?}", req.query);
+
+    let v2 = req.query("list-type") == Some("2");
+    let prefix = req.query("prefix").unwrap_or_default().to_string();
+    let delimiter = req.query("delimiter").unwrap_or_default().to_string();
+    let max_keys = match req.query("max-keys") {
+        Some(v) => v
+            .parse::<usize>()
+            .map_err(|_| ErrorResponse::invalid_argument("max-keys is invalid"))?,
+        None => MAX_KEYS,
+    }
+    .min(MAX_KEYS);
+    let encoding_type = req.query("encoding-type").map(|v| v.to_string());
+    if matches!(&encoding_type, Some(v) if v != "url") {
+        return Err(ErrorResponse::invalid_argument("encoding-type is invalid"));
+    }
+    let url_encoded = encoding_type.is_some();
+    let encode = |v: &str| match url_encoded {
+        true => uri_encode(v, false),
+        false => v.to_string(),
+    };
+
+    let (marker, continuation_token, start_after) = if v2 {
+        let token = req.query("continuation-token").map(|v| v.to_string());
+        let start_after = req.query("start-after").map(|v| v.to_string());
+        (None, token, start_after)
+    } else {
+        (req.query("marker").map(|v| v.to_string()), None, None)
+    };
+    let token_key = match &continuation_token {
+        Some(v) => Some(
+            BASE64_URL
+                .decode(v)
+                .ok()
+                .and_then(|v| String::from_utf8(v).ok())
+                .ok_or_else(|| ErrorResponse::invalid_argument("continuation-token is invalid"))?,
+        ),
+        None => None,
+    };
+    let start = [
+        marker.as_deref(),
+        start_after.as_deref(),
+        token_key.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .max()
+    .unwrap_or_default()
+    .to_string();
+
+    let entries = list_entries(&state.op, &prefix, &delimiter, &start, max_keys + 1).await?;
+    let mut page = entries.iter();
+
+    let (mut contents, mut common_prefixes, mut last_key) = (vec![], vec![], None);
+    for (key, obj) in page.by_ref().take(max_keys) {
+        match obj {
+            Some(obj) => contents.push(Object {
+                key: encode(key),
+                last_modified: obj.last_modified.clone(),
+                etag: obj.etag.clone(),
+                size: obj.size,
+                storage_class: obj.storage_class.clone(),
+            }),
+            None => common_prefixes.push(CommonPrefix {
+                prefix: encode(key),
+            }),
+        }
+        last_key = Some(key.clone());
+    }
+    let is_truncated = page.next().is_some();
+
+    let mut resp = ListBucketResult {
+        xmlns: XMLNS,
+        name: state.bucket.clone(),
+        prefix: encode(&prefix),
+        delimiter: (!delimiter.is_empty()).then(|| encode(&delimiter)),
+        max_keys,
+        is_truncated,
+        encoding_type,
+        ..Default::default()
+    };
+    if v2 {
+        resp.key_count = Some(contents.len() + common_prefixes.len());
+        resp.continuation_token = continuation_token;
+        resp.next_continuation_token = is_truncated
+            .then(|| last_key.map(|v| BASE64_URL.encode(v)))
+            .flatten();
+        resp.start_after = start_after.map(|v| encode(&v));
+    } else {
+        resp.marker = Some(encode(&marker.unwrap_or_default()));
+        resp.next_marker = is_truncated.then(|| last_key.map(|v| encode(&v))).flatten();
+    }
+    resp.contents = contents;
+    resp.common_prefixes = common_prefixes;
+
+    Ok(xml_response(&resp))
 }
 
-impl IntoResponse for OkResponse {
-    fn into_response(self) -> Response {
-        (self.code, self.content).into_response()
+/// Listed object, `None` means common prefix.
+type ListedEntries = BTreeMap<String, Option<Object>>;
+
+/// List the first `limit` entries after `start` under the prefix, grouped by
+/// delimiter and sorted by key.
+///
+/// Services that support `start_after` list keys in order, so listing will
+/// start from `start` and stop once enough entries are found. Other services
+/// have to be listed fully, but only `limit` entries are kept.
+async fn list_entries(
+    op: &Operator,
+    prefix: &str,
+    delimiter: &str,
+    start: &str,
+    limit: usize,
+) -> Result<ListedEntries, ErrorResponse> {
+    let dir = match prefix.rfind('/') {
+        Some(idx) => &prefix[..=idx],
+        None => "",
+    };
+    // Only `/` can be handled by services natively, other delimiters will be
+    // handled by grouping the recursively listed keys.
+    let recursive = delimiter != "/";
+    let sorted = op.info().full_capability().list_with_start_after;
+
+    let mut fut = op.lister_with(dir).recursive(recursive).metakey(
+        Metakey::Mode
+            | Metakey::LastModified
+            | Metakey::Etag
+            | Metakey::ContentLength
+            | Metakey::StorageClass,
+    );
+    if sorted && start > dir {
+        fut = fut.start_after(start);
     }
+    let mut lister = match fut.await {
+        Ok(lister) => lister,
+        Err(err) if err.kind() == opendal::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut entries = BTreeMap::new();
+    while let Some(entry) = lister.next().await {
+        let entry = entry?;
+        let key = entry.path();
+        if key == dir || !key.starts_with(prefix) || is_internal_path(key) {
+            continue;
+        }
+
+        let rest = &key[prefix.len()..];
+        if let Some(idx) = (!delimiter.is_empty())
+            .then(|| rest.find(delimiter))
+            .flatten()
+        {
+            let common_prefix = &key[..prefix.len() + idx + delimiter.len()];
+            if common_prefix > start {
+                entries.insert(common_prefix.to_string(), None);
+            }
+        } else if entry.metadata().is_dir() || key <= start {
+            // Directories are only visible as common prefixes.
+        } else {
+            let meta = entry.metadata();
+            entries.insert(
+                key.to_string(),
+                Some(Object {
+                    key: key.to_string(),
+                    last_modified: iso8601(meta.last_modified().unwrap_or_default()),
+                    etag: object_etag(meta),
+                    size: meta.content_length(),
+                    storage_class: meta.storage_class().unwrap_or("STANDARD").to_string(),
+                }),
+            );
+        }
+
+        if entries.len() > limit {
+            entries.pop_last();
+            // Sorted listing will never yield a smaller entry again.
+            if sorted {
+                break;
+            }
+        }
+    }
+
+    Ok(entries)
 }
 
-struct ErrorResponse {
-    code: StatusCode,
-    err: Error,
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Delete {
+    #[serde(default)]
+    quiet: bool,
+    #[serde(rename = "Object", default)]
+    objects: Vec<ObjectIdentifier>,
 }
 
-impl IntoResponse for ErrorResponse {
-    fn into_response(self) -> Response {
-        (self.code, quick_xml::se::to_string(&self.err).unwrap()).into_response()
-    }
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ObjectIdentifier {
+    key: String,
 }
 
 #[derive(Serialize)]
-#[serde(default, rename_all = "PascalCase")]
-struct Error {
+#[serde(rename_all = "PascalCase")]
+struct DeleteResult {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    deleted: Vec<DeletedObject>,
+    error: Vec<DeleteError>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeletedObject {
+    key: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeleteError {
+    key: String,
     code: String,
     message: String,
-    resource: String,
-    request_id: String,
 }
 
-impl From<opendal::Error> for ErrorResponse {
-    fn from(err: opendal::Error) -> Self {
-        let err = Error {
-            code: "InternalError".to_string(),
-            message: err.to_string(),
-            resource: "".to_string(),
-            request_id: "".to_string(),
-        };
+async fn handle_delete_objects(
+    state: &S3State,
+    mut req: S3Request,
+) -> Result<Response, ErrorResponse> {
+    let body = req.body.read_all(MAX_DELETE_BODY_SIZE).await?;
+    let delete: Delete = quick_xml::de::from_reader(body.as_ref())
+        .map_err(|err| ErrorResponse::malformed_xml(format!("delete is invalid: {err}")))?;
+    if delete.objects.len() > MAX_KEYS {
+        return Err(ErrorResponse::malformed_xml(
+            "The request must contain no more than 1000 keys.",
+        ));
+    }
 
-        ErrorResponse {
-            code: StatusCode::INTERNAL_SERVER_ERROR,
-            err,
+    let (mut deleted, mut error) = (vec![], vec![]);
+    for obj in delete.objects {
+        let res = if is_internal_path(&obj.key) {
+            Err(("AccessDenied".to_string(), "Access Denied".to_string()))
+        } else {
+            state
+                .op
+                .delete(&obj.key)
+                .await
+                .map_err(|err| ErrorResponse::from(err).into_code_message())
+        };
+        match res {
+            Ok(()) if !delete.quiet => deleted.push(DeletedObject { key: obj.key }),
+            Ok(()) => {}
+            Err((code, message)) => error.push(DeleteError {
+                key: obj.key,
+                code,
+                message,
+            }),
         }
     }
+
+    Ok(xml_response(&DeleteResult {
+        xmlns: XMLNS,
+        deleted,
+        error,
+    }))
 }
 
-impl From<anyhow::Error> for ErrorResponse {
-    fn from(err: anyhow::Error) -> Self {
-        let err = Error {
-            code: "InternalError".to_string(),
-            message: err.to_string(),
-            resource: "".to_string(),
-            request_id: "".to_string(),
-        };
+#[cfg(test)]
+mod tests {
+    use opendal::services::Memory;
+
+    use super::*;
 
-        ErrorResponse {
-            code: StatusCode::INTERNAL_SERVER_ERROR,
-            err,
+    #[tokio::test]
+    async fn test_list_entries() -> Result<(), ErrorResponse> {
+        let op = Operator::new(Memory::default())?.finish();
+        for path in ["a/x", "b/y", "c", "d/z", "e", ".oay/multipart/1"] {
+            op.write(path, "data").await?;
         }
+        let keys = |entries: ListedEntries| entries.into_keys().collect::<Vec<_>>();
+
+        let entries = list_entries(&op, "", "/", "", 3).await?;
+        assert_eq!(keys(entries), vec!["a/", "b/", "c"]);
+        let entries = list_entries(&op, "", "/", "b/", 3).await?;
+        assert_eq!(keys(entries), vec!["c", "d/", "e"]);
+        let entries = list_entries(&op, "", "", "c", 10).await?;
+        assert_eq!(keys(entries), vec!["d/z", "e"]);
+        Ok(())
     }
 }
@@ -15,51 +15,63 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::convert::Infallible;
 use std::sync::Arc;
 
-use axum::body::Body;
-use axum::http::Request;
-use axum::routing::any_service;
+use axum::extract::Request;
+use axum::extract::State;
+use axum::response::Response;
 use axum::Router;
 use dav_server::DavHandler;
 use dav_server_opendalfs::OpendalFs;
 use opendal::Operator;
 
+use crate::auth::authenticate;
+use crate::auth::unauthorized;
+use crate::policy::Policies;
+use crate::server::serve;
 use crate::Config;
+use crate::UserCredential;
 
 pub struct WebdavService {
     cfg: Arc<Config>,
-    opendalfs: Box<OpendalFs>,
+    op: Operator,
 }
 
 impl WebdavService {
     pub fn new(cfg: Arc<Config>, op: Operator) -> Self {
-        Self {
-            cfg,
-            opendalfs: OpendalFs::new(op),
-        }
+        Self { cfg, op }
     }
 
     pub async fn serve(&self) -> anyhow::Result<()> {
         let webdav_cfg = &self.cfg.frontends.webdav;
 
-        let webdav_handler = DavHandler::builder()
-            .filesystem(self.opendalfs.clone())
-            .build_handler();
-
-        let webdav_service = tower::service_fn(move |req: Request<Body>| {
-            let webdav_server = webdav_handler.clone();
-            async move { Ok::<_, Infallible>(webdav_server.handle(req).await) }
+        let app = Router::new().fallback(handle).with_state(WebdavState {
+            op: self.op.clone(),
+            users: Arc::new(webdav_cfg.users.clone()),
+            policies: Arc::new(Policies::new(&self.cfg.policies)),
         });
 
-        let app = Router::new().route("/*path", any_service(webdav_service));
+        serve(&webdav_cfg.addr, webdav_cfg.tls.as_ref(), app).await
+    }
+}
+
+#[derive(Clone)]
+struct WebdavState {
+    op: Operator,
+    users: Arc<Vec<UserCredential>>,
+    policies: Arc<Policies>,
+}
 
-        let listener = tokio::net::TcpListener::bind(&webdav_cfg.addr)
-            .await
-            .unwrap();
-        axum::serve(listener, app.into_make_service()).await?;
+async fn handle(State(state): State<WebdavState>, req: Request) -> Response {
+    let Some(user) = authenticate(&state.users, req.headers()) else {
+        return unauthorized();
+    };
 
-        Ok(())
-    }
+    // The filesystem is restricted by the policy of user, so we build the
+    // handler for every request.
+    let op = state.policies.policy(&user).operator(&state.op);
+    let handler = DavHandler::builder()
+        .filesystem(OpendalFs::new(op))
+        .build_handler();
+    handler.handle(req).await.map(axum::body::Body::new)
 }
@@ -0,0 +1,322 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use clap::Args;
+use clap::FromArgMatches;
+use opendal::Operator;
+use opendal::Scheme;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+
+/// Config file of ofs, which shares the same profile format with oli:
+///
+/// ```toml
+/// status_addr = "127.0.0.1:9500"
+///
+/// [profiles.data]
+/// type = "s3"
+/// bucket = "data"
+///
+/// [[mounts]]
+/// path = "/mnt/data"
+/// profile = "data"
+/// options = ["ro", "allow_other"]
+/// cache_size = "10G"
+/// ```
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+pub struct ConfigFile {
+    /// Address to serve the status endpoint.
+    pub status_addr: Option<SocketAddr>,
+    pub profiles: HashMap<String, HashMap<String, String>>,
+    pub mounts: Vec<Mount>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Mount {
+    pub path: PathBuf,
+    pub profile: String,
+    #[serde(flatten)]
+    pub config: MountConfig,
+}
+
+impl ConfigFile {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file {}", path.display()))?;
+        let cfg: ConfigFile = toml::from_str(&content)
+            .with_context(|| format!("failed to parse config file {}", path.display()))?;
+
+        if cfg.mounts.is_empty() {
+            return Err(anyhow!("no mounts in config file {}", path.display()));
+        }
+        for mount in &cfg.mounts {
+            if !cfg.profiles.contains_key(&mount.profile) {
+                return Err(anyhow!(
+                    "profile {} of mount {} not found",
+                    mount.profile,
+                    mount.path.display()
+                ));
+            }
+        }
+        Ok(cfg)
+    }
+
+    /// Build the operator of given profile.
+    pub fn operator(&self, profile: &str) -> Result<Operator> {
+        let options = self
+            .profiles
+            .get(profile)
+            .ok_or_else(|| anyhow!("profile {} not found", profile))?;
+        let svc = options
+            .get("type")
+            .ok_or_else(|| anyhow!("type for profile {} is missing", profile))?;
+        let scheme = match Scheme::from_str(svc) {
+            Ok(Scheme::Custom(_)) | Err(_) => Err(anyhow!("invalid scheme: {}", svc)),
+            Ok(s) => Ok(s),
+        }?;
+
+        let options = options
+            .iter()
+            .filter(|(k, _)| k.as_str() != "type")
+            .map(|(k, v)| (k.clone(), v.clone()));
+        Ok(Operator::via_iter(scheme, options)?)
+    }
+}
+
+/// Options of a mount, which could be set by command line or config file.
+#[derive(Args, Deserialize, Debug)]
+#[serde(default)]
+#[cfg_attr(not(any(target_os = "linux", target_os = "freebsd")), allow(dead_code))]
+pub struct MountConfig {
+    /// fuse mount options, separated by comma
+    /// supported: allow_other, allow_root, default_permissions, nonempty, ro, rw, fsname=<name>
+    #[arg(short = 'o', long = "options", value_delimiter = ',')]
+    pub options: Vec<String>,
+
+    /// uid of files in the mount, default to the current user
+    #[arg(long)]
+    pub uid: Option<u32>,
+
+    /// gid of files in the mount, default to the current group
+    #[arg(long)]
+    pub gid: Option<u32>,
+
+    /// directory to keep cached blocks and staging files, default to the system temp dir
+    #[arg(long, env = "OFS_CACHE_DIR")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// max disk space used by cached blocks, 0 disables the read cache
+    #[arg(long, default_value = "1G", value_parser = parse_size)]
+    #[serde(deserialize_with = "deserialize_size")]
+    pub cache_size: u64,
+
+    /// size of blocks fetched from the backend
+    #[arg(long, default_value = "1M", value_parser = parse_size)]
+    #[serde(deserialize_with = "deserialize_size")]
+    pub block_size: u64,
+
+    /// number of blocks to read ahead for sequential reads
+    #[arg(long, default_value_t = 4)]
+    pub readahead: u64,
+
+    /// send writes to the backend directly instead of staging them locally
+    #[arg(long)]
+    pub no_write_back: bool,
+
+    /// disable local cache for both reads and writes
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// how long the kernel could cache file attributes, like `1s` or `500ms`
+    #[arg(long, default_value = "1s", value_parser = parse_ttl)]
+    #[serde(deserialize_with = "deserialize_ttl")]
+    pub attr_ttl: Duration,
+
+    /// how long the kernel could cache directory entries, like `1s` or `500ms`
+    #[arg(long, default_value = "1s", value_parser = parse_ttl)]
+    #[serde(deserialize_with = "deserialize_ttl")]
+    pub entry_ttl: Duration,
+}
+
+impl Default for MountConfig {
+    /// Use the same defaults as the command line.
+    fn default() -> Self {
+        let cmd = MountConfig::augment_args(clap::Command::new("ofs"));
+        MountConfig::from_arg_matches(&cmd.get_matches_from(["ofs"]))
+            .expect("default mount config must be valid")
+    }
+}
+
+/// Parse size like `4096`, `512K`, `1M` or `1G`.
+pub fn parse_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let (num, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let num: u64 = num.parse().map_err(|_| format!("invalid size: {s}"))?;
+    let unit = match unit
+        .to_ascii_uppercase()
+        .trim_end_matches("IB")
+        .trim_end_matches('B')
+    {
+        "" => 1,
+        "K" => 1024,
+        "M" => 1024 * 1024,
+        "G" => 1024 * 1024 * 1024,
+        _ => return Err(format!("invalid size: {s}")),
+    };
+    num.checked_mul(unit)
+        .ok_or_else(|| format!("size too large: {s}"))
+}
+
+/// Parse ttl like `1s`, `500ms` or `1m`, plain numbers are in seconds.
+pub fn parse_ttl(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let (num, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let num: u64 = num.parse().map_err(|_| format!("invalid ttl: {s}"))?;
+    match unit {
+        "ms" => Ok(Duration::from_millis(num)),
+        "" | "s" => Ok(Duration::from_secs(num)),
+        "m" => Ok(Duration::from_secs(num * 60)),
+        _ => Err(format!("invalid ttl: {s}")),
+    }
+}
+
+/// Sizes and ttls could be written as numbers or strings in config file.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString {
+    Number(u64),
+    String(String),
+}
+
+fn deserialize_size<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(n) => Ok(n),
+        NumberOrString::String(s) => parse_size(&s).map_err(de::Error::custom),
+    }
+}
+
+fn deserialize_ttl<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(n) => Ok(Duration::from_secs(n)),
+        NumberOrString::String(s) => parse_ttl(&s).map_err(de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("512K"), Ok(512 * 1024));
+        assert_eq!(parse_size("1MiB"), Ok(1024 * 1024));
+        assert_eq!(parse_size("2g"), Ok(2 * 1024 * 1024 * 1024));
+        assert!(parse_size("1X").is_err());
+        assert!(parse_size("M").is_err());
+    }
+
+    #[test]
+    fn test_parse_ttl() {
+        assert_eq!(parse_ttl("1"), Ok(Duration::from_secs(1)));
+        assert_eq!(parse_ttl("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_ttl("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_ttl("1h").is_err());
+    }
+
+    #[test]
+    fn test_load_config_file() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("config.toml");
+        fs::write(
+            &path,
+            r#"
+status_addr = "127.0.0.1:9500"
+
+[profiles.local]
+type = "fs"
+root = "/tmp"
+
+[[mounts]]
+path = "/mnt/a"
+profile = "local"
+options = ["ro", "allow_other"]
+uid = 1000
+cache_size = "10G"
+block_size = 4096
+attr_ttl = "500ms"
+
+[[mounts]]
+path = "/mnt/b"
+profile = "local"
+no_cache = true
+"#,
+        )?;
+
+        let cfg = ConfigFile::load(&path)?;
+        assert_eq!(cfg.status_addr, Some("127.0.0.1:9500".parse()?));
+        assert_eq!(cfg.mounts.len(), 2);
+
+        let a = &cfg.mounts[0].config;
+        assert_eq!(a.options, vec!["ro", "allow_other"]);
+        assert_eq!(a.uid, Some(1000));
+        assert_eq!(a.gid, None);
+        assert_eq!(a.cache_size, 10 * 1024 * 1024 * 1024);
+        assert_eq!(a.block_size, 4096);
+        assert_eq!(a.attr_ttl, Duration::from_millis(500));
+        assert_eq!(a.entry_ttl, Duration::from_secs(1));
+
+        let b = &cfg.mounts[1].config;
+        assert!(b.no_cache);
+        assert_eq!(b.cache_size, 1024 * 1024 * 1024);
+        assert_eq!(b.readahead, 4);
+
+        assert_eq!(cfg.operator("local")?.info().scheme(), Scheme::Fs);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_config_file_with_unknown_profile() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("config.toml");
+        fs::write(
+            &path,
+            r#"
+[[mounts]]
+path = "/mnt/a"
+profile = "unknown"
+"#,
+        )?;
+
+        let err = ConfigFile::load(&path).unwrap_err();
+        assert!(err.to_string().contains("profile unknown"));
+        Ok(())
+    }
+}
@@ -15,111 +15,359 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod config;
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+mod status;
+
+use std::fs::OpenOptions;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::path::PathBuf;
+
 use anyhow::anyhow;
+use anyhow::Context;
 use anyhow::Result;
 use clap::Parser;
+use config::MountConfig;
 use url::Url;
 
 #[derive(Parser, Debug)]
 #[command(version, about)]
+#[cfg_attr(not(any(target_os = "linux", target_os = "freebsd")), allow(dead_code))]
 struct Config {
     /// fuse mount path
-    #[arg(env = "OFS_MOUNT_PATH", index = 1)]
-    mount_path: String,
+    #[arg(env = "OFS_MOUNT_PATH", index = 1, required_unless_present = "config")]
+    mount_path: Option<String>,
 
     /// location of opendal service
     /// format: <scheme>://?<key>=<value>&<key>=<value>
     /// example: fs://?root=/tmp
-    #[arg(env = "OFS_BACKEND", index = 2)]
-    backend: Url,
+    #[arg(env = "OFS_BACKEND", index = 2, required_unless_present = "config")]
+    backend: Option<Url>,
+
+    /// config file to mount all backends listed in it, mount options are read from the file
+    #[arg(short, long, env = "OFS_CONFIG", conflicts_with_all = ["mount_path", "backend"])]
+    config: Option<PathBuf>,
+
+    /// address to serve the status endpoint, like 127.0.0.1:9500
+    #[arg(long)]
+    status_addr: Option<SocketAddr>,
+
+    /// run in background, use `--log-file` to keep logs
+    #[arg(short, long)]
+    daemon: bool,
+
+    /// file to write the pid of the daemon
+    #[arg(long, requires = "daemon")]
+    pid_file: Option<PathBuf>,
+
+    /// file to append logs to instead of stderr
+    #[arg(long)]
+    log_file: Option<PathBuf>,
+
+    #[command(flatten)]
+    mount: MountConfig,
 }
 
-#[tokio::main(flavor = "multi_thread")]
-async fn main() -> Result<()> {
+fn main() -> Result<()> {
     let cfg = Config::parse();
 
-    env_logger::init();
-    execute(cfg).await
+    init_logger(cfg.log_file.as_deref())?;
+    run(cfg)
 }
 
+fn init_logger(log_file: Option<&Path>) -> Result<()> {
+    let mut builder = env_logger::Builder::from_default_env();
+    if let Some(path) = log_file {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("failed to open log file {}", path.display()))?;
+        builder.target(env_logger::Target::Pipe(Box::new(file)));
+    }
+    builder.init();
+    Ok(())
+}
+
+fn runtime() -> Result<tokio::runtime::Runtime> {
+    Ok(tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?)
+}
+
+/// A backend to be mounted.
 #[cfg(any(target_os = "linux", target_os = "freebsd"))]
-async fn execute(cfg: Config) -> Result<()> {
-    use std::env;
+struct MountTarget {
+    path: PathBuf,
+    /// Name of the backend, which is the profile name or the scheme.
+    name: String,
+    backend: opendal::Operator,
+    config: MountConfig,
+}
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn run(cfg: Config) -> Result<()> {
+    let daemon = cfg.daemon;
+    let pid_file = cfg.pid_file.clone();
+    // Load all mounts before running in background so that errors could be reported.
+    let (mounts, status_addr) = load_mounts(cfg)?;
+
+    if daemon {
+        // Keep current dir so that relative paths still work.
+        nix::unistd::daemon(true, false).context("failed to run in background")?;
+        if let Some(path) = &pid_file {
+            std::fs::write(path, format!("{}\n", std::process::id()))
+                .with_context(|| format!("failed to write pid file {}", path.display()))?;
+        }
+    }
+
+    let res = runtime()?.block_on(execute(mounts, status_addr));
+    if let Some(path) = &pid_file {
+        let _ = std::fs::remove_file(path);
+    }
+    res
+}
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn load_mounts(cfg: Config) -> Result<(Vec<MountTarget>, Option<SocketAddr>)> {
     use std::str::FromStr;
 
-    use fuse3::path::Session;
-    use fuse3::MountOptions;
+    use config::ConfigFile;
     use opendal::Operator;
     use opendal::Scheme;
 
-    if cfg.backend.has_host() {
+    if let Some(path) = &cfg.config {
+        let file = ConfigFile::load(path)?;
+        let backends = file
+            .mounts
+            .iter()
+            .map(|m| file.operator(&m.profile))
+            .collect::<Result<Vec<_>>>()?;
+        let mounts = file
+            .mounts
+            .into_iter()
+            .zip(backends)
+            .map(|(m, backend)| MountTarget {
+                path: m.path,
+                name: m.profile,
+                backend,
+                config: m.config,
+            })
+            .collect();
+        return Ok((mounts, cfg.status_addr.or(file.status_addr)));
+    }
+
+    let (Some(mount_path), Some(backend)) = (cfg.mount_path, cfg.backend) else {
+        return Err(anyhow!("mount path and backend are required"));
+    };
+    if backend.has_host() {
         log::warn!("backend host will be ignored");
     }
 
-    let scheme_str = cfg.backend.scheme();
-    let op_args = cfg.backend.query_pairs().into_owned();
+    let scheme_str = backend.scheme();
+    let op_args = backend.query_pairs().into_owned();
 
     let scheme = match Scheme::from_str(scheme_str) {
         Ok(Scheme::Custom(_)) | Err(_) => Err(anyhow!("invalid scheme: {}", scheme_str)),
         Ok(s) => Ok(s),
     }?;
-    let backend = Operator::via_iter(scheme, op_args)?;
+    let mount = MountTarget {
+        path: mount_path.into(),
+        name: scheme_str.to_string(),
+        backend: Operator::via_iter(scheme, op_args)?,
+        config: cfg.mount,
+    };
+    Ok((vec![mount], cfg.status_addr))
+}
 
-    let mut mount_options = MountOptions::default();
-    let mut gid = nix::unistd::getgid().into();
-    mount_options.gid(gid);
-    let mut uid = nix::unistd::getuid().into();
-    mount_options.uid(uid);
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+async fn execute(mounts: Vec<MountTarget>, status_addr: Option<SocketAddr>) -> Result<()> {
+    use futures_util::future::select_all;
+    use tokio::signal::unix::signal;
+    use tokio::signal::unix::SignalKind;
 
-    #[cfg(target_os = "linux")]
-    let mut mount_handle = if nix::unistd::getuid().is_root() {
+    let mut handles = Vec::with_capacity(mounts.len());
+    let mut stats = Vec::with_capacity(mounts.len());
+    for target in mounts {
+        let path = target.path.display().to_string();
+        let name = target.name.clone();
+        match mount(target).await {
+            Ok((handle, fs_stats)) => {
+                log::info!("mounted {} on {}", name, path);
+                stats.push(status::MountStats {
+                    path: path.clone(),
+                    backend: name,
+                    stats: fs_stats,
+                });
+                handles.push((path, handle));
+            }
+            Err(err) => {
+                unmount_all(handles).await;
+                return Err(err.context(format!("failed to mount {path}")));
+            }
+        }
+    }
+
+    if let Some(addr) = status_addr {
+        if let Err(err) = status::serve(addr, stats).await {
+            unmount_all(handles).await;
+            return Err(err);
+        }
+    }
+
+    let mut terminate = signal(SignalKind::terminate())?;
+    while !handles.is_empty() {
+        // Mounts could be unmounted by others like `fusermount -u`, keep serving the rest.
+        let finished = tokio::select! {
+            (res, index, _) = select_all(handles.iter_mut().map(|(_, h)| h)) => Some((index, res)),
+            _ = tokio::signal::ctrl_c() => None,
+            _ = terminate.recv() => None,
+        };
+        let Some((index, res)) = finished else {
+            break;
+        };
+
+        let (path, _) = handles.remove(index);
+        match res {
+            Ok(()) => log::info!("{} is unmounted", path),
+            Err(err) => log::error!("{} is stopped: {}", path, err),
+        }
+    }
+
+    unmount_all(handles).await;
+    Ok(())
+}
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+async fn mount(
+    target: MountTarget,
+) -> Result<(
+    fuse3::raw::MountHandle,
+    std::sync::Arc<fuse3_opendal::Stats>,
+)> {
+    use std::env;
+
+    use fuse3::path::Session;
+    use fuse3::MountOptions;
+
+    let cfg = &target.config;
+    let mut uid = nix::unistd::getuid().into();
+    let mut gid = nix::unistd::getgid().into();
+    // Files are owned by the user running `sudo ofs` instead of root.
+    if nix::unistd::getuid().is_root() {
         if let Some(sudo_gid) = env::var("SUDO_GID")
             .ok()
             .and_then(|gid_str| gid_str.parse::<u32>().ok())
         {
-            mount_options.gid(sudo_gid);
             gid = sudo_gid;
         }
 
         if let Some(sudo_uid) = env::var("SUDO_UID")
             .ok()
-            .and_then(|gid_str| gid_str.parse::<u32>().ok())
+            .and_then(|uid_str| uid_str.parse::<u32>().ok())
         {
-            mount_options.uid(uid);
             uid = sudo_uid;
         }
+    }
+    let uid = cfg.uid.unwrap_or(uid);
+    let gid = cfg.gid.unwrap_or(gid);
 
-        let fs = fuse3_opendal::Filesystem::new(backend, uid, gid);
-        Session::new(mount_options)
-            .mount(fs, cfg.mount_path)
-            .await?
+    let mut mount_options = MountOptions::default();
+    mount_options.uid(uid);
+    mount_options.gid(gid);
+    for option in &cfg.options {
+        set_mount_option(&mut mount_options, option)?;
+    }
+
+    let fs = new_filesystem(target.backend, uid, gid, cfg)?;
+    let stats = fs.stats();
+
+    #[cfg(target_os = "linux")]
+    let mount_handle = if nix::unistd::getuid().is_root() {
+        Session::new(mount_options).mount(fs, &target.path).await?
     } else {
-        let fs = fuse3_opendal::Filesystem::new(backend, uid, gid);
         Session::new(mount_options)
-            .mount_with_unprivileged(fs, cfg.mount_path)
+            .mount_with_unprivileged(fs, &target.path)
             .await?
     };
 
     #[cfg(target_os = "freebsd")]
-    let mut mount_handle = Fuse::new().mount(cfg.mount_path, backend).await?;
+    let mount_handle = Session::new(mount_options).mount(fs, &target.path).await?;
 
-    let handle = &mut mount_handle;
-    tokio::select! {
-        res = handle => res?,
-        _ = tokio::signal::ctrl_c() => {
-            mount_handle.unmount().await?
+    Ok((mount_handle, stats))
+}
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+async fn unmount_all(handles: Vec<(String, fuse3::raw::MountHandle)>) {
+    for (path, handle) in handles {
+        match handle.unmount().await {
+            Ok(()) => log::info!("unmounted {}", path),
+            Err(err) => log::error!("failed to unmount {}: {}", path, err),
         }
     }
+}
 
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn set_mount_option(mount_options: &mut fuse3::MountOptions, option: &str) -> Result<()> {
+    match option.split_once('=') {
+        Some(("fsname", name)) => mount_options.fs_name(name),
+        Some(_) => return Err(anyhow!("unsupported mount option: {}", option)),
+        None => match option {
+            "allow_other" => mount_options.allow_other(true),
+            "allow_root" => mount_options.allow_root(true),
+            "default_permissions" => mount_options.default_permissions(true),
+            "nonempty" => mount_options.nonempty(true),
+            "ro" => mount_options.read_only(true),
+            "rw" => mount_options.read_only(false),
+            _ => return Err(anyhow!("unsupported mount option: {}", option)),
+        },
+    };
     Ok(())
 }
 
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+fn new_filesystem(
+    backend: opendal::Operator,
+    uid: u32,
+    gid: u32,
+    cfg: &MountConfig,
+) -> Result<fuse3_opendal::Filesystem> {
+    let mut fs = fuse3_opendal::Filesystem::new(backend, uid, gid)
+        .attr_ttl(cfg.attr_ttl)
+        .entry_ttl(cfg.entry_ttl);
+    if cfg.no_cache {
+        return Ok(fs);
+    }
+
+    if cfg.block_size == 0 {
+        return Err(anyhow!("block size must be greater than 0"));
+    }
+    let dir = cfg.cache_dir.clone().unwrap_or_else(std::env::temp_dir);
+    let cache = fuse3_opendal::Cache::new(&dir)
+        .with_context(|| format!("failed to create cache in {}", dir.display()))?
+        .capacity(cfg.cache_size)
+        .block_size(cfg.block_size)
+        .readahead(cfg.readahead)
+        .write_back(!cfg.no_write_back);
+    fs = fs.cache(cache);
+    Ok(fs)
+}
+
+#[cfg(target_os = "windows")]
+fn run(cfg: Config) -> Result<()> {
+    if cfg.config.is_some() || cfg.daemon {
+        return Err(anyhow!(
+            "config file and daemon mode are not supported on windows"
+        ));
+    }
+    runtime()?.block_on(execute(cfg))
+}
+
 #[cfg(target_os = "windows")]
 async fn execute(cfg: Config) -> Result<()> {
-    use std::path::PathBuf;
     use std::str::FromStr;
 
-    use anyhow::Context;
     use cloud_filter::root::HydrationType;
     use cloud_filter::root::PopulationType;
     use cloud_filter::root::SecurityId;
@@ -133,12 +381,15 @@ async fn execute(cfg: Config) -> Result<()> {
 
     const PROVIDER_NAME: &str = "ofs";
 
-    if cfg.backend.has_host() {
+    let (Some(mount_path), Some(backend_url)) = (cfg.mount_path, cfg.backend) else {
+        return Err(anyhow!("mount path and backend are required"));
+    };
+    if backend_url.has_host() {
         log::warn!("backend host will be ignored");
     }
 
-    let scheme_str = cfg.backend.scheme();
-    let op_args = cfg.backend.query_pairs().into_owned();
+    let scheme_str = backend_url.scheme();
+    let op_args = backend_url.query_pairs().into_owned();
 
     let scheme = match Scheme::from_str(scheme_str) {
         Ok(Scheme::Custom(_)) | Err(_) => Err(anyhow!("invalid scheme: {}", scheme_str)),
@@ -166,7 +417,7 @@ async fn execute(cfg: Config) -> Result<()> {
                     .with_version(env!("CARGO_PKG_VERSION"))
                     .with_recycle_bin_uri("http://cloudmirror.example.com/recyclebin") // FIXME
                     .unwrap()
-                    .with_path(&cfg.mount_path)
+                    .with_path(&mount_path)
                     .context("mount_path is not a folder")?,
             )
             .context("failed to register sync root")?;
@@ -175,8 +426,8 @@ async fn execute(cfg: Config) -> Result<()> {
     let handle = Handle::current();
     let connection = Session::new()
         .connect_async(
-            &cfg.mount_path,
-            cloudfilter_opendal::CloudFilter::new(backend, PathBuf::from(&cfg.mount_path)),
+            &mount_path,
+            cloudfilter_opendal::CloudFilter::new(backend, PathBuf::from(&mount_path)),
             move |f| handle.clone().block_on(f),
         )
         .context("failed to connect to sync root")?;
@@ -192,6 +443,6 @@ async fn execute(cfg: Config) -> Result<()> {
 }
 
 #[cfg(not(any(target_os = "linux", target_os = "freebsd", target_os = "windows")))]
-async fn execute(_cfg: Config) -> Result<()> {
+fn run(_cfg: Config) -> Result<()> {
     Err(anyhow!("platform not supported"))
 }
@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Status endpoint reporting runtime statistics of mounts.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use axum::extract::State;
+use axum::routing::get;
+use axum::Json;
+use axum::Router;
+use fuse3_opendal::Stats;
+use serde::Serialize;
+use tokio::net::TcpListener;
+
+/// A mounted filesystem to report.
+pub struct MountStats {
+    pub path: String,
+    pub backend: String,
+    pub stats: Arc<Stats>,
+}
+
+#[derive(Serialize)]
+struct Status {
+    mounts: Vec<MountStatus>,
+}
+
+#[derive(Serialize)]
+struct MountStatus {
+    path: String,
+    backend: String,
+    in_flight: u64,
+    opened_files: u64,
+    cache_hits: u64,
+    cache_misses: u64,
+    cache_hit_ratio: f64,
+    cache_used: u64,
+}
+
+/// Bind the status endpoint on given address and serve it in background.
+pub async fn serve(addr: SocketAddr, mounts: Vec<MountStats>) -> Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("failed to bind status endpoint on {addr}"))?;
+    let app = Router::new()
+        .route("/status", get(status))
+        .with_state(Arc::new(mounts));
+
+    log::info!("status endpoint is serving on http://{addr}/status");
+    tokio::spawn(async move {
+        if let Err(err) = axum::serve(listener, app).await {
+            log::error!("status endpoint stopped: {err}");
+        }
+    });
+    Ok(())
+}
+
+async fn status(State(mounts): State<Arc<Vec<MountStats>>>) -> Json<Status> {
+    Json(Status {
+        mounts: mounts
+            .iter()
+            .map(|m| MountStatus {
+                path: m.path.clone(),
+                backend: m.backend.clone(),
+                in_flight: m.stats.in_flight(),
+                opened_files: m.stats.opened_files(),
+                cache_hits: m.stats.cache_hits(),
+                cache_misses: m.stats.cache_misses(),
+                cache_hit_ratio: m.stats.cache_hit_ratio(),
+                cache_used: m.stats.cache_used(),
+            })
+            .collect(),
+    })
+}
@@ -33,6 +33,7 @@ pub struct OfsTestContext {
     // This is a false positive, the field is used in the test.
     #[allow(dead_code)]
     pub capability: Capability,
+    cache_dir: TempDir,
     mount_handle: fuse3::raw::MountHandle,
 }
 
@@ -48,6 +49,8 @@ impl TestContext for OfsTestContext {
 
         let mount_point = tempfile::tempdir().unwrap();
         let mount_point_str = mount_point.path().to_string_lossy().to_string();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache = fuse3_opendal::Cache::new(cache_dir.path()).expect("build cache");
         let mount_handle = RUNTIME
             .get_or_init(|| {
                 runtime::Builder::new_multi_thread()
@@ -64,7 +67,7 @@ impl TestContext for OfsTestContext {
                     let uid = nix::unistd::getuid().into();
                     mount_options.uid(uid);
 
-                    let fs = fuse3_opendal::Filesystem::new(backend, uid, gid);
+                    let fs = fuse3_opendal::Filesystem::new(backend, uid, gid).cache(cache);
                     fuse3::path::Session::new(mount_options)
                         .mount_with_unprivileged(fs, mount_point_str)
                         .await
@@ -75,6 +78,7 @@ impl TestContext for OfsTestContext {
         OfsTestContext {
             mount_point,
             capability,
+            cache_dir,
             mount_handle,
         }
     }
@@ -86,5 +90,6 @@ impl TestContext for OfsTestContext {
             .expect("runtime")
             .block_on(async move { self.mount_handle.unmount().await });
         let _ = self.mount_point.close();
+        let _ = self.cache_dir.close();
     }
 }
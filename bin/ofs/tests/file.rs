@@ -124,3 +124,54 @@ fn test_file_truncate(ctx: &mut OfsTestContext) {
 
     fs::remove_file(path).unwrap();
 }
+
+#[test_context(OfsTestContext)]
+#[test]
+fn test_file_random_write(ctx: &mut OfsTestContext) {
+    let path = ctx.mount_point.path().join("test_file_random_write.txt");
+    let mut file = File::create(&path).unwrap();
+    file.write_all(TEST_TEXT.as_bytes()).unwrap();
+    drop(file);
+
+    let mut file = OpenOptions::new().write(true).open(&path).unwrap();
+    file.seek(SeekFrom::Start(TEST_TEXT.len() as u64 / 2))
+        .unwrap();
+    file.write_all(b"test").unwrap();
+    file.seek(SeekFrom::Start(0)).unwrap();
+    file.write_all(b"test").unwrap();
+    drop(file);
+
+    let mut expected = TEST_TEXT.as_bytes().to_vec();
+    expected[TEST_TEXT.len() / 2..TEST_TEXT.len() / 2 + 4].copy_from_slice(b"test");
+    expected[..4].copy_from_slice(b"test");
+    assert_eq!(fs::read(&path).unwrap(), expected);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test_context(OfsTestContext)]
+#[test]
+fn test_file_set_len(ctx: &mut OfsTestContext) {
+    let path = ctx.mount_point.path().join("test_file_set_len.txt");
+    let mut file = File::create(&path).unwrap();
+    file.write_all(TEST_TEXT.as_bytes()).unwrap();
+    drop(file);
+
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&path)
+        .unwrap();
+    file.set_len(TEST_TEXT.len() as u64 / 2).unwrap();
+    assert_eq!(file.metadata().unwrap().len(), TEST_TEXT.len() as u64 / 2);
+    file.seek(SeekFrom::End(0)).unwrap();
+    file.write_all(b"test").unwrap();
+    drop(file);
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        TEST_TEXT[..TEST_TEXT.len() / 2].to_owned() + "test"
+    );
+
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,419 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Benchmark workloads against any configured profile.
+//!
+//! The workloads and their options mirror `core/benches/ops`, but the code
+//! can't be shared: those benches are criterion harnesses that depend on the
+//! `tests` feature of opendal and its test runtime, while this command needs to
+//! run against user profiles for a given duration and report the latency
+//! distribution.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::value_parser;
+use clap::Arg;
+use clap::ArgMatches;
+use clap::Command;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::Buffer;
+use opendal::Operator;
+use rand::prelude::*;
+use tokio::task::JoinSet;
+
+use crate::config::Config;
+use crate::utils::format_size;
+use crate::utils::parse_duration;
+use crate::utils::parse_size;
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Config::load(config_path)?;
+
+    let target = args
+        .get_one::<String>("target")
+        .ok_or_else(|| anyhow!("missing target"))?;
+    let (op, path) = cfg.parse_location(target)?;
+
+    let workloads: Vec<&str> = args
+        .get_many::<String>("workload")
+        .unwrap_or_default()
+        .map(|v| v.as_str())
+        .collect();
+    let sizes = args
+        .get_many::<String>("size")
+        .unwrap_or_default()
+        .map(|v| parse_size(v))
+        .collect::<Result<Vec<_>>>()?;
+    let opts = BenchOptions {
+        parallel: *args
+            .get_one::<usize>("parallel")
+            .ok_or_else(|| anyhow!("missing parallel"))?,
+        duration: parse_duration(
+            args.get_one::<String>("duration")
+                .ok_or_else(|| anyhow!("missing duration"))?,
+        )?,
+        write_chunk: parse_usize(args, "write-chunk")?,
+        write_concurrent: *args
+            .get_one::<usize>("write-concurrent")
+            .ok_or_else(|| anyhow!("missing write-concurrent"))?,
+        read_chunk: parse_usize(args, "read-chunk")?,
+        read_concurrent: *args
+            .get_one::<usize>("read-concurrent")
+            .ok_or_else(|| anyhow!("missing read-concurrent"))?,
+        read_gap: parse_usize(args, "read-gap")?,
+        list_files: *args
+            .get_one::<usize>("list-files")
+            .ok_or_else(|| anyhow!("missing list-files"))?,
+    };
+
+    // Run all workloads in a temporary directory, which will be removed
+    // no matter the benchmark succeeds or not.
+    let path = path.strip_prefix('/').unwrap_or(&path);
+    let root = match path.ends_with('/') || path.is_empty() {
+        true => path.to_string(),
+        false => format!("{path}/"),
+    };
+    let workdir = format!("{root}oli-bench-{}/", uuid::Uuid::new_v4());
+    println!(
+        "Benchmarking {target} with {} parallel tasks for {:?} each",
+        opts.parallel, opts.duration
+    );
+
+    let bench = Bench {
+        op: op.clone(),
+        workdir: workdir.clone(),
+        opts,
+    };
+    let res = bench.run(&workloads, &sizes).await;
+    // Don't let the cleanup error hide the error of benchmark.
+    if let Err(err) = op.remove_all(&workdir).await {
+        match res {
+            Ok(()) => return Err(err.into()),
+            Err(_) => eprintln!("failed to remove {workdir}: {err}"),
+        }
+    }
+    res
+}
+
+fn parse_usize(args: &ArgMatches, name: &str) -> Result<Option<usize>> {
+    args.get_one::<String>(name)
+        .map(|v| parse_size(v).map(|v| v as usize))
+        .transpose()
+}
+
+#[derive(Clone, Copy)]
+struct BenchOptions {
+    /// Number of tasks that run the workload at the same time.
+    parallel: usize,
+    /// How long each workload runs.
+    duration: Duration,
+    write_chunk: Option<usize>,
+    write_concurrent: usize,
+    read_chunk: Option<usize>,
+    read_concurrent: usize,
+    read_gap: Option<usize>,
+    /// Number of files to prepare for the list workload.
+    list_files: usize,
+}
+
+struct Bench {
+    op: Operator,
+    workdir: String,
+    opts: BenchOptions,
+}
+
+impl Bench {
+    async fn run(&self, workloads: &[&str], sizes: &[u64]) -> Result<()> {
+        for workload in workloads {
+            match *workload {
+                "write" => {
+                    for size in sizes {
+                        self.bench_write(*size).await?.report("write", Some(*size));
+                    }
+                }
+                "read" => {
+                    for size in sizes {
+                        self.bench_read(*size).await?.report("read", Some(*size));
+                    }
+                }
+                "stat" => self.bench_stat().await?.report("stat", None),
+                "list" => self.bench_list().await?.report("list", None),
+                v => return Err(anyhow!("unknown workload: {v}")),
+            }
+        }
+        Ok(())
+    }
+
+    async fn bench_write(&self, size: u64) -> Result<Stats> {
+        let content = gen_bytes(size as usize);
+        let (op, workdir, opts) = (self.op.clone(), self.workdir.clone(), self.opts);
+        self.run_workload(size, move |task| {
+            let (op, content) = (op.clone(), content.clone());
+            let path = format!("{workdir}write-{size}-{task}");
+            async move {
+                let mut w = op.writer_with(&path).concurrent(opts.write_concurrent);
+                if let Some(chunk) = opts.write_chunk {
+                    w = w.chunk(chunk);
+                }
+                let mut w = w.await?;
+                w.write(content).await?;
+                w.close().await?;
+                Ok(())
+            }
+        })
+        .await
+    }
+
+    async fn bench_read(&self, size: u64) -> Result<Stats> {
+        let path = format!("{}read-{size}", self.workdir);
+        self.op.write(&path, gen_bytes(size as usize)).await?;
+
+        let (op, opts) = (self.op.clone(), self.opts);
+        self.run_workload(size, move |_| {
+            let (op, path) = (op.clone(), path.clone());
+            async move {
+                let mut r = op.reader_with(&path).concurrent(opts.read_concurrent);
+                if let Some(chunk) = opts.read_chunk {
+                    r = r.chunk(chunk);
+                }
+                if let Some(gap) = opts.read_gap {
+                    r = r.gap(gap);
+                }
+                let bs = r.await?.read(0..size).await?;
+                if bs.len() as u64 != size {
+                    return Err(anyhow!("expect {size} bytes but got {}", bs.len()));
+                }
+                Ok(())
+            }
+        })
+        .await
+    }
+
+    async fn bench_stat(&self) -> Result<Stats> {
+        let path = format!("{}stat", self.workdir);
+        self.op.write(&path, gen_bytes(1024)).await?;
+
+        let op = self.op.clone();
+        self.run_workload(0, move |_| {
+            let (op, path) = (op.clone(), path.clone());
+            async move {
+                op.stat(&path).await?;
+                Ok(())
+            }
+        })
+        .await
+    }
+
+    async fn bench_list(&self) -> Result<Stats> {
+        let dir = format!("{}list/", self.workdir);
+        futures::stream::iter(0..self.opts.list_files)
+            .map(|i| {
+                let path = format!("{dir}{i}");
+                async move { self.op.write(&path, Buffer::new()).await }
+            })
+            .buffer_unordered(16)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let (op, files) = (self.op.clone(), self.opts.list_files);
+        self.run_workload(0, move |_| {
+            let (op, dir) = (op.clone(), dir.clone());
+            async move {
+                let entries = op.list(&dir).await?;
+                // Some services will return the dir itself.
+                if entries.len() < files {
+                    return Err(anyhow!("expect {files} entries but got {}", entries.len()));
+                }
+                Ok(())
+            }
+        })
+        .await
+    }
+
+    /// Run `f` in `parallel` spawned tasks repeatedly until `duration` passed.
+    async fn run_workload<F, Fut>(&self, bytes_per_op: u64, f: F) -> Result<Stats>
+    where
+        F: Fn(usize) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let f = Arc::new(f);
+        let (start, duration) = (Instant::now(), self.opts.duration);
+        let mut tasks = JoinSet::new();
+        for task in 0..self.opts.parallel {
+            let f = f.clone();
+            tasks.spawn(async move {
+                let mut latencies = Vec::new();
+                while start.elapsed() < duration {
+                    let now = Instant::now();
+                    f(task).await?;
+                    latencies.push(now.elapsed());
+                }
+                Ok::<_, anyhow::Error>(latencies)
+            });
+        }
+        let mut latencies = Vec::new();
+        while let Some(res) = tasks.join_next().await {
+            match res.map_err(anyhow::Error::from).and_then(|v| v) {
+                Ok(v) => latencies.extend(v),
+                Err(err) => {
+                    // Stop the other tasks before their files are cleaned up.
+                    tasks.shutdown().await;
+                    return Err(err);
+                }
+            }
+        }
+        Ok(Stats::new(latencies, start.elapsed(), bytes_per_op))
+    }
+}
+
+fn gen_bytes(size: usize) -> Buffer {
+    let mut content = vec![0; size];
+    thread_rng().fill_bytes(&mut content);
+    content.into()
+}
+
+struct Stats {
+    /// Sorted latencies of all operations.
+    latencies: Vec<Duration>,
+    elapsed: Duration,
+    bytes_per_op: u64,
+}
+
+impl Stats {
+    fn new(mut latencies: Vec<Duration>, elapsed: Duration, bytes_per_op: u64) -> Self {
+        latencies.sort();
+        Self {
+            latencies,
+            elapsed,
+            bytes_per_op,
+        }
+    }
+
+    fn percentile(&self, p: f64) -> Duration {
+        if self.latencies.is_empty() {
+            return Duration::ZERO;
+        }
+        let idx = ((self.latencies.len() - 1) as f64 * p).round() as usize;
+        self.latencies[idx]
+    }
+
+    fn report(&self, workload: &str, size: Option<u64>) {
+        let ops = self.latencies.len();
+        let secs = self.elapsed.as_secs_f64();
+        let name = match size {
+            Some(size) => format!("{workload} {}", format_size(size)),
+            None => workload.to_string(),
+        };
+        let mut line = format!("{name}: {ops} ops, {:.2} ops/s", ops as f64 / secs);
+        if self.bytes_per_op > 0 {
+            let mib = (ops as u64 * self.bytes_per_op) as f64 / 1024.0 / 1024.0;
+            line.push_str(&format!(", {:.2} MiB/s", mib / secs));
+        }
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        line.push_str(&format!(
+            ", latency p50 {:.2}ms, p90 {:.2}ms, p99 {:.2}ms, max {:.2}ms",
+            ms(self.percentile(0.5)),
+            ms(self.percentile(0.9)),
+            ms(self.percentile(0.99)),
+            ms(self.percentile(1.0)),
+        ));
+        println!("{line}");
+    }
+}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("benchmark read, write, stat and list against target")
+        .arg(Arg::new("target").required(true))
+        .arg(
+            Arg::new("workload")
+                .long("workload")
+                .short('w')
+                .help("Workloads to run, can be repeated")
+                .value_parser(["write", "read", "stat", "list"])
+                .value_delimiter(',')
+                .default_value("write,read,stat,list"),
+        )
+        .arg(
+            Arg::new("size")
+                .long("size")
+                .short('s')
+                .help("Object sizes for read and write workloads, like `4K` and `4M`")
+                .value_delimiter(',')
+                .default_value("4K,256K,4M"),
+        )
+        .arg(
+            Arg::new("parallel")
+                .long("parallel")
+                .short('p')
+                .help("Number of tasks that run the workload at the same time")
+                .default_value("1")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("duration")
+                .long("duration")
+                .short('d')
+                .help("How long each workload runs, like `10s` and `1m`")
+                .default_value("10s"),
+        )
+        .arg(
+            Arg::new("write-chunk")
+                .long("write-chunk")
+                .help("Chunk size of writer, like `8M`"),
+        )
+        .arg(
+            Arg::new("write-concurrent")
+                .long("write-concurrent")
+                .help("Concurrent of writer")
+                .default_value("1")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("read-chunk")
+                .long("read-chunk")
+                .help("Chunk size of reader, like `4M`"),
+        )
+        .arg(
+            Arg::new("read-concurrent")
+                .long("read-concurrent")
+                .help("Concurrent of reader")
+                .default_value("1")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("read-gap")
+                .long("read-gap")
+                .help("Gap size of reader to merge ranges, like `1M`"),
+        )
+        .arg(
+            Arg::new("list-files")
+                .long("list-files")
+                .help("Number of files to prepare for the list workload")
+                .default_value("100")
+                .value_parser(value_parser!(usize)),
+        )
+}
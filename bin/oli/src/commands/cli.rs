@@ -22,11 +22,23 @@ use clap::Command;
 
 pub async fn main(args: &ArgMatches) -> Result<()> {
     match args.subcommand() {
+        Some(("bench", sub_args)) => super::bench::main(sub_args).await?,
         Some(("cat", sub_args)) => super::cat::main(sub_args).await?,
+        Some(("config", sub_args)) => super::config::main(sub_args).await?,
         Some(("cp", sub_args)) => super::cp::main(sub_args).await?,
+        Some(("du", sub_args)) => super::du::main(sub_args).await?,
+        Some(("find", sub_args)) => super::find::main(sub_args).await?,
+        Some(("head", sub_args)) => super::head::main(sub_args).await?,
         Some(("ls", sub_args)) => super::ls::main(sub_args).await?,
+        Some(("mkdir", sub_args)) => super::mkdir::main(sub_args).await?,
+        Some(("mv", sub_args)) => super::mv::main(sub_args).await?,
+        Some(("presign", sub_args)) => super::presign::main(sub_args).await?,
         Some(("rm", sub_args)) => super::rm::main(sub_args).await?,
+        Some(("shell", sub_args)) => super::shell::main(sub_args).await?,
         Some(("stat", sub_args)) => super::stat::main(sub_args).await?,
+        Some(("sync", sub_args)) => super::sync::main(sub_args).await?,
+        Some(("tail", sub_args)) => super::tail::main(sub_args).await?,
+        Some(("tee", sub_args)) => super::tee::main(sub_args).await?,
         _ => return Err(anyhow!("not handled")),
     }
 
@@ -39,9 +51,21 @@ fn new_cmd(name: &'static str) -> Command {
 
 pub fn cli(cmd: Command) -> Command {
     cmd.about("OpenDAL Command Line Interface")
+        .subcommand(super::bench::cli(new_cmd("bench")))
         .subcommand(super::cat::cli(new_cmd("cat")))
+        .subcommand(super::config::cli(new_cmd("config")))
         .subcommand(super::cp::cli(new_cmd("cp")))
+        .subcommand(super::du::cli(new_cmd("du")))
+        .subcommand(super::find::cli(new_cmd("find")))
+        .subcommand(super::head::cli(new_cmd("head")))
         .subcommand(super::ls::cli(new_cmd("ls")))
+        .subcommand(super::mkdir::cli(new_cmd("mkdir")))
+        .subcommand(super::mv::cli(new_cmd("mv")))
+        .subcommand(super::presign::cli(new_cmd("presign")))
         .subcommand(super::rm::cli(new_cmd("rm")))
+        .subcommand(super::shell::cli(new_cmd("shell")))
         .subcommand(super::stat::cli(new_cmd("stat")))
+        .subcommand(super::sync::cli(new_cmd("sync")))
+        .subcommand(super::tail::cli(new_cmd("tail")))
+        .subcommand(super::tee::cli(new_cmd("tee")))
 }
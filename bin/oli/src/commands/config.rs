@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::Arg;
+use clap::ArgMatches;
+use clap::Command;
+
+use crate::config::Config;
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Config::load(config_path)?;
+
+    match args.subcommand() {
+        Some(("list", _)) => {
+            for name in cfg.profile_names() {
+                let profile = cfg.raw_profile(name)?;
+                let svc = profile.get("type").map_or("<unknown>", |v| v.as_str());
+                println!("{name}\t{svc}");
+            }
+        }
+        Some(("show", sub_args)) => {
+            let name = sub_args
+                .get_one::<String>("profile")
+                .ok_or_else(|| anyhow!("missing profile"))?;
+            let profile = cfg.raw_profile(name)?;
+            let mut keys: Vec<_> = profile.keys().collect();
+            keys.sort();
+            println!("[profiles.{name}]");
+            for key in keys {
+                println!("{key} = {:?}", display_value(key, &profile[key]));
+            }
+        }
+        Some(("validate", sub_args)) => {
+            let names = match sub_args.get_one::<String>("profile") {
+                Some(name) => vec![name.as_str()],
+                None => cfg.profile_names(),
+            };
+            let mut failed = 0;
+            for name in names {
+                let res = match cfg.operator(name) {
+                    Ok(op) => op.check().await.map_err(|err| err.into()),
+                    Err(err) => Err(err),
+                };
+                match res {
+                    Ok(()) => println!("{name}: ok"),
+                    Err(err) => {
+                        println!("{name}: {err}");
+                        failed += 1;
+                    }
+                }
+            }
+            if failed > 0 {
+                return Err(anyhow!("{failed} profiles are invalid"));
+            }
+        }
+        _ => return Err(anyhow!("not handled")),
+    }
+    Ok(())
+}
+
+/// Hide values of sensitive options.
+///
+/// References are hidden too, since `cmd://` may carry secrets in its arguments.
+fn display_value<'a>(key: &str, value: &'a str) -> &'a str {
+    let key = key.to_lowercase();
+    let is_sensitive = ["secret", "password", "token", "credential", "private"]
+        .iter()
+        .any(|v| key.contains(v))
+        || key.ends_with("_key");
+    let is_reference = ["env://", "file://", "cmd://"]
+        .iter()
+        .any(|v| value.starts_with(v));
+    match is_sensitive || is_reference {
+        true => "******",
+        false => value,
+    }
+}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("manage profiles in config")
+        .subcommand_required(true)
+        .subcommand(Command::new("list").about("list all profiles"))
+        .subcommand(
+            Command::new("show")
+                .about("show options of profile with secrets hidden")
+                .arg(Arg::new("profile").required(true)),
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("check that profiles can be accessed, all profiles by default")
+                .arg(Arg::new("profile").required(false)),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_value() {
+        assert_eq!(display_value("bucket", "test"), "test");
+        assert_eq!(display_value("access_key_id", "id"), "id");
+        assert_eq!(display_value("secret_access_key", "secret"), "******");
+        assert_eq!(display_value("account_key", "key"), "******");
+        assert_eq!(display_value("password", "env://PASSWORD"), "******");
+        assert_eq!(display_value("token", "cmd://echo token"), "******");
+        assert_eq!(
+            display_value("endpoint", "cmd://get-endpoint --key x"),
+            "******"
+        );
+        assert_eq!(display_value("region", "env://REGION"), "******");
+        assert_eq!(display_value("root", "file:///etc/root"), "******");
+    }
+}
@@ -0,0 +1,121 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::Arg;
+use clap::ArgAction;
+use clap::ArgMatches;
+use clap::Command;
+use futures::TryStreamExt;
+use opendal::Metakey;
+
+use crate::config::Config;
+use crate::utils::format_size;
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Config::load(config_path)?;
+    let summarize = args.get_flag("summarize");
+    let human = args.get_flag("human-readable");
+
+    let target = args
+        .get_one::<String>("target")
+        .ok_or_else(|| anyhow!("missing target"))?;
+    let (op, path) = cfg.parse_location(target)?;
+    let path = path.strip_prefix('/').unwrap_or(&path);
+    let root = match path.ends_with('/') || path.is_empty() {
+        true => path.to_string(),
+        false => format!("{path}/"),
+    };
+
+    // Sizes of every directory directly under root, and the total.
+    let mut dirs: BTreeMap<String, Usage> = BTreeMap::new();
+    let mut total = Usage::default();
+    let mut ds = op
+        .lister_with(&root)
+        .recursive(true)
+        .metakey(Metakey::Mode | Metakey::ContentLength)
+        .await?;
+    while let Some(de) = ds.try_next().await? {
+        let meta = de.metadata();
+        if meta.mode().is_dir() {
+            continue;
+        }
+        let path = de.path().strip_prefix('/').unwrap_or(de.path());
+        let fp = path.strip_prefix(&root).expect("invalid path");
+        total.add(meta.content_length());
+        if let Some((dir, _)) = fp.split_once('/') {
+            dirs.entry(dir.to_string())
+                .or_default()
+                .add(meta.content_length());
+        }
+    }
+
+    if !summarize {
+        let target = target.trim_end_matches('/');
+        for (dir, usage) in &dirs {
+            usage.print(&format!("{target}/{dir}/"), human);
+        }
+    }
+    total.print(target, human);
+    Ok(())
+}
+
+#[derive(Default)]
+struct Usage {
+    files: u64,
+    bytes: u64,
+}
+
+impl Usage {
+    fn add(&mut self, size: u64) {
+        self.files += 1;
+        self.bytes += size;
+    }
+
+    fn print(&self, path: &str, human: bool) {
+        let size = match human {
+            true => format_size(self.bytes),
+            false => self.bytes.to_string(),
+        };
+        println!("{size}\t{} files\t{path}", self.files);
+    }
+}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("show disk usage of a prefix")
+        .arg(Arg::new("target").required(true))
+        .arg(
+            Arg::new("summarize")
+                .long("summarize")
+                .short('s')
+                .help("Display only the total of target")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("human-readable")
+                .long("human-readable")
+                .help("Print sizes in human readable format like 1.5K and 20M")
+                .action(ArgAction::SetTrue),
+        )
+}
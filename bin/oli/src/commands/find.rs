@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::Arg;
+use clap::ArgMatches;
+use clap::Command;
+use futures::TryStreamExt;
+use globset::Glob;
+use globset::GlobMatcher;
+use opendal::Metadata;
+use opendal::Metakey;
+
+use crate::config::Config;
+use crate::utils::parse_duration;
+use crate::utils::parse_size;
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Config::load(config_path)?;
+
+    let target = args
+        .get_one::<String>("target")
+        .ok_or_else(|| anyhow!("missing target"))?;
+    let (op, path) = cfg.parse_location(target)?;
+
+    let now = SystemTime::now();
+    let predicate = Predicate {
+        name: args
+            .get_one::<String>("name")
+            .map(|v| Glob::new(v).map(|g| g.compile_matcher()))
+            .transpose()?,
+        file_type: args.get_one::<String>("type").cloned(),
+        min_size: args
+            .get_one::<String>("min-size")
+            .map(|v| parse_size(v))
+            .transpose()?,
+        max_size: args
+            .get_one::<String>("max-size")
+            .map(|v| parse_size(v))
+            .transpose()?,
+        newer: args
+            .get_one::<String>("newer")
+            .map(|v| parse_duration(v).map(|d| now - d))
+            .transpose()?,
+        older: args
+            .get_one::<String>("older")
+            .map(|v| parse_duration(v).map(|d| now - d))
+            .transpose()?,
+    };
+
+    let mut ds = op
+        .lister_with(&path)
+        .recursive(true)
+        .metakey(Metakey::Mode | Metakey::ContentLength | Metakey::LastModified)
+        .await?;
+    while let Some(de) = ds.try_next().await? {
+        // Skip the target itself.
+        if de.path().trim_matches('/') == path.trim_matches('/') {
+            continue;
+        }
+        if predicate.matches(de.name(), de.metadata()) {
+            println!("{}", de.path());
+        }
+    }
+    Ok(())
+}
+
+/// Conditions that an entry must satisfy, all of them are optional.
+struct Predicate {
+    name: Option<GlobMatcher>,
+    file_type: Option<String>,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+    newer: Option<SystemTime>,
+    older: Option<SystemTime>,
+}
+
+impl Predicate {
+    fn matches(&self, name: &str, meta: &Metadata) -> bool {
+        if let Some(matcher) = &self.name {
+            if !matcher.is_match(name.trim_end_matches('/')) {
+                return false;
+            }
+        }
+        match self.file_type.as_deref() {
+            Some("f") if !meta.mode().is_file() => return false,
+            Some("d") if !meta.mode().is_dir() => return false,
+            _ => {}
+        }
+        // Size and time predicates only make sense for files.
+        if self.min_size.is_none()
+            && self.max_size.is_none()
+            && self.newer.is_none()
+            && self.older.is_none()
+        {
+            return true;
+        }
+        if !meta.mode().is_file() {
+            return false;
+        }
+
+        let size = meta.content_length();
+        if self.min_size.is_some_and(|v| size < v) || self.max_size.is_some_and(|v| size > v) {
+            return false;
+        }
+        if self.newer.is_none() && self.older.is_none() {
+            return true;
+        }
+        let Some(modified) = meta.last_modified().map(SystemTime::from) else {
+            return false;
+        };
+        !(self.newer.is_some_and(|v| modified < v) || self.older.is_some_and(|v| modified > v))
+    }
+}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("find objects by name, size and modified time")
+        .arg(Arg::new("target").required(true))
+        .arg(
+            Arg::new("name")
+                .long("name")
+                .help("Only show entries whose name matches this glob, like `*.txt`"),
+        )
+        .arg(
+            Arg::new("type")
+                .long("type")
+                .help("Only show files (f) or directories (d)")
+                .value_parser(["f", "d"]),
+        )
+        .arg(
+            Arg::new("min-size")
+                .long("min-size")
+                .help("Only show files not smaller than this size, like `10K` and `1M`"),
+        )
+        .arg(
+            Arg::new("max-size")
+                .long("max-size")
+                .help("Only show files not larger than this size, like `10K` and `1M`"),
+        )
+        .arg(
+            Arg::new("newer")
+                .long("newer")
+                .help("Only show files modified within this duration, like `30m` and `7d`"),
+        )
+        .arg(
+            Arg::new("older")
+                .long("older")
+                .help("Only show files modified before this duration, like `30m` and `7d`"),
+        )
+}
@@ -33,9 +33,21 @@
 //! }
 //! ```
 
+pub mod bench;
 pub mod cat;
 pub mod cli;
+pub mod config;
 pub mod cp;
+pub mod du;
+pub mod find;
+pub mod head;
 pub mod ls;
+pub mod mkdir;
+pub mod mv;
+pub mod presign;
 pub mod rm;
+pub mod shell;
 pub mod stat;
+pub mod sync;
+pub mod tail;
+pub mod tee;
@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Reverse;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::Arg;
+use clap::ArgAction;
+use clap::ArgMatches;
+use clap::Command;
+use futures::TryStreamExt;
+use opendal::Operator;
+
+use crate::config::Config;
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Config::load(config_path)?;
+    let recursive = args.get_flag("recursive");
+
+    let src = args
+        .get_one::<String>("source")
+        .ok_or_else(|| anyhow!("missing source"))?;
+    let (src_op, src_path) = cfg.parse_location(src)?;
+
+    let dst = args
+        .get_one::<String>("destination")
+        .ok_or_else(|| anyhow!("missing destination"))?;
+    let (dst_op, dst_path) = cfg.parse_location(dst)?;
+
+    // Operators built from the same profile point to the same storage, so we
+    // can move files by the server side rename or copy.
+    let (src_info, dst_info) = (src_op.info(), dst_op.info());
+    let same_storage = src_info.scheme() == dst_info.scheme()
+        && src_info.name() == dst_info.name()
+        && src_info.root() == dst_info.root();
+
+    if !recursive {
+        return move_file(&src_op, &src_path, &dst_op, &dst_path, same_storage).await;
+    }
+
+    let src_path = src_path.strip_prefix('/').unwrap_or(&src_path);
+    let src_root = match src_path.ends_with('/') || src_path.is_empty() {
+        true => src_path.to_string(),
+        false => format!("{src_path}/"),
+    };
+    let dst_path = dst_path.strip_prefix('/').unwrap_or(&dst_path);
+    let dst_root = match dst_path.ends_with('/') || dst_path.is_empty() {
+        true => dst_path.to_string(),
+        false => format!("{dst_path}/"),
+    };
+
+    if same_storage && dst_root.starts_with(&src_root) {
+        return Err(anyhow!("cannot move {src} into itself"));
+    }
+
+    // Collect all files first, since moving files while listing could
+    // confuse some services.
+    let (mut files, mut dirs) = (Vec::new(), vec![src_root.clone()]);
+    let mut ds = src_op.lister_with(&src_root).recursive(true).await?;
+    while let Some(de) = ds.try_next().await? {
+        let path = de.path().strip_prefix('/').unwrap_or(de.path());
+        if de.metadata().mode().is_dir() {
+            if path != src_root {
+                dirs.push(path.to_string());
+            }
+            continue;
+        }
+        let fp = path.strip_prefix(&src_root).expect("invalid path");
+        files.push(fp.to_string());
+    }
+
+    for fp in files {
+        let (from, to) = (format!("{src_root}{fp}"), format!("{dst_root}{fp}"));
+        move_file(&src_op, &from, &dst_op, &to, same_storage).await?;
+    }
+
+    // Remove the directories left behind from the deepest one, directories
+    // containing files created after listing will be kept.
+    dirs.sort_by_key(|v| Reverse(v.len()));
+    for dir in dirs {
+        if !dir.is_empty() && is_empty_dir(&src_op, &dir).await? {
+            src_op.delete(&dir).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn is_empty_dir(op: &Operator, dir: &str) -> Result<bool> {
+    let mut ds = op.lister(dir).await?;
+    while let Some(de) = ds.try_next().await? {
+        if de.path().strip_prefix('/').unwrap_or(de.path()) != dir {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+async fn move_file(
+    src_op: &Operator,
+    from: &str,
+    dst_op: &Operator,
+    to: &str,
+    same_storage: bool,
+) -> Result<()> {
+    println!("Moving {from} to {to}");
+    if same_storage {
+        let cap = src_op.info().full_capability();
+        if cap.rename {
+            src_op.rename(from, to).await?;
+            return Ok(());
+        }
+        if cap.copy {
+            src_op.copy(from, to).await?;
+            src_op.delete(from).await?;
+            return Ok(());
+        }
+    }
+
+    src_op
+        .transfer_with(from, dst_op, to)
+        .chunk(8 * 1024 * 1024)
+        .await?;
+    src_op.delete(from).await?;
+    Ok(())
+}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("move")
+        .arg(Arg::new("source").required(true))
+        .arg(Arg::new("destination").required(true))
+        .arg(
+            Arg::new("recursive")
+                .required(false)
+                .long("recursive")
+                .short('r')
+                .help("Move files under source recursively to destination")
+                .action(ArgAction::SetTrue),
+        )
+}
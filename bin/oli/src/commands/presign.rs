@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::value_parser;
+use clap::Arg;
+use clap::ArgMatches;
+use clap::Command;
+
+use crate::config::Config;
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Config::load(config_path)?;
+    let expire = Duration::from_secs(
+        *args
+            .get_one::<u64>("expire")
+            .ok_or_else(|| anyhow!("missing expire"))?,
+    );
+
+    let target = args
+        .get_one::<String>("target")
+        .ok_or_else(|| anyhow!("missing target"))?;
+    let (op, path) = cfg.parse_location(target)?;
+
+    let req = match args.get_one::<String>("method").map(|v| v.as_str()) {
+        Some("read") => op.presign_read(&path, expire).await?,
+        Some("write") => op.presign_write(&path, expire).await?,
+        Some("stat") => op.presign_stat(&path, expire).await?,
+        Some("delete") => op.presign_delete(&path, expire).await?,
+        _ => return Err(anyhow!("unknown method")),
+    };
+    println!("{} {}", req.method(), req.uri());
+    // Signed headers must be sent along with the request.
+    for (name, value) in req.header() {
+        println!("{name}: {}", value.to_str()?);
+    }
+    Ok(())
+}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("generate presigned request for object")
+        .arg(Arg::new("target").required(true))
+        .arg(
+            Arg::new("method")
+                .long("method")
+                .short('m')
+                .help("The operation to presign")
+                .default_value("read")
+                .value_parser(["read", "write", "stat", "delete"]),
+        )
+        .arg(
+            Arg::new("expire")
+                .long("expire")
+                .short('e')
+                .help("Expire time of the presigned request in seconds")
+                .default_value("3600")
+                .value_parser(value_parser!(u64)),
+        )
+}
@@ -0,0 +1,502 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::value_parser;
+use clap::Arg;
+use clap::ArgMatches;
+use clap::Command;
+use rustyline::completion::Completer;
+use rustyline::completion::Pair;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::Context;
+use rustyline::Editor;
+use rustyline::Helper;
+use tokio::runtime::Handle;
+use url::Url;
+
+use crate::config::resolve_relative_path;
+use crate::config::Config;
+
+const COMMANDS: [&str; 12] = [
+    "cat", "cd", "exit", "get", "help", "ls", "put", "pwd", "quit", "rm", "stat", "tee",
+];
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Arc::new(Config::load(config_path)?);
+
+    let target = args
+        .get_one::<String>("target")
+        .ok_or_else(|| anyhow!("missing target"))?;
+    let (scheme, cwd) = match target.contains(":/") {
+        true => {
+            let location = Url::parse(target)?;
+            (
+                format!("{}://", location.scheme()),
+                location.path().to_string(),
+            )
+        }
+        false => (
+            String::new(),
+            resolve_relative_path(Path::new(target))
+                .to_string_lossy()
+                .to_string(),
+        ),
+    };
+    // Make sure the target can be accessed before entering the shell.
+    cfg.parse_location(target)?;
+
+    let shell = Shell {
+        config_path: config_path.clone(),
+        state: State {
+            cfg,
+            scheme,
+            cwd: Arc::new(Mutex::new(normalize_dir(&cwd))),
+        },
+    };
+    shell.run(args.get_one::<PathBuf>("history")).await
+}
+
+/// State shared between the shell and the completer.
+#[derive(Clone)]
+struct State {
+    cfg: Arc<Config>,
+    /// The scheme part of location like `s3://`, empty for local fs.
+    scheme: String,
+    /// The absolute path of current directory, always ends with `/`.
+    cwd: Arc<Mutex<String>>,
+}
+
+impl State {
+    fn cwd(&self) -> String {
+        self.cwd.lock().unwrap().clone()
+    }
+
+    /// Resolve `path` relative to current directory into an absolute path.
+    fn resolve(&self, path: &str) -> String {
+        let base = match path.starts_with('/') {
+            true => String::new(),
+            false => self.cwd(),
+        };
+        let full = format!("{base}{path}");
+
+        let mut parts: Vec<&str> = Vec::new();
+        for part in full.split('/') {
+            match part {
+                "" | "." => {}
+                ".." => {
+                    parts.pop();
+                }
+                v => parts.push(v),
+            }
+        }
+        let mut resolved = format!("/{}", parts.join("/"));
+        let is_dir = path.is_empty() || path.ends_with('/') || path.ends_with("/.") || path == ".";
+        if (is_dir || path.ends_with("..")) && !resolved.ends_with('/') {
+            resolved.push('/');
+        }
+        resolved
+    }
+
+    /// Build the location that can be passed to other commands.
+    fn location(&self, path: &str) -> String {
+        format!("{}{}", self.scheme, self.resolve(path))
+    }
+}
+
+struct Shell {
+    config_path: PathBuf,
+    state: State,
+}
+
+impl Shell {
+    async fn run(self, history: Option<&PathBuf>) -> Result<()> {
+        let mut editor = Editor::<ShellHelper, DefaultHistory>::new()?;
+        editor.set_helper(Some(ShellHelper {
+            state: self.state.clone(),
+            handle: Handle::current(),
+        }));
+        if let Some(history) = history {
+            // History file doesn't exist for the first time.
+            let _ = editor.load_history(history);
+        }
+
+        loop {
+            let prompt = format!("{}{}> ", self.state.scheme, self.state.cwd());
+            // Completion lists entries by blocking on the runtime handle, so
+            // readline must not run in the runtime.
+            let (ed, line) = tokio::task::spawn_blocking(move || {
+                let line = editor.readline(&prompt);
+                (editor, line)
+            })
+            .await?;
+            editor = ed;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(err) => return Err(err.into()),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            editor.add_history_entry(line.as_str())?;
+
+            let Some(args) = shlex::split(&line) else {
+                eprintln!("Error: invalid quoting");
+                continue;
+            };
+            match self.execute(&args).await {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(err) => eprintln!("Error: {err}"),
+            }
+        }
+
+        if let Some(history) = history {
+            if let Some(parent) = history.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            editor.save_history(history)?;
+        }
+        Ok(())
+    }
+
+    /// Execute a command line, returns `false` if the shell should exit.
+    async fn execute(&self, args: &[String]) -> Result<bool> {
+        let (name, rest) = args.split_first().expect("args must not be empty");
+        let (flags, paths): (Vec<&String>, Vec<&String>) =
+            rest.iter().partition(|v| v.starts_with('-'));
+
+        match name.as_str() {
+            "exit" | "quit" => return Ok(false),
+            "help" => print_help(),
+            "pwd" => println!("{}", self.state.cwd()),
+            "cd" => {
+                let path = self
+                    .state
+                    .resolve(paths.first().map_or("/", |v| v.as_str()));
+                let path = normalize_dir(&path);
+                // Root always exists, and services may not support stat on it.
+                if path != "/" {
+                    let (op, p) = self.state.cfg.parse_location(&self.state.location(&path))?;
+                    if !op.stat(&p).await?.is_dir() {
+                        return Err(anyhow!("{path} is not a directory"));
+                    }
+                }
+                *self.state.cwd.lock().unwrap() = path;
+            }
+            "ls" => {
+                let mut target = self
+                    .state
+                    .location(paths.first().map_or("", |v| v.as_str()));
+                // List the content of directory even if `/` is not given.
+                if !target.ends_with('/') {
+                    let (op, p) = self.state.cfg.parse_location(&target)?;
+                    if op.stat(&p).await.is_ok_and(|v| v.is_dir()) {
+                        target.push('/');
+                    }
+                }
+                let args = self.args(super::ls::cli, "ls", [target], &flags)?;
+                super::ls::main(&args).await?;
+            }
+            "cat" => {
+                let target = self.required(name, &paths)?;
+                let args = self.args(super::cat::cli, name, [target], &flags)?;
+                super::cat::main(&args).await?;
+            }
+            "stat" => {
+                let target = self.required(name, &paths)?;
+                let args = self.args(super::stat::cli, name, [target], &flags)?;
+                super::stat::main(&args).await?;
+            }
+            "rm" => {
+                let target = self.required(name, &paths)?;
+                let args = self.args(super::rm::cli, name, [target], &flags)?;
+                super::rm::main(&args).await?;
+            }
+            "tee" => {
+                let target = self.required(name, &paths)?;
+                let args = self.args(super::tee::cli, name, [target], &flags)?;
+                super::tee::main(&args).await?;
+            }
+            "get" => {
+                let remote = paths
+                    .first()
+                    .ok_or_else(|| anyhow!("usage: get <remote> [local]"))?;
+                let local = match paths.get(1) {
+                    Some(v) => v.to_string(),
+                    None => file_name(remote)?,
+                };
+                let args = self.args(
+                    super::cp::cli,
+                    "cp",
+                    [self.state.location(remote), local],
+                    &flags,
+                )?;
+                super::cp::main(&args).await?;
+            }
+            "put" => {
+                let local = paths
+                    .first()
+                    .ok_or_else(|| anyhow!("usage: put <local> [remote]"))?;
+                let remote = match paths.get(1) {
+                    Some(v) => v.to_string(),
+                    None => file_name(local)?,
+                };
+                let args = self.args(
+                    super::cp::cli,
+                    "cp",
+                    [local.to_string(), self.state.location(&remote)],
+                    &flags,
+                )?;
+                super::cp::main(&args).await?;
+            }
+            v => return Err(anyhow!("unknown command: {v}, type `help` for help")),
+        }
+        Ok(true)
+    }
+
+    /// Get the location of the first path, which is required by the command.
+    fn required(&self, name: &str, paths: &[&String]) -> Result<String> {
+        let path = paths
+            .first()
+            .ok_or_else(|| anyhow!("usage: {name} <path>"))?;
+        Ok(self.state.location(path))
+    }
+
+    /// Build args for other commands so that we can reuse their implementations.
+    fn args(
+        &self,
+        cli: fn(Command) -> Command,
+        name: &str,
+        targets: impl IntoIterator<Item = String>,
+        flags: &[&String],
+    ) -> Result<ArgMatches> {
+        let cmd = cli(Command::new(name.to_string())).arg(
+            Arg::new("config")
+                .long("config")
+                .value_parser(value_parser!(PathBuf)),
+        );
+        let mut args = vec![name.to_string(), "--config".to_string()];
+        args.push(self.config_path.to_string_lossy().to_string());
+        args.extend(targets);
+        args.extend(flags.iter().map(|v| v.to_string()));
+        Ok(cmd.try_get_matches_from(args)?)
+    }
+}
+
+fn print_help() {
+    println!(
+        "\
+Commands:
+  ls [path] [-r]         List entries under path
+  cd [path]              Change current directory, defaults to root
+  pwd                    Print current directory
+  cat <path>             Display object content
+  stat <path>            Show object metadata
+  get <remote> [local]   Download object to local
+  put <local> [remote]   Upload local file to remote
+  tee <path> [-a]        Write stdin to object
+  rm <path> [-r]         Remove object
+  help                   Show this message
+  exit, quit             Exit the shell"
+    );
+}
+
+fn normalize_dir(path: &str) -> String {
+    match path.ends_with('/') {
+        true => path.to_string(),
+        false => format!("{path}/"),
+    }
+}
+
+fn file_name(path: &str) -> Result<String> {
+    path.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|v| !v.is_empty() && *v != "..")
+        .map(|v| v.to_string())
+        .ok_or_else(|| anyhow!("can't get file name from {path}"))
+}
+
+struct ShellHelper {
+    state: State,
+    handle: Handle,
+}
+
+impl ShellHelper {
+    /// List entries under the dir part of `word` that start with the rest.
+    fn complete_path(&self, word: &str) -> Result<Vec<Pair>> {
+        let (dir, prefix) = match word.rfind('/') {
+            Some(idx) => word.split_at(idx + 1),
+            None => ("", word),
+        };
+        let (op, path) = self.state.cfg.parse_location(&self.state.location(dir))?;
+        let entries = self.handle.block_on(op.list(&path))?;
+
+        let mut pairs: Vec<Pair> = entries
+            .iter()
+            .map(|e| e.name())
+            .filter(|name| !name.is_empty() && name.starts_with(prefix))
+            .map(|name| Pair {
+                display: name.to_string(),
+                replacement: format!("{dir}{name}"),
+            })
+            .collect();
+        pairs.sort_by(|a, b| a.display.cmp(&b.display));
+        Ok(pairs)
+    }
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let start = line[..pos].rfind(' ').map_or(0, |v| v + 1);
+        let word = &line[start..pos];
+
+        // Complete command names for the first word.
+        if line[..start].trim().is_empty() {
+            let pairs = COMMANDS
+                .iter()
+                .filter(|v| v.starts_with(word))
+                .map(|v| Pair {
+                    display: v.to_string(),
+                    replacement: format!("{v} "),
+                })
+                .collect();
+            return Ok((start, pairs));
+        }
+        // Ignore errors like permission denied during completion.
+        Ok((start, self.complete_path(word).unwrap_or_default()))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("start an interactive shell")
+        .arg(Arg::new("target").required(true))
+        .arg(
+            Arg::new("history")
+                .long("history")
+                .help("Path to the history file")
+                .value_parser(value_parser!(PathBuf))
+                .default_value(default_history_path()),
+        )
+}
+
+fn default_history_path() -> std::ffi::OsString {
+    dirs::config_dir()
+        .map(|d| d.join("oli/history"))
+        .unwrap_or_else(|| PathBuf::from(".oli_history"))
+        .into_os_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let state = State {
+            cfg: Arc::new(Config::default()),
+            scheme: "s3://".to_string(),
+            cwd: Arc::new(Mutex::new("/a/b/".to_string())),
+        };
+
+        let cases = [
+            ("", "/a/b/"),
+            ("c", "/a/b/c"),
+            ("c/", "/a/b/c/"),
+            ("..", "/a/"),
+            ("../c", "/a/c"),
+            ("./c/../d", "/a/b/d"),
+            ("/x/y", "/x/y"),
+            ("../../..", "/"),
+            (".", "/a/b/"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(state.resolve(input), expected, "resolve {input}");
+        }
+        assert_eq!(state.location("c"), "s3:///a/b/c");
+    }
+
+    #[test]
+    fn test_complete() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::create_dir_all(dir.path().join("sub"))?;
+        std::fs::write(dir.path().join("sub/abc.txt"), "a")?;
+        std::fs::write(dir.path().join("sub/abd.txt"), "a")?;
+        std::fs::write(dir.path().join("sub/x.txt"), "a")?;
+
+        let runtime = tokio::runtime::Runtime::new()?;
+        let helper = ShellHelper {
+            state: State {
+                cfg: Arc::new(Config::default()),
+                scheme: String::new(),
+                cwd: Arc::new(Mutex::new(normalize_dir(&dir.path().to_string_lossy()))),
+            },
+            handle: runtime.handle().clone(),
+        };
+        let history = DefaultHistory::new();
+        let ctx = Context::new(&history);
+
+        let (start, pairs) = helper.complete("st", 2, &ctx)?;
+        assert_eq!(start, 0);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].replacement, "stat ");
+
+        let line = "cat sub/ab";
+        let (start, pairs) = helper.complete(line, line.len(), &ctx)?;
+        assert_eq!(start, 4);
+        let names: Vec<_> = pairs.iter().map(|v| v.replacement.as_str()).collect();
+        assert_eq!(names, ["sub/abc.txt", "sub/abd.txt"]);
+
+        let (_, pairs) = helper.complete("ls s", 4, &ctx)?;
+        assert_eq!(pairs[0].replacement, "sub/");
+        Ok(())
+    }
+}
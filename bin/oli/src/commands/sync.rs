@@ -0,0 +1,287 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::value_parser;
+use clap::Arg;
+use clap::ArgAction;
+use clap::ArgMatches;
+use clap::Command;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use globset::Glob;
+use globset::GlobSet;
+use globset::GlobSetBuilder;
+use opendal::Metadata;
+use opendal::Metakey;
+use opendal::Operator;
+
+use crate::config::Config;
+
+pub async fn main(args: &ArgMatches) -> Result<()> {
+    let config_path = args
+        .get_one::<PathBuf>("config")
+        .ok_or_else(|| anyhow!("missing config path"))?;
+    let cfg = Config::load(config_path)?;
+
+    let src = args
+        .get_one::<String>("source")
+        .ok_or_else(|| anyhow!("missing source"))?;
+    let (src_op, src_path) = cfg.parse_location(src)?;
+
+    let dst = args
+        .get_one::<String>("destination")
+        .ok_or_else(|| anyhow!("missing destination"))?;
+    let (dst_op, dst_path) = cfg.parse_location(dst)?;
+
+    let filter = Filter::new(
+        args.get_many::<String>("include").unwrap_or_default(),
+        args.get_many::<String>("exclude").unwrap_or_default(),
+    )?;
+    let delete = args.get_flag("delete");
+    let dry_run = args.get_flag("dry-run");
+    let concurrent = *args
+        .get_one::<usize>("concurrent")
+        .ok_or_else(|| anyhow!("missing concurrent"))?;
+
+    let src_path = dir_path(&src_path);
+    let dst_path = dir_path(&dst_path);
+    let src_files = list_files(&src_op, &src_path, &filter).await?;
+    let mut dst_files = list_files(&dst_op, &dst_path, &filter).await?;
+
+    let mut copies = Vec::new();
+    let mut skipped = 0;
+    let mut sorted: Vec<_> = src_files.iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, meta) in sorted {
+        match need_copy(meta, dst_files.remove(name).as_ref()) {
+            Some(reason) => copies.push((name.as_str(), meta.content_length(), reason)),
+            None => skipped += 1,
+        }
+    }
+    // Files left in destination don't exist in source.
+    let mut extraneous: Vec<_> = dst_files.into_keys().collect();
+    extraneous.sort();
+
+    let prefix = if dry_run { "(dry run) " } else { "" };
+    let copied = AtomicU64::new(0);
+    let copied_bytes = AtomicU64::new(0);
+    let failed = AtomicU64::new(0);
+    futures::stream::iter(&copies)
+        .for_each_concurrent(concurrent, |(name, size, reason)| {
+            let (src_op, dst_op) = (&src_op, &dst_op);
+            let (from, to) = (format!("{src_path}{name}"), format!("{dst_path}{name}"));
+            let (copied, copied_bytes, failed) = (&copied, &copied_bytes, &failed);
+            async move {
+                println!("{prefix}Copying {from} ({reason})");
+                if dry_run {
+                    copied.fetch_add(1, Ordering::Relaxed);
+                    copied_bytes.fetch_add(*size, Ordering::Relaxed);
+                    return;
+                }
+                match src_op
+                    .transfer_with(&from, dst_op, &to)
+                    .chunk(8 * 1024 * 1024)
+                    .await
+                {
+                    Ok(()) => {
+                        copied.fetch_add(1, Ordering::Relaxed);
+                        copied_bytes.fetch_add(*size, Ordering::Relaxed);
+                    }
+                    Err(err) => {
+                        eprintln!("Failed to copy {from}: {err}");
+                        failed.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+        })
+        .await;
+
+    let mut deleted = 0;
+    if delete {
+        for name in &extraneous {
+            let path = format!("{dst_path}{name}");
+            println!("{prefix}Deleting {path}");
+            if dry_run {
+                deleted += 1;
+                continue;
+            }
+            match dst_op.delete(&path).await {
+                Ok(()) => deleted += 1,
+                Err(err) => {
+                    eprintln!("Failed to delete {path}: {err}");
+                    failed.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    let failed = failed.into_inner();
+    println!(
+        "{prefix}Synced: {} copied ({} bytes), {} skipped, {} deleted, {} failed",
+        copied.into_inner(),
+        copied_bytes.into_inner(),
+        skipped,
+        deleted,
+        failed
+    );
+    if failed > 0 {
+        return Err(anyhow!("{failed} files failed to sync"));
+    }
+    Ok(())
+}
+
+/// Filter files by their path relative to the sync root.
+///
+/// Files must match one of the include patterns if any is given, and must
+/// not match any of the exclude patterns.
+struct Filter {
+    include: Option<GlobSet>,
+    exclude: GlobSet,
+}
+
+impl Filter {
+    fn new<'a>(
+        include: impl IntoIterator<Item = &'a String>,
+        exclude: impl IntoIterator<Item = &'a String>,
+    ) -> Result<Self> {
+        fn build<'a>(patterns: impl IntoIterator<Item = &'a String>) -> Result<(GlobSet, bool)> {
+            let mut builder = GlobSetBuilder::new();
+            let mut empty = true;
+            for pattern in patterns {
+                builder.add(Glob::new(pattern)?);
+                empty = false;
+            }
+            Ok((builder.build()?, empty))
+        }
+
+        let (include, no_include) = build(include)?;
+        let (exclude, _) = build(exclude)?;
+        Ok(Filter {
+            include: (!no_include).then_some(include),
+            exclude,
+        })
+    }
+
+    fn matches(&self, path: &str) -> bool {
+        self.include.as_ref().map_or(true, |v| v.is_match(path)) && !self.exclude.is_match(path)
+    }
+}
+
+/// Make sure the path can be used as a directory prefix.
+fn dir_path(path: &str) -> String {
+    let path = path.strip_prefix('/').unwrap_or(path);
+    if path.is_empty() || path.ends_with('/') {
+        path.to_string()
+    } else {
+        format!("{path}/")
+    }
+}
+
+/// List all files under `root` recursively, keyed by their relative path.
+async fn list_files(
+    op: &Operator,
+    root: &str,
+    filter: &Filter,
+) -> Result<HashMap<String, Metadata>> {
+    let mut files = HashMap::new();
+    let mut ds = op
+        .lister_with(root)
+        .recursive(true)
+        .metakey(Metakey::Mode | Metakey::ContentLength | Metakey::LastModified | Metakey::Etag)
+        .await?;
+    while let Some(de) = ds.try_next().await? {
+        let (path, meta) = de.into_parts();
+        if !meta.mode().is_file() {
+            continue;
+        }
+        let path = path.strip_prefix('/').unwrap_or(&path);
+        let Some(name) = path.strip_prefix(root) else {
+            continue;
+        };
+        if filter.matches(name) {
+            files.insert(name.to_string(), meta);
+        }
+    }
+    Ok(files)
+}
+
+/// Decide whether a file should be copied, returning the reason if so.
+///
+/// Files are considered unchanged if their size is the same and either the
+/// etag is the same or the destination is not older than the source.
+fn need_copy(src: &Metadata, dst: Option<&Metadata>) -> Option<&'static str> {
+    let Some(dst) = dst else {
+        return Some("new");
+    };
+    if src.content_length() != dst.content_length() {
+        return Some("size changed");
+    }
+    if let (Some(a), Some(b)) = (src.etag(), dst.etag()) {
+        if a == b {
+            return None;
+        }
+    }
+    match (src.last_modified(), dst.last_modified()) {
+        (Some(a), Some(b)) if a > b => Some("modified"),
+        _ => None,
+    }
+}
+
+pub fn cli(cmd: Command) -> Command {
+    cmd.about("sync files from source to destination")
+        .arg(Arg::new("source").required(true))
+        .arg(Arg::new("destination").required(true))
+        .arg(
+            Arg::new("delete")
+                .long("delete")
+                .help("Delete files in destination that don't exist in source")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("include")
+                .long("include")
+                .help("Only sync files whose relative path matches this glob, can be repeated")
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("exclude")
+                .long("exclude")
+                .help("Skip files whose relative path matches this glob, can be repeated")
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .help("Show what would be done without changing anything")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("concurrent")
+                .long("concurrent")
+                .short('j')
+                .help("Number of files to copy concurrently")
+                .default_value("8")
+                .value_parser(value_parser!(usize)),
+        )
+}
@@ -22,7 +22,9 @@ use std::fs;
 use std::path::Component;
 use std::path::Path;
 use std::path::PathBuf;
+use std::process::Command;
 use std::str::FromStr;
+use std::sync::Mutex;
 
 use anyhow::anyhow;
 use anyhow::Result;
@@ -36,6 +38,10 @@ use url::Url;
 #[derive(Deserialize, Default)]
 pub struct Config {
     profiles: HashMap<String, HashMap<String, String>>,
+    /// Profiles with secret references resolved, so that commands like
+    /// `cmd://` only run once per profile.
+    #[serde(skip)]
+    resolved: Mutex<HashMap<String, HashMap<String, String>>>,
 }
 
 /// resolve_relative_path turns a relative path to a absolute path.
@@ -83,7 +89,10 @@ impl Config {
                 acc
             },
         );
-        Ok(Config { profiles })
+        Ok(Config {
+            profiles,
+            ..Default::default()
+        })
     }
     /// Parse a local config file.
     ///
@@ -122,7 +131,10 @@ impl Config {
                     acc
                 },
             );
-        Config { profiles }
+        Config {
+            profiles,
+            ..Default::default()
+        }
     }
 
     /// Parse `<profile>://abc/def` into `op` and `location`.
@@ -153,18 +165,91 @@ impl Config {
 
         let profile_name = location.scheme();
         let path = location.path().to_string();
+        let op = self.operator(profile_name)?;
+        Ok((op, path))
+    }
+
+    /// Names of all profiles in alphabetical order.
+    pub fn profile_names(&self) -> Vec<&str> {
+        let mut names: Vec<_> = self.profiles.keys().map(|v| v.as_str()).collect();
+        names.sort();
+        names
+    }
+
+    /// Get the profile as it's configured, without resolving secret references.
+    pub fn raw_profile(&self, name: &str) -> Result<&HashMap<String, String>> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| anyhow!("unknown profile: {}", name))
+    }
+
+    /// Get the profile with all secret references resolved.
+    ///
+    /// References are resolved at the first call, later calls reuse the result.
+    pub fn profile(&self, name: &str) -> Result<HashMap<String, String>> {
+        let mut resolved = self.resolved.lock().expect("lock must succeed");
+        if let Some(profile) = resolved.get(name) {
+            return Ok(profile.clone());
+        }
         let profile = self
-            .profiles
-            .get(profile_name)
-            .ok_or_else(|| anyhow!("unknown profile: {}", profile_name))?;
+            .raw_profile(name)?
+            .iter()
+            .map(|(k, v)| {
+                let v = resolve_value(v).map_err(|err| anyhow!("resolve '{k}' failed: {err}"))?;
+                Ok((k.clone(), v))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        resolved.insert(name.to_string(), profile.clone());
+        Ok(profile)
+    }
 
+    /// Build the operator of given profile.
+    pub fn operator(&self, name: &str) -> Result<Operator> {
+        let profile = self.profile(name)?;
         let svc = profile
             .get("type")
             .ok_or_else(|| anyhow!("missing 'type' in profile"))?;
         let scheme = Scheme::from_str(svc)?;
-        let op = Operator::via_iter(scheme, profile.clone())?;
-        Ok((op, path))
+        Ok(Operator::via_iter(scheme, profile)?)
+    }
+}
+
+/// Resolve the value of a profile option, so that secrets don't need to be
+/// stored in the config file in plain text.
+///
+/// - `env://NAME`: the value of environment variable `NAME`.
+/// - `file:///path/to/secret`: the content of the file.
+/// - `cmd://command args`: the stdout of the command, which is run by shell.
+///
+/// The trailing newline of files and command outputs is removed. Other values
+/// are returned as is.
+pub fn resolve_value(value: &str) -> Result<String> {
+    if let Some(name) = value.strip_prefix("env://") {
+        return env::var(name).map_err(|_| anyhow!("environment variable {name} is not set"));
+    }
+    if let Some(path) = value.strip_prefix("file://") {
+        let content =
+            fs::read_to_string(path).map_err(|err| anyhow!("read file {path} failed: {err}"))?;
+        return Ok(content.trim_end_matches(['\r', '\n']).to_string());
+    }
+    if let Some(cmd) = value.strip_prefix("cmd://") {
+        let output = if cfg!(windows) {
+            Command::new("cmd").arg("/C").arg(cmd).output()
+        } else {
+            Command::new("sh").arg("-c").arg(cmd).output()
+        }
+        .map_err(|err| anyhow!("run command `{cmd}` failed: {err}"))?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "command `{cmd}` exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let stdout = String::from_utf8(output.stdout)?;
+        return Ok(stdout.trim_end_matches(['\r', '\n']).to_string());
     }
+    Ok(value.to_string())
 }
 
 #[cfg(test)]
@@ -254,6 +339,54 @@ enable_virtual_host_style = "on"
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_value() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let secret = dir.path().join("secret");
+        fs::write(&secret, "from_file\n")?;
+        env::set_var("OLI_TEST_RESOLVE_VALUE", "from_env");
+
+        assert_eq!(resolve_value("plain")?, "plain");
+        assert_eq!(resolve_value("env://OLI_TEST_RESOLVE_VALUE")?, "from_env");
+        assert_eq!(
+            resolve_value(&format!("file://{}", secret.display()))?,
+            "from_file"
+        );
+        assert_eq!(resolve_value("cmd://echo from_cmd")?, "from_cmd");
+
+        assert!(resolve_value("env://OLI_TEST_NOT_EXIST").is_err());
+        assert!(resolve_value("file:///not/exist").is_err());
+        assert!(resolve_value("cmd://exit 1").is_err());
+
+        env::remove_var("OLI_TEST_RESOLVE_VALUE");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_location_with_references() -> Result<()> {
+        env::set_var("OLI_TEST_BUCKET", "mybucket");
+        let cfg = Config {
+            profiles: HashMap::from([(
+                "mys3".into(),
+                HashMap::from([
+                    ("type".into(), "s3".into()),
+                    ("bucket".into(), "env://OLI_TEST_BUCKET".into()),
+                    ("region".into(), "cmd://echo us-east-1".into()),
+                ]),
+            )]),
+            ..Default::default()
+        };
+        let (op, _) = cfg.parse_location("mys3:///foo/1.txt")?;
+        assert_eq!("mybucket", op.info().name());
+        // Resolved values are cached, so later changes are not seen.
+        env::set_var("OLI_TEST_BUCKET", "otherbucket");
+        let (op, _) = cfg.parse_location("mys3:///foo/2.txt")?;
+        assert_eq!("mybucket", op.info().name());
+        assert_eq!(cfg.raw_profile("mys3")?["bucket"], "env://OLI_TEST_BUCKET");
+        env::remove_var("OLI_TEST_BUCKET");
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fs_location() {
         struct TestCase {
@@ -296,6 +429,7 @@ enable_virtual_host_style = "on"
                     ("region".into(), "us-east-1".into()),
                 ]),
             )]),
+            ..Default::default()
         };
         let (op, path) = cfg.parse_location("mys3:///foo/1.txt").unwrap();
         assert_eq!("/foo/1.txt", path);
@@ -315,6 +449,7 @@ enable_virtual_host_style = "on"
                     ("region".into(), "us-east-1".into()),
                 ]),
             )]),
+            ..Default::default()
         };
         let (op, path) = cfg.parse_location("mys3:/foo/1.txt").unwrap();
         assert_eq!("/foo/1.txt", path);
@@ -334,6 +469,7 @@ enable_virtual_host_style = "on"
                     ("region".into(), "us-east-1".into()),
                 ]),
             )]),
+            ..Default::default()
         };
 
         let uri = "mys3://foo/1.txt";
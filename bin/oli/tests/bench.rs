@@ -0,0 +1,62 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_basic_bench() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    let target = dir.path().to_string_lossy().to_string() + "/";
+    cmd.arg("bench")
+        .arg(&target)
+        .args(["--workload", "write,read,list"])
+        .args(["--size", "4K"])
+        .args(["--duration", "1s"])
+        .args(["--parallel", "2"])
+        .args(["--list-files", "10"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("write 4.0K: "))
+        .stdout(predicate::str::contains("read 4.0K: "))
+        .stdout(predicate::str::contains("list: "))
+        .stdout(predicate::str::contains("MiB/s, latency p50 "));
+
+    // Temporary files must be removed after bench.
+    assert_eq!(fs::read_dir(dir.path())?.count(), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bench_invalid_workload() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    cmd.arg("bench")
+        .arg(dir.path())
+        .args(["--workload", "unknown"]);
+    cmd.assert().failure();
+    Ok(())
+}
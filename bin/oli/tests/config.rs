@@ -0,0 +1,98 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_config_commands() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let data = dir.path().join("data");
+    fs::create_dir_all(&data)?;
+    fs::write(data.join("a.txt"), "hello")?;
+    fs::write(dir.path().join("root"), format!("{}\n", data.display()))?;
+    let config = dir.path().join("config.toml");
+    fs::write(
+        &config,
+        format!(
+            r#"
+[profiles.local]
+type = "fs"
+root = "file://{}"
+
+[profiles.broken]
+type = "fs"
+root = "env://OLI_TEST_CONFIG_NOT_EXIST"
+
+[profiles.mem]
+type = "memory"
+secret_access_key = "plain_secret"
+"#,
+            dir.path().join("root").display()
+        ),
+    )?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("config").arg("list").arg("--config").arg(&config);
+    cmd.assert()
+        .success()
+        .stdout("broken\tfs\nlocal\tfs\nmem\tmemory\n");
+
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("config")
+        .arg("show")
+        .arg("mem")
+        .arg("--config")
+        .arg(&config);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("secret_access_key = \"******\""))
+        .stdout(predicate::str::contains("plain_secret").not());
+
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("config")
+        .arg("validate")
+        .arg("local")
+        .arg("--config")
+        .arg(&config);
+    cmd.assert().success().stdout("local: ok\n");
+
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("config")
+        .arg("validate")
+        .arg("--config")
+        .arg(&config);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "broken: resolve 'root' failed: environment variable OLI_TEST_CONFIG_NOT_EXIST is not set",
+        ))
+        .stdout(predicate::str::contains("mem: ok"));
+
+    // Secret references are resolved when the profile is used.
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("cat")
+        .arg("local:///a.txt")
+        .arg("--config")
+        .arg(&config);
+    cmd.assert().success().stdout("hello");
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+#[tokio::test]
+async fn test_basic_du() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir_all(dir.path().join("sub"))?;
+    fs::write(dir.path().join("a.txt"), "hello")?;
+    fs::write(dir.path().join("sub/b.txt"), "world!")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    let target = dir.path().to_string_lossy().to_string() + "/";
+    cmd.arg("du").arg(&target);
+    let res = cmd.assert().success();
+    let output_stdout = String::from_utf8(res.get_output().stdout.clone())?;
+
+    assert!(output_stdout.contains(&format!("6\t1 files\t{target}sub/")));
+    assert!(output_stdout.contains(&format!("11\t2 files\t{target}")));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_du_summarize() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir_all(dir.path().join("sub"))?;
+    fs::write(dir.path().join("sub/b.txt"), vec![0; 2048])?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    cmd.arg("du")
+        .arg(dir.path().as_os_str())
+        .arg("-s")
+        .arg("--human-readable");
+    let res = cmd.assert().success();
+    let output_stdout = String::from_utf8(res.get_output().stdout.clone())?;
+
+    assert_eq!(output_stdout.lines().count(), 1);
+    assert!(output_stdout.starts_with("2.0K\t1 files\t"));
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+fn find(dir: &std::path::Path, args: &[&str]) -> Result<Vec<String>> {
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    let target = dir.to_string_lossy().to_string() + "/";
+    cmd.arg("find").arg(&target).args(args);
+    let res = cmd.assert().success();
+    let output_stdout = String::from_utf8(res.get_output().stdout.clone())?;
+
+    let mut paths: Vec<_> = output_stdout.lines().map(|v| v.to_string()).collect();
+    paths.sort();
+    Ok(paths)
+}
+
+#[tokio::test]
+async fn test_basic_find() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir_all(dir.path().join("sub"))?;
+    fs::write(dir.path().join("a.txt"), "hello")?;
+    fs::write(dir.path().join("b.log"), vec![0; 2048])?;
+    fs::write(dir.path().join("sub/c.txt"), "world")?;
+
+    assert_eq!(find(dir.path(), &["--name", "*.txt"])?.len(), 2);
+    assert_eq!(find(dir.path(), &["--type", "d"])?.len(), 1);
+    assert_eq!(find(dir.path(), &["--type", "f"])?.len(), 3);
+
+    let paths = find(dir.path(), &["--min-size", "1K"])?;
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].ends_with("b.log"));
+
+    let paths = find(dir.path(), &["--max-size", "10", "--name", "c.*"])?;
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].ends_with("sub/c.txt"));
+
+    assert_eq!(
+        find(dir.path(), &["--newer", "1h", "--type", "f"])?.len(),
+        3
+    );
+    assert_eq!(find(dir.path(), &["--older", "1h"])?.len(), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_find_invalid_size() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    cmd.arg("find").arg(dir.path()).arg("--min-size").arg("10X");
+    cmd.assert().failure();
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+fn s3_cmd() -> Result<Command> {
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.env("OLI_PROFILE_PRESIGNS3_TYPE", "s3")
+        .env("OLI_PROFILE_PRESIGNS3_BUCKET", "test")
+        .env("OLI_PROFILE_PRESIGNS3_REGION", "us-east-1")
+        .env("OLI_PROFILE_PRESIGNS3_ENDPOINT", "http://127.0.0.1:9000")
+        .env("OLI_PROFILE_PRESIGNS3_ACCESS_KEY_ID", "access_key")
+        .env("OLI_PROFILE_PRESIGNS3_SECRET_ACCESS_KEY", "secret_key");
+    Ok(cmd)
+}
+
+#[tokio::test]
+async fn test_basic_presign() -> Result<()> {
+    let mut cmd = s3_cmd()?;
+
+    cmd.arg("presign").arg("presigns3:///a.txt");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "GET http://127.0.0.1:9000/test/a.txt?",
+        ))
+        .stdout(predicate::str::contains("X-Amz-Expires=3600"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_presign_write() -> Result<()> {
+    let mut cmd = s3_cmd()?;
+
+    cmd.arg("presign")
+        .arg("presigns3:///a.txt")
+        .arg("--method")
+        .arg("write")
+        .arg("--expire")
+        .arg("60");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("PUT "))
+        .stdout(predicate::str::contains("X-Amz-Expires=60"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_presign_unsupported() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let dst_path = dir.path().join("dst.txt");
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    cmd.arg("presign").arg(dst_path.as_os_str());
+    cmd.assert().failure();
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs;
+
+use anyhow::Result;
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_basic_shell() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir_all(dir.path().join("sub"))?;
+    fs::write(dir.path().join("sub/a.txt"), "hello")?;
+    let local = dir.path().join("local.txt");
+    fs::write(&local, "world")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    let input = format!(
+        "cd sub\npwd\nls\ncat a.txt\nput {} b.txt\ncd ..\nls sub\nrm sub/a.txt\nexit\n",
+        local.display()
+    );
+    cmd.arg("shell")
+        .arg(dir.path())
+        .arg("--history")
+        .arg(dir.path().join("history"))
+        .write_stdin(input);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "{}/sub/\n",
+            dir.path().display()
+        )))
+        .stdout(predicate::str::contains("a.txt\nhello"))
+        .stdout(predicate::str::contains("b.txt"));
+
+    assert_eq!(fs::read_to_string(dir.path().join("sub/b.txt"))?, "world");
+    assert!(!dir.path().join("sub/a.txt").exists());
+    assert!(fs::read_to_string(dir.path().join("history"))?.contains("cd sub"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shell_continue_after_error() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    cmd.arg("shell")
+        .arg(dir.path())
+        .arg("--history")
+        .arg(dir.path().join("history"))
+        .write_stdin("unknown\ncd not_exist\npwd\n");
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("unknown command: unknown"))
+        .stdout(predicate::str::contains(format!(
+            "{}/",
+            dir.path().display()
+        )));
+    Ok(())
+}
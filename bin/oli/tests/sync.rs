@@ -0,0 +1,139 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+#[tokio::test]
+async fn test_basic_sync() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let src = dir.path().join("src");
+    let dst = dir.path().join("dst");
+    fs::create_dir_all(src.join("sub"))?;
+    fs::create_dir_all(&dst)?;
+    fs::write(src.join("a.txt"), "hello")?;
+    fs::write(src.join("sub/b.txt"), "world")?;
+    fs::write(dst.join("stale.txt"), "stale")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("sync").arg(&src).arg(&dst).arg("--delete");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "2 copied (10 bytes), 0 skipped, 1 deleted",
+    ));
+
+    assert_eq!(fs::read_to_string(dst.join("a.txt"))?, "hello");
+    assert_eq!(fs::read_to_string(dst.join("sub/b.txt"))?, "world");
+    assert!(!dst.join("stale.txt").exists());
+
+    // Nothing changed, everything should be skipped.
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("sync").arg(&src).arg(&dst);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("0 copied (0 bytes), 2 skipped"));
+
+    fs::write(src.join("a.txt"), "hello again")?;
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("sync").arg(&src).arg(&dst);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 copied (11 bytes), 1 skipped"));
+    assert_eq!(fs::read_to_string(dst.join("a.txt"))?, "hello again");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sync_with_filters() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let src = dir.path().join("src");
+    let dst = dir.path().join("dst");
+    fs::create_dir_all(src.join("logs"))?;
+    fs::create_dir_all(&dst)?;
+    fs::write(src.join("a.txt"), "a")?;
+    fs::write(src.join("b.log"), "b")?;
+    fs::write(src.join("logs/c.txt"), "c")?;
+    fs::write(dst.join("d.log"), "d")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("sync")
+        .arg(&src)
+        .arg(&dst)
+        .arg("--include")
+        .arg("*.txt")
+        .arg("--exclude")
+        .arg("logs/**")
+        .arg("--delete");
+    cmd.assert().success();
+
+    assert!(dst.join("a.txt").exists());
+    assert!(!dst.join("b.log").exists());
+    assert!(!dst.join("logs/c.txt").exists());
+    // Excluded files in destination are kept.
+    assert!(dst.join("d.log").exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sync_dry_run() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let src = dir.path().join("src");
+    let dst = dir.path().join("dst");
+    fs::create_dir_all(&src)?;
+    fs::create_dir_all(&dst)?;
+    fs::write(src.join("a.txt"), "a")?;
+    fs::write(dst.join("b.txt"), "b")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("sync")
+        .arg(&src)
+        .arg(&dst)
+        .arg("--delete")
+        .arg("--dry-run");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "(dry run) Synced: 1 copied (1 bytes), 0 skipped, 1 deleted",
+    ));
+
+    assert!(!dst.join("a.txt").exists());
+    assert!(dst.join("b.txt").exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sync_between_profiles() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let src = dir.path().join("src");
+    let dst = dir.path().join("dst");
+    fs::create_dir_all(&src)?;
+    fs::write(src.join("a.txt"), "hello")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+    cmd.arg("sync")
+        .arg("src:///")
+        .arg("dst:///backup")
+        .env("OLI_PROFILE_SRC_TYPE", "fs")
+        .env("OLI_PROFILE_SRC_ROOT", &src)
+        .env("OLI_PROFILE_DST_TYPE", "fs")
+        .env("OLI_PROFILE_DST_ROOT", &dst);
+    cmd.assert().success();
+
+    assert_eq!(fs::read_to_string(dst.join("backup/a.txt"))?, "hello");
+    Ok(())
+}
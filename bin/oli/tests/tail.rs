@@ -0,0 +1,48 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+#[tokio::test]
+async fn test_basic_tail() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let dst_path = dir.path().join("dst.txt");
+    fs::write(&dst_path, "0123456789")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    cmd.arg("tail").arg(dst_path.as_os_str()).arg("-c").arg("4");
+    cmd.assert().success().stdout("6789");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tail_larger_than_file() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let dst_path = dir.path().join("dst.txt");
+    fs::write(&dst_path, "0123456789")?;
+
+    let mut cmd = Command::cargo_bin("oli")?;
+
+    cmd.arg("tail").arg(dst_path.as_os_str());
+    cmd.assert().success().stdout("0123456789");
+    Ok(())
+}
@@ -79,6 +79,14 @@ typedef enum opendal_code {
    * The range of the content is not satisfied.
    */
   OPENDAL_RANGE_NOT_SATISFIED,
+  /**
+   * The given path is protected by a retention period or legal hold.
+   */
+  OPENDAL_OBJECT_LOCKED,
+  /**
+   * The given path is archived and must be restored before reading.
+   */
+  OPENDAL_OBJECT_ARCHIVED,
 } opendal_code;
 
 /**
@@ -180,7 +188,7 @@ typedef struct BlockingOperator BlockingOperator;
  *         EntryMode::DIR => {
  *             println!("Handling dir {}", entry.path())
  *         }
- *         EntryMode::Unknown => continue,
+ *         EntryMode::Symlink | EntryMode::Unknown => continue,
  *     }
  * }
  * # Ok(())
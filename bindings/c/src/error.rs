@@ -49,6 +49,10 @@ pub enum opendal_code {
     OPENDAL_CONDITION_NOT_MATCH,
     /// The range of the content is not satisfied.
     OPENDAL_RANGE_NOT_SATISFIED,
+    /// The given path is protected by a retention period or legal hold.
+    OPENDAL_OBJECT_LOCKED,
+    /// The given path is archived and must be restored before reading.
+    OPENDAL_OBJECT_ARCHIVED,
 }
 
 impl From<core::ErrorKind> for opendal_code {
@@ -66,6 +70,8 @@ impl From<core::ErrorKind> for opendal_code {
             core::ErrorKind::IsSameFile => opendal_code::OPENDAL_IS_SAME_FILE,
             core::ErrorKind::ConditionNotMatch => opendal_code::OPENDAL_CONDITION_NOT_MATCH,
             core::ErrorKind::RangeNotSatisfied => opendal_code::OPENDAL_RANGE_NOT_SATISFIED,
+            core::ErrorKind::ObjectLocked => opendal_code::OPENDAL_OBJECT_LOCKED,
+            core::ErrorKind::ObjectArchived => opendal_code::OPENDAL_OBJECT_ARCHIVED,
             // if this is triggered, check the [`core`] crate and add a
             // new error code accordingly
             _ => panic!("The newly added ErrorKind in core crate is not handled in C bindings"),
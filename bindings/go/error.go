@@ -67,6 +67,10 @@ const (
 	//
 	// OpenDAL returns this error to indicate that the range of the read request is not satisfied.
 	CodeRangeNotSatisfied
+	// The given path is protected by a retention period or legal hold.
+	CodeObjectLocked
+	// The given path is archived and must be restored before reading.
+	CodeObjectArchived
 )
 
 func parseError(ctx context.Context, err *opendalError) error {
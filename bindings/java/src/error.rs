@@ -58,6 +58,8 @@ impl Error {
             ErrorKind::IsSameFile => "IsSameFile",
             ErrorKind::ConditionNotMatch => "ConditionNotMatch",
             ErrorKind::RangeNotSatisfied => "RangeNotSatisfied",
+            ErrorKind::ObjectLocked => "ObjectLocked",
+            ErrorKind::ObjectArchived => "ObjectArchived",
             _ => "Unexpected",
         })?;
         let message = env.new_string(format!("{:?}", self.inner))?;
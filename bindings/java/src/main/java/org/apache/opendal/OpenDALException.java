@@ -72,5 +72,7 @@ public class OpenDALException extends RuntimeException {
         IsSameFile,
         ConditionNotMatch,
         RangeNotSatisfied,
+        ObjectLocked,
+        ObjectArchived,
     }
 }
@@ -50,6 +50,7 @@ pub fn format_pyerr(err: ocore::Error) -> PyErr {
         AlreadyExists => AlreadyExistsError::new_err(err.to_string()),
         IsSameFile => IsSameFileError::new_err(err.to_string()),
         ConditionNotMatch => ConditionNotMatchError::new_err(err.to_string()),
+        ObjectLocked => PermissionDeniedError::new_err(err.to_string()),
         _ => UnexpectedError::new_err(err.to_string()),
     }
 }
@@ -111,6 +111,7 @@ impl EntryMode {
         match self.0 {
             ocore::EntryMode::FILE => "EntryMode.FILE",
             ocore::EntryMode::DIR => "EntryMode.DIR",
+            ocore::EntryMode::Symlink => "EntryMode.SYMLINK",
             ocore::EntryMode::Unknown => "EntryMode.UNKNOWN",
         }
     }
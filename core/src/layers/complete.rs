@@ -421,6 +421,24 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
                 ),
             ));
         }
+        if args.resume().is_some() && !capability.write_can_resume {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with resume",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.offset().is_some() && !capability.write_can_random {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with offset",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         let (rp, w) = self.inner.write(path, args.clone()).await?;
         let w = CompleteWriter::new(w);
@@ -472,6 +490,18 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
         if !capability.batch {
             return Err(self.new_unsupported_error(Operation::Batch));
         }
+        for (_, op) in args.operation() {
+            let supported = match op {
+                BatchOperation::Delete(_) => capability.batch_delete,
+                BatchOperation::Stat(_) => capability.batch_stat,
+                BatchOperation::Read(_) => capability.batch_read,
+            };
+            if !supported {
+                return Err(self
+                    .new_unsupported_error(Operation::Batch)
+                    .with_context("batched", op.operation().into_static()));
+            }
+        }
 
         self.inner().batch(args).await
     }
@@ -485,6 +515,127 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
         self.inner.presign(path, args).await
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let capability = self.meta.full_capability();
+        if !capability.list_multipart_uploads {
+            return Err(self.new_unsupported_error(Operation::ListMultipartUploads));
+        }
+
+        self.inner.list_multipart_uploads(path, args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let capability = self.meta.full_capability();
+        if !capability.abort_multipart_upload {
+            return Err(self.new_unsupported_error(Operation::AbortMultipartUpload));
+        }
+
+        self.inner.abort_multipart_upload(path, args).await
+    }
+
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        let capability = self.meta.full_capability();
+        if !capability.summary {
+            return Err(self.new_unsupported_error(Operation::Summary));
+        }
+
+        self.inner.summary(path, args).await
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        let capability = self.meta.full_capability();
+        if !capability.create_symlink {
+            return Err(self.new_unsupported_error(Operation::CreateSymlink));
+        }
+
+        self.inner.create_symlink(path, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        let capability = self.meta.full_capability();
+        if !capability.read_link {
+            return Err(self.new_unsupported_error(Operation::ReadLink));
+        }
+
+        self.inner.read_link(path, args).await
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        let capability = self.meta.full_capability();
+        if !capability.set_attr
+            || (args.mode().is_some() && !capability.set_attr_with_mode)
+            || (args.has_owner() && !capability.set_attr_with_owner)
+            || (args.has_times() && !capability.set_attr_with_times)
+            || (args.size().is_some() && !capability.set_attr_with_size)
+        {
+            return Err(self.new_unsupported_error(Operation::SetAttr));
+        }
+
+        self.inner.set_attr(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        let capability = self.meta.full_capability();
+        if !capability.get_tags {
+            return Err(self.new_unsupported_error(Operation::GetTags));
+        }
+
+        self.inner.get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let capability = self.meta.full_capability();
+        if !capability.set_tags {
+            return Err(self.new_unsupported_error(Operation::SetTags));
+        }
+
+        self.inner.set_tags(path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        let capability = self.meta.full_capability();
+        if !capability.set_retention {
+            return Err(self.new_unsupported_error(Operation::SetRetention));
+        }
+
+        self.inner.set_retention(path, args).await
+    }
+
+    async fn set_legal_hold(&self, path: &str, args: OpSetLegalHold) -> Result<RpSetLegalHold> {
+        let capability = self.meta.full_capability();
+        if !capability.set_legal_hold {
+            return Err(self.new_unsupported_error(Operation::SetLegalHold));
+        }
+
+        self.inner.set_legal_hold(path, args).await
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let capability = self.meta.full_capability();
+        if !capability.restore {
+            return Err(self.new_unsupported_error(Operation::Restore));
+        }
+
+        self.inner.restore(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let capability = self.meta.full_capability();
+        if !capability.presign_post {
+            return Err(self.new_unsupported_error(Operation::PresignPost));
+        }
+
+        self.inner.presign_post(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.complete_blocking_create_dir(path, args)
     }
@@ -516,6 +667,24 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
                 ),
             ));
         }
+        if args.resume().is_some() && !capability.write_can_resume {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with resume",
+                    self.info().scheme()
+                ),
+            ));
+        }
+        if args.offset().is_some() && !capability.write_can_random {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "service {} doesn't support operation write with offset",
+                    self.info().scheme()
+                ),
+            ));
+        }
 
         self.inner
             .blocking_write(path, args)
@@ -777,6 +946,40 @@ mod tests {
                 HeaderMap::new(),
             )))
         }
+
+        async fn list_multipart_uploads(
+            &self,
+            _: &str,
+            _: OpListMultipartUploads,
+        ) -> Result<RpListMultipartUploads> {
+            Ok(RpListMultipartUploads::default())
+        }
+
+        async fn abort_multipart_upload(
+            &self,
+            _: &str,
+            _: OpAbortMultipartUpload,
+        ) -> Result<RpAbortMultipartUpload> {
+            Ok(RpAbortMultipartUpload::new())
+        }
+
+        async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+            let results = args
+                .into_operation()
+                .into_iter()
+                .map(|(path, op)| {
+                    let reply = match op {
+                        BatchOperation::Delete(_) => RpDelete {}.into(),
+                        BatchOperation::Stat(_) => {
+                            RpStat::new(Metadata::new(EntryMode::FILE)).into()
+                        }
+                        BatchOperation::Read(_) => (RpRead::new(), Buffer::new()).into(),
+                    };
+                    (path, Ok(reply))
+                })
+                .collect();
+            Ok(RpBatch::new(results))
+        }
     }
 
     fn new_test_operator(capability: Capability) -> Operator {
@@ -922,4 +1125,81 @@ mod tests {
         let res = op.presign_read("path", Duration::from_secs(1)).await;
         assert!(res.is_ok())
     }
+
+    #[tokio::test]
+    async fn test_list_multipart_uploads() {
+        let op = new_test_operator(Capability::default());
+        let res = op.list_multipart_uploads("path/").await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            list_multipart_uploads: true,
+            ..Default::default()
+        });
+        let res = op.list_multipart_uploads("path/").await;
+        assert!(res.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_abort_multipart_upload() {
+        let upload = MultipartUpload::new("path");
+        let op = new_test_operator(Capability {
+            list_multipart_uploads: true,
+            ..Default::default()
+        });
+        let res = op.abort_multipart_upload(&upload).await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            abort_multipart_upload: true,
+            ..Default::default()
+        });
+        let res = op.abort_multipart_upload(&upload).await;
+        assert!(res.is_ok())
+    }
+
+    #[tokio::test]
+    async fn test_batch() {
+        let op = new_test_operator(Capability {
+            batch: true,
+            batch_delete: true,
+            ..Default::default()
+        });
+        let res = op
+            .inner()
+            .batch(OpBatch::new(vec![(
+                "path".to_string(),
+                OpStat::new().into(),
+            )]))
+            .await;
+        assert!(res.is_err());
+        assert_eq!(res.err().unwrap().kind(), ErrorKind::Unsupported);
+
+        let op = new_test_operator(Capability {
+            stat: true,
+            batch: true,
+            batch_stat: true,
+            ..Default::default()
+        });
+        let res = op
+            .stat_many(vec!["a".to_string(), "b/".to_string()])
+            .await
+            .expect("stat many must succeed");
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].0, "a");
+        assert_eq!(res[0].1.as_ref().unwrap().mode(), EntryMode::FILE);
+        assert_eq!(res[1].0, "b/");
+
+        let res = op
+            .stat_many(vec!["a".to_string(), "c".to_string(), "a".to_string()])
+            .await
+            .expect("stat many must succeed");
+        assert_eq!(res.len(), 3);
+        for (idx, path) in ["a", "c", "a"].into_iter().enumerate() {
+            assert_eq!(res[idx].0, path);
+            assert_eq!(res[idx].1.as_ref().unwrap().mode(), EntryMode::FILE);
+        }
+    }
 }
@@ -226,6 +226,118 @@ impl<A: Access> LayeredAccess for ErrorContextAccessor<A> {
         })
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        self.inner
+            .list_multipart_uploads(path, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::ListMultipartUploads)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let upload_id = args.upload_id().unwrap_or_default().to_string();
+        self.inner
+            .abort_multipart_upload(path, args)
+            .await
+            .map_err(|err| {
+                err.with_operation(Operation::AbortMultipartUpload)
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+                    .with_context("upload_id", upload_id)
+            })
+    }
+
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        self.inner.summary(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Summary)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        self.inner.create_symlink(path, args).await.map_err(|err| {
+            err.with_operation(Operation::CreateSymlink)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        self.inner.read_link(path, args).await.map_err(|err| {
+            err.with_operation(Operation::ReadLink)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        self.inner.set_attr(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetAttr)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.inner.get_tags(path, args).await.map_err(|err| {
+            err.with_operation(Operation::GetTags)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        self.inner.set_tags(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetTags)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        self.inner.set_retention(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetRetention)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_legal_hold(&self, path: &str, args: OpSetLegalHold) -> Result<RpSetLegalHold> {
+        self.inner.set_legal_hold(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetLegalHold)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.inner.restore(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Restore)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.inner.presign_post(path, args).await.map_err(|err| {
+            err.with_operation(Operation::PresignPost)
+                .with_context("service", self.meta.scheme())
+                .with_context("path", path)
+        })
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner.blocking_create_dir(path, args).map_err(|err| {
             err.with_operation(Operation::BlockingCreateDir)
@@ -805,6 +805,7 @@ mod tests {
                 list: true,
                 list_with_recursive: true,
                 batch: true,
+                batch_delete: true,
                 ..Default::default()
             });
 
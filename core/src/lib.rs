@@ -151,8 +151,8 @@ mod tests {
     #[test]
     fn assert_size() {
         assert_eq!(40, size_of::<Operator>());
-        assert_eq!(304, size_of::<Entry>());
-        assert_eq!(280, size_of::<Metadata>());
+        assert_eq!(464, size_of::<Entry>());
+        assert_eq!(440, size_of::<Metadata>());
         assert_eq!(1, size_of::<EntryMode>());
         assert_eq!(24, size_of::<Scheme>());
     }
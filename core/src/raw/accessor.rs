@@ -266,6 +266,265 @@ pub trait Access: Send + Sync + Debug + Unpin + 'static {
         )))
     }
 
+    /// Invoke the `list_multipart_uploads` operation on the specified path.
+    ///
+    /// Require [`Capability::list_multipart_uploads`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Path is used as prefix, all incomplete uploads under it should be returned.
+    fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> impl Future<Output = Result<RpListMultipartUploads>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `abort_multipart_upload` operation on the specified path.
+    ///
+    /// Require [`Capability::abort_multipart_upload`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Aborting a not existing upload should be treated as success.
+    fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> impl Future<Output = Result<RpAbortMultipartUpload>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `summary` operation on the specified path.
+    ///
+    /// Require [`Capability::summary`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Path will always be a dir that ends with `/`.
+    /// - Summary should cover all entries under the path recursively.
+    fn summary(
+        &self,
+        path: &str,
+        args: OpSummary,
+    ) -> impl Future<Output = Result<RpSummary>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `create_symlink` operation on the specified path.
+    ///
+    /// Require [`Capability::create_symlink`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Path is the symlink to create, the target is passed by args.
+    /// - Parent dirs of the path should be created if not exist.
+    fn create_symlink(
+        &self,
+        path: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `read_link` operation on the specified path.
+    ///
+    /// Require [`Capability::read_link`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Returns the target of the symlink as is, without resolving it.
+    /// - Return an error if the path is not a symlink.
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_attr` operation on the specified path.
+    ///
+    /// Require [`Capability::set_attr`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Only the attributes set in `OpSetAttr` should be changed.
+    /// - Services should return `Unsupported` if the given attribute can't be changed.
+    fn set_attr(
+        &self,
+        path: &str,
+        args: OpSetAttr,
+    ) -> impl Future<Output = Result<RpSetAttr>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `get_tags` operation on the specified path.
+    ///
+    /// Require [`Capability::get_tags`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Tags are key/value pairs attached to the object, separated from user metadata.
+    fn get_tags(
+        &self,
+        path: &str,
+        args: OpGetTags,
+    ) -> impl Future<Output = Result<RpGetTags>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_tags` operation on the specified path.
+    ///
+    /// Require [`Capability::set_tags`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - The given tags replace all existing tags of the object.
+    fn set_tags(
+        &self,
+        path: &str,
+        args: OpSetTags,
+    ) -> impl Future<Output = Result<RpSetTags>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_retention` operation on the specified path.
+    ///
+    /// Require [`Capability::set_retention`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Set the retention mode and retain until date of the object.
+    /// - Return [`ErrorKind::ObjectLocked`] if the new retention shortens an existing one without bypass.
+    fn set_retention(
+        &self,
+        path: &str,
+        args: OpSetRetention,
+    ) -> impl Future<Output = Result<RpSetRetention>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_legal_hold` operation on the specified path.
+    ///
+    /// Require [`Capability::set_legal_hold`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Place or release the legal hold of the object.
+    fn set_legal_hold(
+        &self,
+        path: &str,
+        args: OpSetLegalHold,
+    ) -> impl Future<Output = Result<RpSetLegalHold>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `restore` operation on the specified path.
+    ///
+    /// Require [`Capability::restore`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Restore an archived object so that it can be read.
+    /// - Return `Ok` if the restore has been accepted or is already in progress, check [`Metadata::restore_status`] for the progress.
+    fn restore(
+        &self,
+        path: &str,
+        args: OpRestore,
+    ) -> impl Future<Output = Result<RpRestore>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `presign_post` operation on the specified path.
+    ///
+    /// Require [`Capability::presign_post`]
+    ///
+    /// # Behavior
+    ///
+    /// - This API is optional, return [`std::io::ErrorKind::Unsupported`] if not supported.
+    /// - Generate a POST policy that allows browsers to upload via HTML form without credentials.
+    /// - The policy must expire after `args.expire()`.
+    fn presign_post(
+        &self,
+        path: &str,
+        args: OpPresignPost,
+    ) -> impl Future<Output = Result<RpPresignPost>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
     /// Invoke the `batch` operations.
     ///
     /// Require [`Capability::batch`]
@@ -449,6 +708,78 @@ pub trait AccessDyn: Send + Sync + Debug + Unpin {
         path: &'a str,
         args: OpPresign,
     ) -> BoxedFuture<'a, Result<RpPresign>>;
+    /// Dyn version of [`Accessor::list_multipart_uploads`]
+    fn list_multipart_uploads_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpListMultipartUploads,
+    ) -> BoxedFuture<'a, Result<RpListMultipartUploads>>;
+    /// Dyn version of [`Accessor::abort_multipart_upload`]
+    fn abort_multipart_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpAbortMultipartUpload,
+    ) -> BoxedFuture<'a, Result<RpAbortMultipartUpload>>;
+    /// Dyn version of [`Accessor::summary`]
+    fn summary_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSummary,
+    ) -> BoxedFuture<'a, Result<RpSummary>>;
+    /// Dyn version of [`Accessor::create_symlink`]
+    fn create_symlink_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpCreateSymlink,
+    ) -> BoxedFuture<'a, Result<RpCreateSymlink>>;
+    /// Dyn version of [`Accessor::read_link`]
+    fn read_link_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpReadLink,
+    ) -> BoxedFuture<'a, Result<RpReadLink>>;
+    /// Dyn version of [`Accessor::set_attr`]
+    fn set_attr_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetAttr,
+    ) -> BoxedFuture<'a, Result<RpSetAttr>>;
+    /// Dyn version of [`Accessor::get_tags`]
+    fn get_tags_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpGetTags,
+    ) -> BoxedFuture<'a, Result<RpGetTags>>;
+    /// Dyn version of [`Accessor::set_tags`]
+    fn set_tags_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetTags,
+    ) -> BoxedFuture<'a, Result<RpSetTags>>;
+    /// Dyn version of [`Accessor::set_retention`]
+    fn set_retention_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetRetention,
+    ) -> BoxedFuture<'a, Result<RpSetRetention>>;
+    /// Dyn version of [`Accessor::set_legal_hold`]
+    fn set_legal_hold_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetLegalHold,
+    ) -> BoxedFuture<'a, Result<RpSetLegalHold>>;
+    /// Dyn version of [`Accessor::restore`]
+    fn restore_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpRestore,
+    ) -> BoxedFuture<'a, Result<RpRestore>>;
+    /// Dyn version of [`Accessor::presign_post`]
+    fn presign_post_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpPresignPost,
+    ) -> BoxedFuture<'a, Result<RpPresignPost>>;
     /// Dyn version of [`Accessor::batch`]
     fn batch_dyn(&self, args: OpBatch) -> BoxedFuture<'_, Result<RpBatch>>;
     /// Dyn version of [`Accessor::blocking_create_dir`]
@@ -558,6 +889,102 @@ where
         Box::pin(self.presign(path, args))
     }
 
+    fn list_multipart_uploads_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpListMultipartUploads,
+    ) -> BoxedFuture<'a, Result<RpListMultipartUploads>> {
+        Box::pin(self.list_multipart_uploads(path, args))
+    }
+
+    fn abort_multipart_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpAbortMultipartUpload,
+    ) -> BoxedFuture<'a, Result<RpAbortMultipartUpload>> {
+        Box::pin(self.abort_multipart_upload(path, args))
+    }
+
+    fn summary_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSummary,
+    ) -> BoxedFuture<'a, Result<RpSummary>> {
+        Box::pin(self.summary(path, args))
+    }
+
+    fn create_symlink_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpCreateSymlink,
+    ) -> BoxedFuture<'a, Result<RpCreateSymlink>> {
+        Box::pin(self.create_symlink(path, args))
+    }
+
+    fn read_link_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpReadLink,
+    ) -> BoxedFuture<'a, Result<RpReadLink>> {
+        Box::pin(self.read_link(path, args))
+    }
+
+    fn set_attr_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetAttr,
+    ) -> BoxedFuture<'a, Result<RpSetAttr>> {
+        Box::pin(self.set_attr(path, args))
+    }
+
+    fn get_tags_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpGetTags,
+    ) -> BoxedFuture<'a, Result<RpGetTags>> {
+        Box::pin(self.get_tags(path, args))
+    }
+
+    fn set_tags_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetTags,
+    ) -> BoxedFuture<'a, Result<RpSetTags>> {
+        Box::pin(self.set_tags(path, args))
+    }
+
+    fn set_retention_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetRetention,
+    ) -> BoxedFuture<'a, Result<RpSetRetention>> {
+        Box::pin(self.set_retention(path, args))
+    }
+
+    fn set_legal_hold_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetLegalHold,
+    ) -> BoxedFuture<'a, Result<RpSetLegalHold>> {
+        Box::pin(self.set_legal_hold(path, args))
+    }
+
+    fn restore_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpRestore,
+    ) -> BoxedFuture<'a, Result<RpRestore>> {
+        Box::pin(self.restore(path, args))
+    }
+
+    fn presign_post_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpPresignPost,
+    ) -> BoxedFuture<'a, Result<RpPresignPost>> {
+        Box::pin(self.presign_post(path, args))
+    }
+
     fn batch_dyn(&self, args: OpBatch) -> BoxedFuture<'_, Result<RpBatch>> {
         Box::pin(self.batch(args))
     }
@@ -647,6 +1074,62 @@ impl Access for dyn AccessDyn {
         self.presign_dyn(path, args).await
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        self.list_multipart_uploads_dyn(path, args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        self.abort_multipart_upload_dyn(path, args).await
+    }
+
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        self.summary_dyn(path, args).await
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        self.create_symlink_dyn(path, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        self.read_link_dyn(path, args).await
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        self.set_attr_dyn(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        self.get_tags_dyn(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        self.set_tags_dyn(path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        self.set_retention_dyn(path, args).await
+    }
+
+    async fn set_legal_hold(&self, path: &str, args: OpSetLegalHold) -> Result<RpSetLegalHold> {
+        self.set_legal_hold_dyn(path, args).await
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        self.restore_dyn(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.presign_post_dyn(path, args).await
+    }
+
     fn batch(&self, args: OpBatch) -> impl Future<Output = Result<RpBatch>> + MaybeSend {
         self.batch_dyn(args)
     }
@@ -792,6 +1275,102 @@ impl<T: Access + ?Sized> Access for Arc<T> {
         async move { self.as_ref().presign(path, args).await }
     }
 
+    fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> impl Future<Output = Result<RpListMultipartUploads>> + MaybeSend {
+        async move { self.as_ref().list_multipart_uploads(path, args).await }
+    }
+
+    fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> impl Future<Output = Result<RpAbortMultipartUpload>> + MaybeSend {
+        async move { self.as_ref().abort_multipart_upload(path, args).await }
+    }
+
+    fn summary(
+        &self,
+        path: &str,
+        args: OpSummary,
+    ) -> impl Future<Output = Result<RpSummary>> + MaybeSend {
+        async move { self.as_ref().summary(path, args).await }
+    }
+
+    fn create_symlink(
+        &self,
+        path: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        async move { self.as_ref().create_symlink(path, args).await }
+    }
+
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        async move { self.as_ref().read_link(path, args).await }
+    }
+
+    fn set_attr(
+        &self,
+        path: &str,
+        args: OpSetAttr,
+    ) -> impl Future<Output = Result<RpSetAttr>> + MaybeSend {
+        async move { self.as_ref().set_attr(path, args).await }
+    }
+
+    fn get_tags(
+        &self,
+        path: &str,
+        args: OpGetTags,
+    ) -> impl Future<Output = Result<RpGetTags>> + MaybeSend {
+        async move { self.as_ref().get_tags(path, args).await }
+    }
+
+    fn set_tags(
+        &self,
+        path: &str,
+        args: OpSetTags,
+    ) -> impl Future<Output = Result<RpSetTags>> + MaybeSend {
+        async move { self.as_ref().set_tags(path, args).await }
+    }
+
+    fn set_retention(
+        &self,
+        path: &str,
+        args: OpSetRetention,
+    ) -> impl Future<Output = Result<RpSetRetention>> + MaybeSend {
+        async move { self.as_ref().set_retention(path, args).await }
+    }
+
+    fn set_legal_hold(
+        &self,
+        path: &str,
+        args: OpSetLegalHold,
+    ) -> impl Future<Output = Result<RpSetLegalHold>> + MaybeSend {
+        async move { self.as_ref().set_legal_hold(path, args).await }
+    }
+
+    fn restore(
+        &self,
+        path: &str,
+        args: OpRestore,
+    ) -> impl Future<Output = Result<RpRestore>> + MaybeSend {
+        async move { self.as_ref().restore(path, args).await }
+    }
+
+    fn presign_post(
+        &self,
+        path: &str,
+        args: OpPresignPost,
+    ) -> impl Future<Output = Result<RpPresignPost>> + MaybeSend {
+        async move { self.as_ref().presign_post(path, args).await }
+    }
+
     fn batch(&self, args: OpBatch) -> impl Future<Output = Result<RpBatch>> + MaybeSend {
         async move { self.as_ref().batch(args).await }
     }
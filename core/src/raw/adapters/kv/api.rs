@@ -48,6 +48,26 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         .with_operation("kv::Adapter::blocking_get"))
     }
 
+    /// Get multiple keys from service at once.
+    ///
+    /// Adapters should enable `batch_read` in capability if this
+    /// operation is supported.
+    ///
+    /// - return results in the same order as input paths.
+    /// - return `None` for the key that is not exist.
+    fn get_many(
+        &self,
+        paths: &[String],
+    ) -> impl Future<Output = Result<Vec<Option<Buffer>>>> + MaybeSend {
+        let _ = paths;
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::get_many")))
+    }
+
     /// Set a key into service.
     fn set(&self, path: &str, value: Buffer) -> impl Future<Output = Result<()>> + MaybeSend;
 
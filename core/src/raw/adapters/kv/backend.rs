@@ -78,10 +78,22 @@ impl<S: Adapter> Access for Backend<S> {
             cap.delete = true;
         }
 
+        // Random write and truncate are done by replacing the whole value.
+        if cap.read && cap.write {
+            cap.write_can_random = true;
+            cap.set_attr = true;
+            cap.set_attr_with_size = true;
+        }
+
         if cap.list {
             cap.list_with_recursive = true;
         }
 
+        if cap.batch_read {
+            cap.batch = true;
+            cap.batch_stat = true;
+        }
+
         am.set_native_capability(cap);
 
         am.into()
@@ -105,16 +117,36 @@ impl<S: Adapter> Access for Backend<S> {
         Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            KvWriter::new(self.kv.clone(), p, args.offset()),
+        ))
     }
 
-    fn blocking_write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
         let p = build_abs_path(&self.root, path);
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            KvWriter::new(self.kv.clone(), p, args.offset()),
+        ))
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        let p = build_abs_path(&self.root, path);
+
+        if let Some(size) = args.size() {
+            let bs = match self.kv.get(&p).await? {
+                Some(bs) => bs,
+                None => return Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
+            };
+            self.kv.set(&p, resize_buffer(bs, size)).await?;
+        }
+
+        Ok(RpSetAttr::default())
     }
 
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
@@ -180,6 +212,55 @@ impl<S: Adapter> Access for Backend<S> {
 
         Ok((RpList::default(), lister))
     }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = args.into_operation();
+        let root = build_abs_path(&self.root, "");
+        let keys: Vec<String> = ops
+            .iter()
+            .map(|(path, _)| build_abs_path(&self.root, path))
+            .collect();
+
+        let values = self.kv.get_many(&keys).await?;
+        if values.len() != ops.len() {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "kv returns different number of values with keys",
+            )
+            .with_operation("kv::Adapter::get_many"));
+        }
+
+        let results = ops
+            .into_iter()
+            .zip(keys)
+            .zip(values)
+            .map(|(((path, op), key), value)| {
+                let result = match (op, value) {
+                    (BatchOperation::Stat(_), _) if key == root => {
+                        Ok(RpStat::new(Metadata::new(EntryMode::DIR)).into())
+                    }
+                    (BatchOperation::Stat(_), Some(bs)) => Ok(RpStat::new(
+                        Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
+                    )
+                    .into()),
+                    (BatchOperation::Read(args), Some(bs)) => {
+                        Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())).into())
+                    }
+                    (BatchOperation::Stat(_) | BatchOperation::Read(_), None) => {
+                        Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path"))
+                    }
+                    (op, _) => Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "kv doesn't support this operation in batch",
+                    )
+                    .with_operation(op.operation())),
+                };
+                (path, result)
+            })
+            .collect();
+
+        Ok(RpBatch::new(results))
+    }
 }
 
 pub struct KvLister {
@@ -223,14 +304,16 @@ impl oio::BlockingList for KvLister {
 pub struct KvWriter<S> {
     kv: Arc<S>,
     path: String,
+    offset: Option<u64>,
     buffer: QueueBuf,
 }
 
 impl<S> KvWriter<S> {
-    fn new(kv: Arc<S>, path: String) -> Self {
+    fn new(kv: Arc<S>, path: String, offset: Option<u64>) -> Self {
         KvWriter {
             kv,
             path,
+            offset,
             buffer: QueueBuf::new(),
         }
     }
@@ -248,7 +331,11 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
     }
 
     async fn close(&mut self) -> Result<()> {
-        let buf = self.buffer.clone().collect();
+        let mut buf = self.buffer.clone().collect();
+        if let Some(offset) = self.offset {
+            let origin = self.kv.get(&self.path).await?.unwrap_or_default();
+            buf = write_buffer_at(origin, offset, buf);
+        }
         self.kv.set(&self.path, buf).await
     }
 
@@ -265,7 +352,11 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
     }
 
     fn close(&mut self) -> Result<()> {
-        let buf = self.buffer.clone().collect();
+        let mut buf = self.buffer.clone().collect();
+        if let Some(offset) = self.offset {
+            let origin = self.kv.blocking_get(&self.path)?.unwrap_or_default();
+            buf = write_buffer_at(origin, offset, buf);
+        }
         self.kv.blocking_set(&self.path, buf)?;
         Ok(())
     }
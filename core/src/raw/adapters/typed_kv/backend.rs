@@ -87,6 +87,13 @@ impl<S: Adapter> Access for Backend<S> {
             cap.list_with_recursive = true;
         }
 
+        // Random write and truncate are done by replacing the whole value.
+        if kv_cap.get && kv_cap.set {
+            cap.write_can_random = true;
+            cap.set_attr = true;
+            cap.set_attr_with_size = true;
+        }
+
         cap.blocking = true;
 
         am.set_native_capability(cap);
@@ -158,6 +165,22 @@ impl<S: Adapter> Access for Backend<S> {
         }
     }
 
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        let p = build_abs_path(&self.root, path);
+
+        if let Some(size) = args.size() {
+            let mut value = match self.kv.get(&p).await? {
+                Some(value) => value,
+                None => return Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
+            };
+            value.value = resize_buffer(value.value, size);
+            value.metadata.set_content_length(size);
+            self.kv.set(&p, value).await?;
+        }
+
+        Ok(RpSetAttr::default())
+    }
+
     async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         let p = build_abs_path(&self.root, path);
 
@@ -254,10 +277,22 @@ impl<S> KvWriter<S> {
         }
     }
 
-    fn build(&mut self) -> Value {
-        let value = self.buf.take().map(QueueBuf::collect).unwrap_or_default();
+    /// Build the value to write, `origin` is the existing value that will be
+    /// written into if offset is set.
+    fn build(&mut self, origin: Option<Value>) -> Value {
+        let mut value = self.buf.take().map(QueueBuf::collect).unwrap_or_default();
 
         let mut metadata = Metadata::new(EntryMode::FILE);
+        if let Some(offset) = self.op.offset() {
+            let origin = match origin {
+                Some(origin) => {
+                    metadata = origin.metadata;
+                    origin.value
+                }
+                None => Buffer::new(),
+            };
+            value = write_buffer_at(origin, offset, value);
+        }
         metadata.set_content_length(value.len() as u64);
 
         if let Some(v) = self.op.cache_control() {
@@ -286,7 +321,11 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
         let value = match &self.value {
             Some(value) => value.clone(),
             None => {
-                let value = self.build();
+                let origin = match self.op.offset() {
+                    Some(_) => self.kv.get(&self.path).await?,
+                    None => None,
+                };
+                let value = self.build(origin);
                 self.value = Some(value.clone());
                 value
             }
@@ -314,7 +353,11 @@ impl<S: Adapter> oio::BlockingWrite for KvWriter<S> {
         let value = match &self.value {
             Some(value) => value.clone(),
             None => {
-                let value = self.build();
+                let origin = match self.op.offset() {
+                    Some(_) => kv.blocking_get(&self.path)?,
+                    None => None,
+                };
+                let value = self.build(origin);
                 self.value = Some(value.clone());
                 value
             }
@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+
+use crate::*;
+
+/// Write `data` into `origin` starting at `offset`, and return the new content.
+///
+/// Content outside the written range will be kept, and the gap between the end
+/// of `origin` and `offset` will be filled with zeros.
+///
+/// This is used by services that can only replace the whole value to support
+/// random write.
+pub fn write_buffer_at(origin: Buffer, offset: u64, data: Buffer) -> Buffer {
+    let offset = offset as usize;
+    let end = offset + data.len();
+
+    let mut bs: Vec<Bytes> = Vec::new();
+    bs.extend(origin.slice(..offset.min(origin.len())));
+    if offset > origin.len() {
+        bs.push(Bytes::from(vec![0; offset - origin.len()]));
+    }
+    bs.extend(data);
+    if end < origin.len() {
+        bs.extend(origin.slice(end..));
+    }
+
+    bs.into_iter().collect()
+}
+
+/// Resize `origin` to `size`, the new space will be filled with zeros.
+pub fn resize_buffer(origin: Buffer, size: u64) -> Buffer {
+    let size = size as usize;
+    if size <= origin.len() {
+        return origin.slice(..size);
+    }
+
+    let zeros = Bytes::from(vec![0; size - origin.len()]);
+    origin.chain(Some(zeros)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_buffer_at() {
+        let cases = vec![
+            (
+                "overwrite head",
+                "Hello, World!",
+                0,
+                "hallo",
+                "hallo, World!",
+            ),
+            (
+                "overwrite middle",
+                "Hello, World!",
+                7,
+                "Rust!",
+                "Hello, Rust!!",
+            ),
+            ("extend tail", "Hello", 3, "p me", "Help me"),
+            ("append", "Hello", 5, "!", "Hello!"),
+            ("fill gap", "ab", 4, "cd", "ab\0\0cd"),
+            ("empty origin", "", 2, "ab", "\0\0ab"),
+        ];
+
+        for (name, origin, offset, data, expected) in cases {
+            let bs = write_buffer_at(
+                Buffer::from(origin.as_bytes().to_vec()),
+                offset,
+                Buffer::from(data.as_bytes().to_vec()),
+            );
+            assert_eq!(bs.to_bytes(), expected.as_bytes(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_resize_buffer() {
+        let bs = resize_buffer(Buffer::from("Hello, World!"), 5);
+        assert_eq!(bs.to_bytes(), "Hello".as_bytes());
+
+        let bs = resize_buffer(Buffer::from("Hello"), 7);
+        assert_eq!(bs.to_bytes(), "Hello\0\0".as_bytes());
+    }
+}
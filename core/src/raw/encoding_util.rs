@@ -0,0 +1,106 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::Read;
+
+use bytes::Buf;
+use flate2::read::DeflateDecoder;
+use flate2::read::MultiGzDecoder;
+use flate2::read::ZlibDecoder;
+
+use crate::*;
+
+/// Decode content by given `Content-Encoding`.
+///
+/// `Content-Encoding` could contain multiple codings in the order they were
+/// applied, so we will decode them in reverse order.
+///
+/// Only `gzip`, `deflate` and `identity` are supported for now, other codings
+/// will return an `Unsupported` error.
+pub fn decode_content(content_encoding: &str, bs: Buffer) -> Result<Buffer> {
+    let mut bs = bs;
+
+    for coding in content_encoding.rsplit(',') {
+        let coding = coding.trim().to_ascii_lowercase();
+
+        bs = match coding.as_str() {
+            "" | "identity" => bs,
+            "gzip" | "x-gzip" => read_all(MultiGzDecoder::new(bs.reader()), &coding)?,
+            // `deflate` in HTTP means zlib format, but some servers send raw
+            // deflate data, so we fallback to raw deflate if zlib failed.
+            "deflate" => match read_all(ZlibDecoder::new(bs.clone().reader()), &coding) {
+                Ok(v) => v,
+                Err(_) => read_all(DeflateDecoder::new(bs.reader()), &coding)?,
+            },
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "content encoding is not supported to decode",
+                )
+                .with_context("content_encoding", content_encoding))
+            }
+        };
+    }
+
+    Ok(bs)
+}
+
+fn read_all(mut r: impl Read, coding: &str) -> Result<Buffer> {
+    let mut buf = Vec::new();
+    r.read_to_end(&mut buf).map_err(|err| {
+        Error::new(ErrorKind::Unexpected, "decode content failed")
+            .with_context("content_encoding", coding)
+            .set_source(err)
+    })?;
+    Ok(Buffer::from(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_content() -> Result<()> {
+        let content = b"Hello, World!".repeat(16);
+
+        let mut w = GzEncoder::new(Vec::new(), Compression::default());
+        w.write_all(&content).unwrap();
+        let gzipped = w.finish().unwrap();
+        let bs = decode_content("gzip", Buffer::from(gzipped.clone()))?;
+        assert_eq!(bs.to_vec(), content);
+
+        let mut w = ZlibEncoder::new(Vec::new(), Compression::default());
+        w.write_all(&gzipped).unwrap();
+        let both = w.finish().unwrap();
+        let bs = decode_content("gzip, deflate", Buffer::from(both))?;
+        assert_eq!(bs.to_vec(), content);
+
+        let bs = decode_content("identity", Buffer::from(content.clone()))?;
+        assert_eq!(bs.to_vec(), content);
+
+        let err = decode_content("br", Buffer::from(content)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,581 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// The xattr namespace that user metadata will be stored in.
+const XATTR_USER_PREFIX: &str = "user.";
+/// Xattrs with this prefix are reserved and won't be returned as user metadata.
+const XATTR_RESERVED_PREFIX: &str = "user.opendal.";
+/// The xattr that content encoding will be stored in.
+const XATTR_CONTENT_ENCODING: &str = "user.opendal.content_encoding";
+/// The xattr that records the user metadata keys written by OpenDAL.
+const XATTR_USER_METADATA_KEYS: &str = "user.opendal.user_metadata_keys";
+/// The suffix of sidecar files.
+const SIDECAR_SUFFIX: &str = ".opendal-meta";
+
+/// FsMeta carries metadata of local files that can't be stored in the file
+/// itself, like content encoding and user metadata.
+///
+/// FsMeta will be stored in `user.*` extended attributes, and fallback to a
+/// hidden sidecar file `.{name}.opendal-meta` in the same dir on filesystems or
+/// platforms without xattr support.
+///
+/// All `user.*` xattrs will be returned as user metadata, but only the ones
+/// written by OpenDAL will be replaced while storing, xattrs set by other
+/// tools are left untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FsMeta {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_encoding: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    user_metadata: HashMap<String, String>,
+}
+
+impl FsMeta {
+    /// Build FsMeta from the args of write.
+    pub fn from_op_write(op: &OpWrite) -> Self {
+        Self {
+            content_encoding: op.content_encoding().map(|v| v.to_string()),
+            user_metadata: op.user_metadata().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Check if there is nothing to store.
+    pub fn is_empty(&self) -> bool {
+        self.content_encoding.is_none() && self.user_metadata.is_empty()
+    }
+
+    /// Get the content encoding.
+    pub fn content_encoding(&self) -> Option<&str> {
+        self.content_encoding.as_deref()
+    }
+
+    /// Get the user metadata.
+    pub fn user_metadata(&self) -> &HashMap<String, String> {
+        &self.user_metadata
+    }
+
+    /// Fill content encoding and user metadata into given metadata.
+    pub fn apply(self, m: &mut Metadata) {
+        if let Some(v) = &self.content_encoding {
+            m.set_content_encoding(v);
+        }
+        if !self.user_metadata.is_empty() {
+            m.with_user_metadata(self.user_metadata);
+        }
+    }
+
+    /// Load FsMeta of the file at given path.
+    ///
+    /// Set `follow` to `false` to load FsMeta of the symlink itself.
+    pub fn load(p: &Path, follow: bool) -> Result<Self> {
+        let mut meta = FsMeta::default();
+
+        match xattr::list(p, follow) {
+            Ok(names) => {
+                for name in names {
+                    if !name.starts_with(XATTR_USER_PREFIX) {
+                        continue;
+                    }
+                    let Some(value) = xattr::get(p, &name, follow).map_err(new_std_io_error)?
+                    else {
+                        continue;
+                    };
+
+                    if name == XATTR_CONTENT_ENCODING {
+                        meta.content_encoding = Some(value);
+                    } else if !name.starts_with(XATTR_RESERVED_PREFIX) {
+                        let key = name[XATTR_USER_PREFIX.len()..].to_string();
+                        meta.user_metadata.insert(key, value);
+                    }
+                }
+            }
+            Err(err) if is_unsupported(&err) => {}
+            Err(err) => return Err(new_std_io_error(err)),
+        }
+
+        match std::fs::read(fs_meta_sidecar_of(p)) {
+            Ok(bs) => {
+                let sidecar: FsMeta =
+                    serde_json::from_slice(&bs).map_err(new_json_deserialize_error)?;
+                if meta.content_encoding.is_none() {
+                    meta.content_encoding = sidecar.content_encoding;
+                }
+                for (k, v) in sidecar.user_metadata {
+                    meta.user_metadata.entry(k).or_insert(v);
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(new_std_io_error(err)),
+        }
+
+        Ok(meta)
+    }
+
+    /// Store FsMeta for the file at given path.
+    ///
+    /// FsMeta stored by OpenDAL before will be replaced, just like overwriting
+    /// an object replaces all its metadata.
+    pub fn store(&self, p: &Path) -> Result<()> {
+        match self.store_xattr(p) {
+            Ok(()) => remove_if_exists(&fs_meta_sidecar_of(p)),
+            Err(err) if is_unsupported(&err) || is_too_large(&err) => {
+                // Xattrs could be written partially, remove them so that they
+                // won't shadow the sidecar file.
+                if is_too_large(&err) {
+                    self.remove_xattr(p).map_err(new_std_io_error)?;
+                }
+                if self.is_empty() {
+                    return remove_if_exists(&fs_meta_sidecar_of(p));
+                }
+
+                let bs = serde_json::to_vec(self).map_err(new_json_serialize_error)?;
+                std::fs::write(fs_meta_sidecar_of(p), bs).map_err(new_std_io_error)
+            }
+            Err(err) => Err(new_std_io_error(err)),
+        }
+    }
+
+    fn store_xattr(&self, p: &Path) -> io::Result<()> {
+        let mut attrs: HashMap<String, &str> = self
+            .user_metadata
+            .iter()
+            .map(|(k, v)| (format!("{XATTR_USER_PREFIX}{k}"), v.as_str()))
+            .collect();
+        if let Some(v) = &self.content_encoding {
+            attrs.insert(XATTR_CONTENT_ENCODING.to_string(), v);
+        }
+
+        // Only remove the xattrs written by OpenDAL before.
+        let mut owned = vec![XATTR_CONTENT_ENCODING.to_string()];
+        if let Some(v) = xattr::get(p, XATTR_USER_METADATA_KEYS, true)? {
+            let keys: Vec<String> = serde_json::from_str(&v).unwrap_or_default();
+            owned.extend(keys.iter().map(|k| format!("{XATTR_USER_PREFIX}{k}")));
+        }
+        for name in owned {
+            if !attrs.contains_key(&name) {
+                xattr::remove(p, &name)?;
+            }
+        }
+        for (name, value) in attrs {
+            xattr::set(p, &name, value)?;
+        }
+
+        if self.user_metadata.is_empty() {
+            xattr::remove(p, XATTR_USER_METADATA_KEYS)
+        } else {
+            let mut keys: Vec<&String> = self.user_metadata.keys().collect();
+            keys.sort();
+            let keys = serde_json::to_string(&keys)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            xattr::set(p, XATTR_USER_METADATA_KEYS, &keys)
+        }
+    }
+
+    /// Remove the xattrs that could be written by [`FsMeta::store_xattr`].
+    fn remove_xattr(&self, p: &Path) -> io::Result<()> {
+        let mut names = vec![
+            XATTR_CONTENT_ENCODING.to_string(),
+            XATTR_USER_METADATA_KEYS.to_string(),
+        ];
+        names.extend(
+            self.user_metadata
+                .keys()
+                .map(|k| format!("{XATTR_USER_PREFIX}{k}")),
+        );
+        for name in names {
+            xattr::remove(p, &name)?;
+        }
+        Ok(())
+    }
+
+    /// Remove FsMeta of the file at given path.
+    ///
+    /// Only the sidecar file needs to be removed, xattrs will be removed along
+    /// with the file.
+    pub fn remove(p: &Path) -> Result<()> {
+        remove_if_exists(&fs_meta_sidecar_of(p))
+    }
+
+    /// Move FsMeta along with the file renamed from `from` to `to`.
+    pub fn rename(from: &Path, to: &Path) -> Result<()> {
+        match std::fs::rename(fs_meta_sidecar_of(from), fs_meta_sidecar_of(to)) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                remove_if_exists(&fs_meta_sidecar_of(to))
+            }
+            Err(err) => Err(new_std_io_error(err)),
+        }
+    }
+
+    /// Copy FsMeta along with the file copied from `from` to `to`.
+    pub fn copy(from: &Path, to: &Path) -> Result<()> {
+        FsMeta::load(from, true)?.store(to)
+    }
+}
+
+/// Get the sidecar file path of given file.
+pub fn fs_meta_sidecar_of(p: &Path) -> PathBuf {
+    let name = p.file_name().unwrap_or_default().to_string_lossy();
+    p.with_file_name(format!(".{name}{SIDECAR_SUFFIX}"))
+}
+
+/// Check if the file name is a sidecar file, which should be hidden from list.
+pub fn is_fs_meta_sidecar(name: &str) -> bool {
+    name.len() > SIDECAR_SUFFIX.len() + 1 && name.starts_with('.') && name.ends_with(SIDECAR_SUFFIX)
+}
+
+fn remove_if_exists(p: &Path) -> Result<()> {
+    match std::fs::remove_file(p) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(new_std_io_error(err)),
+    }
+}
+
+fn is_unsupported(err: &io::Error) -> bool {
+    #[cfg(target_os = "linux")]
+    if err.raw_os_error() == Some(libc::ENOTSUP) {
+        return true;
+    }
+
+    err.kind() == io::ErrorKind::Unsupported
+}
+
+/// Filesystems limit the size of xattrs, like a single block on ext4.
+#[cfg(target_os = "linux")]
+fn is_too_large(err: &io::Error) -> bool {
+    matches!(err.raw_os_error(), Some(libc::E2BIG | libc::ENOSPC))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_too_large(_: &io::Error) -> bool {
+    false
+}
+
+#[cfg(target_os = "linux")]
+mod xattr {
+    use std::ffi::CString;
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    fn to_cstring(v: &[u8]) -> io::Result<CString> {
+        CString::new(v).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+    }
+
+    pub fn list(p: &Path, follow: bool) -> io::Result<Vec<String>> {
+        let path = to_cstring(p.as_os_str().as_bytes())?;
+
+        loop {
+            // SAFETY: path is nul terminated, and null buffer with zero size
+            // is allowed to query the size of list.
+            let size = unsafe {
+                if follow {
+                    libc::listxattr(path.as_ptr(), std::ptr::null_mut(), 0)
+                } else {
+                    libc::llistxattr(path.as_ptr(), std::ptr::null_mut(), 0)
+                }
+            };
+            if size < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if size == 0 {
+                return Ok(vec![]);
+            }
+
+            let mut buf = vec![0u8; size as usize];
+            // SAFETY: path is nul terminated and buf is valid during the call.
+            let n = unsafe {
+                if follow {
+                    libc::listxattr(path.as_ptr(), buf.as_mut_ptr() as _, buf.len())
+                } else {
+                    libc::llistxattr(path.as_ptr(), buf.as_mut_ptr() as _, buf.len())
+                }
+            };
+            if n < 0 {
+                let err = io::Error::last_os_error();
+                // The list has been changed after we got the size, try again.
+                if err.raw_os_error() == Some(libc::ERANGE) {
+                    continue;
+                }
+                return Err(err);
+            }
+            buf.truncate(n as usize);
+
+            return Ok(buf
+                .split(|v| *v == 0)
+                .filter(|v| !v.is_empty())
+                .map(|v| String::from_utf8_lossy(v).to_string())
+                .collect());
+        }
+    }
+
+    pub fn get(p: &Path, name: &str, follow: bool) -> io::Result<Option<String>> {
+        let path = to_cstring(p.as_os_str().as_bytes())?;
+        let name = to_cstring(name.as_bytes())?;
+
+        loop {
+            // SAFETY: path and name are nul terminated, and null buffer with
+            // zero size is allowed to query the size of value.
+            let size = unsafe {
+                if follow {
+                    libc::getxattr(path.as_ptr(), name.as_ptr(), std::ptr::null_mut(), 0)
+                } else {
+                    libc::lgetxattr(path.as_ptr(), name.as_ptr(), std::ptr::null_mut(), 0)
+                }
+            };
+            if size < 0 {
+                let err = io::Error::last_os_error();
+                if err.raw_os_error() == Some(libc::ENODATA) {
+                    return Ok(None);
+                }
+                return Err(err);
+            }
+
+            let mut buf = vec![0u8; size as usize];
+            // SAFETY: path and name are nul terminated and buf is valid during the call.
+            let n = unsafe {
+                if follow {
+                    libc::getxattr(
+                        path.as_ptr(),
+                        name.as_ptr(),
+                        buf.as_mut_ptr() as _,
+                        buf.len(),
+                    )
+                } else {
+                    libc::lgetxattr(
+                        path.as_ptr(),
+                        name.as_ptr(),
+                        buf.as_mut_ptr() as _,
+                        buf.len(),
+                    )
+                }
+            };
+            if n < 0 {
+                let err = io::Error::last_os_error();
+                match err.raw_os_error() {
+                    Some(libc::ERANGE) => continue,
+                    Some(libc::ENODATA) => return Ok(None),
+                    _ => return Err(err),
+                }
+            }
+            buf.truncate(n as usize);
+
+            return Ok(Some(String::from_utf8_lossy(&buf).to_string()));
+        }
+    }
+
+    pub fn set(p: &Path, name: &str, value: &str) -> io::Result<()> {
+        let path = to_cstring(p.as_os_str().as_bytes())?;
+        let name = to_cstring(name.as_bytes())?;
+
+        // SAFETY: path and name are nul terminated and value is valid during the call.
+        let ret = unsafe {
+            libc::setxattr(
+                path.as_ptr(),
+                name.as_ptr(),
+                value.as_ptr() as _,
+                value.len(),
+                0,
+            )
+        };
+        if ret < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    pub fn remove(p: &Path, name: &str) -> io::Result<()> {
+        let path = to_cstring(p.as_os_str().as_bytes())?;
+        let name = to_cstring(name.as_bytes())?;
+
+        // SAFETY: path and name are nul terminated.
+        let ret = unsafe { libc::removexattr(path.as_ptr(), name.as_ptr()) };
+        if ret < 0 {
+            let err = io::Error::last_os_error();
+            if err.raw_os_error() == Some(libc::ENODATA) {
+                return Ok(());
+            }
+            return Err(err);
+        }
+        Ok(())
+    }
+}
+
+/// Xattr is only supported on linux for now, other platforms will always
+/// fallback to sidecar files.
+#[cfg(not(target_os = "linux"))]
+mod xattr {
+    use std::io;
+    use std::path::Path;
+
+    pub fn list(_: &Path, _: bool) -> io::Result<Vec<String>> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn get(_: &Path, _: &str, _: bool) -> io::Result<Option<String>> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set(_: &Path, _: &str, _: &str) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn remove(_: &Path, _: &str) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_test_file() -> PathBuf {
+        let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&dir).unwrap();
+        let p = dir.join("file");
+        std::fs::write(&p, b"Hello, World!").unwrap();
+        p
+    }
+
+    #[test]
+    fn test_sidecar_of() {
+        let p = fs_meta_sidecar_of(Path::new("/tmp/dir/file.txt"));
+        assert_eq!(p, Path::new("/tmp/dir/.file.txt.opendal-meta"));
+        assert!(is_fs_meta_sidecar(".file.txt.opendal-meta"));
+        assert!(!is_fs_meta_sidecar("file.txt"));
+        assert!(!is_fs_meta_sidecar(".opendal-meta"));
+    }
+
+    #[test]
+    fn test_store_and_load() -> Result<()> {
+        let p = new_test_file();
+
+        let meta = FsMeta {
+            content_encoding: Some("gzip".to_string()),
+            user_metadata: HashMap::from([("location".to_string(), "everywhere".to_string())]),
+        };
+        meta.store(&p)?;
+        assert_eq!(FsMeta::load(&p, true)?, meta);
+
+        // Store again should replace all metadata.
+        let meta = FsMeta {
+            content_encoding: None,
+            user_metadata: HashMap::from([("owner".to_string(), "opendal".to_string())]),
+        };
+        meta.store(&p)?;
+        assert_eq!(FsMeta::load(&p, true)?, meta);
+
+        FsMeta::default().store(&p)?;
+        assert!(FsMeta::load(&p, true)?.is_empty());
+
+        std::fs::remove_dir_all(p.parent().unwrap()).unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_too_large() -> Result<()> {
+        let p = new_test_file();
+
+        // Larger than the max size of a single xattr value.
+        let meta = FsMeta {
+            content_encoding: Some("gzip".to_string()),
+            user_metadata: HashMap::from([("large".to_string(), "a".repeat(128 * 1024))]),
+        };
+        meta.store(&p)?;
+        assert_eq!(FsMeta::load(&p, true)?, meta);
+
+        // Xattrs are used again once it fits.
+        let meta = FsMeta {
+            content_encoding: None,
+            user_metadata: HashMap::from([("small".to_string(), "a".to_string())]),
+        };
+        meta.store(&p)?;
+        assert_eq!(FsMeta::load(&p, true)?, meta);
+
+        std::fs::remove_dir_all(p.parent().unwrap()).unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_keeps_foreign_xattrs() -> Result<()> {
+        let p = new_test_file();
+        match xattr::set(&p, "user.foreign", "value") {
+            Ok(()) => {}
+            // Xattr is not supported here, nothing to test.
+            Err(err) if is_unsupported(&err) => return Ok(()),
+            Err(err) => return Err(new_std_io_error(err)),
+        }
+
+        let meta = FsMeta {
+            content_encoding: None,
+            user_metadata: HashMap::from([("location".to_string(), "everywhere".to_string())]),
+        };
+        meta.store(&p)?;
+        FsMeta::default().store(&p)?;
+
+        let meta = FsMeta::load(&p, true)?;
+        assert_eq!(
+            meta.user_metadata(),
+            &HashMap::from([("foreign".to_string(), "value".to_string())])
+        );
+
+        std::fs::remove_dir_all(p.parent().unwrap()).unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_from_sidecar() -> Result<()> {
+        let p = new_test_file();
+        std::fs::write(
+            fs_meta_sidecar_of(&p),
+            r#"{"content_encoding":"gzip","user_metadata":{"location":"everywhere"}}"#,
+        )
+        .unwrap();
+
+        let meta = FsMeta::load(&p, true)?;
+        assert_eq!(meta.content_encoding(), Some("gzip"));
+        assert_eq!(
+            meta.user_metadata().get("location").map(|v| v.as_str()),
+            Some("everywhere")
+        );
+
+        let to = p.with_file_name("renamed");
+        std::fs::rename(&p, &to).unwrap();
+        FsMeta::rename(&p, &to)?;
+        assert!(!fs_meta_sidecar_of(&p).exists());
+        assert_eq!(FsMeta::load(&to, true)?, meta);
+
+        std::fs::remove_file(&to).unwrap();
+        FsMeta::remove(&to)?;
+        assert!(!fs_meta_sidecar_of(&to).exists());
+
+        std::fs::remove_dir_all(p.parent().unwrap()).unwrap();
+        Ok(())
+    }
+}
@@ -38,6 +38,7 @@ use crate::EntryMode;
 use crate::Error;
 use crate::ErrorKind;
 use crate::Metadata;
+use crate::RestoreStatus;
 use crate::Result;
 
 /// Parse redirect location from header map
@@ -186,9 +187,44 @@ pub fn parse_into_metadata(path: &str, headers: &HeaderMap) -> Result<Metadata>
         m.set_content_disposition(v);
     }
 
+    if let Some(v) = parse_content_encoding(headers)? {
+        m.set_content_encoding(v);
+    }
+
     Ok(m)
 }
 
+/// Parse restore status from `x-amz-restore` like header.
+///
+/// S3 compatible services like OSS return the same format:
+///
+/// - `ongoing-request="true"`
+/// - `ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT"`
+pub fn parse_restore_status(v: &str) -> Result<Option<RestoreStatus>> {
+    let mut ongoing = None;
+    let mut expiry = None;
+
+    // Values are quoted and may contain `,`, so we can't simply split by `,`.
+    let mut rest = v;
+    while let Some((key, remain)) = rest.split_once("=\"") {
+        let Some((value, remain)) = remain.split_once('"') else {
+            break;
+        };
+        match key.trim_start_matches([',', ' ']) {
+            "ongoing-request" => ongoing = Some(value == "true"),
+            "expiry-date" => expiry = Some(parse_datetime_from_rfc2822(value)?),
+            _ => {}
+        }
+        rest = remain;
+    }
+
+    Ok(match ongoing {
+        Some(true) => Some(RestoreStatus::InProgress),
+        Some(false) => Some(RestoreStatus::Completed { expiry }),
+        None => None,
+    })
+}
+
 /// format content md5 header by given input.
 pub fn format_content_md5(bs: &[u8]) -> String {
     let mut hasher = md5::Md5::new();
@@ -307,4 +343,21 @@ mod tests {
             assert_eq!(actual, expected)
         }
     }
+
+    #[test]
+    fn test_parse_restore_status() {
+        assert_eq!(
+            parse_restore_status(r#"ongoing-request="true""#).expect("must success"),
+            Some(RestoreStatus::InProgress)
+        );
+        assert_eq!(
+            parse_restore_status(
+                r#"ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT""#
+            )
+            .expect("must success"),
+            Some(RestoreStatus::Completed {
+                expiry: Some(DateTime::from_timestamp(1356048000, 0).unwrap())
+            })
+        );
+    }
 }
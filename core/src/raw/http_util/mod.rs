@@ -44,8 +44,10 @@ pub use header::parse_header_to_str;
 pub use header::parse_into_metadata;
 pub use header::parse_last_modified;
 pub use header::parse_location;
+pub use header::parse_restore_status;
 
 mod uri;
+pub use uri::format_tagging_query;
 pub use uri::percent_decode_path;
 pub use uri::percent_encode_path;
 
@@ -66,3 +68,9 @@ pub use multipart::FormDataPart;
 pub use multipart::MixedPart;
 pub use multipart::Multipart;
 pub use multipart::Part;
+
+mod post_policy;
+pub use post_policy::PostPolicy;
+
+mod tagging;
+pub use tagging::Tagging;
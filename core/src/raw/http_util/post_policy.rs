@@ -0,0 +1,160 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::raw::*;
+
+/// PostPolicy is the policy document used by browser based POST uploads.
+///
+/// s3, oss, cos and gcs share the same policy format, only the signing
+/// fields are different. Services should build the policy via [`PostPolicy::new`],
+/// add their own signing fields and sign the result of [`PostPolicy::encode`].
+#[derive(Debug, Clone)]
+pub struct PostPolicy {
+    expiration: DateTime<Utc>,
+    conditions: Vec<Value>,
+    fields: Vec<(String, String)>,
+}
+
+impl PostPolicy {
+    /// Create a new policy for given absolute key.
+    ///
+    /// The `key`, `Content-Type` and `content-length-range` conditions
+    /// will be generated from `args`.
+    pub fn new(now: DateTime<Utc>, key: &str, args: &OpPresignPost) -> Self {
+        let mut policy = Self {
+            expiration: now + args.expire(),
+            conditions: vec![],
+            fields: vec![],
+        };
+
+        if args.key_prefix() {
+            policy.conditions.push(json!(["starts-with", "$key", key]));
+            policy
+                .fields
+                .push(("key".to_string(), format!("{key}${{filename}}")));
+        } else {
+            policy = policy.with_field("key", key);
+        }
+
+        if let Some(v) = args.content_type() {
+            policy = policy.with_field("Content-Type", v);
+        }
+
+        if let Some((min, max)) = args.content_length_range() {
+            policy
+                .conditions
+                .push(json!(["content-length-range", min, max]));
+        }
+
+        policy
+    }
+
+    /// Get the expiration of this policy.
+    pub fn expiration(&self) -> DateTime<Utc> {
+        self.expiration
+    }
+
+    /// Add a form field along with an exact match condition for it.
+    pub fn with_field(mut self, name: &str, value: &str) -> Self {
+        self.conditions.push(json!({ name: value }));
+        self.fields.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add an exact match condition without form field, like `bucket`.
+    pub fn with_condition(mut self, name: &str, value: &str) -> Self {
+        self.conditions.push(json!({ name: value }));
+        self
+    }
+
+    /// Serialize this policy into json.
+    pub fn to_json(&self) -> String {
+        // Build the json by hand to keep `expiration` the first key.
+        format!(
+            r#"{{"expiration":"{}","conditions":{}}}"#,
+            self.expiration.to_rfc3339_opts(SecondsFormat::Millis, true),
+            Value::Array(self.conditions.clone())
+        )
+    }
+
+    /// Serialize this policy into base64 encoded json which should be
+    /// sent as the `policy` field.
+    pub fn encode(&self) -> String {
+        BASE64_STANDARD.encode(self.to_json())
+    }
+
+    /// Consume the policy to get all form fields added so far.
+    pub fn into_fields(self) -> Vec<(String, String)> {
+        self.fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_post_policy() {
+        let now = DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let args = OpPresignPost::new(Duration::from_secs(3600))
+            .with_content_type("image/png")
+            .with_content_length_range(1, 1024);
+
+        let policy = PostPolicy::new(now, "root/a.png", &args).with_condition("bucket", "test");
+        assert_eq!(
+            policy.to_json(),
+            r#"{"expiration":"2024-01-02T04:04:05.000Z","conditions":[{"key":"root/a.png"},{"Content-Type":"image/png"},["content-length-range",1,1024],{"bucket":"test"}]}"#
+        );
+        assert_eq!(
+            policy.into_fields(),
+            vec![
+                ("key".to_string(), "root/a.png".to_string()),
+                ("Content-Type".to_string(), "image/png".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_post_policy_with_key_prefix() {
+        let now = DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let args = OpPresignPost::new(Duration::from_secs(60)).with_key_prefix(true);
+
+        let policy = PostPolicy::new(now, "root/uploads/", &args);
+        assert_eq!(
+            policy.to_json(),
+            r#"{"expiration":"2024-01-02T03:05:05.000Z","conditions":[["starts-with","$key","root/uploads/"]]}"#
+        );
+        assert_eq!(
+            policy.into_fields(),
+            vec![("key".to_string(), "root/uploads/${filename}".to_string())]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Tagging is the request of PutObjectTagging and the output of GetObjectTagging.
+///
+/// s3, oss, cos and obs share the same XML format for object tags.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tagging", rename_all = "PascalCase")]
+pub struct Tagging {
+    tag_set: TagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct TagSet {
+    tag: Vec<Tag>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct Tag {
+    key: String,
+    value: String,
+}
+
+impl Tagging {
+    /// Build tagging from tags, tags are sorted by key.
+    pub fn from_tags(tags: &HashMap<String, String>) -> Self {
+        let mut tag: Vec<_> = tags
+            .iter()
+            .map(|(k, v)| Tag {
+                key: k.clone(),
+                value: v.clone(),
+            })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Self {
+            tag_set: TagSet { tag },
+        }
+    }
+
+    /// Convert tagging into tags.
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tag_set
+            .tag
+            .into_iter()
+            .map(|t| (t.key, t.value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Buf;
+    use bytes::Bytes;
+
+    use super::*;
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectTagging.html#API_GetObjectTagging_Examples
+    #[test]
+    fn test_tagging() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Tagging xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <TagSet>
+     <Tag>
+       <Key>tag1</Key>
+       <Value>val1</Value>
+     </Tag>
+     <Tag>
+       <Key>tag2</Key>
+       <Value>val2</Value>
+     </Tag>
+  </TagSet>
+</Tagging>"#,
+        );
+
+        let out: Tagging = quick_xml::de::from_reader(bs.reader()).expect("must success");
+        let tags = out.into_tags();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags["tag1"], "val1");
+        assert_eq!(tags["tag2"], "val2");
+
+        let req = quick_xml::se::to_string(&Tagging::from_tags(&tags)).expect("must success");
+        assert_eq!(
+            req,
+            "<Tagging><TagSet><Tag><Key>tag1</Key><Value>val1</Value></Tag><Tag><Key>tag2</Key><Value>val2</Value></Tag></TagSet></Tagging>"
+        );
+
+        let out: Tagging =
+            quick_xml::de::from_str("<Tagging><TagSet></TagSet></Tagging>").expect("must success");
+        assert!(out.into_tags().is_empty());
+    }
+}
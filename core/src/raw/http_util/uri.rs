@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use percent_encoding::percent_decode_str;
 use percent_encoding::utf8_percent_encode;
 use percent_encoding::AsciiSet;
@@ -48,6 +50,36 @@ pub fn percent_encode_path(path: &str) -> String {
     utf8_percent_encode(path, &PATH_ENCODE_SET).to_string()
 }
 
+/// QUERY_ENCODE_SET is the encode set for http query key and value.
+///
+/// Only the unreserved characters `A-Z a-z 0-9 - _ . ~` defined by
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-2.3) are kept.
+static QUERY_ENCODE_SET: AsciiSet = NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// format_tagging_query will format tags into url query like `k1=v1&k2=v2`.
+///
+/// Services like s3 accept tags in this format via headers like `x-amz-tagging`.
+/// Tags are sorted by key so that the output is stable.
+pub fn format_tagging_query(tags: &HashMap<String, String>) -> String {
+    let mut tags: Vec<_> = tags.iter().collect();
+    tags.sort();
+
+    tags.into_iter()
+        .map(|(k, v)| {
+            format!(
+                "{}={}",
+                utf8_percent_encode(k, &QUERY_ENCODE_SET),
+                utf8_percent_encode(v, &QUERY_ENCODE_SET)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
 /// percent_decode_path will do percent decoding for http decode path.
 ///
 /// If the input is not percent encoded or not valid utf8, return the input.
@@ -91,6 +123,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_format_tagging_query() {
+        let tags = HashMap::from([
+            ("team".to_string(), "data eng".to_string()),
+            ("cost-center".to_string(), "a/b&c".to_string()),
+        ]);
+
+        assert_eq!(
+            format_tagging_query(&tags),
+            "cost-center=a%2Fb%26c&team=data%20eng"
+        );
+        assert_eq!(format_tagging_query(&HashMap::new()), "");
+    }
+
     #[test]
     fn test_percent_decode_path() {
         let cases = vec![
@@ -212,6 +212,102 @@ pub trait LayeredAccess: Send + Sync + Debug + Unpin + 'static {
         self.inner().presign(path, args)
     }
 
+    fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> impl Future<Output = Result<RpListMultipartUploads>> + MaybeSend {
+        self.inner().list_multipart_uploads(path, args)
+    }
+
+    fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> impl Future<Output = Result<RpAbortMultipartUpload>> + MaybeSend {
+        self.inner().abort_multipart_upload(path, args)
+    }
+
+    fn summary(
+        &self,
+        path: &str,
+        args: OpSummary,
+    ) -> impl Future<Output = Result<RpSummary>> + MaybeSend {
+        self.inner().summary(path, args)
+    }
+
+    fn create_symlink(
+        &self,
+        path: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        self.inner().create_symlink(path, args)
+    }
+
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        self.inner().read_link(path, args)
+    }
+
+    fn set_attr(
+        &self,
+        path: &str,
+        args: OpSetAttr,
+    ) -> impl Future<Output = Result<RpSetAttr>> + MaybeSend {
+        self.inner().set_attr(path, args)
+    }
+
+    fn get_tags(
+        &self,
+        path: &str,
+        args: OpGetTags,
+    ) -> impl Future<Output = Result<RpGetTags>> + MaybeSend {
+        self.inner().get_tags(path, args)
+    }
+
+    fn set_tags(
+        &self,
+        path: &str,
+        args: OpSetTags,
+    ) -> impl Future<Output = Result<RpSetTags>> + MaybeSend {
+        self.inner().set_tags(path, args)
+    }
+
+    fn set_retention(
+        &self,
+        path: &str,
+        args: OpSetRetention,
+    ) -> impl Future<Output = Result<RpSetRetention>> + MaybeSend {
+        self.inner().set_retention(path, args)
+    }
+
+    fn set_legal_hold(
+        &self,
+        path: &str,
+        args: OpSetLegalHold,
+    ) -> impl Future<Output = Result<RpSetLegalHold>> + MaybeSend {
+        self.inner().set_legal_hold(path, args)
+    }
+
+    fn restore(
+        &self,
+        path: &str,
+        args: OpRestore,
+    ) -> impl Future<Output = Result<RpRestore>> + MaybeSend {
+        self.inner().restore(path, args)
+    }
+
+    fn presign_post(
+        &self,
+        path: &str,
+        args: OpPresignPost,
+    ) -> impl Future<Output = Result<RpPresignPost>> + MaybeSend {
+        self.inner().presign_post(path, args)
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         self.inner().blocking_create_dir(path, args)
     }
@@ -291,6 +387,62 @@ impl<L: LayeredAccess> Access for L {
         (self as &L).presign(path, args).await
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        args: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        (self as &L).list_multipart_uploads(path, args).await
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        (self as &L).abort_multipart_upload(path, args).await
+    }
+
+    async fn summary(&self, path: &str, args: OpSummary) -> Result<RpSummary> {
+        (self as &L).summary(path, args).await
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        (self as &L).create_symlink(path, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        (self as &L).read_link(path, args).await
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        (self as &L).set_attr(path, args).await
+    }
+
+    async fn get_tags(&self, path: &str, args: OpGetTags) -> Result<RpGetTags> {
+        (self as &L).get_tags(path, args).await
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        (self as &L).set_tags(path, args).await
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        (self as &L).set_retention(path, args).await
+    }
+
+    async fn set_legal_hold(&self, path: &str, args: OpSetLegalHold) -> Result<RpSetLegalHold> {
+        (self as &L).set_legal_hold(path, args).await
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        (self as &L).restore(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        (self as &L).presign_post(path, args).await
+    }
+
     fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
         (self as &L).blocking_create_dir(path, args)
     }
@@ -69,6 +69,19 @@ pub use tokio_util::*;
 mod std_io_util;
 pub use std_io_util::*;
 
+#[cfg(feature = "decode-content")]
+mod encoding_util;
+#[cfg(feature = "decode-content")]
+pub use encoding_util::*;
+
+mod buffer_util;
+pub use buffer_util::*;
+
+#[cfg(feature = "internal-fs-meta")]
+mod fs_meta;
+#[cfg(feature = "internal-fs-meta")]
+pub use fs_meta::*;
+
 mod futures_util;
 pub use futures_util::BoxedFuture;
 pub use futures_util::BoxedStaticFuture;
@@ -0,0 +1,690 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::raw::*;
+use crate::*;
+
+/// GlobLister will walk dirs hierarchically and only return entries that
+/// match given glob pattern.
+///
+/// The following syntax is supported:
+///
+/// - `?` matches any single character except `/`.
+/// - `*` matches any sequence of characters except `/`.
+/// - `**` as a whole segment matches zero or more directories.
+/// - `[abc]`, `[a-z]` matches one character in the set, `[!abc]` negates it.
+/// - `\` escapes the next character.
+///
+/// Patterns ending with `/` will only match dirs.
+///
+/// Given pattern `logs/2024-*/*.json`, GlobLister will:
+///
+/// - Start from the longest literal prefix and push it down to the service, like
+///   listing `logs/2024-` instead of `logs/`.
+/// - Only descend into dirs that could still match the pattern, so
+///   `logs/2023-12/` will never be visited.
+pub struct GlobLister<A: Access, L> {
+    acc: A,
+    args: OpList,
+    pattern: GlobPattern,
+
+    /// dirs that still need to be visited, along with the path to list.
+    next_dirs: Vec<(String, String)>,
+    active_lister: Option<(String, L)>,
+}
+
+/// # Safety
+///
+/// wasm32 is a special target that we only have one event-loop for this GlobLister.
+unsafe impl<A: Access, L> Send for GlobLister<A, L> {}
+/// # Safety
+///
+/// We will only take `&mut Self` reference for GlobLister.
+unsafe impl<A: Access, L> Sync for GlobLister<A, L> {}
+
+impl<A, L> GlobLister<A, L>
+where
+    A: Access,
+{
+    /// Create a new glob lister.
+    ///
+    /// `pattern` must be a normalized path relative to the root of `acc`.
+    pub fn new(acc: A, pattern: &str, args: OpList) -> Result<GlobLister<A, L>> {
+        // Dirs are walked one by one, there is no global order to start after.
+        if args.start_after().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "list with glob doesn't support start_after",
+            )
+            .with_operation("GlobLister::new")
+            .with_context("pattern", pattern));
+        }
+
+        let pattern = GlobPattern::parse(pattern)?;
+        let root = pattern.literal_dir();
+        let path = pattern.list_path(&root);
+
+        // Only forward the args that make sense for every level.
+        let mut list_args = OpList::new()
+            .with_metakey(args.metakey())
+            .with_concurrent(args.concurrent());
+        if let Some(limit) = args.limit() {
+            list_args = list_args.with_limit(limit);
+        }
+
+        Ok(GlobLister {
+            acc,
+            args: list_args,
+            pattern,
+            next_dirs: vec![(root, path)],
+            active_lister: None,
+        })
+    }
+
+    /// Check the entry, returns whether the entry should be output.
+    ///
+    /// Dirs that could contain matched entries will be pushed into `next_dirs`.
+    fn check(&mut self, dir: &str, entry: &oio::Entry) -> bool {
+        // Some services will return the dir itself, skip it.
+        if entry.path() == dir {
+            return false;
+        }
+        let is_dir = entry.mode().is_dir();
+        let segs = split_segments(entry.path());
+
+        if is_dir && self.pattern.could_match(&segs) {
+            let path = self.pattern.list_path(entry.path());
+            self.next_dirs.push((entry.path().to_string(), path));
+        }
+
+        (is_dir || !self.pattern.dir_only) && self.pattern.matches(&segs)
+    }
+
+    fn list_args(&self) -> OpList {
+        self.args.clone()
+    }
+}
+
+impl<A, L> oio::List for GlobLister<A, L>
+where
+    A: Access<Lister = L>,
+    L: oio::List,
+{
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            if self.active_lister.is_none() {
+                let Some((dir, path)) = self.next_dirs.pop() else {
+                    return Ok(None);
+                };
+                let (_, l) = self.acc.list(&path, self.list_args()).await?;
+                self.active_lister = Some((dir, l));
+            }
+
+            let (dir, lister) = self.active_lister.as_mut().expect("lister must be valid");
+            match lister.next().await? {
+                Some(entry) => {
+                    let dir = dir.clone();
+                    if self.check(&dir, &entry) {
+                        return Ok(Some(entry));
+                    }
+                }
+                None => self.active_lister = None,
+            }
+        }
+    }
+}
+
+impl<A, P> oio::BlockingList for GlobLister<A, P>
+where
+    A: Access<BlockingLister = P>,
+    P: oio::BlockingList,
+{
+    fn next(&mut self) -> Result<Option<oio::Entry>> {
+        loop {
+            if self.active_lister.is_none() {
+                let Some((dir, path)) = self.next_dirs.pop() else {
+                    return Ok(None);
+                };
+                let (_, l) = self.acc.blocking_list(&path, self.list_args())?;
+                self.active_lister = Some((dir, l));
+            }
+
+            let (dir, lister) = self.active_lister.as_mut().expect("lister must be valid");
+            match lister.next()? {
+                Some(entry) => {
+                    let dir = dir.clone();
+                    if self.check(&dir, &entry) {
+                        return Ok(Some(entry));
+                    }
+                }
+                None => self.active_lister = None,
+            }
+        }
+    }
+}
+
+/// Build a glob pattern that matches `pattern` under given list path.
+///
+/// The path will be escaped so that it will be matched literally.
+pub(crate) fn join_glob(path: &str, pattern: &str) -> String {
+    if path == "/" {
+        return pattern.to_string();
+    }
+
+    let mut s = String::with_capacity(path.len() + pattern.len());
+    for c in path.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            s.push('\\');
+        }
+        s.push(c);
+    }
+    s.push_str(pattern);
+    s
+}
+
+fn split_segments(path: &str) -> Vec<&str> {
+    path.trim_end_matches('/')
+        .split('/')
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Char(char),
+    /// `?`
+    Any,
+    /// `*`
+    Star,
+    /// `[...]`
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// `**`
+    Recursive,
+    Tokens(Vec<Token>),
+}
+
+impl Segment {
+    /// Returns the literal string of this segment if it contains no wildcards.
+    fn literal(&self) -> Option<String> {
+        match self {
+            Segment::Recursive => None,
+            Segment::Tokens(tokens) => tokens
+                .iter()
+                .map(|t| match t {
+                    Token::Char(c) => Some(*c),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the literal prefix of this segment before the first wildcard.
+    fn literal_prefix(&self) -> String {
+        match self {
+            Segment::Recursive => String::new(),
+            Segment::Tokens(tokens) => tokens
+                .iter()
+                .map_while(|t| match t {
+                    Token::Char(c) => Some(*c),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            Segment::Recursive => true,
+            Segment::Tokens(tokens) => {
+                let chars: Vec<char> = name.chars().collect();
+                match_tokens(tokens, &chars)
+            }
+        }
+    }
+}
+
+fn match_tokens(tokens: &[Token], chars: &[char]) -> bool {
+    let (mut ti, mut ci) = (0, 0);
+    // The position to backtrack to when the last `*` failed to match.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while ci < chars.len() {
+        match tokens.get(ti) {
+            Some(Token::Star) => {
+                backtrack = Some((ti, ci));
+                ti += 1;
+                continue;
+            }
+            Some(t) if match_token(t, chars[ci]) => {
+                ti += 1;
+                ci += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        match backtrack {
+            Some((bt, bc)) => {
+                backtrack = Some((bt, bc + 1));
+                ti = bt + 1;
+                ci = bc + 1;
+            }
+            None => return false,
+        }
+    }
+
+    tokens[ti..].iter().all(|t| *t == Token::Star)
+}
+
+fn match_token(token: &Token, c: char) -> bool {
+    match token {
+        Token::Char(v) => *v == c,
+        Token::Any => true,
+        Token::Star => true,
+        Token::Class { negated, ranges } => {
+            ranges.iter().any(|(lo, hi)| *lo <= c && c <= *hi) != *negated
+        }
+    }
+}
+
+/// GlobPattern is a parsed glob pattern split by `/`.
+#[derive(Debug, Clone, PartialEq)]
+struct GlobPattern {
+    segments: Vec<Segment>,
+    dir_only: bool,
+}
+
+impl GlobPattern {
+    fn parse(pattern: &str) -> Result<Self> {
+        let new_error = |msg: &str| {
+            Error::new(ErrorKind::ConfigInvalid, msg)
+                .with_operation("GlobPattern::parse")
+                .with_context("pattern", pattern)
+        };
+
+        let dir_only = pattern.ends_with('/');
+        let mut segments = vec![];
+        for seg in pattern.split('/').filter(|v| !v.is_empty()) {
+            if seg == "**" {
+                // Continuous `**` are the same as one.
+                if segments.last() != Some(&Segment::Recursive) {
+                    segments.push(Segment::Recursive);
+                }
+                continue;
+            }
+
+            let mut tokens = vec![];
+            let mut chars = seg.chars();
+            while let Some(c) = chars.next() {
+                let token = match c {
+                    '?' => Token::Any,
+                    '*' => Token::Star,
+                    '\\' => match chars.next() {
+                        Some(c) => Token::Char(c),
+                        None => return Err(new_error("glob pattern ends with dangling escape")),
+                    },
+                    '[' => {
+                        let mut negated = false;
+                        let mut ranges = vec![];
+                        let mut closed = false;
+                        let mut first = true;
+                        while let Some(c) = chars.next() {
+                            match c {
+                                '!' | '^' if first && !negated => {
+                                    negated = true;
+                                    continue;
+                                }
+                                ']' if !first => {
+                                    closed = true;
+                                    break;
+                                }
+                                _ => {}
+                            }
+                            first = false;
+
+                            let lo = if c == '\\' {
+                                chars.next().ok_or_else(|| {
+                                    new_error("glob pattern ends with dangling escape")
+                                })?
+                            } else {
+                                c
+                            };
+                            let mut lookahead = chars.clone();
+                            match (lookahead.next(), lookahead.next()) {
+                                (Some('-'), Some(hi)) if hi != ']' => {
+                                    chars = lookahead;
+                                    ranges.push((lo, hi));
+                                }
+                                _ => ranges.push((lo, lo)),
+                            }
+                        }
+                        if !closed {
+                            return Err(new_error("glob pattern has unclosed character class"));
+                        }
+                        Token::Class { negated, ranges }
+                    }
+                    c => Token::Char(c),
+                };
+                tokens.push(token);
+            }
+            segments.push(Segment::Tokens(tokens));
+        }
+
+        if segments.is_empty() {
+            return Err(new_error("glob pattern is empty"));
+        }
+
+        Ok(GlobPattern { segments, dir_only })
+    }
+
+    /// Returns the dir that built by the leading literal segments.
+    ///
+    /// The last segment is never included so that it can be matched against
+    /// listed entries.
+    fn literal_dir(&self) -> String {
+        let mut dir = String::new();
+        for seg in &self.segments[..self.segments.len() - 1] {
+            match seg.literal() {
+                Some(v) => {
+                    dir.push_str(&v);
+                    dir.push('/');
+                }
+                None => break,
+            }
+        }
+
+        if dir.is_empty() {
+            "/".to_string()
+        } else {
+            dir
+        }
+    }
+
+    /// Returns the path to list for given dir.
+    ///
+    /// The literal prefix of the next segment will be pushed down if there is
+    /// no `**` before it.
+    fn list_path(&self, dir: &str) -> String {
+        let depth = split_segments(dir).len();
+        if self.segments[..depth].contains(&Segment::Recursive) {
+            return dir.to_string();
+        }
+        let prefix = match self.segments.get(depth) {
+            Some(seg) => seg.literal_prefix(),
+            None => String::new(),
+        };
+
+        match (dir, prefix.is_empty()) {
+            (_, true) => dir.to_string(),
+            ("/", false) => prefix,
+            (_, false) => format!("{dir}{prefix}"),
+        }
+    }
+
+    /// Check if the given path segments match the whole pattern.
+    fn matches(&self, segs: &[&str]) -> bool {
+        fn inner(pattern: &[Segment], segs: &[&str]) -> bool {
+            match pattern.first() {
+                None => segs.is_empty(),
+                Some(Segment::Recursive) => {
+                    (0..=segs.len()).any(|i| inner(&pattern[1..], &segs[i..]))
+                }
+                Some(seg) => match segs.first() {
+                    Some(name) => seg.matches(name) && inner(&pattern[1..], &segs[1..]),
+                    None => false,
+                },
+            }
+        }
+
+        inner(&self.segments, segs)
+    }
+
+    /// Check if entries under the dir of given path segments could match the pattern.
+    fn could_match(&self, segs: &[&str]) -> bool {
+        fn inner(pattern: &[Segment], segs: &[&str]) -> bool {
+            if segs.is_empty() {
+                return !pattern.is_empty();
+            }
+            match pattern.first() {
+                None => false,
+                Some(Segment::Recursive) => true,
+                Some(seg) => seg.matches(segs[0]) && inner(&pattern[1..], &segs[1..]),
+            }
+        }
+
+        inner(&self.segments, segs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::vec::IntoIter;
+
+    use oio::BlockingList;
+
+    use super::*;
+
+    fn matches(pattern: &str, path: &str) -> bool {
+        let pattern = GlobPattern::parse(pattern).unwrap();
+        pattern.matches(&split_segments(path))
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        let cases = vec![
+            ("*.txt", "a.txt", true),
+            ("*.txt", "dir/a.txt", false),
+            ("dir/*.txt", "dir/a.txt", true),
+            ("dir/?.txt", "dir/ab.txt", false),
+            ("dir/[a-c].txt", "dir/b.txt", true),
+            ("dir/[!a-c].txt", "dir/b.txt", false),
+            ("dir/[!a-c].txt", "dir/d.txt", true),
+            ("**/*.txt", "a.txt", true),
+            ("**/*.txt", "x/y/z/a.txt", true),
+            ("x/**/a.txt", "x/a.txt", true),
+            ("x/**/a.txt", "x/y/z/a.txt", true),
+            ("x/**/a.txt", "y/a.txt", false),
+            ("a*b*c", "abbbc", true),
+            ("a*b*c", "abbbcd", false),
+            ("\\*.txt", "*.txt", true),
+            ("\\*.txt", "a.txt", false),
+        ];
+
+        for (pattern, path, expected) in cases {
+            assert_eq!(
+                matches(pattern, path),
+                expected,
+                "pattern {pattern} with path {path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_glob_parse_invalid() {
+        for pattern in ["a/[bc", "a\\", "/"] {
+            let err = GlobPattern::parse(pattern).expect_err("pattern must be invalid");
+            assert_eq!(err.kind(), ErrorKind::ConfigInvalid, "pattern {pattern}");
+        }
+    }
+
+    #[test]
+    fn test_glob_list_path() {
+        let cases = vec![
+            ("*.txt", "/", "/"),
+            ("abc*.txt", "/", "abc"),
+            ("logs/2024-*/*.json", "logs/", "logs/2024-"),
+            ("logs/2024-*/data/*.json", "logs/", "logs/2024-"),
+            ("a/b/c.txt", "a/b/", "a/b/c.txt"),
+            ("a/**/c*.txt", "a/", "a/"),
+        ];
+
+        for (pattern, dir, path) in cases {
+            let pattern = GlobPattern::parse(pattern).unwrap();
+            assert_eq!(pattern.literal_dir(), dir, "literal dir of {pattern:?}");
+            assert_eq!(pattern.list_path(dir), path, "list path of {pattern:?}");
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct MockService {
+        map: HashMap<&'static str, Vec<&'static str>>,
+        visited: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl MockService {
+        fn new() -> Self {
+            let mut map = HashMap::default();
+            map.insert("/", vec!["logs/", "readme"]);
+            map.insert(
+                "logs/",
+                vec!["logs/2023-12/", "logs/2024-01/", "logs/2024-02/"],
+            );
+            map.insert("logs/2023-12/", vec!["logs/2023-12/a.json"]);
+            map.insert(
+                "logs/2024-01/",
+                vec!["logs/2024-01/a.json", "logs/2024-01/b.txt"],
+            );
+            map.insert(
+                "logs/2024-02/",
+                vec!["logs/2024-02/c.json", "logs/2024-02/x/"],
+            );
+            map.insert("logs/2024-02/x/", vec!["logs/2024-02/x/d.json"]);
+
+            Self {
+                map,
+                ..Default::default()
+            }
+        }
+
+        /// Simulate the behavior of services that list with prefix.
+        fn get(&self, path: &str) -> MockLister {
+            self.visited.lock().unwrap().push(path.to_string());
+
+            let inner = match path.ends_with('/') {
+                true => self.map.get(path).cloned().unwrap_or_default(),
+                false => self
+                    .map
+                    .get(get_parent(path))
+                    .map(|v| v.iter().copied().filter(|p| p.starts_with(path)).collect())
+                    .unwrap_or_default(),
+            };
+
+            MockLister {
+                inner: inner.into_iter(),
+            }
+        }
+    }
+
+    impl Access for MockService {
+        type Reader = ();
+        type BlockingReader = ();
+        type Writer = ();
+        type BlockingWriter = ();
+        type Lister = ();
+        type BlockingLister = MockLister;
+
+        fn info(&self) -> Arc<AccessorInfo> {
+            let mut am = AccessorInfo::default();
+            am.full_capability_mut().list = true;
+
+            am.into()
+        }
+
+        fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingLister)> {
+            Ok((RpList::default(), self.get(path)))
+        }
+    }
+
+    struct MockLister {
+        inner: IntoIter<&'static str>,
+    }
+
+    impl BlockingList for MockLister {
+        fn next(&mut self) -> Result<Option<oio::Entry>> {
+            Ok(self.inner.next().map(|path| {
+                if path.ends_with('/') {
+                    oio::Entry::new(path, Metadata::new(EntryMode::DIR))
+                } else {
+                    oio::Entry::new(path, Metadata::new(EntryMode::FILE))
+                }
+            }))
+        }
+    }
+
+    fn list(pattern: &str) -> Result<(Vec<String>, Vec<String>)> {
+        let acc = MockService::new();
+        let visited = acc.visited.clone();
+        let mut lister = GlobLister::new(acc, pattern, OpList::new())?;
+
+        let mut entries = Vec::default();
+        while let Some(e) = lister.next()? {
+            entries.push(e.path().to_string())
+        }
+        entries.sort();
+
+        let visited = visited.lock().unwrap().clone();
+        Ok((entries, visited))
+    }
+
+    #[test]
+    fn test_blocking_glob_list() -> Result<()> {
+        let (entries, visited) = list("logs/2024-*/*.json")?;
+        assert_eq!(entries, vec!["logs/2024-01/a.json", "logs/2024-02/c.json"]);
+        assert!(!visited.contains(&"logs/2023-12/".to_string()));
+        assert!(!visited.contains(&"logs/2024-02/x/".to_string()));
+        assert_eq!(visited[0], "logs/2024-");
+
+        let (entries, visited) = list("logs/**/*.json")?;
+        assert_eq!(
+            entries,
+            vec![
+                "logs/2023-12/a.json",
+                "logs/2024-01/a.json",
+                "logs/2024-02/c.json",
+                "logs/2024-02/x/d.json",
+            ]
+        );
+        assert_eq!(visited[0], "logs/");
+
+        let (entries, _) = list("logs/*/")?;
+        assert_eq!(
+            entries,
+            vec!["logs/2023-12/", "logs/2024-01/", "logs/2024-02/"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_glob_list_with_start_after() {
+        let args = OpList::new().with_start_after("logs/2024-01/");
+        let res = GlobLister::<_, MockLister>::new(MockService::new(), "logs/*/", args);
+        assert_eq!(
+            res.err().map(|err| err.kind()),
+            Some(ErrorKind::Unsupported)
+        );
+    }
+}
@@ -34,3 +34,7 @@ pub use hierarchy_list::HierarchyLister;
 
 mod prefix_list;
 pub use prefix_list::PrefixLister;
+
+mod glob_list;
+pub(crate) use glob_list::join_glob;
+pub use glob_list::GlobLister;
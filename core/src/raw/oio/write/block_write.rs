@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::select;
@@ -84,6 +85,21 @@ pub trait BlockWrite: Send + Sync + Unpin + 'static {
 
     /// abort_block will cancel the block upload and purge all data.
     fn abort_block(&self, block_ids: Vec<Uuid>) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// list_blocks will list the blocks that have been uploaded but not
+    /// committed yet, returns the block id and the size of the block.
+    ///
+    /// BlockWriter will call this API while resuming from a checkpoint.
+    ///
+    /// Services don't support resume can keep the default implementation.
+    fn list_blocks(&self) -> impl Future<Output = Result<Vec<(Uuid, u64)>>> + MaybeSend {
+        async {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "block writer doesn't support list blocks",
+            ))
+        }
+    }
 }
 
 struct WriteInput<W: BlockWrite> {
@@ -101,8 +117,13 @@ pub struct BlockWriter<W: BlockWrite> {
 
     started: bool,
     block_ids: Vec<Uuid>,
+    /// All block ids that have been submitted in order, used to build checkpoint.
+    submitted: Vec<Uuid>,
+    /// Size of blocks in `block_ids` which have been uploaded successfully.
+    confirmed: u64,
+    recorder: Option<oio::CheckpointRecorder>,
     cache: Option<Buffer>,
-    tasks: ConcurrentTasks<WriteInput<W>, Uuid>,
+    tasks: ConcurrentTasks<WriteInput<W>, (Uuid, u64)>,
 }
 
 impl<W: BlockWrite> BlockWriter<W> {
@@ -115,6 +136,9 @@ impl<W: BlockWrite> BlockWriter<W> {
             executor: executor.clone(),
             started: false,
             block_ids: Vec::new(),
+            submitted: Vec::new(),
+            confirmed: 0,
+            recorder: None,
             cache: None,
 
             tasks: ConcurrentTasks::new(executor, concurrent, |input| {
@@ -126,7 +150,7 @@ impl<W: BlockWrite> BlockWriter<W> {
                             input.bytes.len() as u64,
                             input.bytes.clone(),
                         )
-                        .map_ok(|_| input.block_id);
+                        .map_ok(|_| (input.block_id, input.bytes.len() as u64));
                     match input.executor.timeout() {
                         None => {
                             let result = fut.await;
@@ -152,6 +176,73 @@ impl<W: BlockWrite> BlockWriter<W> {
         }
     }
 
+    /// Report checkpoint to the recorder in args, and resume from the
+    /// checkpoint in args if it's set.
+    ///
+    /// Blocks that have been uploaded will be fetched via [`BlockWrite::list_blocks`].
+    /// Only the leading blocks in checkpoint that still exist are kept, the rest
+    /// will be uploaded again.
+    pub async fn with_checkpoint(mut self, args: &OpWrite) -> Result<Self> {
+        self.recorder = args.checkpoint_recorder().cloned();
+
+        let Some(checkpoint) = args.resume() else {
+            return Ok(self);
+        };
+
+        let uploaded: HashMap<Uuid, u64> = self.w.list_blocks().await?.into_iter().collect();
+        let mut offset = 0;
+        for id in checkpoint.block_ids() {
+            let Some((id, size)) = Uuid::parse_str(id)
+                .ok()
+                .and_then(|id| uploaded.get(&id).map(|size| (id, *size)))
+            else {
+                break;
+            };
+            offset += size;
+            self.block_ids.push(id);
+        }
+
+        self.started = true;
+        self.submitted = self.block_ids.clone();
+        self.confirmed = offset;
+        self.record(offset);
+        Ok(self)
+    }
+
+    fn record(&self, offset: u64) {
+        if let Some(recorder) = &self.recorder {
+            recorder.record(
+                WriteCheckpoint::new()
+                    .with_block_ids(self.submitted.iter().map(|v| v.to_string()).collect())
+                    .with_offset(offset),
+            );
+        }
+    }
+
+    async fn submit(&mut self, bytes: Buffer) -> Result<()> {
+        let block_id = Uuid::new_v4();
+        self.tasks
+            .execute(WriteInput {
+                w: self.w.clone(),
+                executor: self.executor.clone(),
+                block_id,
+                bytes,
+            })
+            .await?;
+        self.submitted.push(block_id);
+
+        // Collect finished blocks so that the checkpoint advances with them.
+        while self.tasks.has_result() {
+            let Some((block_id, size)) = self.tasks.next().await.transpose()? else {
+                break;
+            };
+            self.block_ids.push(block_id);
+            self.confirmed += size;
+        }
+        self.record(self.confirmed);
+        Ok(())
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
@@ -173,15 +264,12 @@ where
         // The block upload process has been started.
         self.started = true;
 
-        let bytes = self.cache.clone().expect("pending write must exist");
-        self.tasks
-            .execute(WriteInput {
-                w: self.w.clone(),
-                executor: self.executor.clone(),
-                block_id: Uuid::new_v4(),
-                bytes,
-            })
-            .await?;
+        // Upload has been resumed, fill cache with the first write.
+        let Some(bytes) = self.cache.clone() else {
+            self.fill_cache(bs);
+            return Ok(());
+        };
+        self.submit(bytes).await?;
         self.cache = None;
         self.fill_cache(bs);
         Ok(())
@@ -200,22 +288,16 @@ where
         }
 
         if let Some(cache) = self.cache.clone() {
-            self.tasks
-                .execute(WriteInput {
-                    w: self.w.clone(),
-                    executor: self.executor.clone(),
-                    block_id: Uuid::new_v4(),
-                    bytes: cache,
-                })
-                .await?;
+            self.submit(cache).await?;
             self.cache = None;
         }
 
         loop {
-            let Some(result) = self.tasks.next().await.transpose()? else {
+            let Some((block_id, size)) = self.tasks.next().await.transpose()? else {
                 break;
             };
-            self.block_ids.push(result);
+            self.block_ids.push(block_id);
+            self.confirmed += size;
         }
 
         let block_ids = self.block_ids.clone();
@@ -304,6 +386,15 @@ mod tests {
         async fn abort_block(&self, _: Vec<Uuid>) -> Result<()> {
             Ok(())
         }
+
+        async fn list_blocks(&self) -> Result<Vec<(Uuid, u64)>> {
+            let this = self.lock().unwrap();
+            Ok(this
+                .bytes
+                .iter()
+                .map(|(id, bs)| (*id, bs.len() as u64))
+                .collect())
+        }
     }
 
     #[tokio::test]
@@ -348,4 +439,89 @@ mod tests {
             "content must be the same"
         );
     }
+
+    #[tokio::test]
+    async fn test_block_writer_resume() -> Result<()> {
+        let inner = TestWrite::new();
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        {
+            let mut this = inner.lock().unwrap();
+            this.bytes.insert(first, Buffer::from(vec![1; 1024]));
+            this.bytes.insert(second, Buffer::from(vec![2; 1024]));
+        }
+
+        // The block between first and second has been lost, so second must be uploaded again.
+        let checkpoint = WriteCheckpoint::new().with_block_ids(vec![
+            first.to_string(),
+            Uuid::new_v4().to_string(),
+            second.to_string(),
+        ]);
+        let recorder = oio::CheckpointRecorder::new();
+        let args = OpWrite::new()
+            .with_resume(checkpoint)
+            .with_checkpoint_recorder(recorder.clone());
+        let mut w = BlockWriter::new(inner.clone(), None, 1)
+            .with_checkpoint(&args)
+            .await?;
+        assert_eq!(
+            recorder.get(),
+            Some(
+                WriteCheckpoint::new()
+                    .with_block_ids(vec![first.to_string()])
+                    .with_offset(1024)
+            )
+        );
+
+        // TestWrite fails randomly, retry until succeed.
+        for bs in [vec![3; 1024], vec![4; 1024]] {
+            while w.write(Buffer::from(bs.clone())).await.is_err() {}
+        }
+        while w.close().await.is_err() {}
+
+        let expected: Vec<u8> = [vec![1; 1024], vec![3; 1024], vec![4; 1024]].concat();
+        let content = inner.lock().unwrap().content.clone().unwrap();
+        assert_eq!(content.to_vec(), expected);
+        assert_eq!(recorder.get().unwrap().block_ids().len(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_block_writer_interrupt_and_resume() -> Result<()> {
+        let inner = TestWrite::new();
+
+        let recorder = oio::CheckpointRecorder::new();
+        let args = OpWrite::new().with_checkpoint_recorder(recorder.clone());
+        let mut w = BlockWriter::new(inner.clone(), None, 1)
+            .with_checkpoint(&args)
+            .await?;
+        for i in 0..3 {
+            while w.write(Buffer::from(vec![i; 1024])).await.is_err() {}
+        }
+        // The last write is still cached, drop the writer to simulate an interruption.
+        drop(w);
+
+        let checkpoint = recorder.get().expect("checkpoint must be recorded");
+        assert_eq!(checkpoint.offset(), 2048);
+        assert_eq!(checkpoint.block_ids().len(), 2);
+
+        let recorder = oio::CheckpointRecorder::new();
+        let args = OpWrite::new()
+            .with_resume(checkpoint)
+            .with_checkpoint_recorder(recorder.clone());
+        let mut w = BlockWriter::new(inner.clone(), None, 1)
+            .with_checkpoint(&args)
+            .await?;
+        let offset = recorder.get().map(|v| v.offset()).unwrap_or_default();
+        assert_eq!(offset, 2048);
+
+        for i in offset / 1024..4 {
+            while w.write(Buffer::from(vec![i as u8; 1024])).await.is_err() {}
+        }
+        while w.close().await.is_err() {}
+
+        let expected: Vec<u8> = (0..4).flat_map(|i| vec![i; 1024]).collect();
+        let content = inner.lock().unwrap().content.clone().unwrap();
+        assert_eq!(content.to_vec(), expected);
+        Ok(())
+    }
 }
@@ -0,0 +1,56 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::*;
+
+/// CheckpointRecorder is shared between [`Writer`] and the underlying
+/// writers so that they can report the latest [`WriteCheckpoint`].
+///
+/// It's carried by [`OpWrite`](crate::raw::OpWrite) and cloned cheaply
+/// through layers.
+#[derive(Clone, Default)]
+pub struct CheckpointRecorder(Arc<Mutex<Option<WriteCheckpoint>>>);
+
+impl CheckpointRecorder {
+    /// Create a new recorder without checkpoint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the latest checkpoint.
+    pub fn record(&self, checkpoint: WriteCheckpoint) {
+        *self.0.lock().expect("lock must be acquired") = Some(checkpoint);
+    }
+
+    /// Get the latest checkpoint.
+    pub fn get(&self) -> Option<WriteCheckpoint> {
+        self.0.lock().expect("lock must be acquired").clone()
+    }
+}
+
+impl Debug for CheckpointRecorder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CheckpointRecorder")
+            .field(&self.get())
+            .finish()
+    }
+}
@@ -21,6 +21,9 @@ pub use api::BlockingWriter;
 pub use api::Write;
 pub use api::Writer;
 
+mod checkpoint;
+pub use checkpoint::CheckpointRecorder;
+
 mod multipart_write;
 pub use multipart_write::MultipartPart;
 pub use multipart_write::MultipartWrite;
@@ -97,6 +97,29 @@ pub trait MultipartWrite: Send + Sync + Unpin + 'static {
 
     /// abort_part will cancel the multipart upload and purge all data.
     fn abort_part(&self, upload_id: &str) -> impl Future<Output = Result<()>> + MaybeSend;
+
+    /// list_parts will list the parts that have been uploaded for given
+    /// upload id.
+    ///
+    /// MultipartWriter will call this API while resuming from a checkpoint.
+    ///
+    /// - Returned parts must be the same as returned by `write_part`.
+    /// - Returned parts must be ordered and continuous from the first part,
+    ///   parts after a missing one should be dropped, they will be uploaded again.
+    /// - Services don't support resume can keep the default implementation.
+    fn list_parts(
+        &self,
+        upload_id: &str,
+    ) -> impl Future<Output = Result<Vec<MultipartPart>>> + MaybeSend {
+        let _ = upload_id;
+
+        async {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "multipart writer doesn't support list parts",
+            ))
+        }
+    }
 }
 
 /// The result of [`MultipartWrite::write_part`].
@@ -104,12 +127,15 @@ pub trait MultipartWrite: Send + Sync + Unpin + 'static {
 /// services implement should convert MultipartPart to their own represents.
 ///
 /// - `part_number` is the index of the part, starting from 0.
+/// - `size` is the size of the part.
 /// - `etag` is the `ETag` of the part.
 /// - `checksum` is the optional checksum of the part.
 #[derive(Clone)]
 pub struct MultipartPart {
     /// The number of the part, starting from 0.
     pub part_number: usize,
+    /// The size of the part.
+    pub size: u64,
     /// The etag of the part.
     pub etag: String,
     /// The checksum of the part.
@@ -134,6 +160,7 @@ pub struct MultipartWriter<W: MultipartWrite> {
     parts: Vec<MultipartPart>,
     cache: Option<Buffer>,
     next_part_number: usize,
+    recorder: Option<oio::CheckpointRecorder>,
 
     tasks: ConcurrentTasks<WriteInput<W>, MultipartPart>,
 }
@@ -154,6 +181,7 @@ impl<W: MultipartWrite> MultipartWriter<W> {
             parts: Vec::new(),
             cache: None,
             next_part_number: 0,
+            recorder: None,
 
             tasks: ConcurrentTasks::new(executor, concurrent, |input| {
                 Box::pin({
@@ -191,6 +219,42 @@ impl<W: MultipartWrite> MultipartWriter<W> {
         }
     }
 
+    /// Report checkpoint to the recorder in args, and resume from the
+    /// checkpoint in args if it's set.
+    ///
+    /// Parts that have been uploaded will be fetched via [`MultipartWrite::list_parts`].
+    pub async fn with_checkpoint(mut self, args: &OpWrite) -> Result<Self> {
+        self.recorder = args.checkpoint_recorder().cloned();
+
+        let Some(checkpoint) = args.resume() else {
+            return Ok(self);
+        };
+        let Some(upload_id) = checkpoint.upload_id() else {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "checkpoint doesn't contain upload id",
+            ));
+        };
+
+        let parts = self.w.list_parts(upload_id).await?;
+        let offset = parts.iter().map(|v| v.size).sum();
+        self.next_part_number = parts.len();
+        self.parts = parts;
+        self.upload_id = Some(Arc::new(upload_id.to_string()));
+        self.record(upload_id, offset);
+        Ok(self)
+    }
+
+    fn record(&self, upload_id: &str, offset: u64) {
+        if let Some(recorder) = &self.recorder {
+            recorder.record(
+                WriteCheckpoint::new()
+                    .with_upload_id(upload_id)
+                    .with_offset(offset),
+            );
+        }
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
@@ -214,13 +278,18 @@ where
                 }
 
                 let upload_id = self.w.initiate_part().await?;
+                self.record(&upload_id, 0);
                 let upload_id = Arc::new(upload_id);
                 self.upload_id = Some(upload_id.clone());
                 upload_id
             }
         };
 
-        let bytes = self.cache.clone().expect("pending write must exist");
+        // Upload has been resumed, fill cache with the first write.
+        let Some(bytes) = self.cache.clone() else {
+            self.fill_cache(bs);
+            return Ok(());
+        };
         let part_number = self.next_part_number;
 
         self.tasks
@@ -235,6 +304,18 @@ where
         self.cache = None;
         self.next_part_number += 1;
         self.fill_cache(bs);
+
+        // Collect finished parts so that the checkpoint advances with them.
+        if self.tasks.has_result() {
+            while self.tasks.has_result() {
+                let Some(part) = self.tasks.next().await.transpose()? else {
+                    break;
+                };
+                self.parts.push(part);
+            }
+            let offset = self.parts.iter().map(|v| v.size).sum();
+            self.record(&upload_id, offset);
+        }
         Ok(())
     }
 
@@ -374,6 +455,7 @@ mod tests {
 
             Ok(MultipartPart {
                 part_number,
+                size,
                 etag: "etag".to_string(),
                 checksum: None,
             })
@@ -464,4 +546,136 @@ mod tests {
         let actual_size = w.w.lock().await.length;
         assert_eq!(actual_size, total_size);
     }
+
+    /// ResumeWrite keeps uploaded parts across writers, just like services do
+    /// across processes.
+    struct ResumeWrite {
+        uploaded: Vec<MultipartPart>,
+        completed: Vec<usize>,
+    }
+
+    impl MultipartWrite for Arc<Mutex<ResumeWrite>> {
+        async fn write_once(&self, _: u64, _: Buffer) -> Result<()> {
+            unreachable!("resumed upload must not write once")
+        }
+
+        async fn initiate_part(&self) -> Result<String> {
+            Ok("upload".to_string())
+        }
+
+        async fn write_part(
+            &self,
+            upload_id: &str,
+            part_number: usize,
+            size: u64,
+            _: Buffer,
+        ) -> Result<MultipartPart> {
+            assert_eq!(upload_id, "upload");
+            let part = MultipartPart {
+                part_number,
+                size,
+                etag: "etag".to_string(),
+                checksum: None,
+            };
+            self.lock().await.uploaded.push(part.clone());
+            Ok(part)
+        }
+
+        async fn complete_part(&self, upload_id: &str, parts: &[MultipartPart]) -> Result<()> {
+            assert_eq!(upload_id, "upload");
+            self.lock().await.completed = parts.iter().map(|v| v.part_number).collect();
+            Ok(())
+        }
+
+        async fn abort_part(&self, _: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn list_parts(&self, upload_id: &str) -> Result<Vec<MultipartPart>> {
+            assert_eq!(upload_id, "upload");
+            Ok(self.lock().await.uploaded.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multipart_upload_writer_resume() -> Result<()> {
+        let part = |part_number| MultipartPart {
+            part_number,
+            size: 1024,
+            etag: "etag".to_string(),
+            checksum: None,
+        };
+        let inner = Arc::new(Mutex::new(ResumeWrite {
+            uploaded: vec![part(0), part(1)],
+            completed: vec![],
+        }));
+
+        let recorder = oio::CheckpointRecorder::new();
+        let args = OpWrite::new()
+            .with_resume(WriteCheckpoint::new().with_upload_id("upload"))
+            .with_checkpoint_recorder(recorder.clone());
+        let mut w = MultipartWriter::new(inner.clone(), None, 1)
+            .with_checkpoint(&args)
+            .await?;
+        assert_eq!(
+            recorder.get(),
+            Some(
+                WriteCheckpoint::new()
+                    .with_upload_id("upload")
+                    .with_offset(2048)
+            )
+        );
+
+        w.write(vec![0; 1024].into()).await?;
+        w.write(vec![0; 1024].into()).await?;
+        w.close().await?;
+
+        assert_eq!(inner.lock().await.completed, vec![0, 1, 2, 3]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_multipart_upload_writer_interrupt_and_resume() -> Result<()> {
+        let inner = Arc::new(Mutex::new(ResumeWrite {
+            uploaded: vec![],
+            completed: vec![],
+        }));
+
+        let recorder = oio::CheckpointRecorder::new();
+        let args = OpWrite::new().with_checkpoint_recorder(recorder.clone());
+        let mut w = MultipartWriter::new(inner.clone(), None, 1)
+            .with_checkpoint(&args)
+            .await?;
+        for _ in 0..3 {
+            w.write(vec![0; 1024].into()).await?;
+        }
+        // The last write is still cached, drop the writer to simulate an interruption.
+        drop(w);
+
+        let checkpoint = recorder.get().expect("checkpoint must be recorded");
+        assert_eq!(
+            checkpoint,
+            WriteCheckpoint::new()
+                .with_upload_id("upload")
+                .with_offset(2048)
+        );
+
+        let recorder = oio::CheckpointRecorder::new();
+        let args = OpWrite::new()
+            .with_resume(checkpoint)
+            .with_checkpoint_recorder(recorder.clone());
+        let mut w = MultipartWriter::new(inner.clone(), None, 1)
+            .with_checkpoint(&args)
+            .await?;
+        let offset = recorder.get().map(|v| v.offset()).unwrap_or_default();
+        assert_eq!(offset, 2048);
+
+        for _ in offset / 1024..4 {
+            w.write(vec![0; 1024].into()).await?;
+        }
+        w.close().await?;
+
+        assert_eq!(inner.lock().await.completed, vec![0, 1, 2, 3]);
+        Ok(())
+    }
 }
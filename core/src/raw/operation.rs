@@ -55,6 +55,30 @@ pub enum Operation {
     Batch,
     /// Operation for [`crate::raw::Access::presign`]
     Presign,
+    /// Operation for [`crate::raw::Access::list_multipart_uploads`]
+    ListMultipartUploads,
+    /// Operation for [`crate::raw::Access::abort_multipart_upload`]
+    AbortMultipartUpload,
+    /// Operation for [`crate::raw::Access::summary`]
+    Summary,
+    /// Operation for [`crate::raw::Access::create_symlink`]
+    CreateSymlink,
+    /// Operation for [`crate::raw::Access::read_link`]
+    ReadLink,
+    /// Operation for [`crate::raw::Access::set_attr`]
+    SetAttr,
+    /// Operation for [`crate::raw::Access::get_tags`]
+    GetTags,
+    /// Operation for [`crate::raw::Access::set_tags`]
+    SetTags,
+    /// Operation for [`crate::raw::Access::set_retention`]
+    SetRetention,
+    /// Operation for [`crate::raw::Access::set_legal_hold`]
+    SetLegalHold,
+    /// Operation for [`crate::raw::Access::restore`]
+    Restore,
+    /// Operation for [`crate::raw::Access::presign_post`]
+    PresignPost,
     /// Operation for [`crate::raw::Access::blocking_create_dir`]
     BlockingCreateDir,
     /// Operation for [`crate::raw::Access::blocking_read`]
@@ -129,6 +153,18 @@ impl From<Operation> for &'static str {
             Operation::List => "list",
             Operation::ListerNext => "List::next",
             Operation::Presign => "presign",
+            Operation::ListMultipartUploads => "list_multipart_uploads",
+            Operation::AbortMultipartUpload => "abort_multipart_upload",
+            Operation::Summary => "summary",
+            Operation::CreateSymlink => "create_symlink",
+            Operation::ReadLink => "read_link",
+            Operation::SetAttr => "set_attr",
+            Operation::GetTags => "get_tags",
+            Operation::SetTags => "set_tags",
+            Operation::SetRetention => "set_retention",
+            Operation::SetLegalHold => "set_legal_hold",
+            Operation::Restore => "restore",
+            Operation::PresignPost => "presign_post",
             Operation::Batch => "batch",
             Operation::BlockingCreateDir => "blocking_create_dir",
             Operation::BlockingRead => "blocking_read",
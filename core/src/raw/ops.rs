@@ -20,8 +20,13 @@
 //! By using ops, users can add more context for operation.
 
 use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::DateTime;
+use chrono::Utc;
 use flagset::FlagSet;
 
 use crate::raw::*;
@@ -100,6 +105,12 @@ pub struct OpList {
     /// - If this is set to > 1, the list operation will be concurrent,
     ///   and the maximum number of concurrent operations will be determined by this value.
     concurrent: usize,
+    /// The glob pattern used to filter entries.
+    ///
+    /// The pattern is relative to the root instead of the list path. If set,
+    /// `recursive` will be ignored and only entries matching this pattern will
+    /// be returned.
+    glob: Option<String>,
 }
 
 impl Default for OpList {
@@ -111,6 +122,7 @@ impl Default for OpList {
             // By default, we want to know what's the mode of this entry.
             metakey: Metakey::Mode.into(),
             concurrent: 1,
+            glob: None,
         }
     }
 }
@@ -184,6 +196,68 @@ impl OpList {
     pub fn concurrent(&self) -> usize {
         self.concurrent
     }
+
+    /// Change the glob pattern of this list operation.
+    pub fn with_glob(mut self, pattern: &str) -> Self {
+        self.glob = Some(pattern.into());
+        self
+    }
+
+    /// Get the glob pattern of list operation.
+    pub fn glob(&self) -> Option<&str> {
+        self.glob.as_deref()
+    }
+}
+
+/// Args for `summary` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSummary {}
+
+impl OpSummary {
+    /// Create a new `OpSummary`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `list_multipart_uploads` operation.
+///
+/// The path is used as the prefix of uploads to list.
+#[derive(Debug, Clone, Default)]
+pub struct OpListMultipartUploads {}
+
+impl OpListMultipartUploads {
+    /// Create a new `OpListMultipartUploads`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Args for `abort_multipart_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpAbortMultipartUpload {
+    upload_id: Option<String>,
+}
+
+impl OpAbortMultipartUpload {
+    /// Create a new `OpAbortMultipartUpload`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the upload id to abort.
+    pub fn with_upload_id(mut self, upload_id: &str) -> Self {
+        self.upload_id = Some(upload_id.to_string());
+        self
+    }
+
+    /// Get the upload id to abort.
+    ///
+    /// Services that don't have upload id (like azblob) will abort all
+    /// pending data of the path.
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload_id.as_deref()
+    }
 }
 
 /// Args for `presign` operation.
@@ -231,6 +305,21 @@ pub enum PresignOperation {
     Read(OpRead),
     /// Presign a write operation.
     Write(OpWrite),
+    /// Presign a delete operation.
+    Delete(OpDelete),
+    /// Presign a list operation.
+    List(OpList),
+    /// Presign an initiate multipart upload operation.
+    ///
+    /// The returned request creates a new multipart upload, and the
+    /// upload id must be extracted from the response by callers.
+    InitiateMultipartUpload(OpWrite),
+    /// Presign an upload part operation.
+    UploadPart(OpUploadPart),
+    /// Presign a complete multipart upload operation.
+    ///
+    /// Callers must send the service-specific parts list as the body.
+    CompleteMultipartUpload(OpCompleteMultipartUpload),
 }
 
 impl From<OpStat> for PresignOperation {
@@ -251,6 +340,143 @@ impl From<OpWrite> for PresignOperation {
     }
 }
 
+impl From<OpDelete> for PresignOperation {
+    fn from(v: OpDelete) -> Self {
+        Self::Delete(v)
+    }
+}
+
+impl From<OpList> for PresignOperation {
+    fn from(v: OpList) -> Self {
+        Self::List(v)
+    }
+}
+
+impl From<OpUploadPart> for PresignOperation {
+    fn from(v: OpUploadPart) -> Self {
+        Self::UploadPart(v)
+    }
+}
+
+impl From<OpCompleteMultipartUpload> for PresignOperation {
+    fn from(v: OpCompleteMultipartUpload) -> Self {
+        Self::CompleteMultipartUpload(v)
+    }
+}
+
+/// Args for presigning an `upload_part` request of multipart upload.
+#[derive(Debug, Clone, Default)]
+pub struct OpUploadPart {
+    upload_id: String,
+    part_number: usize,
+}
+
+impl OpUploadPart {
+    /// Create a new `OpUploadPart`.
+    ///
+    /// `part_number` starts from 1 for all services.
+    pub fn new(upload_id: &str, part_number: usize) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            part_number,
+        }
+    }
+
+    /// Get the upload id of this part.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get the part number of this part.
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+}
+
+/// Args for presigning a `complete_multipart_upload` request.
+#[derive(Debug, Clone, Default)]
+pub struct OpCompleteMultipartUpload {
+    upload_id: String,
+}
+
+impl OpCompleteMultipartUpload {
+    /// Create a new `OpCompleteMultipartUpload`.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+        }
+    }
+
+    /// Get the upload id to complete.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+}
+
+/// Args for `presign_post` operation.
+///
+/// The path must be normalized.
+#[derive(Debug, Clone)]
+pub struct OpPresignPost {
+    expire: Duration,
+    content_type: Option<String>,
+    content_length_range: Option<(u64, u64)>,
+    key_prefix: bool,
+}
+
+impl OpPresignPost {
+    /// Create a new `OpPresignPost`.
+    pub fn new(expire: Duration) -> Self {
+        Self {
+            expire,
+            content_type: None,
+            content_length_range: None,
+            key_prefix: false,
+        }
+    }
+
+    /// Get expire from op.
+    pub fn expire(&self) -> Duration {
+        self.expire
+    }
+
+    /// Require the uploaded object to have the given content type.
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Get the required content type.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Require the uploaded object size to be in `[min, max]` bytes.
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.content_length_range = Some((min, max));
+        self
+    }
+
+    /// Get the allowed content length range.
+    pub fn content_length_range(&self) -> Option<(u64, u64)> {
+        self.content_length_range
+    }
+
+    /// Treat the path as a key prefix instead of an exact key.
+    ///
+    /// Uploads are allowed for any key that starts with path, and the
+    /// generated `key` field will be `{path}${filename}`.
+    pub fn with_key_prefix(mut self, key_prefix: bool) -> Self {
+        self.key_prefix = key_prefix;
+        self
+    }
+
+    /// Check if the path is a key prefix.
+    pub fn key_prefix(&self) -> bool {
+        self.key_prefix
+    }
+}
+
 /// Args for `batch` operation.
 #[derive(Debug, Clone)]
 pub struct OpBatch {
@@ -280,6 +506,13 @@ impl OpBatch {
 pub enum BatchOperation {
     /// Batch delete operation.
     Delete(OpDelete),
+    /// Batch stat operation.
+    Stat(OpStat),
+    /// Batch read operation.
+    ///
+    /// Services will read the whole content into memory, so it's only
+    /// suitable for small objects.
+    Read(OpRead),
 }
 
 impl From<OpDelete> for BatchOperation {
@@ -288,12 +521,26 @@ impl From<OpDelete> for BatchOperation {
     }
 }
 
+impl From<OpStat> for BatchOperation {
+    fn from(op: OpStat) -> Self {
+        Self::Stat(op)
+    }
+}
+
+impl From<OpRead> for BatchOperation {
+    fn from(op: OpRead) -> Self {
+        Self::Read(op)
+    }
+}
+
 impl BatchOperation {
     /// Return the operation of this batch.
     pub fn operation(&self) -> Operation {
         use BatchOperation::*;
         match self {
             Delete(_) => Operation::Delete,
+            Stat(_) => Operation::Stat,
+            Read(_) => Operation::Read,
         }
     }
 }
@@ -435,6 +682,8 @@ pub struct OpReader {
     chunk: Option<usize>,
     /// The gap size of each request.
     gap: Option<usize>,
+    /// Decode the content by its content encoding.
+    decode: bool,
 }
 
 impl Default for OpReader {
@@ -443,6 +692,7 @@ impl Default for OpReader {
             concurrent: 1,
             chunk: None,
             gap: None,
+            decode: false,
         }
     }
 }
@@ -485,6 +735,17 @@ impl OpReader {
     pub fn gap(&self) -> Option<usize> {
         self.gap
     }
+
+    /// Set the decode of the option
+    pub fn with_decode(mut self, decode: bool) -> Self {
+        self.decode = decode;
+        self
+    }
+
+    /// Get decode from option
+    pub fn decode(&self) -> bool {
+        self.decode
+    }
 }
 
 /// Args for `stat` operation.
@@ -496,6 +757,7 @@ pub struct OpStat {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    no_follow: bool,
 }
 
 impl OpStat {
@@ -570,18 +832,311 @@ impl OpStat {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set whether to return the metadata of the symlink itself instead of
+    /// the path it points to.
+    pub fn with_no_follow(mut self, no_follow: bool) -> Self {
+        self.no_follow = no_follow;
+        self
+    }
+
+    /// Get no_follow from option
+    pub fn no_follow(&self) -> bool {
+        self.no_follow
+    }
+}
+
+/// Args for `create_symlink` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpCreateSymlink {
+    target: String,
+}
+
+impl OpCreateSymlink {
+    /// Create a new `OpCreateSymlink` that points to `target`.
+    ///
+    /// The target is stored as is, OpenDAL will not normalize or resolve it.
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+        }
+    }
+
+    /// Get the target of the symlink.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+}
+
+/// Args for `read_link` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpReadLink {}
+
+impl OpReadLink {
+    /// Create a new `OpReadLink`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `get_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpGetTags {}
+
+impl OpGetTags {
+    /// Create a new `OpGetTags`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_tags` operation.
+///
+/// The given tags will replace all existing tags of the object.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetTags {
+    tags: HashMap<String, String>,
+}
+
+impl OpSetTags {
+    /// Create a new `OpSetTags` with given tags.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        Self { tags }
+    }
+
+    /// Get the tags to set.
+    pub fn tags(&self) -> &HashMap<String, String> {
+        &self.tags
+    }
+}
+
+/// Args for `set_retention` operation.
+#[derive(Debug, Clone)]
+pub struct OpSetRetention {
+    mode: RetentionMode,
+    retain_until: DateTime<Utc>,
+    bypass_governance: bool,
+}
+
+impl OpSetRetention {
+    /// Create a new `OpSetRetention` with given mode and retain until date.
+    pub fn new(mode: RetentionMode, retain_until: DateTime<Utc>) -> Self {
+        Self {
+            mode,
+            retain_until,
+            bypass_governance: false,
+        }
+    }
+
+    /// Get the retention mode.
+    pub fn mode(&self) -> RetentionMode {
+        self.mode
+    }
+
+    /// Get the retain until date.
+    pub fn retain_until(&self) -> DateTime<Utc> {
+        self.retain_until
+    }
+
+    /// Set whether to bypass an existing governance mode retention.
+    ///
+    /// Shortening or removing a governance mode retention requires this flag
+    /// on services like s3 and gcs.
+    pub fn with_bypass_governance(mut self, v: bool) -> Self {
+        self.bypass_governance = v;
+        self
+    }
+
+    /// Get whether to bypass an existing governance mode retention.
+    pub fn bypass_governance(&self) -> bool {
+        self.bypass_governance
+    }
+}
+
+/// Args for `set_legal_hold` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetLegalHold {
+    hold: bool,
+}
+
+impl OpSetLegalHold {
+    /// Create a new `OpSetLegalHold`, `true` places the hold and `false` releases it.
+    pub fn new(hold: bool) -> Self {
+        Self { hold }
+    }
+
+    /// Get the legal hold status to set.
+    pub fn hold(&self) -> bool {
+        self.hold
+    }
+}
+
+/// Args for `restore` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpRestore {
+    days: u32,
+    tier: RestoreTier,
+}
+
+impl OpRestore {
+    /// Create a new `OpRestore`.
+    ///
+    /// `days` is how long the restored copy will be kept, services that restore
+    /// objects permanently will ignore it.
+    pub fn new(days: u32, tier: RestoreTier) -> Self {
+        Self { days, tier }
+    }
+
+    /// Get the days to keep the restored copy.
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Get the tier of this restore.
+    pub fn tier(&self) -> RestoreTier {
+        self.tier
+    }
+}
+
+/// Args for `set_attr` operation.
+///
+/// All fields are optional, only the attributes that have been set will be
+/// changed.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetAttr {
+    mode: Option<u32>,
+    uid: Option<u32>,
+    gid: Option<u32>,
+    owner: Option<String>,
+    group: Option<String>,
+    last_modified: Option<DateTime<Utc>>,
+    last_accessed: Option<DateTime<Utc>>,
+    size: Option<u64>,
+}
+
+impl OpSetAttr {
+    /// Create a new `OpSetAttr`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the unix permission bits, like `chmod`.
+    pub fn with_mode(mut self, mode: u32) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Get the unix permission bits.
+    pub fn mode(&self) -> Option<u32> {
+        self.mode
+    }
+
+    /// Set the numeric user id of owner, like `chown`.
+    pub fn with_uid(mut self, uid: u32) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    /// Get the numeric user id of owner.
+    pub fn uid(&self) -> Option<u32> {
+        self.uid
+    }
+
+    /// Set the numeric group id, like `chgrp`.
+    pub fn with_gid(mut self, gid: u32) -> Self {
+        self.gid = Some(gid);
+        self
+    }
+
+    /// Get the numeric group id.
+    pub fn gid(&self) -> Option<u32> {
+        self.gid
+    }
+
+    /// Set the owner by name.
+    pub fn with_owner(mut self, owner: &str) -> Self {
+        self.owner = Some(owner.to_string());
+        self
+    }
+
+    /// Get the owner name.
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Set the group by name.
+    pub fn with_group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Get the group name.
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// Set the last modified time, like `touch -m`.
+    pub fn with_last_modified(mut self, v: DateTime<Utc>) -> Self {
+        self.last_modified = Some(v);
+        self
+    }
+
+    /// Get the last modified time.
+    pub fn last_modified(&self) -> Option<DateTime<Utc>> {
+        self.last_modified
+    }
+
+    /// Set the last accessed time, like `touch -a`.
+    pub fn with_last_accessed(mut self, v: DateTime<Utc>) -> Self {
+        self.last_accessed = Some(v);
+        self
+    }
+
+    /// Get the last accessed time.
+    pub fn last_accessed(&self) -> Option<DateTime<Utc>> {
+        self.last_accessed
+    }
+
+    /// Set the size of file, like `truncate`.
+    pub fn with_size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Get the size of file.
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Check if this op will change the owner.
+    pub fn has_owner(&self) -> bool {
+        self.uid.is_some() || self.gid.is_some() || self.owner.is_some() || self.group.is_some()
+    }
+
+    /// Check if this op will change the times.
+    pub fn has_times(&self) -> bool {
+        self.last_modified.is_some() || self.last_accessed.is_some()
+    }
 }
 
 /// Args for `write` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpWrite {
     append: bool,
+    offset: Option<u64>,
     concurrent: usize,
     content_type: Option<String>,
     content_disposition: Option<String>,
+    content_encoding: Option<String>,
     cache_control: Option<String>,
     executor: Option<Executor>,
     user_metadata: Option<HashMap<String, String>>,
+    tags: Option<HashMap<String, String>>,
+    retention: Option<(RetentionMode, DateTime<Utc>)>,
+    legal_hold: Option<bool>,
+    storage_class: Option<String>,
+    resume: Option<WriteCheckpoint>,
+    checkpoint_recorder: Option<oio::CheckpointRecorder>,
 }
 
 impl OpWrite {
@@ -611,6 +1166,27 @@ impl OpWrite {
         self
     }
 
+    /// Get the offset from op.
+    ///
+    /// The offset is the position of the existing file that this write operation starts at.
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the data will be written starting at the offset of the
+    /// existing file, and the content outside the written range will be kept. The gap
+    /// between the end of file and the offset will be filled with zeros.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support random write.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
     /// Get the content type from option
     pub fn content_type(&self) -> Option<&str> {
         self.content_type.as_deref()
@@ -644,6 +1220,17 @@ impl OpWrite {
         self
     }
 
+    /// Get the content encoding from option
+    pub fn content_encoding(&self) -> Option<&str> {
+        self.content_encoding.as_deref()
+    }
+
+    /// Set the content encoding of option
+    pub fn with_content_encoding(mut self, content_encoding: &str) -> Self {
+        self.content_encoding = Some(content_encoding.to_string());
+        self
+    }
+
     /// Get the concurrent.
     pub fn concurrent(&self) -> usize {
         self.concurrent
@@ -690,6 +1277,76 @@ impl OpWrite {
     pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
         self.user_metadata.as_ref()
     }
+
+    /// Set the tags of the object to write.
+    pub fn with_tags(mut self, tags: HashMap<String, String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Get the tags from the op
+    pub fn tags(&self) -> Option<&HashMap<String, String>> {
+        self.tags.as_ref()
+    }
+
+    /// Set the retention mode and retain until date of the object to write.
+    pub fn with_retention(mut self, mode: RetentionMode, retain_until: DateTime<Utc>) -> Self {
+        self.retention = Some((mode, retain_until));
+        self
+    }
+
+    /// Get the retention mode and retain until date from the op
+    pub fn retention(&self) -> Option<(RetentionMode, DateTime<Utc>)> {
+        self.retention
+    }
+
+    /// Set the legal hold status of the object to write.
+    pub fn with_legal_hold(mut self, hold: bool) -> Self {
+        self.legal_hold = Some(hold);
+        self
+    }
+
+    /// Get the legal hold status from the op
+    pub fn legal_hold(&self) -> Option<bool> {
+        self.legal_hold
+    }
+
+    /// Set the storage class of the object to write.
+    pub fn with_storage_class(mut self, storage_class: &str) -> Self {
+        self.storage_class = Some(storage_class.to_string());
+        self
+    }
+
+    /// Get the storage class from the op
+    pub fn storage_class(&self) -> Option<&str> {
+        self.storage_class.as_deref()
+    }
+
+    /// Get the checkpoint to resume from.
+    pub fn resume(&self) -> Option<&WriteCheckpoint> {
+        self.resume.as_ref()
+    }
+
+    /// Resume a previous upload from the given checkpoint.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support resume.
+    pub fn with_resume(mut self, checkpoint: WriteCheckpoint) -> Self {
+        self.resume = Some(checkpoint);
+        self
+    }
+
+    /// Get the checkpoint recorder from option.
+    pub fn checkpoint_recorder(&self) -> Option<&oio::CheckpointRecorder> {
+        self.checkpoint_recorder.as_ref()
+    }
+
+    /// Set the checkpoint recorder which writers will report checkpoint to.
+    pub fn with_checkpoint_recorder(mut self, recorder: oio::CheckpointRecorder) -> Self {
+        self.checkpoint_recorder = Some(recorder);
+        self
+    }
 }
 
 /// Args for `writer` operation.
@@ -726,6 +1383,103 @@ impl OpWriter {
     }
 }
 
+/// Args for `transfer` operation.
+#[derive(Clone)]
+pub struct OpTransfer {
+    concurrent: usize,
+    chunk: Option<usize>,
+    checksum: bool,
+    checkpoint: Option<TransferCheckpoint>,
+    progress: Option<TransferProgressFn>,
+}
+
+impl Default for OpTransfer {
+    fn default() -> Self {
+        Self {
+            concurrent: 1,
+            chunk: None,
+            checksum: false,
+            checkpoint: None,
+            progress: None,
+        }
+    }
+}
+
+impl Debug for OpTransfer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpTransfer")
+            .field("concurrent", &self.concurrent)
+            .field("chunk", &self.chunk)
+            .field("checksum", &self.checksum)
+            .field("checkpoint", &self.checkpoint)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OpTransfer {
+    /// Create a new `OpTransfer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the concurrent of the option
+    ///
+    /// Both the ranged reads and multipart writes will be sent concurrently.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent.max(1);
+        self
+    }
+
+    /// Get concurrent from option
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Set the chunk of the option
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk.max(1));
+        self
+    }
+
+    /// Get chunk from option
+    pub fn chunk(&self) -> Option<usize> {
+        self.chunk
+    }
+
+    /// Set whether to verify the checksum of destination after transfer.
+    pub fn with_checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Get checksum from option
+    pub fn checksum(&self) -> bool {
+        self.checksum
+    }
+
+    /// Set the checkpoint to resume from.
+    pub fn with_checkpoint(mut self, checkpoint: TransferCheckpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Get checkpoint from option
+    pub fn checkpoint(&self) -> Option<&TransferCheckpoint> {
+        self.checkpoint.as_ref()
+    }
+
+    /// Set the progress callback of the option.
+    pub fn with_progress(mut self, f: impl Fn(&TransferProgress) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(f));
+        self
+    }
+
+    /// Get progress callback from option
+    pub(crate) fn progress(&self) -> Option<&TransferProgressFn> {
+        self.progress.as_ref()
+    }
+}
+
 /// Args for `copy` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpCopy {}
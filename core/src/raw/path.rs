@@ -217,6 +217,7 @@ pub fn validate_path(path: &str, mode: EntryMode) -> bool {
     match mode {
         EntryMode::FILE => !path.ends_with('/'),
         EntryMode::DIR => path.ends_with('/'),
+        EntryMode::Symlink => !path.ends_with('/'),
         EntryMode::Unknown => false,
     }
 }
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+
 use http::Request;
 
 use crate::raw::*;
@@ -97,6 +99,52 @@ impl<T: Default> From<PresignedRequest> for Request<T> {
     }
 }
 
+/// Reply for `presign_post` operation.
+#[derive(Debug, Clone)]
+pub struct RpPresignPost {
+    post: PresignedPost,
+}
+
+impl RpPresignPost {
+    /// Create a new reply for `presign_post`.
+    pub fn new(post: PresignedPost) -> Self {
+        RpPresignPost { post }
+    }
+
+    /// Consume reply to build a presigned post.
+    pub fn into_presigned_post(self) -> PresignedPost {
+        self.post
+    }
+}
+
+/// PresignedPost is a presigned browser-based form upload return by `presign_post`.
+///
+/// Users should send a `multipart/form-data` POST request to [`PresignedPost::uri`]
+/// with all [`PresignedPost::fields`] as form fields, followed by a `file` field
+/// carrying the content.
+#[derive(Debug, Clone)]
+pub struct PresignedPost {
+    uri: http::Uri,
+    fields: Vec<(String, String)>,
+}
+
+impl PresignedPost {
+    /// Create a new PresignedPost
+    pub fn new(uri: http::Uri, fields: Vec<(String, String)>) -> Self {
+        Self { uri, fields }
+    }
+
+    /// Return the uri that the form should be posted to.
+    pub fn uri(&self) -> &http::Uri {
+        &self.uri
+    }
+
+    /// Return the form fields in the order they should be sent.
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.fields
+    }
+}
+
 /// Reply for `read` operation.
 #[derive(Debug, Clone, Default)]
 pub struct RpRead {
@@ -176,9 +224,16 @@ impl RpBatch {
 }
 
 /// Batch results of `batch` operations.
+///
+/// Batched replies are moved into results directly, so it's fine to keep `Stat` unboxed.
+#[allow(clippy::large_enum_variant)]
 pub enum BatchedReply {
     /// results of `delete batch` operation
     Delete(RpDelete),
+    /// results of `stat batch` operation
+    Stat(RpStat),
+    /// results of `read batch` operation, the content is fully loaded.
+    Read(RpRead, Buffer),
 }
 
 impl From<RpDelete> for BatchedReply {
@@ -187,6 +242,18 @@ impl From<RpDelete> for BatchedReply {
     }
 }
 
+impl From<RpStat> for BatchedReply {
+    fn from(rp: RpStat) -> Self {
+        Self::Stat(rp)
+    }
+}
+
+impl From<(RpRead, Buffer)> for BatchedReply {
+    fn from((rp, bs): (RpRead, Buffer)) -> Self {
+        Self::Read(rp, bs)
+    }
+}
+
 /// Reply for `stat` operation.
 #[derive(Debug, Clone)]
 pub struct RpStat {
@@ -233,6 +300,157 @@ impl RpCopy {
     }
 }
 
+/// Reply for `create_symlink` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpCreateSymlink {}
+
+impl RpCreateSymlink {
+    /// Create a new reply for `create_symlink`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `get_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpGetTags {
+    tags: HashMap<String, String>,
+}
+
+impl RpGetTags {
+    /// Create a new reply for `get_tags`.
+    pub fn new(tags: HashMap<String, String>) -> Self {
+        Self { tags }
+    }
+
+    /// Consume reply to get the tags.
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tags
+    }
+}
+
+/// Reply for `set_tags` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetTags {}
+
+impl RpSetTags {
+    /// Create a new reply for `set_tags`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `set_retention` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetRetention {}
+
+impl RpSetRetention {
+    /// Create a new reply for `set_retention`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `set_legal_hold` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetLegalHold {}
+
+impl RpSetLegalHold {
+    /// Create a new reply for `set_legal_hold`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `restore` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpRestore {}
+
+impl RpRestore {
+    /// Create a new reply for `restore`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `set_attr` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetAttr {}
+
+impl RpSetAttr {
+    /// Create a new reply for `set_attr`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `read_link` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpReadLink {
+    target: String,
+}
+
+impl RpReadLink {
+    /// Create a new reply for `read_link`.
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+        }
+    }
+
+    /// Consume reply to get the target of the symlink.
+    pub fn into_target(self) -> String {
+        self.target
+    }
+}
+
+/// Reply for `summary` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSummary {
+    summary: Summary,
+}
+
+impl RpSummary {
+    /// Create a new reply for `summary`.
+    pub fn new(summary: Summary) -> Self {
+        Self { summary }
+    }
+
+    /// Consume reply to get the summary.
+    pub fn into_summary(self) -> Summary {
+        self.summary
+    }
+}
+
+/// Reply for `list_multipart_uploads` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpListMultipartUploads {
+    uploads: Vec<MultipartUpload>,
+}
+
+impl RpListMultipartUploads {
+    /// Create a new reply for `list_multipart_uploads`.
+    pub fn new(uploads: Vec<MultipartUpload>) -> Self {
+        Self { uploads }
+    }
+
+    /// Consume reply to get the incomplete uploads.
+    pub fn into_uploads(self) -> Vec<MultipartUpload> {
+        self.uploads
+    }
+}
+
+/// Reply for `abort_multipart_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpAbortMultipartUpload {}
+
+impl RpAbortMultipartUpload {
+    /// Create a new reply for `abort_multipart_upload`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
 /// Reply for `rename` operation.
 #[derive(Debug, Clone, Default)]
 pub struct RpRename {}
@@ -16,6 +16,7 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
@@ -39,7 +40,11 @@ use super::error::parse_error;
 use super::lister::AzblobLister;
 use super::writer::AzblobWriter;
 use crate::raw::*;
+use crate::services::azblob::core::parse_access_tier_metadata;
+use crate::services::azblob::core::parse_immutability_metadata;
 use crate::services::azblob::core::AzblobCore;
+use crate::services::azblob::core::BlobTags;
+use crate::services::azblob::core::ListBlobsOutput;
 use crate::services::azblob::writer::AzblobWriters;
 use crate::*;
 
@@ -577,8 +582,14 @@ impl Access for AzblobBackend {
                 write_can_append: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_can_resume: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
+                write_with_content_encoding: true,
+                write_with_tags: true,
+                write_with_retention: true,
+                write_with_legal_hold: true,
+                write_with_storage_class: true,
 
                 delete: true,
                 copy: true,
@@ -586,6 +597,17 @@ impl Access for AzblobBackend {
                 list: true,
                 list_with_recursive: true,
 
+                list_multipart_uploads: true,
+                abort_multipart_upload: true,
+
+                get_tags: true,
+                set_tags: true,
+
+                set_retention: true,
+                set_legal_hold: true,
+
+                restore: true,
+
                 presign: self.has_sas_token,
                 presign_stat: self.has_sas_token,
                 presign_read: self.has_sas_token,
@@ -607,7 +629,13 @@ impl Access for AzblobBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => {
+                let headers = resp.headers();
+                let mut meta = parse_into_metadata(path, headers)?;
+                parse_immutability_metadata(headers, &mut meta)?;
+                parse_access_tier_metadata(headers, &mut meta)?;
+                Ok(RpStat::new(meta))
+            }
             _ => Err(parse_error(resp).await?),
         }
     }
@@ -631,11 +659,11 @@ impl Access for AzblobBackend {
         let w = if args.append() {
             AzblobWriters::Two(oio::AppendWriter::new(w))
         } else {
-            AzblobWriters::One(oio::BlockWriter::new(
-                w,
-                args.executor().cloned(),
-                args.concurrent(),
-            ))
+            AzblobWriters::One(
+                oio::BlockWriter::new(w, args.executor().cloned(), args.concurrent())
+                    .with_checkpoint(&args)
+                    .await?,
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -663,6 +691,60 @@ impl Access for AzblobBackend {
         Ok((RpList::default(), oio::PageLister::new(l)))
     }
 
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.azblob_get_blob_tags(path).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let tags: BlobTags = quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+                Ok(RpGetTags::new(tags.into_tags()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self.core.azblob_set_blob_tags(path, args.tags()).await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT => Ok(RpSetTags::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        let resp = self
+            .core
+            .azblob_set_immutability_policy(path, &args)
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetRetention::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_legal_hold(&self, path: &str, args: OpSetLegalHold) -> Result<RpSetLegalHold> {
+        let resp = self.core.azblob_set_legal_hold(path, args.hold()).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetLegalHold::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let resp = self.core.azblob_set_blob_tier(path, &args).await?;
+
+        match resp.status() {
+            // 202 means the rehydration has been started, 200 means the blob is
+            // not archived and the tier has been changed already.
+            StatusCode::ACCEPTED | StatusCode::OK => Ok(RpRestore::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
         let resp = self.core.azblob_copy_blob(from, to).await?;
 
@@ -674,6 +756,89 @@ impl Access for AzblobBackend {
         }
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        _: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        // Blobs that have been committed are listed without `uncommittedblobs`,
+        // the rest are the ones that only have uncommitted blocks.
+        //
+        // Committed blobs that still have uncommitted blocks are skipped: they
+        // can't be aborted without committing the blob again, and azblob will
+        // garbage collect these blocks within a week.
+        let mut committed = HashSet::new();
+        let mut next_marker = String::new();
+        loop {
+            let resp = self
+                .core
+                .azblob_list_blobs(path, &next_marker, "", None)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let output: ListBlobsOutput = quick_xml::de::from_reader(resp.into_body().reader())
+                .map_err(new_xml_deserialize_error)?;
+            committed.extend(output.blobs.blob.into_iter().map(|v| v.name));
+
+            match output.next_marker {
+                Some(v) if !v.is_empty() => next_marker = v,
+                _ => break,
+            }
+        }
+
+        let mut uploads = Vec::new();
+        let mut next_marker = String::new();
+        loop {
+            let resp = self
+                .core
+                .azblob_list_uncommitted_blobs(path, &next_marker)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let output: ListBlobsOutput = quick_xml::de::from_reader(resp.into_body().reader())
+                .map_err(new_xml_deserialize_error)?;
+            for blob in output.blobs.blob {
+                if committed.contains(&blob.name) {
+                    continue;
+                }
+
+                let path = build_rel_path(&self.core.root, &blob.name);
+                let mut v = MultipartUpload::new(&path);
+                if let Ok(initiated) = parse_datetime_from_rfc2822(&blob.properties.last_modified) {
+                    v = v.with_initiated(initiated);
+                }
+                uploads.push(v);
+            }
+
+            match output.next_marker {
+                Some(v) if !v.is_empty() => next_marker = v,
+                _ => break,
+            }
+        }
+
+        Ok(RpListMultipartUploads::new(uploads))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        _: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let resp = self.core.azblob_discard_uncommitted_blocks(path).await?;
+        match resp.status() {
+            // The blob has been committed by others which discards the
+            // uncommitted blocks too.
+            StatusCode::ACCEPTED | StatusCode::NOT_FOUND | StatusCode::PRECONDITION_FAILED => {
+                Ok(RpAbortMultipartUpload::new())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => self.core.azblob_head_blob_request(path, v)?,
@@ -685,6 +850,12 @@ impl Access for AzblobBackend {
                 self.core
                     .azblob_put_blob_request(path, None, &OpWrite::default(), Buffer::new())?
             }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "azblob doesn't support this presign operation",
+                ))
+            }
         };
 
         self.core.sign_query(&mut req).await?;
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
@@ -24,14 +25,18 @@ use std::time::Duration;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
 use http::header::HeaderName;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
 use http::header::IF_NONE_MATCH;
+use http::HeaderMap;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
+use http::StatusCode;
 use reqsign::AzureStorageCredential;
 use reqsign::AzureStorageLoader;
 use reqsign::AzureStorageSigner;
@@ -48,7 +53,15 @@ mod constants {
     pub const X_MS_BLOB_TYPE: &str = "x-ms-blob-type";
     pub const X_MS_COPY_SOURCE: &str = "x-ms-copy-source";
     pub const X_MS_BLOB_CACHE_CONTROL: &str = "x-ms-blob-cache-control";
+    pub const X_MS_BLOB_CONTENT_ENCODING: &str = "x-ms-blob-content-encoding";
     pub const X_MS_BLOB_CONDITION_APPENDPOS: &str = "x-ms-blob-condition-appendpos";
+    pub const X_MS_TAGS: &str = "x-ms-tags";
+    pub const X_MS_IMMUTABILITY_POLICY_UNTIL_DATE: &str = "x-ms-immutability-policy-until-date";
+    pub const X_MS_IMMUTABILITY_POLICY_MODE: &str = "x-ms-immutability-policy-mode";
+    pub const X_MS_LEGAL_HOLD: &str = "x-ms-legal-hold";
+    pub const X_MS_ACCESS_TIER: &str = "x-ms-access-tier";
+    pub const X_MS_ARCHIVE_STATUS: &str = "x-ms-archive-status";
+    pub const X_MS_REHYDRATE_PRIORITY: &str = "x-ms-rehydrate-priority";
 
     // Server-side encryption with customer-provided headers
     pub const X_MS_ENCRYPTION_KEY: &str = "x-ms-encryption-key";
@@ -225,6 +238,31 @@ impl AzblobCore {
         self.client.fetch(req).await
     }
 
+    /// Insert immutability policy and legal hold headers for write operations.
+    pub fn insert_immutability_headers(
+        &self,
+        mut req: http::request::Builder,
+        args: &OpWrite,
+    ) -> http::request::Builder {
+        if let Some((mode, retain_until)) = args.retention() {
+            req = req
+                .header(
+                    constants::X_MS_IMMUTABILITY_POLICY_UNTIL_DATE,
+                    format_immutability_until_date(retain_until),
+                )
+                .header(
+                    constants::X_MS_IMMUTABILITY_POLICY_MODE,
+                    format_immutability_policy_mode(mode),
+                );
+        }
+
+        if let Some(hold) = args.legal_hold() {
+            req = req.header(constants::X_MS_LEGAL_HOLD, hold.to_string());
+        }
+
+        req
+    }
+
     pub fn azblob_put_blob_request(
         &self,
         path: &str,
@@ -249,6 +287,9 @@ impl AzblobCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
         }
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(constants::X_MS_BLOB_CONTENT_ENCODING, encoding);
+        }
         if let Some(size) = size {
             req = req.header(CONTENT_LENGTH, size)
         }
@@ -262,6 +303,16 @@ impl AzblobCore {
             "BlockBlob",
         );
 
+        if let Some(tags) = args.tags() {
+            req = req.header(constants::X_MS_TAGS, format_tagging_query(tags));
+        }
+
+        req = self.insert_immutability_headers(req, args);
+
+        if let Some(tier) = args.storage_class() {
+            req = req.header(constants::X_MS_ACCESS_TIER, tier);
+        }
+
         // Set body
         let req = req.body(body).map_err(new_request_build_error)?;
 
@@ -319,6 +370,15 @@ impl AzblobCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
         }
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(constants::X_MS_BLOB_CONTENT_ENCODING, encoding);
+        }
+
+        if let Some(tags) = args.tags() {
+            req = req.header(constants::X_MS_TAGS, format_tagging_query(tags));
+        }
+
+        req = self.insert_immutability_headers(req, args);
 
         let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
@@ -442,6 +502,18 @@ impl AzblobCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
         }
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(constants::X_MS_BLOB_CONTENT_ENCODING, encoding);
+        }
+        if let Some(tags) = args.tags() {
+            req = req.header(constants::X_MS_TAGS, format_tagging_query(tags));
+        }
+
+        req = self.insert_immutability_headers(req, args);
+
+        if let Some(tier) = args.storage_class() {
+            req = req.header(constants::X_MS_ACCESS_TIER, tier);
+        }
 
         let content = quick_xml::se::to_string(&PutBlockListRequest {
             latest: block_ids
@@ -478,6 +550,162 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    /// Get the index tags of the blob.
+    ///
+    /// refer to https://learn.microsoft.com/en-us/rest/api/storageservices/get-blob-tags
+    pub async fn azblob_get_blob_tags(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/{}/{}?comp=tags",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+        );
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Replace the index tags of the blob.
+    ///
+    /// refer to https://learn.microsoft.com/en-us/rest/api/storageservices/set-blob-tags
+    pub async fn azblob_set_blob_tags(
+        &self,
+        path: &str,
+        tags: &HashMap<String, String>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/{}/{}?comp=tags",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+        );
+
+        let content = quick_xml::se::to_string(&BlobTags::from_tags(tags))
+            .map_err(new_xml_deserialize_error)?;
+
+        let mut req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Set the immutability policy of the blob.
+    ///
+    /// refer to https://learn.microsoft.com/en-us/rest/api/storageservices/set-blob-immutability-policy
+    pub async fn azblob_set_immutability_policy(
+        &self,
+        path: &str,
+        args: &OpSetRetention,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/{}/{}?comp=immutabilityPolicies",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+        );
+
+        let mut req = Request::put(&url)
+            .header(
+                constants::X_MS_IMMUTABILITY_POLICY_UNTIL_DATE,
+                format_immutability_until_date(args.retain_until()),
+            )
+            .header(
+                constants::X_MS_IMMUTABILITY_POLICY_MODE,
+                format_immutability_policy_mode(args.mode()),
+            )
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Set or clear the legal hold of the blob.
+    ///
+    /// refer to https://learn.microsoft.com/en-us/rest/api/storageservices/set-blob-legal-hold
+    pub async fn azblob_set_legal_hold(&self, path: &str, hold: bool) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/{}/{}?comp=legalhold",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+        );
+
+        let mut req = Request::put(&url)
+            .header(constants::X_MS_LEGAL_HOLD, hold.to_string())
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Rehydrate an archived blob by moving it to the hot tier.
+    ///
+    /// refer to https://learn.microsoft.com/en-us/rest/api/storageservices/set-blob-tier
+    pub async fn azblob_set_blob_tier(
+        &self,
+        path: &str,
+        args: &OpRestore,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/{}/{}?comp=tier",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+        );
+
+        let priority = match args.tier() {
+            RestoreTier::Expedited => "High",
+            RestoreTier::Standard | RestoreTier::Bulk => "Standard",
+        };
+
+        let mut req = Request::put(&url)
+            .header(constants::X_MS_ACCESS_TIER, "Hot")
+            .header(constants::X_MS_REHYDRATE_PRIORITY, priority)
+            .header(CONTENT_LENGTH, 0)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// List the uncommitted blocks of the blob.
+    ///
+    /// refer to https://learn.microsoft.com/en-us/rest/api/storageservices/get-block-list
+    pub async fn azblob_get_uncommitted_block_list(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!(
+            "{}/{}/{}?comp=blocklist&blocklisttype=uncommitted",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+        );
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub fn azblob_head_blob_request(&self, path: &str, args: &OpStat) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
@@ -592,7 +820,8 @@ impl AzblobCore {
             write!(url, "&delimiter={delimiter}").expect("write into string must succeed");
         }
         if !next_marker.is_empty() {
-            write!(url, "&marker={next_marker}").expect("write into string must succeed");
+            write!(url, "&marker={}", percent_encode_path(next_marker))
+                .expect("write into string must succeed");
         }
 
         let mut req = Request::get(&url)
@@ -603,6 +832,70 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    /// List blobs including the ones that only have uncommitted blocks.
+    pub async fn azblob_list_uncommitted_blobs(
+        &self,
+        path: &str,
+        next_marker: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/{}?restype=container&comp=list&include=uncommittedblobs",
+            self.endpoint, self.container
+        );
+        if !p.is_empty() {
+            write!(url, "&prefix={}", percent_encode_path(&p))
+                .expect("write into string must succeed");
+        }
+        if !next_marker.is_empty() {
+            write!(url, "&marker={}", percent_encode_path(next_marker))
+                .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Discard all uncommitted blocks of a blob that has never been committed.
+    ///
+    /// Azblob doesn't provide an API to drop uncommitted blocks, we commit an
+    /// empty block list only if the blob doesn't exist, and delete it then.
+    ///
+    /// The delete is conditioned on the ETag of the empty blob, so a blob
+    /// written by others in between will be kept.
+    pub async fn azblob_discard_uncommitted_blocks(&self, path: &str) -> Result<Response<Buffer>> {
+        let mut req = self
+            .azblob_complete_put_block_list_request(path, vec![], &OpWrite::default())
+            .await?;
+        req.headers_mut()
+            .insert(IF_NONE_MATCH, HeaderValue::from_static("*"));
+
+        self.sign(&mut req).await?;
+        let resp = self.send(req).await?;
+        if resp.status() != StatusCode::CREATED {
+            return Ok(resp);
+        }
+
+        let etag = parse_etag(resp.headers())?.ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "ETag not present in returning response",
+            )
+        })?;
+
+        let mut req = self.azblob_delete_blob_request(path)?;
+        req.headers_mut()
+            .insert(IF_MATCH, build_header_value(etag)?);
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn azblob_batch_delete(&self, paths: &[String]) -> Result<Response<Buffer>> {
         let url = format!(
             "{}/{}?restype=container&comp=batch",
@@ -628,6 +921,100 @@ impl AzblobCore {
     }
 }
 
+pub fn format_immutability_policy_mode(mode: RetentionMode) -> &'static str {
+    match mode {
+        RetentionMode::Governance => "Unlocked",
+        RetentionMode::Compliance => "Locked",
+    }
+}
+
+pub fn format_immutability_until_date(v: DateTime<Utc>) -> String {
+    v.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Parse immutability policy and legal hold headers of Get Blob Properties into metadata.
+pub fn parse_immutability_metadata(headers: &HeaderMap, meta: &mut Metadata) -> Result<()> {
+    if let Some(v) = parse_header_to_str(headers, constants::X_MS_IMMUTABILITY_POLICY_MODE)? {
+        if v.eq_ignore_ascii_case("unlocked") {
+            meta.set_retention_mode(RetentionMode::Governance);
+        } else if v.eq_ignore_ascii_case("locked") {
+            meta.set_retention_mode(RetentionMode::Compliance);
+        }
+    }
+
+    if let Some(v) = parse_header_to_str(headers, constants::X_MS_IMMUTABILITY_POLICY_UNTIL_DATE)? {
+        meta.set_retain_until(parse_datetime_from_rfc2822(v)?);
+    }
+
+    if let Some(v) = parse_header_to_str(headers, constants::X_MS_LEGAL_HOLD)? {
+        meta.set_legal_hold(v.eq_ignore_ascii_case("true"));
+    }
+
+    Ok(())
+}
+
+/// Parse access tier and archive status headers of Get Blob Properties into metadata.
+pub fn parse_access_tier_metadata(headers: &HeaderMap, meta: &mut Metadata) -> Result<()> {
+    if let Some(v) = parse_header_to_str(headers, constants::X_MS_ACCESS_TIER)? {
+        meta.set_storage_class(v);
+    }
+
+    // Archive status is `rehydrate-pending-to-hot` or `rehydrate-pending-to-cool`
+    // while the blob is being rehydrated.
+    if let Some(v) = parse_header_to_str(headers, constants::X_MS_ARCHIVE_STATUS)? {
+        if v.starts_with("rehydrate-pending") {
+            meta.set_restore_status(RestoreStatus::InProgress);
+        }
+    }
+
+    Ok(())
+}
+
+/// Request and output of Set Blob Tags/Get Blob Tags.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename = "Tags", rename_all = "PascalCase")]
+pub struct BlobTags {
+    pub tag_set: BlobTagSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BlobTagSet {
+    pub tag: Vec<BlobTag>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BlobTag {
+    pub key: String,
+    pub value: String,
+}
+
+impl BlobTags {
+    pub fn from_tags(tags: &HashMap<String, String>) -> Self {
+        let mut tag: Vec<_> = tags
+            .iter()
+            .map(|(k, v)| BlobTag {
+                key: k.clone(),
+                value: v.clone(),
+            })
+            .collect();
+        tag.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Self {
+            tag_set: BlobTagSet { tag },
+        }
+    }
+
+    pub fn into_tags(self) -> HashMap<String, String> {
+        self.tag_set
+            .tag
+            .into_iter()
+            .map(|t| (t.key, t.value))
+            .collect()
+    }
+}
+
 /// Request of PutBlockListRequest
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(default, rename = "BlockList", rename_all = "PascalCase")]
@@ -635,6 +1022,26 @@ pub struct PutBlockListRequest {
     pub latest: Vec<String>,
 }
 
+/// Output of GetBlockList
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename = "BlockList", rename_all = "PascalCase")]
+pub struct GetBlockListOutput {
+    pub uncommitted_blocks: BlockListBlocks,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BlockListBlocks {
+    pub block: Vec<BlockListBlock>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BlockListBlock {
+    pub name: String,
+    pub size: u64,
+}
+
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
 pub struct ListBlobsOutput {
@@ -675,6 +1082,7 @@ pub struct Properties {
     #[serde(rename = "Content-Type")]
     pub content_type: String,
     pub etag: String,
+    pub access_tier: Option<String>,
 }
 
 #[cfg(test)]
@@ -864,6 +1272,33 @@ mod tests {
         de::from_reader(Bytes::from(bs).reader()).expect("must success")
     }
 
+    /// This example is from https://learn.microsoft.com/en-us/rest/api/storageservices/get-block-list
+    #[test]
+    fn test_deserialize_get_block_list_output() {
+        let bs = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+            <BlockList>
+              <UncommittedBlocks>
+                <Block>
+                  <Name>BlockId001</Name>
+                  <Size>1024</Size>
+                </Block>
+                <Block>
+                  <Name>BlockId002</Name>
+                  <Size>2048</Size>
+                </Block>
+              </UncommittedBlocks>
+            </BlockList>";
+
+        let out: GetBlockListOutput =
+            de::from_reader(Bytes::from(bs).reader()).expect("must success");
+        let blocks = out.uncommitted_blocks.block;
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].name, "BlockId001");
+        assert_eq!(blocks[0].size, 1024);
+        assert_eq!(blocks[1].name, "BlockId002");
+        assert_eq!(blocks[1].size, 2048);
+    }
+
     /// This example is from https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-list?tabs=microsoft-entra-id
     #[test]
     fn test_serialize_put_block_list_request() {
@@ -901,4 +1336,42 @@ mod tests {
             vec!["1".to_string(), "2".to_string(), "3".to_string()]
         );
     }
+
+    #[test]
+    fn test_parse_immutability_metadata() {
+        let until = DateTime::from_timestamp(1893456000, 0).unwrap();
+        assert_eq!(
+            format_immutability_until_date(until),
+            "Tue, 01 Jan 2030 00:00:00 GMT"
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-ms-immutability-policy-until-date",
+            "Tue, 01 Jan 2030 00:00:00 GMT".parse().unwrap(),
+        );
+        headers.insert("x-ms-immutability-policy-mode", "locked".parse().unwrap());
+        headers.insert("x-ms-legal-hold", "false".parse().unwrap());
+
+        let mut meta = Metadata::new(EntryMode::FILE);
+        parse_immutability_metadata(&headers, &mut meta).expect("must success");
+        assert_eq!(meta.retention_mode(), Some(RetentionMode::Compliance));
+        assert_eq!(meta.retain_until(), Some(until));
+        assert_eq!(meta.legal_hold(), Some(false));
+    }
+
+    #[test]
+    fn test_parse_access_tier_metadata() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ms-access-tier", "Archive".parse().unwrap());
+        headers.insert(
+            "x-ms-archive-status",
+            "rehydrate-pending-to-hot".parse().unwrap(),
+        );
+
+        let mut meta = Metadata::new(EntryMode::FILE);
+        parse_access_tier_metadata(&headers, &mut meta).expect("must success");
+        assert_eq!(meta.storage_class(), Some("Archive"));
+        assert_eq!(meta.restore_status(), Some(RestoreStatus::InProgress));
+    }
 }
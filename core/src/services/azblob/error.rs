@@ -63,7 +63,7 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
     let (parts, mut body) = resp.into_parts();
     let bs = body.copy_to_bytes(body.remaining());
 
-    let (kind, retryable) = match parts.status {
+    let (mut kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
         StatusCode::PRECONDITION_FAILED | StatusCode::NOT_MODIFIED => {
@@ -96,6 +96,14 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
         }
     }
 
+    // Blobs protected by immutability policy or legal hold will return 409
+    // with `BlobImmutableDueToPolicy` or `BlobImmutableDueToLegalHold`.
+    if let Some(code) = parts.headers.get("x-ms-error-code") {
+        if code.as_bytes().starts_with(b"BlobImmutableDueTo") {
+            kind = ErrorKind::ObjectLocked;
+        }
+    }
+
     let mut err = Error::new(kind, &message);
 
     err = with_error_response_context(err, parts);
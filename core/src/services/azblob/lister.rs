@@ -90,7 +90,7 @@ impl oio::PageList for AzblobLister {
                 continue;
             }
 
-            let meta = Metadata::new(EntryMode::from_path(&path))
+            let mut meta = Metadata::new(EntryMode::from_path(&path))
                 // Keep fit with ETag header.
                 .with_etag(format!("\"{}\"", object.properties.etag.as_str()))
                 .with_content_length(object.properties.content_length)
@@ -99,6 +99,9 @@ impl oio::PageList for AzblobLister {
                 .with_last_modified(parse_datetime_from_rfc2822(
                     object.properties.last_modified.as_str(),
                 )?);
+            if let Some(tier) = &object.properties.access_tier {
+                meta.set_storage_class(tier);
+            }
 
             let de = oio::Entry::with(path, meta);
             ctx.entries.push_back(de);
@@ -17,10 +17,14 @@
 
 use std::sync::Arc;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Buf;
 use http::StatusCode;
 use uuid::Uuid;
 
 use super::core::AzblobCore;
+use super::core::GetBlockListOutput;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
@@ -154,4 +158,32 @@ impl oio::BlockWrite for AzblobWriter {
         // If Put Blob is called on the blob, any uncommitted blocks are garbage collected.
         Ok(())
     }
+
+    async fn list_blocks(&self) -> Result<Vec<(Uuid, u64)>> {
+        let resp = self
+            .core
+            .azblob_get_uncommitted_block_list(&self.path)
+            .await?;
+
+        let status = resp.status();
+        let output: GetBlockListOutput = match status {
+            StatusCode::OK => quick_xml::de::from_reader(resp.into_body().reader())
+                .map_err(new_xml_deserialize_error)?,
+            // The blob doesn't exist means no block has been uploaded.
+            StatusCode::NOT_FOUND => return Ok(vec![]),
+            _ => return Err(parse_error(resp).await?),
+        };
+
+        // Blocks that are not uploaded by us will be ignored.
+        Ok(output
+            .uncommitted_blocks
+            .block
+            .into_iter()
+            .filter_map(|block| {
+                let id = BASE64_STANDARD.decode(block.name).ok()?;
+                let id = Uuid::from_slice(&id).ok()?;
+                Some((id, block.size))
+            })
+            .collect())
+    }
 }
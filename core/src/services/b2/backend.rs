@@ -20,6 +20,7 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use bytes::Buf;
+use chrono::DateTime;
 use http::Request;
 use http::Response;
 use http::StatusCode;
@@ -38,6 +39,7 @@ use super::writer::B2Writers;
 use crate::raw::*;
 use crate::services::b2::core::B2Signer;
 use crate::services::b2::core::ListFileNamesResponse;
+use crate::services::b2::core::ListUnfinishedLargeFilesResponse;
 use crate::*;
 
 /// Config for backblaze b2 services support.
@@ -297,6 +299,9 @@ impl Access for B2Backend {
                 list_with_start_after: true,
                 list_with_recursive: true,
 
+                list_multipart_uploads: true,
+                abort_multipart_upload: true,
+
                 presign: true,
                 presign_read: true,
                 presign_write: true,
@@ -439,6 +444,63 @@ impl Access for B2Backend {
         }
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        _: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let mut uploads = Vec::new();
+        let mut start_file_id = None;
+        loop {
+            let resp = self
+                .core
+                .list_unfinished_large_files(path, start_file_id.as_deref())
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let output: ListUnfinishedLargeFilesResponse =
+                serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+            for file in output.files {
+                let mut v = MultipartUpload::new(&build_rel_path(&self.core.root, &file.file_name))
+                    .with_upload_id(&file.file_id);
+                if let Some(initiated) = DateTime::from_timestamp_millis(file.upload_timestamp) {
+                    v = v.with_initiated(initiated);
+                }
+                uploads.push(v);
+            }
+
+            match output.next_file_id {
+                Some(v) => start_file_id = Some(v),
+                None => break,
+            }
+        }
+
+        Ok(RpListMultipartUploads::new(uploads))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        _: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let Some(upload_id) = args.upload_id() else {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "upload id is required to abort multipart upload",
+            ));
+        };
+
+        let resp = self.core.cancel_large_file(upload_id).await?;
+        match resp.status() {
+            // b2 returns code 200 if abort succeeds.
+            StatusCode::OK => Ok(RpAbortMultipartUpload::new()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         match args.operation() {
             PresignOperation::Stat(_) => {
@@ -515,6 +577,10 @@ impl Access for B2Backend {
                     parts.headers,
                 )))
             }
+            _ => Err(Error::new(
+                ErrorKind::Unsupported,
+                "b2 doesn't support this presign operation",
+            )),
         }
     }
 }
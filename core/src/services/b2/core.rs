@@ -452,6 +452,38 @@ impl B2Core {
         self.send(req).await
     }
 
+    pub async fn list_unfinished_large_files(
+        &self,
+        prefix: &str,
+        start_file_id: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let auth_info = self.get_auth_info().await?;
+
+        let prefix = build_abs_path(&self.root, prefix);
+        let mut url = format!(
+            "{}/b2api/v2/b2_list_unfinished_large_files?bucketId={}&namePrefix={}",
+            auth_info.api_url,
+            self.bucket_id,
+            percent_encode_path(&prefix)
+        );
+
+        if let Some(start_file_id) = start_file_id {
+            url.push_str(&format!(
+                "&startFileId={}",
+                percent_encode_path(start_file_id)
+            ));
+        }
+
+        let mut req = Request::get(&url);
+
+        req = req.header(header::AUTHORIZATION, auth_info.authorization_token);
+
+        // Set body
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
     pub async fn copy_file(&self, source_file_id: String, to: &str) -> Result<Response<Buffer>> {
         let to = build_abs_path(&self.root, to);
 
@@ -631,6 +663,23 @@ pub struct File {
     pub file_name: String,
 }
 
+/// Response of [list_unfinished_large_files](https://www.backblaze.com/apidocs/b2-list-unfinished-large-files).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListUnfinishedLargeFilesResponse {
+    pub files: Vec<UnfinishedLargeFile>,
+    pub next_file_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfinishedLargeFile {
+    pub file_id: String,
+    pub file_name: String,
+    /// Milliseconds since midnight, January 1, 1970 UTC.
+    pub upload_timestamp: i64,
+}
+
 pub(super) fn parse_file_info(file: &File) -> Metadata {
     if file.file_name.ends_with('/') {
         return Metadata::new(EntryMode::DIR);
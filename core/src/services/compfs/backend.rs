@@ -132,6 +132,8 @@ impl Access for CompfsBackend {
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_with_content_encoding: true,
+                write_with_user_metadata: true,
                 create_dir: true,
                 delete: true,
 
@@ -159,10 +161,15 @@ impl Access for CompfsBackend {
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let path = self.core.prepare_path(path);
 
+        let p = path.clone();
         let meta = self
             .core
-            .exec(move || async move { compio::fs::metadata(path).await })
+            .exec(move || async move { compio::fs::metadata(p).await })
             .await?;
+        let fs_meta = self
+            .core
+            .exec_blocking(move || FsMeta::load(&path, true))
+            .await??;
         let ty = meta.file_type();
         let mode = if ty.is_dir() {
             EntryMode::DIR
@@ -172,7 +179,8 @@ impl Access for CompfsBackend {
             EntryMode::Unknown
         };
         let last_mod = meta.modified().map_err(new_std_io_error)?.into();
-        let ret = Metadata::new(mode).with_last_modified(last_mod);
+        let mut ret = Metadata::new(mode).with_last_modified(last_mod);
+        fs_meta.apply(&mut ret);
 
         Ok(RpStat::new(ret))
     }
@@ -185,9 +193,13 @@ impl Access for CompfsBackend {
                 .await?;
         } else {
             let path = self.core.prepare_path(path);
+            let p = path.clone();
             self.core
-                .exec(move || async move { compio::fs::remove_file(path).await })
+                .exec(move || async move { compio::fs::remove_file(p).await })
                 .await?;
+            self.core
+                .exec_blocking(move || FsMeta::remove(&path))
+                .await??;
         }
 
         Ok(RpDelete::default())
@@ -197,10 +209,11 @@ impl Access for CompfsBackend {
         let from = self.core.prepare_path(from);
         let to = self.core.prepare_path(to);
 
+        let (f, t) = (from.clone(), to.clone());
         self.core
             .exec(move || async move {
-                let from = OpenOptions::new().read(true).open(from).await?;
-                let to = OpenOptions::new().write(true).create(true).open(to).await?;
+                let from = OpenOptions::new().read(true).open(f).await?;
+                let to = OpenOptions::new().write(true).create(true).open(t).await?;
 
                 let (mut from, mut to) = (Cursor::new(from), Cursor::new(to));
                 compio::io::copy(&mut from, &mut to).await?;
@@ -208,6 +221,9 @@ impl Access for CompfsBackend {
                 Ok(())
             })
             .await?;
+        self.core
+            .exec_blocking(move || FsMeta::copy(&from, &to))
+            .await??;
 
         Ok(RpCopy::default())
     }
@@ -216,9 +232,13 @@ impl Access for CompfsBackend {
         let from = self.core.prepare_path(from);
         let to = self.core.prepare_path(to);
 
+        let (f, t) = (from.clone(), to.clone());
         self.core
-            .exec(move || async move { compio::fs::rename(from, to).await })
+            .exec(move || async move { compio::fs::rename(f, t).await })
             .await?;
+        self.core
+            .exec_blocking(move || FsMeta::rename(&from, &to))
+            .await??;
 
         Ok(RpRename::default())
     }
@@ -238,6 +258,7 @@ impl Access for CompfsBackend {
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let path = self.core.prepare_path(path);
         let append = args.append();
+        let p = path.clone();
         let file = self
             .core
             .exec(move || async move {
@@ -245,12 +266,18 @@ impl Access for CompfsBackend {
                     .create(true)
                     .write(true)
                     .truncate(!append)
-                    .open(path)
+                    .open(p)
                     .await
             })
             .await
             .map(Cursor::new)?;
 
+        // Appending to an existing file without metadata should keep the old one.
+        let meta = FsMeta::from_op_write(&args);
+        if !append || !meta.is_empty() {
+            self.core.exec_blocking(move || meta.store(&path)).await??;
+        }
+
         let w = CompfsWriter::new(self.core.clone(), file);
         Ok((RpWrite::new(), w))
     }
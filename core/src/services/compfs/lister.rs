@@ -39,8 +39,14 @@ impl CompfsLister {
 }
 
 fn next_entry(read_dir: &mut ReadDir, root: &Path) -> std::io::Result<Option<oio::Entry>> {
-    let Some(entry) = read_dir.next().transpose()? else {
-        return Ok(None);
+    // Sidecar files of FsMeta should be hidden from users.
+    let entry = loop {
+        let Some(entry) = read_dir.next().transpose()? else {
+            return Ok(None);
+        };
+        if !is_fs_meta_sidecar(&entry.file_name().to_string_lossy()) {
+            break entry;
+        }
     };
     let path = entry.path();
     let rel_path = normalize_path(
@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use bytes::Buf;
 use http::Response;
 use http::StatusCode;
 use http::Uri;
@@ -286,6 +287,7 @@ impl Access for CosBackend {
                 write_can_multi: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
+                write_with_tags: true,
                 write_with_content_disposition: true,
                 // The min multipart size of COS is 1 MiB.
                 //
@@ -306,10 +308,20 @@ impl Access for CosBackend {
                 list: true,
                 list_with_recursive: true,
 
+                list_multipart_uploads: true,
+                abort_multipart_upload: true,
+
+                get_tags: true,
+                set_tags: true,
+
                 presign: true,
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_delete: true,
+                presign_list: true,
+                presign_multipart: true,
+                presign_post: true,
 
                 ..Default::default()
             });
@@ -379,6 +391,28 @@ impl Access for CosBackend {
         Ok((RpList::default(), oio::PageLister::new(l)))
     }
 
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.cos_get_object_tagging(path).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let tagging: Tagging = quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+                Ok(RpGetTags::new(tagging.into_tags()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self.core.cos_put_object_tagging(path, args.tags()).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(RpSetTags::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
         let resp = self.core.cos_copy_object(from, to).await?;
 
@@ -390,6 +424,71 @@ impl Access for CosBackend {
         }
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        _: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let mut uploads = Vec::new();
+        let (mut key_marker, mut upload_id_marker) = (None, None);
+        loop {
+            let resp = self
+                .core
+                .cos_list_multipart_uploads(
+                    path,
+                    key_marker.as_deref(),
+                    upload_id_marker.as_deref(),
+                )
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let output: ListMultipartUploadsOutput =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+            for upload in output.upload {
+                let mut v = MultipartUpload::new(&build_rel_path(&self.core.root, &upload.key))
+                    .with_upload_id(&upload.upload_id);
+                if let Ok(initiated) = parse_datetime_from_rfc3339(&upload.initiated) {
+                    v = v.with_initiated(initiated);
+                }
+                uploads.push(v);
+            }
+
+            if !output.is_truncated {
+                break;
+            }
+            key_marker = output.next_key_marker;
+            upload_id_marker = output.next_upload_id_marker;
+        }
+
+        Ok(RpListMultipartUploads::new(uploads))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let Some(upload_id) = args.upload_id() else {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "upload id is required to abort multipart upload",
+            ));
+        };
+
+        let resp = self
+            .core
+            .cos_abort_multipart_upload(path, upload_id)
+            .await?;
+        match resp.status() {
+            // Upload that has been aborted or completed will return 404.
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(RpAbortMultipartUpload::new()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => self.core.cos_head_object_request(path, v)?,
@@ -401,6 +500,32 @@ impl Access for CosBackend {
                 self.core
                     .cos_put_object_request(path, None, v, Buffer::new())?
             }
+            PresignOperation::Delete(_) => self.core.cos_delete_object_request(path)?,
+            PresignOperation::List(v) => {
+                let delimiter = if v.recursive() { "" } else { "/" };
+                // COS uses `marker` to list keys after it.
+                let marker = v
+                    .start_after()
+                    .map(|v| percent_encode_path(&build_abs_path(&self.core.root, v)))
+                    .unwrap_or_default();
+                self.core
+                    .cos_list_objects_request(path, &marker, delimiter, v.limit())?
+            }
+            PresignOperation::InitiateMultipartUpload(v) => {
+                self.core.cos_initiate_multipart_upload_request(path, v)?
+            }
+            PresignOperation::UploadPart(v) => self.core.cos_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number(),
+                None,
+                Buffer::new(),
+            )?,
+            PresignOperation::CompleteMultipartUpload(v) => self
+                .core
+                .cos_complete_multipart_upload_request(path, v.upload_id())
+                .body(Buffer::new())
+                .map_err(new_request_build_error)?,
         };
         self.core.sign_query(&mut req, args.expire()).await?;
 
@@ -413,4 +538,10 @@ impl Access for CosBackend {
             parts.headers,
         )))
     }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.cos_presign_post(path, &args).await?;
+
+        Ok(RpPresignPost::new(post))
+    }
 }
@@ -15,11 +15,17 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::fmt::Write;
 use std::time::Duration;
 
 use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
 use http::header::CONTENT_LENGTH;
@@ -33,6 +39,8 @@ use reqsign::TencentCosCredentialLoader;
 use reqsign::TencentCosSigner;
 use serde::Deserialize;
 use serde::Serialize;
+use sha1::Digest;
+use sha1::Sha1;
 
 use crate::raw::*;
 use crate::*;
@@ -165,6 +173,9 @@ impl CosCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control)
         }
+        if let Some(tags) = args.tags() {
+            req = req.header("x-cos-tagging", format_tagging_query(tags));
+        }
         if let Some(pos) = args.content_disposition() {
             req = req.header(CONTENT_DISPOSITION, pos)
         }
@@ -205,14 +216,18 @@ impl CosCore {
         Ok(req)
     }
 
-    pub async fn cos_delete_object(&self, path: &str) -> Result<Response<Buffer>> {
+    pub fn cos_delete_object_request(&self, path: &str) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
 
         let req = Request::delete(&url);
 
-        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        req.body(Buffer::new()).map_err(new_request_build_error)
+    }
+
+    pub async fn cos_delete_object(&self, path: &str) -> Result<Response<Buffer>> {
+        let mut req = self.cos_delete_object_request(path)?;
 
         self.sign(&mut req).await?;
 
@@ -250,6 +265,9 @@ impl CosCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control)
         }
+        if let Some(tags) = args.tags() {
+            req = req.header("x-cos-tagging", format_tagging_query(tags));
+        }
 
         let req = req.body(body).map_err(new_request_build_error)?;
         Ok(req)
@@ -272,13 +290,54 @@ impl CosCore {
         self.send(req).await
     }
 
-    pub async fn cos_list_objects(
+    pub async fn cos_get_object_tagging(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn cos_put_object_tagging(
+        &self,
+        path: &str,
+        tags: &HashMap<String, String>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let content = quick_xml::se::to_string(&Tagging::from_tags(tags))
+            .map_err(new_xml_deserialize_error)?;
+
+        let req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            // Set content-md5 as required by API.
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        let mut req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub fn cos_list_objects_request(
         &self,
         path: &str,
         next_marker: &str,
         delimiter: &str,
         limit: Option<usize>,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let mut queries = vec![];
@@ -301,20 +360,30 @@ impl CosCore {
             format!("{}?{}", self.endpoint, queries.join("&"))
         };
 
-        let mut req = Request::get(&url)
+        Request::get(&url)
             .body(Buffer::new())
-            .map_err(new_request_build_error)?;
+            .map_err(new_request_build_error)
+    }
+
+    pub async fn cos_list_objects(
+        &self,
+        path: &str,
+        next_marker: &str,
+        delimiter: &str,
+        limit: Option<usize>,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.cos_list_objects_request(path, next_marker, delimiter, limit)?;
 
         self.sign(&mut req).await?;
 
         self.send(req).await
     }
 
-    pub async fn cos_initiate_multipart_upload(
+    pub fn cos_initiate_multipart_upload_request(
         &self,
         path: &str,
         args: &OpWrite,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}?uploads", self.endpoint, percent_encode_path(&p));
@@ -332,22 +401,33 @@ impl CosCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control)
         }
+        if let Some(tags) = args.tags() {
+            req = req.header("x-cos-tagging", format_tagging_query(tags));
+        }
 
-        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        req.body(Buffer::new()).map_err(new_request_build_error)
+    }
+
+    pub async fn cos_initiate_multipart_upload(
+        &self,
+        path: &str,
+        args: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.cos_initiate_multipart_upload_request(path, args)?;
 
         self.sign(&mut req).await?;
 
         self.send(req).await
     }
 
-    pub async fn cos_upload_part_request(
+    pub fn cos_upload_part_request(
         &self,
         path: &str,
         upload_id: &str,
         part_number: usize,
-        size: u64,
+        size: Option<u64>,
         body: Buffer,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -359,21 +439,37 @@ impl CosCore {
         );
 
         let mut req = Request::put(&url);
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
         // Set body
-        let mut req = req.body(body).map_err(new_request_build_error)?;
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn cos_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let mut req =
+            self.cos_upload_part_request(path, upload_id, part_number, Some(size), body)?;
 
         self.sign(&mut req).await?;
 
         self.send(req).await
     }
 
-    pub async fn cos_complete_multipart_upload(
+    /// Build the complete multipart upload request without body.
+    ///
+    /// Presigned requests will leave the body to users.
+    pub fn cos_complete_multipart_upload_request(
         &self,
         path: &str,
         upload_id: &str,
-        parts: Vec<CompleteMultipartUploadRequestPart>,
-    ) -> Result<Response<Buffer>> {
+    ) -> http::request::Builder {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -383,7 +479,16 @@ impl CosCore {
             percent_encode_path(upload_id)
         );
 
-        let req = Request::post(&url);
+        Request::post(&url)
+    }
+
+    pub async fn cos_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<CompleteMultipartUploadRequestPart>,
+    ) -> Result<Response<Buffer>> {
+        let req = self.cos_complete_multipart_upload_request(path, upload_id);
 
         let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest { part: parts })
             .map_err(new_xml_deserialize_error)?;
@@ -401,6 +506,64 @@ impl CosCore {
         self.send(req).await
     }
 
+    /// Generate a POST policy signed with COS signature.
+    ///
+    /// Reference: <https://cloud.tencent.com/document/product/436/14690>
+    pub async fn cos_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+    ) -> Result<PresignedPost> {
+        let Some(cred) = self.load_credential().await? else {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "presign post requires credential but anonymous access is used",
+            ));
+        };
+
+        let p = build_abs_path(&self.root, path);
+        let now = Utc::now();
+
+        let policy = PostPolicy::new(now, &p, args).with_condition("bucket", &self.bucket);
+        let fields = sign_post_policy(policy, now, &cred);
+
+        let uri = self.endpoint.parse().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "endpoint is not a valid uri").set_source(err)
+        })?;
+
+        Ok(PresignedPost::new(uri, fields))
+    }
+
+    /// List the incomplete multipart uploads under given prefix.
+    pub async fn cos_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: Option<&str>,
+        upload_id_marker: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/?uploads&prefix={}",
+            self.endpoint,
+            percent_encode_path(&p)
+        );
+        if let Some(marker) = key_marker {
+            write!(url, "&key-marker={}", percent_encode_path(marker))
+                .expect("write into string must succeed");
+        }
+        if let Some(marker) = upload_id_marker {
+            write!(url, "&upload-id-marker={}", percent_encode_path(marker))
+                .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     /// Abort an on-going multipart upload.
     pub async fn cos_abort_multipart_upload(
         &self,
@@ -499,12 +662,105 @@ pub struct ListObjectsOutputContent {
     pub size: u64,
 }
 
+/// Output of ListMultipartUploads.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutput {
+    pub is_truncated: bool,
+    pub next_key_marker: Option<String>,
+    pub next_upload_id_marker: Option<String>,
+    pub upload: Vec<ListMultipartUploadsOutputUpload>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutputUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: String,
+}
+
+fn hmac_sha1_hex(key: &[u8], content: &[u8]) -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("hmac accepts key of any size");
+    mac.update(content);
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Add signing fields into the POST policy and sign it, return all form fields.
+pub fn sign_post_policy(
+    policy: PostPolicy,
+    now: DateTime<Utc>,
+    cred: &TencentCosCredential,
+) -> Vec<(String, String)> {
+    let key_time = format!("{};{}", now.timestamp(), policy.expiration().timestamp());
+
+    let mut policy = policy
+        .with_field("q-sign-algorithm", "sha1")
+        .with_field("q-ak", &cred.secret_id)
+        .with_condition("q-sign-time", &key_time);
+    if let Some(token) = &cred.security_token {
+        policy = policy.with_field("x-cos-security-token", token);
+    }
+
+    let sign_key = hmac_sha1_hex(cred.secret_key.as_bytes(), key_time.as_bytes());
+    let string_to_sign = Sha1::digest(policy.to_json().as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<String>();
+    let signature = hmac_sha1_hex(sign_key.as_bytes(), string_to_sign.as_bytes());
+    let encoded = policy.encode();
+
+    let mut fields = policy.into_fields();
+    fields.push(("q-key-time".to_string(), key_time));
+    fields.push(("policy".to_string(), encoded));
+    fields.push(("q-signature".to_string(), signature));
+    fields
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use bytes::Buf;
 
     use super::*;
 
+    #[test]
+    fn test_sign_post_policy() {
+        let now = DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let cred = TencentCosCredential {
+            secret_id: "secret_id".to_string(),
+            secret_key: "secret_key".to_string(),
+            security_token: None,
+            expires_in: None,
+        };
+        let args = OpPresignPost::new(Duration::from_secs(3600));
+        let policy =
+            PostPolicy::new(now, "a.png", &args).with_condition("bucket", "test-1250000000");
+
+        let fields = sign_post_policy(policy, now, &cred);
+        let names = fields.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "key",
+                "q-sign-algorithm",
+                "q-ak",
+                "q-key-time",
+                "policy",
+                "q-signature"
+            ]
+        );
+        assert_eq!(fields[3].1, "1704164645;1704168245");
+        assert_eq!(fields[5].1, "e27f22be10179ed2eea989828a7a81a6b78c1c46");
+    }
+
     #[test]
     fn test_parse_xml() {
         let bs = bytes::Bytes::from(
@@ -95,7 +95,7 @@ impl oio::MultipartWrite for CosWriter {
 
         let resp = self
             .core
-            .cos_upload_part_request(&self.path, upload_id, part_number, size, body)
+            .cos_upload_part(&self.path, upload_id, part_number, size, body)
             .await?;
 
         let status = resp.status();
@@ -113,6 +113,7 @@ impl oio::MultipartWrite for CosWriter {
 
                 Ok(oio::MultipartPart {
                     part_number,
+                    size,
                     etag,
                     checksum: None,
                 })
@@ -193,6 +193,9 @@ impl Access for FsBackend {
                 write_can_empty: true,
                 write_can_append: true,
                 write_can_multi: true,
+                write_can_random: true,
+                write_with_content_encoding: true,
+                write_with_user_metadata: true,
                 create_dir: true,
                 delete: true,
 
@@ -202,6 +205,15 @@ impl Access for FsBackend {
                 rename: true,
                 blocking: true,
 
+                create_symlink: true,
+                read_link: true,
+
+                set_attr: true,
+                set_attr_with_mode: cfg!(unix),
+                set_attr_with_owner: cfg!(unix),
+                set_attr_with_times: true,
+                set_attr_with_size: true,
+
                 ..Default::default()
             });
 
@@ -218,15 +230,22 @@ impl Access for FsBackend {
         Ok(RpCreateDir::default())
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
-        let meta = tokio::fs::metadata(&p).await.map_err(new_std_io_error)?;
+        let meta = if args.no_follow() {
+            tokio::fs::symlink_metadata(&p).await
+        } else {
+            tokio::fs::metadata(&p).await
+        }
+        .map_err(new_std_io_error)?;
 
         let mode = if meta.is_dir() {
             EntryMode::DIR
         } else if meta.is_file() {
             EntryMode::FILE
+        } else if meta.is_symlink() {
+            EntryMode::Symlink
         } else {
             EntryMode::Unknown
         };
@@ -237,6 +256,12 @@ impl Access for FsBackend {
                     .map(DateTime::from)
                     .map_err(new_std_io_error)?,
             );
+        let mut m = with_posix_attrs(m, &meta);
+        let follow = !args.no_follow();
+        tokio::task::spawn_blocking(move || FsMeta::load(&p, follow))
+            .await
+            .map_err(new_task_join_error)??
+            .apply(&mut m);
 
         Ok(RpStat::new(m))
     }
@@ -287,10 +312,14 @@ impl Access for FsBackend {
                 .await?;
 
             // If the target file exists, we should append to the end of it directly.
-            if op.append()
-                && tokio::fs::try_exists(&target_path)
-                    .await
-                    .map_err(new_std_io_error)?
+            //
+            // Random write always happens on the target file since the existing
+            // content must be kept.
+            if op.offset().is_some()
+                || op.append()
+                    && tokio::fs::try_exists(&target_path)
+                        .await
+                        .map_err(new_std_io_error)?
             {
                 (target_path, None)
             } else {
@@ -309,18 +338,30 @@ impl Access for FsBackend {
         open_options.create(true).write(true);
         if op.append() {
             open_options.append(true);
-        } else {
+        } else if op.offset().is_none() {
             open_options.truncate(true);
         }
 
-        let f = open_options
+        let mut f = open_options
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .await
             .map_err(new_std_io_error)?;
+        if let Some(offset) = op.offset() {
+            use tokio::io::AsyncSeekExt;
+
+            f.seek(SeekFrom::Start(offset))
+                .await
+                .map_err(new_std_io_error)?;
+        }
 
-        let w = FsWriter::new(target_path, tmp_path, f);
+        let mut w = FsWriter::new(target_path, tmp_path, f);
+        // Writing into an existing file without metadata should keep the old one.
+        let meta = FsMeta::from_op_write(&op);
+        if !(op.append() || op.offset().is_some()) || !meta.is_empty() {
+            w = w.with_meta(meta);
+        }
 
-        let w = if op.append() {
+        let w = if op.append() || op.offset().is_some() {
             FsWriters::One(w)
         } else {
             FsWriters::Two(oio::PositionWriter::new(
@@ -336,7 +377,8 @@ impl Access for FsBackend {
     async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
-        let meta = tokio::fs::metadata(&p).await;
+        // Don't follow symlinks so that the link itself will be removed.
+        let meta = tokio::fs::symlink_metadata(&p).await;
 
         match meta {
             Ok(meta) => {
@@ -344,6 +386,9 @@ impl Access for FsBackend {
                     tokio::fs::remove_dir(&p).await.map_err(new_std_io_error)?;
                 } else {
                     tokio::fs::remove_file(&p).await.map_err(new_std_io_error)?;
+                    tokio::task::spawn_blocking(move || FsMeta::remove(&p))
+                        .await
+                        .map_err(new_task_join_error)??;
                 }
 
                 Ok(RpDelete::default())
@@ -383,7 +428,12 @@ impl Access for FsBackend {
             .ensure_write_abs_path(&self.core.root, to.trim_end_matches('/'))
             .await?;
 
-        tokio::fs::copy(from, to).await.map_err(new_std_io_error)?;
+        tokio::fs::copy(&from, &to)
+            .await
+            .map_err(new_std_io_error)?;
+        tokio::task::spawn_blocking(move || FsMeta::copy(&from, &to))
+            .await
+            .map_err(new_task_join_error)??;
 
         Ok(RpCopy::default())
     }
@@ -399,13 +449,56 @@ impl Access for FsBackend {
             .ensure_write_abs_path(&self.core.root, to.trim_end_matches('/'))
             .await?;
 
-        tokio::fs::rename(from, to)
+        tokio::fs::rename(&from, &to)
             .await
             .map_err(new_std_io_error)?;
+        tokio::task::spawn_blocking(move || FsMeta::rename(&from, &to))
+            .await
+            .map_err(new_task_join_error)??;
 
         Ok(RpRename::default())
     }
 
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        let p = self
+            .core
+            .ensure_write_abs_path(&self.core.root, path)
+            .await?;
+
+        #[cfg(unix)]
+        tokio::fs::symlink(args.target(), &p)
+            .await
+            .map_err(new_std_io_error)?;
+        #[cfg(windows)]
+        tokio::fs::symlink_file(args.target(), &p)
+            .await
+            .map_err(new_std_io_error)?;
+
+        Ok(RpCreateSymlink::default())
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let p = self.core.root.join(path.trim_end_matches('/'));
+
+        let target = tokio::fs::read_link(&p).await.map_err(new_std_io_error)?;
+        let target = target.to_str().ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "symlink target is not valid utf-8")
+                .with_context("target", target.to_string_lossy())
+        })?;
+
+        Ok(RpReadLink::new(target))
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        let p = self.core.root.join(path.trim_end_matches('/'));
+
+        tokio::task::spawn_blocking(move || set_attr(&p, &args))
+            .await
+            .map_err(new_task_join_error)??;
+
+        Ok(RpSetAttr::default())
+    }
+
     fn blocking_create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
@@ -414,15 +507,22 @@ impl Access for FsBackend {
         Ok(RpCreateDir::default())
     }
 
-    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
-        let meta = std::fs::metadata(p).map_err(new_std_io_error)?;
+        let meta = if args.no_follow() {
+            std::fs::symlink_metadata(&p)
+        } else {
+            std::fs::metadata(&p)
+        }
+        .map_err(new_std_io_error)?;
 
         let mode = if meta.is_dir() {
             EntryMode::DIR
         } else if meta.is_file() {
             EntryMode::FILE
+        } else if meta.is_symlink() {
+            EntryMode::Symlink
         } else {
             EntryMode::Unknown
         };
@@ -433,6 +533,8 @@ impl Access for FsBackend {
                     .map(DateTime::from)
                     .map_err(new_std_io_error)?,
             );
+        let mut m = with_posix_attrs(m, &meta);
+        FsMeta::load(&p, !args.no_follow())?.apply(&mut m);
 
         Ok(RpStat::new(m))
     }
@@ -470,10 +572,14 @@ impl Access for FsBackend {
                 .blocking_ensure_write_abs_path(atomic_write_dir, &tmp_file_of(path))?;
 
             // If the target file exists, we should append to the end of it directly.
-            if op.append()
-                && Path::new(&target_path)
-                    .try_exists()
-                    .map_err(new_std_io_error)?
+            //
+            // Random write always happens on the target file since the existing
+            // content must be kept.
+            if op.offset().is_some()
+                || op.append()
+                    && Path::new(&target_path)
+                        .try_exists()
+                        .map_err(new_std_io_error)?
             {
                 (target_path, None)
             } else {
@@ -492,21 +598,33 @@ impl Access for FsBackend {
 
         if op.append() {
             f.append(true);
-        } else {
+        } else if op.offset().is_none() {
             f.truncate(true);
         }
 
-        let f = f
+        let mut f = f
             .open(tmp_path.as_ref().unwrap_or(&target_path))
             .map_err(new_std_io_error)?;
+        if let Some(offset) = op.offset() {
+            use std::io::Seek;
+
+            f.seek(SeekFrom::Start(offset)).map_err(new_std_io_error)?;
+        }
+
+        let mut w = FsWriter::new(target_path, tmp_path, f);
+        // Writing into an existing file without metadata should keep the old one.
+        let meta = FsMeta::from_op_write(&op);
+        if !(op.append() || op.offset().is_some()) || !meta.is_empty() {
+            w = w.with_meta(meta);
+        }
 
-        Ok((RpWrite::new(), FsWriter::new(target_path, tmp_path, f)))
+        Ok((RpWrite::new(), w))
     }
 
     fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
-        let meta = std::fs::metadata(&p);
+        let meta = std::fs::symlink_metadata(&p);
 
         match meta {
             Ok(meta) => {
@@ -514,6 +632,7 @@ impl Access for FsBackend {
                     std::fs::remove_dir(&p).map_err(new_std_io_error)?;
                 } else {
                     std::fs::remove_file(&p).map_err(new_std_io_error)?;
+                    FsMeta::remove(&p)?;
                 }
 
                 Ok(RpDelete::default())
@@ -552,7 +671,8 @@ impl Access for FsBackend {
             .core
             .blocking_ensure_write_abs_path(&self.core.root, to.trim_end_matches('/'))?;
 
-        std::fs::copy(from, to).map_err(new_std_io_error)?;
+        std::fs::copy(&from, &to).map_err(new_std_io_error)?;
+        FsMeta::copy(&from, &to)?;
 
         Ok(RpCopy::default())
     }
@@ -567,7 +687,8 @@ impl Access for FsBackend {
             .core
             .blocking_ensure_write_abs_path(&self.core.root, to.trim_end_matches('/'))?;
 
-        std::fs::rename(from, to).map_err(new_std_io_error)?;
+        std::fs::rename(&from, &to).map_err(new_std_io_error)?;
+        FsMeta::rename(&from, &to)?;
 
         Ok(RpRename::default())
     }
@@ -86,6 +86,81 @@ impl FsCore {
     }
 }
 
+/// Fill posix attributes like permission bits, owner and last accessed
+/// from std metadata.
+pub fn with_posix_attrs(mut m: Metadata, meta: &std::fs::Metadata) -> Metadata {
+    if let Ok(t) = meta.accessed() {
+        m.set_last_accessed(t.into());
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        m.set_unix_mode(meta.mode())
+            .set_uid(meta.uid())
+            .set_gid(meta.gid());
+    }
+
+    m
+}
+
+/// Set attributes of the entry at given path.
+///
+/// Size and times are changed first so that they won't be blocked by the new
+/// permission bits, and mode is changed after owner since `chown` will clear
+/// setuid and setgid bits.
+pub fn set_attr(p: &Path, args: &OpSetAttr) -> Result<()> {
+    if args.owner().is_some() || args.group().is_some() {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "fs only supports set owner by numeric uid and gid",
+        ));
+    }
+
+    if let Some(size) = args.size() {
+        let f = std::fs::OpenOptions::new()
+            .write(true)
+            .open(p)
+            .map_err(new_std_io_error)?;
+        f.set_len(size).map_err(new_std_io_error)?;
+    }
+
+    if args.has_times() {
+        let mut times = std::fs::FileTimes::new();
+        if let Some(v) = args.last_modified() {
+            times = times.set_modified(v.into());
+        }
+        if let Some(v) = args.last_accessed() {
+            times = times.set_accessed(v.into());
+        }
+        let f = std::fs::File::open(p).map_err(new_std_io_error)?;
+        f.set_times(times).map_err(new_std_io_error)?;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        if args.uid().is_some() || args.gid().is_some() {
+            std::os::unix::fs::chown(p, args.uid(), args.gid()).map_err(new_std_io_error)?;
+        }
+        if let Some(mode) = args.mode() {
+            std::fs::set_permissions(p, std::fs::Permissions::from_mode(mode))
+                .map_err(new_std_io_error)?;
+        }
+    }
+    #[cfg(not(unix))]
+    if args.mode().is_some() || args.uid().is_some() || args.gid().is_some() {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "fs only supports set mode and owner on unix",
+        ));
+    }
+
+    Ok(())
+}
+
 #[inline]
 pub fn tmp_file_of(path: &str) -> String {
     let name = get_basename(path);
@@ -47,8 +47,14 @@ unsafe impl<P> Sync for FsLister<P> {}
 
 impl oio::List for FsLister<tokio::fs::ReadDir> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
-        let Some(de) = self.rd.next_entry().await.map_err(new_std_io_error)? else {
-            return Ok(None);
+        // Sidecar files of FsMeta should be hidden from users.
+        let de = loop {
+            let Some(de) = self.rd.next_entry().await.map_err(new_std_io_error)? else {
+                return Ok(None);
+            };
+            if !is_fs_meta_sidecar(&de.file_name().to_string_lossy()) {
+                break de;
+            }
         };
 
         let entry_path = de.path();
@@ -68,6 +74,8 @@ impl oio::List for FsLister<tokio::fs::ReadDir> {
                 Metadata::new(EntryMode::FILE)
             } else if ft.is_dir() {
                 Metadata::new(EntryMode::DIR)
+            } else if ft.is_symlink() {
+                Metadata::new(EntryMode::Symlink)
             } else {
                 Metadata::new(EntryMode::Unknown)
             }
@@ -77,6 +85,8 @@ impl oio::List for FsLister<tokio::fs::ReadDir> {
                 Metadata::new(EntryMode::FILE)
             } else if fs_meta.file_type().is_dir() {
                 Metadata::new(EntryMode::DIR)
+            } else if fs_meta.file_type().is_symlink() {
+                Metadata::new(EntryMode::Symlink)
             } else {
                 Metadata::new(EntryMode::Unknown)
             };
@@ -98,9 +108,15 @@ impl oio::List for FsLister<tokio::fs::ReadDir> {
 
 impl oio::BlockingList for FsLister<std::fs::ReadDir> {
     fn next(&mut self) -> Result<Option<oio::Entry>> {
-        let de = match self.rd.next() {
-            Some(de) => de.map_err(new_std_io_error)?,
-            None => return Ok(None),
+        // Sidecar files of FsMeta should be hidden from users.
+        let de = loop {
+            let de = match self.rd.next() {
+                Some(de) => de.map_err(new_std_io_error)?,
+                None => return Ok(None),
+            };
+            if !is_fs_meta_sidecar(&de.file_name().to_string_lossy()) {
+                break de;
+            }
         };
 
         let entry_path = de.path();
@@ -120,6 +136,8 @@ impl oio::BlockingList for FsLister<std::fs::ReadDir> {
                 Metadata::new(EntryMode::FILE)
             } else if ft.is_dir() {
                 Metadata::new(EntryMode::DIR)
+            } else if ft.is_symlink() {
+                Metadata::new(EntryMode::Symlink)
             } else {
                 Metadata::new(EntryMode::Unknown)
             }
@@ -129,6 +147,8 @@ impl oio::BlockingList for FsLister<std::fs::ReadDir> {
                 Metadata::new(EntryMode::FILE)
             } else if fs_meta.file_type().is_dir() {
                 Metadata::new(EntryMode::DIR)
+            } else if fs_meta.file_type().is_symlink() {
+                Metadata::new(EntryMode::Symlink)
             } else {
                 Metadata::new(EntryMode::Unknown)
             };
@@ -33,6 +33,7 @@ pub struct FsWriter<F> {
     tmp_path: Option<PathBuf>,
 
     f: Option<F>,
+    meta: Option<FsMeta>,
 }
 
 impl<F> FsWriter<F> {
@@ -42,8 +43,34 @@ impl<F> FsWriter<F> {
             tmp_path,
 
             f: Some(f),
+            meta: None,
         }
     }
+
+    /// Set the FsMeta that will be stored after the file has been written.
+    pub fn with_meta(mut self, meta: FsMeta) -> Self {
+        self.meta = Some(meta);
+        self
+    }
+
+    fn store_meta(&self) -> Result<()> {
+        match &self.meta {
+            Some(meta) => meta.store(&self.target_path),
+            None => Ok(()),
+        }
+    }
+
+    /// Store FsMeta in a blocking thread so that xattr syscalls won't block
+    /// the async runtime.
+    async fn store_meta_async(&self) -> Result<()> {
+        let Some(meta) = self.meta.clone() else {
+            return Ok(());
+        };
+        let p = self.target_path.clone();
+        tokio::task::spawn_blocking(move || meta.store(&p))
+            .await
+            .map_err(new_task_join_error)?
+    }
 }
 
 /// # Safety
@@ -73,7 +100,7 @@ impl oio::Write for FsWriter<tokio::fs::File> {
                 .await
                 .map_err(new_std_io_error)?;
         }
-        Ok(())
+        self.store_meta_async().await
     }
 
     async fn abort(&mut self) -> Result<()> {
@@ -109,6 +136,7 @@ impl oio::BlockingWrite for FsWriter<std::fs::File> {
             if let Some(tmp_path) = &self.tmp_path {
                 std::fs::rename(tmp_path, &self.target_path).map_err(new_std_io_error)?;
             }
+            self.store_meta()?;
         }
 
         Ok(())
@@ -162,7 +190,7 @@ impl oio::PositionWrite for FsWriter<tokio::fs::File> {
                 .await
                 .map_err(new_std_io_error)?;
         }
-        Ok(())
+        self.store_meta_async().await
     }
 
     async fn abort(&self) -> Result<()> {
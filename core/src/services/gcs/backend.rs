@@ -19,6 +19,8 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use bytes::Buf;
 use http::Response;
 use http::StatusCode;
@@ -30,6 +32,7 @@ use reqsign::GoogleTokenLoader;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json;
+use serde_json::json;
 
 use super::core::*;
 use super::error::parse_error;
@@ -346,6 +349,20 @@ impl Builder for GcsBuilder {
         if let Ok(Some(cred)) = cred_loader.load() {
             token_loader = token_loader.with_credentials(cred)
         }
+
+        // Only service account in config could be used to sign POST policies.
+        let service_account = if let Some(cred) = &self.config.credential {
+            BASE64_STANDARD
+                .decode(cred)
+                .ok()
+                .and_then(|bs| GcsServiceAccount::from_slice(&bs))
+        } else if let Some(path) = &self.config.credential_path {
+            std::fs::read(path)
+                .ok()
+                .and_then(|bs| GcsServiceAccount::from_slice(&bs))
+        } else {
+            None
+        };
         if let Some(loader) = self.customized_token_loader {
             token_loader = token_loader.with_customized_token_loader(loader)
         }
@@ -367,6 +384,7 @@ impl Builder for GcsBuilder {
                 token: self.config.token,
                 scope: scope.to_string(),
                 credential_loader: cred_loader,
+                service_account,
                 predefined_acl: self.config.predefined_acl.clone(),
                 default_storage_class: self.config.default_storage_class.clone(),
                 allow_anonymous: self.config.allow_anonymous,
@@ -409,7 +427,12 @@ impl Access for GcsBackend {
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_can_resume: true,
                 write_with_content_type: true,
+                write_with_content_encoding: true,
+                write_with_retention: true,
+                write_with_legal_hold: true,
+                write_with_storage_class: true,
                 // The min multipart size of Gcs is 5 MiB.
                 //
                 // ref: <https://cloud.google.com/storage/docs/xml-api/put-object-multipart>
@@ -431,12 +454,21 @@ impl Access for GcsBackend {
                 list_with_start_after: true,
                 list_with_recursive: true,
 
+                set_retention: true,
+                set_legal_hold: true,
+
                 batch: true,
+                batch_delete: true,
+                batch_stat: true,
                 batch_max_operations: Some(100),
                 presign: true,
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_delete: true,
+                presign_list: true,
+                presign_multipart: true,
+                presign_post: true,
 
                 ..Default::default()
             });
@@ -455,23 +487,34 @@ impl Access for GcsBackend {
         let meta: GetObjectJsonResponse =
             serde_json::from_reader(slc.reader()).map_err(new_json_deserialize_error)?;
 
-        let mut m = Metadata::new(EntryMode::FILE);
+        parse_object_metadata(meta).map(RpStat::new)
+    }
 
-        m.set_etag(&meta.etag);
-        m.set_content_md5(&meta.md5_hash);
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        let metadata = json!({
+            "retention": format_object_retention(args.mode(), args.retain_until()),
+        });
+        let resp = self
+            .core
+            .gcs_patch_object(path, metadata, args.bypass_governance())
+            .await?;
 
-        let size = meta
-            .size
-            .parse::<u64>()
-            .map_err(|e| Error::new(ErrorKind::Unexpected, "parse u64").set_source(e))?;
-        m.set_content_length(size);
-        if !meta.content_type.is_empty() {
-            m.set_content_type(&meta.content_type);
+        if resp.status().is_success() {
+            Ok(RpSetRetention::default())
+        } else {
+            Err(parse_error(resp))
         }
+    }
 
-        m.set_last_modified(parse_datetime_from_rfc3339(&meta.updated)?);
+    async fn set_legal_hold(&self, path: &str, args: OpSetLegalHold) -> Result<RpSetLegalHold> {
+        let metadata = json!({ "temporaryHold": args.hold() });
+        let resp = self.core.gcs_patch_object(path, metadata, false).await?;
 
-        Ok(RpStat::new(m))
+        if resp.status().is_success() {
+            Ok(RpSetLegalHold::default())
+        } else {
+            Err(parse_error(resp))
+        }
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
@@ -494,8 +537,10 @@ impl Access for GcsBackend {
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let concurrent = args.concurrent();
         let executor = args.executor().cloned();
-        let w = GcsWriter::new(self.core.clone(), path, args);
-        let w = oio::MultipartWriter::new(w, executor, concurrent);
+        let w = GcsWriter::new(self.core.clone(), path, args.clone());
+        let w = oio::MultipartWriter::new(w, executor, concurrent)
+            .with_checkpoint(&args)
+            .await?;
 
         Ok((RpWrite::default(), w))
     }
@@ -542,6 +587,31 @@ impl Access for GcsBackend {
                 self.core
                     .gcs_insert_object_xml_request(path, v, Buffer::new())?
             }
+            PresignOperation::Delete(_) => self.core.gcs_delete_object_xml_request(path)?,
+            PresignOperation::List(v) => {
+                let delimiter = if v.recursive() { "" } else { "/" };
+                self.core.gcs_list_objects_xml_request(
+                    path,
+                    delimiter,
+                    v.limit(),
+                    v.start_after().map(String::from),
+                )?
+            }
+            PresignOperation::InitiateMultipartUpload(v) => self
+                .core
+                .gcs_initiate_multipart_upload_request(&percent_encode_path(path), v)?,
+            PresignOperation::UploadPart(v) => self.core.gcs_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number(),
+                None,
+                Buffer::new(),
+            )?,
+            PresignOperation::CompleteMultipartUpload(v) => self
+                .core
+                .gcs_complete_multipart_upload_request(path, v.upload_id())
+                .body(Buffer::new())
+                .map_err(new_request_build_error)?,
         };
 
         self.core.sign_query(&mut req, args.expire()).await?;
@@ -556,64 +626,161 @@ impl Access for GcsBackend {
         )))
     }
 
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.gcs_presign_post(path, &args)?;
+
+        Ok(RpPresignPost::new(post))
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let ops = args.into_operation();
         if ops.len() > 100 {
             return Err(Error::new(
                 ErrorKind::Unsupported,
-                "gcs services only allow delete less than 100 keys at once",
+                "gcs services only allow batch less than 100 operations at once",
             )
             .with_context("length", ops.len().to_string()));
         }
 
+        if ops
+            .iter()
+            .all(|(_, op)| matches!(op, BatchOperation::Stat(_)))
+        {
+            let ops: Vec<(String, OpStat)> = ops
+                .into_iter()
+                .filter_map(|(p, op)| match op {
+                    BatchOperation::Stat(op) => Some((p, op)),
+                    _ => None,
+                })
+                .collect();
+            let resp = self.core.gcs_stat_objects(&ops).await?;
+            let parts = parse_batch_response(resp)?;
+
+            let mut batched_result = Vec::with_capacity(parts.len());
+            for ((path, _), resp) in ops.into_iter().zip(parts) {
+                if resp.status().is_success() {
+                    let meta: GetObjectJsonResponse =
+                        serde_json::from_reader(resp.into_body().reader())
+                            .map_err(new_json_deserialize_error)?;
+                    let result = parse_object_metadata(meta).map(|m| RpStat::new(m).into());
+                    batched_result.push((path, result));
+                } else {
+                    batched_result.push((path, Err(parse_error(resp))));
+                }
+            }
+
+            return Ok(RpBatch::new(batched_result));
+        }
+
+        if !ops
+            .iter()
+            .all(|(_, op)| matches!(op, BatchOperation::Delete(_)))
+        {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "gcs services don't support mixed operations in one batch",
+            ));
+        }
+
         let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
         let resp = self.core.gcs_delete_objects(paths.clone()).await?;
+        let parts = parse_batch_response(resp)?;
 
-        let status = resp.status();
+        let mut batched_result = Vec::with_capacity(parts.len());
 
-        if let StatusCode::OK = status {
-            let content_type = parse_content_type(resp.headers())?.ok_or_else(|| {
-                Error::new(
-                    ErrorKind::Unexpected,
-                    "gcs batch delete response content type is empty",
-                )
-            })?;
-            let boundary = content_type
-                .strip_prefix("multipart/mixed; boundary=")
-                .ok_or_else(|| {
-                    Error::new(
-                        ErrorKind::Unexpected,
-                        "gcs batch delete response content type is not multipart/mixed",
-                    )
-                })?
-                .trim_matches('"');
-            let multipart: Multipart<MixedPart> = Multipart::new()
-                .with_boundary(boundary)
-                .parse(resp.into_body().to_bytes())?;
-            let parts = multipart.into_parts();
+        for (i, resp) in parts.into_iter().enumerate() {
+            // TODO: maybe we can take it directly?
+            let path = paths[i].clone();
 
-            let mut batched_result = Vec::with_capacity(parts.len());
+            // deleting not existing objects is ok
+            if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
+                batched_result.push((path, Ok(RpDelete::default().into())));
+            } else {
+                batched_result.push((path, Err(parse_error(resp))));
+            }
+        }
 
-            for (i, part) in parts.into_iter().enumerate() {
-                let resp = part.into_response();
-                // TODO: maybe we can take it directly?
-                let path = paths[i].clone();
+        Ok(RpBatch::new(batched_result))
+    }
+}
 
-                // deleting not existing objects is ok
-                if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
-                    batched_result.push((path, Ok(RpDelete::default().into())));
-                } else {
-                    batched_result.push((path, Err(parse_error(resp))));
-                }
-            }
+/// Split the response of gcs batch request into responses of every sub-request.
+fn parse_batch_response(resp: Response<Buffer>) -> Result<Vec<Response<Buffer>>> {
+    // If the overall request isn't formatted correctly and Cloud Storage is unable to parse it into sub-requests, you receive a 400 error.
+    // Otherwise, Cloud Storage returns a 200 status code, even if some or all of the sub-requests fail.
+    if resp.status() != StatusCode::OK {
+        return Err(parse_error(resp));
+    }
 
-            Ok(RpBatch::new(batched_result))
-        } else {
-            // If the overall request isn't formatted correctly and Cloud Storage is unable to parse it into sub-requests, you receive a 400 error.
-            // Otherwise, Cloud Storage returns a 200 status code, even if some or all of the sub-requests fail.
-            Err(parse_error(resp))
+    let content_type = parse_content_type(resp.headers())?.ok_or_else(|| {
+        Error::new(
+            ErrorKind::Unexpected,
+            "gcs batch response content type is empty",
+        )
+    })?;
+    let boundary = content_type
+        .strip_prefix("multipart/mixed; boundary=")
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "gcs batch response content type is not multipart/mixed",
+            )
+        })?
+        .trim_matches('"');
+    let multipart: Multipart<MixedPart> = Multipart::new()
+        .with_boundary(boundary)
+        .parse(resp.into_body().to_bytes())?;
+
+    Ok(multipart
+        .into_parts()
+        .into_iter()
+        .map(|part| part.into_response())
+        .collect())
+}
+
+/// Build metadata from the json response of object get.
+fn parse_object_metadata(meta: GetObjectJsonResponse) -> Result<Metadata> {
+    let mut m = Metadata::new(EntryMode::FILE);
+
+    m.set_etag(&meta.etag);
+    m.set_content_md5(&meta.md5_hash);
+
+    let size = meta
+        .size
+        .parse::<u64>()
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "parse u64").set_source(e))?;
+    m.set_content_length(size);
+    if !meta.content_type.is_empty() {
+        m.set_content_type(&meta.content_type);
+    }
+    if !meta.content_encoding.is_empty() {
+        m.set_content_encoding(&meta.content_encoding);
+    }
+
+    m.set_last_modified(parse_datetime_from_rfc3339(&meta.updated)?);
+
+    if !meta.storage_class.is_empty() {
+        m.set_storage_class(&meta.storage_class);
+    }
+
+    if let Some(retention) = meta.retention {
+        match retention.mode.as_str() {
+            "Unlocked" => m.set_retention_mode(RetentionMode::Governance),
+            "Locked" => m.set_retention_mode(RetentionMode::Compliance),
+            _ => &mut m,
+        };
+        if !retention.retain_until_time.is_empty() {
+            m.set_retain_until(parse_datetime_from_rfc3339(&retention.retain_until_time)?);
         }
     }
+
+    if meta.temporary_hold.is_some() || meta.event_based_hold.is_some() {
+        m.set_legal_hold(
+            meta.temporary_hold.unwrap_or_default() || meta.event_based_hold.unwrap_or_default(),
+        );
+    }
+
+    Ok(m)
 }
 
 /// The raw json response returned by [`get`](https://cloud.google.com/storage/docs/json_api/v1/objects/get)
@@ -640,6 +807,29 @@ struct GetObjectJsonResponse {
     ///
     /// For example: `"contentType": "image/png",`
     content_type: String,
+    /// Content encoding of this object.
+    ///
+    /// For example: `"contentEncoding": "gzip",`
+    content_encoding: String,
+    /// Storage class of this object.
+    ///
+    /// For example: `"storageClass": "STANDARD",`
+    storage_class: String,
+    /// Retention of this object, only present when the object has a retention set.
+    ///
+    /// For example: `"retention": {"mode": "Unlocked", "retainUntilTime": "2025-01-01T00:00:00Z"}`
+    retention: Option<GetObjectJsonRetention>,
+    /// Whether this object is under temporary hold.
+    temporary_hold: Option<bool>,
+    /// Whether this object is under event-based hold.
+    event_based_hold: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct GetObjectJsonRetention {
+    mode: String,
+    retain_until_time: String,
 }
 
 #[cfg(test)]
@@ -665,7 +855,12 @@ mod tests {
   "etag": "CKWasoTgyPkCEAE=",
   "timeCreated": "2022-08-15T11:33:34.866Z",
   "updated": "2022-08-15T11:33:34.866Z",
-  "timeStorageClassUpdated": "2022-08-15T11:33:34.866Z"
+  "timeStorageClassUpdated": "2022-08-15T11:33:34.866Z",
+  "temporaryHold": true,
+  "retention": {
+    "mode": "Locked",
+    "retainUntilTime": "2030-01-01T00:00:00Z"
+  }
 }"#;
 
         let meta: GetObjectJsonResponse =
@@ -676,5 +871,11 @@ mod tests {
         assert_eq!(meta.md5_hash, "fHcEH1vPwA6eTPqxuasXcg==");
         assert_eq!(meta.etag, "CKWasoTgyPkCEAE=");
         assert_eq!(meta.content_type, "image/png");
+        assert_eq!(meta.storage_class, "STANDARD");
+        assert_eq!(meta.temporary_hold, Some(true));
+        assert_eq!(meta.event_based_hold, None);
+        let retention = meta.retention.expect("retention must be present");
+        assert_eq!(retention.mode, "Locked");
+        assert_eq!(retention.retain_until_time, "2030-01-01T00:00:00Z");
     }
 }
@@ -24,6 +24,11 @@ use std::time::Duration;
 use backon::ExponentialBuilder;
 use backon::Retryable;
 use bytes::Bytes;
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use http::header::ACCEPT_ENCODING;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::HOST;
@@ -37,6 +42,11 @@ use reqsign::GoogleCredentialLoader;
 use reqsign::GoogleSigner;
 use reqsign::GoogleToken;
 use reqsign::GoogleTokenLoader;
+use rsa::pkcs1v15::SigningKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::sha2::Sha256;
+use rsa::signature::SignatureEncoding;
+use rsa::signature::Signer;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::json;
@@ -56,6 +66,11 @@ pub struct GcsCore {
     pub token: Option<String>,
     pub scope: String,
     pub credential_loader: GoogleCredentialLoader,
+    /// Service account used to sign POST policies.
+    ///
+    /// reqsign doesn't expose the private key of loaded credential, so we
+    /// keep our own copy here.
+    pub service_account: Option<GcsServiceAccount>,
 
     pub predefined_acl: Option<String>,
     pub default_storage_class: Option<String>,
@@ -183,7 +198,9 @@ impl GcsCore {
             percent_encode_path(&p)
         );
 
-        let mut req = Request::get(&url);
+        // Accept gzip to disable decompressive transcoding, so that we will
+        // always get the stored content of objects with content encoding.
+        let mut req = Request::get(&url).header(ACCEPT_ENCODING, "gzip");
 
         if let Some(if_match) = args.if_match() {
             req = req.header(IF_MATCH, if_match);
@@ -242,11 +259,22 @@ impl GcsCore {
         let p = build_abs_path(&self.root, path);
 
         let mut metadata = HashMap::new();
-        if let Some(storage_class) = &self.default_storage_class {
-            metadata.insert("storageClass", storage_class.as_str());
+        if let Some(storage_class) = op.storage_class() {
+            metadata.insert("storageClass", json!(storage_class));
+        } else if let Some(storage_class) = &self.default_storage_class {
+            metadata.insert("storageClass", json!(storage_class));
         }
         if let Some(cache_control) = op.cache_control() {
-            metadata.insert("cacheControl", cache_control);
+            metadata.insert("cacheControl", json!(cache_control));
+        }
+        if let Some(content_encoding) = op.content_encoding() {
+            metadata.insert("contentEncoding", json!(content_encoding));
+        }
+        if let Some((mode, retain_until)) = op.retention() {
+            metadata.insert("retention", format_object_retention(mode, retain_until));
+        }
+        if let Some(hold) = op.legal_hold() {
+            metadata.insert("temporaryHold", json!(hold));
         }
 
         let mut url = format!(
@@ -322,11 +350,17 @@ impl GcsCore {
             req = req.header(CONTENT_TYPE, content_type);
         }
 
+        if let Some(content_encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, content_encoding);
+        }
+
         if let Some(acl) = &self.predefined_acl {
             req = req.header("x-goog-acl", acl);
         }
 
-        if let Some(storage_class) = &self.default_storage_class {
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header("x-goog-storage-class", storage_class);
+        } else if let Some(storage_class) = &self.default_storage_class {
             req = req.header("x-goog-storage-class", storage_class);
         }
 
@@ -385,6 +419,39 @@ impl GcsCore {
         Ok(req)
     }
 
+    /// Update the given metadata fields of the object.
+    ///
+    /// refer to https://cloud.google.com/storage/docs/json_api/v1/objects/patch
+    pub async fn gcs_patch_object(
+        &self,
+        path: &str,
+        metadata: serde_json::Value,
+        override_unlocked_retention: bool,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/storage/v1/b/{}/o/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+        if override_unlocked_retention {
+            url.push_str("?overrideUnlockedRetention=true");
+        }
+
+        let content = metadata.to_string();
+
+        let mut req = Request::patch(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/json")
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn gcs_get_object_metadata(
         &self,
         path: &str,
@@ -404,6 +471,22 @@ impl GcsCore {
         self.send(req).await
     }
 
+    // It's for presign operation. Gcs only supports query sign over XML API.
+    pub fn gcs_delete_object_xml_request(&self, path: &str) -> Result<Request<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        Request::delete(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)
+    }
+
     pub fn gcs_delete_object_request(&self, path: &str) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
@@ -439,6 +522,26 @@ impl GcsCore {
         self.send(req).await
     }
 
+    pub async fn gcs_stat_objects(&self, ops: &[(String, OpStat)]) -> Result<Response<Buffer>> {
+        let uri = format!("{}/batch/storage/v1", self.endpoint);
+
+        let mut multipart = Multipart::new();
+
+        for (idx, (path, args)) in ops.iter().enumerate() {
+            let req = self.gcs_head_object_request(path, args)?;
+
+            multipart = multipart.part(
+                MixedPart::from_request(req).part_header("content-id".parse().unwrap(), idx.into()),
+            );
+        }
+
+        let req = Request::post(uri);
+        let mut req = multipart.apply(req)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn gcs_copy_object(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
         let source = build_abs_path(&self.root, from);
         let dest = build_abs_path(&self.root, to);
@@ -509,28 +612,85 @@ impl GcsCore {
         self.send(req).await
     }
 
-    pub async fn gcs_initiate_multipart_upload(&self, path: &str) -> Result<Response<Buffer>> {
+    // It's for presign operation. Gcs only supports query sign over XML API.
+    pub fn gcs_list_objects_xml_request(
+        &self,
+        path: &str,
+        delimiter: &str,
+        limit: Option<usize>,
+        start_after: Option<String>,
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let url = format!("{}/{}/{}?uploads", self.endpoint, self.bucket, p);
+        let mut url = format!(
+            "{}/{}?list-type=2&prefix={}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+        if !delimiter.is_empty() {
+            write!(url, "&delimiter={delimiter}").expect("write into string must succeed");
+        }
+        if let Some(limit) = limit {
+            write!(url, "&max-keys={limit}").expect("write into string must succeed");
+        }
+        if let Some(start_after) = start_after {
+            let start_after = build_abs_path(&self.root, &start_after);
+            write!(url, "&start-after={}", percent_encode_path(&start_after))
+                .expect("write into string must succeed");
+        }
 
-        let mut req = Request::post(&url)
-            .header(CONTENT_LENGTH, 0)
+        Request::get(&url)
             .body(Buffer::new())
-            .map_err(new_request_build_error)?;
+            .map_err(new_request_build_error)
+    }
+
+    /// Build the initiate multipart upload request.
+    ///
+    /// The path must be percent encoded by caller.
+    pub fn gcs_initiate_multipart_upload_request(
+        &self,
+        path: &str,
+        args: &OpWrite,
+    ) -> Result<Request<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}/{}?uploads", self.endpoint, self.bucket, p);
+
+        let mut req = Request::post(&url).header(CONTENT_LENGTH, 0);
+
+        if let Some(content_encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, content_encoding);
+        }
+
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header("x-goog-storage-class", storage_class);
+        } else if let Some(storage_class) = &self.default_storage_class {
+            req = req.header("x-goog-storage-class", storage_class);
+        }
+
+        req.body(Buffer::new()).map_err(new_request_build_error)
+    }
+
+    pub async fn gcs_initiate_multipart_upload(
+        &self,
+        path: &str,
+        args: &OpWrite,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.gcs_initiate_multipart_upload_request(path, args)?;
 
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
-    pub async fn gcs_upload_part(
+    pub fn gcs_upload_part_request(
         &self,
         path: &str,
         upload_id: &str,
         part_number: usize,
-        size: u64,
+        size: Option<u64>,
         body: Buffer,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -544,20 +704,36 @@ impl GcsCore {
 
         let mut req = Request::put(&url);
 
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+
+        req.body(body).map_err(new_request_build_error)
+    }
 
-        let mut req = req.body(body).map_err(new_request_build_error)?;
+    pub async fn gcs_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let mut req =
+            self.gcs_upload_part_request(path, upload_id, part_number, Some(size), body)?;
 
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
-    pub async fn gcs_complete_multipart_upload(
+    /// Build the complete multipart upload request without body.
+    ///
+    /// Presigned requests will leave the body to users.
+    pub fn gcs_complete_multipart_upload_request(
         &self,
         path: &str,
         upload_id: &str,
-        parts: Vec<CompleteMultipartUploadRequestPart>,
-    ) -> Result<Response<Buffer>> {
+    ) -> http::request::Builder {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -568,7 +744,16 @@ impl GcsCore {
             percent_encode_path(upload_id)
         );
 
-        let req = Request::post(&url);
+        Request::post(&url)
+    }
+
+    pub async fn gcs_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<CompleteMultipartUploadRequestPart>,
+    ) -> Result<Response<Buffer>> {
+        let req = self.gcs_complete_multipart_upload_request(path, upload_id);
 
         let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest { part: parts })
             .map_err(new_xml_deserialize_error)?;
@@ -585,6 +770,32 @@ impl GcsCore {
         self.send(req).await
     }
 
+    /// Generate a POST policy signed with GOOG4-RSA-SHA256.
+    ///
+    /// Reference: <https://cloud.google.com/storage/docs/xml-api/post-object-forms>
+    pub fn gcs_presign_post(&self, path: &str, args: &OpPresignPost) -> Result<PresignedPost> {
+        let Some(sa) = &self.service_account else {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "presign post requires a service account credential",
+            ));
+        };
+
+        let p = build_abs_path(&self.root, path);
+        let now = Utc::now();
+
+        let policy = PostPolicy::new(now, &p, args).with_condition("bucket", &self.bucket);
+        let fields = sign_post_policy(policy, now, sa)?;
+
+        let uri = format!("{}/{}", self.endpoint, self.bucket)
+            .parse()
+            .map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "endpoint is not a valid uri").set_source(err)
+            })?;
+
+        Ok(PresignedPost::new(uri, fields))
+    }
+
     pub async fn gcs_abort_multipart_upload(
         &self,
         path: &str,
@@ -606,6 +817,45 @@ impl GcsCore {
         self.sign(&mut req).await?;
         self.send(req).await
     }
+
+    pub async fn gcs_list_parts(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number_marker: Option<usize>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/{}/{}?uploadId={}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p),
+            percent_encode_path(upload_id)
+        );
+        if let Some(marker) = part_number_marker {
+            write!(url, "&part-number-marker={marker}").expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+}
+
+pub fn format_object_retention(
+    mode: RetentionMode,
+    retain_until: DateTime<Utc>,
+) -> serde_json::Value {
+    json!({
+        "mode": match mode {
+            RetentionMode::Governance => "Unlocked",
+            RetentionMode::Compliance => "Locked",
+        },
+        "retainUntilTime": retain_until.to_rfc3339_opts(SecondsFormat::Millis, true),
+    })
 }
 
 /// Response JSON from GCS list objects API.
@@ -635,6 +885,7 @@ pub struct ListResponseItem {
     pub md5_hash: String,
     pub updated: String,
     pub content_type: String,
+    pub storage_class: String,
 }
 
 /// Result of CreateMultipartUpload
@@ -660,10 +911,176 @@ pub struct CompleteMultipartUploadRequestPart {
     pub etag: String,
 }
 
+/// Result of ListParts
+///
+/// refer to https://cloud.google.com/storage/docs/xml-api/get-object-multipart for details
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListPartsResult {
+    pub is_truncated: bool,
+    pub next_part_number_marker: Option<usize>,
+    pub part: Vec<ListPartsResultPart>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListPartsResultPart {
+    pub part_number: usize,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub size: u64,
+}
+
+/// Service account in google credential file.
+#[derive(Clone, Deserialize)]
+pub struct GcsServiceAccount {
+    pub client_email: String,
+    pub private_key: String,
+}
+
+impl GcsServiceAccount {
+    /// Parse service account from the content of google credential file.
+    ///
+    /// Returns `None` if the credential is not a service account.
+    pub fn from_slice(bs: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bs).ok()
+    }
+}
+
+/// Add signing fields into the POST policy and sign it, return all form fields.
+pub fn sign_post_policy(
+    policy: PostPolicy,
+    now: DateTime<Utc>,
+    sa: &GcsServiceAccount,
+) -> Result<Vec<(String, String)>> {
+    let credential = format!(
+        "{}/{}/auto/storage/goog4_request",
+        sa.client_email,
+        now.format("%Y%m%d")
+    );
+
+    let policy = policy
+        .with_field("x-goog-algorithm", "GOOG4-RSA-SHA256")
+        .with_field("x-goog-credential", &credential)
+        .with_field("x-goog-date", &now.format("%Y%m%dT%H%M%SZ").to_string());
+
+    let private_key = rsa::RsaPrivateKey::from_pkcs8_pem(&sa.private_key).map_err(|err| {
+        Error::new(
+            ErrorKind::ConfigInvalid,
+            "private key of service account is invalid",
+        )
+        .set_source(err)
+    })?;
+    let encoded = policy.encode();
+    let signature = SigningKey::<Sha256>::new(private_key)
+        .sign(encoded.as_bytes())
+        .to_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<String>();
+
+    let mut fields = policy.into_fields();
+    fields.push(("policy".to_string(), encoded));
+    fields.push(("x-goog-signature".to_string(), signature));
+    Ok(fields)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use rsa::pkcs1v15::Signature;
+    use rsa::pkcs1v15::VerifyingKey;
+    use rsa::pkcs8::EncodePrivateKey;
+    use rsa::pkcs8::LineEnding;
+    use rsa::signature::Verifier;
+
     use super::*;
 
+    #[test]
+    fn test_sign_post_policy() {
+        let now = DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let private_key = rsa::RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+        let sa = GcsServiceAccount {
+            client_email: "test@example.iam.gserviceaccount.com".to_string(),
+            private_key: private_key
+                .to_pkcs8_pem(LineEnding::LF)
+                .unwrap()
+                .to_string(),
+        };
+        let args = OpPresignPost::new(Duration::from_secs(3600));
+        let policy = PostPolicy::new(now, "a.png", &args).with_condition("bucket", "test");
+
+        let fields = sign_post_policy(policy, now, &sa).unwrap();
+        let names = fields.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "key",
+                "x-goog-algorithm",
+                "x-goog-credential",
+                "x-goog-date",
+                "policy",
+                "x-goog-signature"
+            ]
+        );
+        assert_eq!(
+            fields[2].1,
+            "test@example.iam.gserviceaccount.com/20240102/auto/storage/goog4_request"
+        );
+
+        let signature = (0..fields[5].1.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&fields[5].1[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+        VerifyingKey::<Sha256>::new(private_key.to_public_key())
+            .verify(
+                fields[4].1.as_bytes(),
+                &Signature::try_from(signature.as_slice()).unwrap(),
+            )
+            .expect("signature must be valid");
+    }
+
+    #[test]
+    fn test_deserialize_list_parts_result() {
+        let bs = bytes::Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListPartsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Bucket>travel-maps</Bucket>
+  <Key>paris.jpg</Key>
+  <UploadId>VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA</UploadId>
+  <PartNumberMarker>0</PartNumberMarker>
+  <NextPartNumberMarker>2</NextPartNumberMarker>
+  <MaxParts>2</MaxParts>
+  <IsTruncated>true</IsTruncated>
+  <Part>
+    <PartNumber>1</PartNumber>
+    <LastModified>2021-11-10T20:48:34.000Z</LastModified>
+    <ETag>"7778aef83f66abc1fa1e8477f296d394"</ETag>
+    <Size>10485760</Size>
+  </Part>
+  <Part>
+    <PartNumber>2</PartNumber>
+    <LastModified>2021-11-10T20:48:33.000Z</LastModified>
+    <ETag>"aaaa18db4cc2f85cedef654fccc4a4x8"</ETag>
+    <Size>10485760</Size>
+  </Part>
+</ListPartsResult>"#,
+        );
+
+        let out: ListPartsResult =
+            quick_xml::de::from_reader(bytes::Buf::reader(bs)).expect("must success");
+
+        assert!(out.is_truncated);
+        assert_eq!(out.next_part_number_marker, Some(2));
+        assert_eq!(out.part.len(), 2);
+        assert_eq!(out.part[0].part_number, 1);
+        assert_eq!(out.part[1].etag, "\"aaaa18db4cc2f85cedef654fccc4a4x8\"");
+        assert_eq!(out.part[1].size, 10485760);
+    }
+
     #[test]
     fn test_deserialize_list_response() {
         let content = r#"
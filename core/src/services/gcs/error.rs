@@ -52,7 +52,7 @@ pub fn parse_error(resp: Response<Buffer>) -> Error {
     let (parts, body) = resp.into_parts();
     let bs = body.to_bytes();
 
-    let (kind, retryable) = match parts.status {
+    let (mut kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::NotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::PermissionDenied, false),
         StatusCode::PRECONDITION_FAILED | StatusCode::NOT_MODIFIED => {
@@ -66,7 +66,12 @@ pub fn parse_error(resp: Response<Buffer>) -> Error {
     };
 
     let message = match de::from_slice::<GcsErrorResponse>(&bs) {
-        Ok(gcs_err) => format!("{gcs_err:?}"),
+        Ok(gcs_err) => {
+            if parts.status == StatusCode::FORBIDDEN && is_object_locked(&gcs_err.error) {
+                kind = ErrorKind::ObjectLocked;
+            }
+            format!("{gcs_err:?}")
+        }
         Err(_) => String::from_utf8_lossy(&bs).into_owned(),
     };
 
@@ -81,6 +86,33 @@ pub fn parse_error(resp: Response<Buffer>) -> Error {
     err
 }
 
+/// Messages returned by GCS when an object is protected by retention or holds.
+///
+/// - `Object 'x' is subject to object retention and cannot be deleted, overwritten or archived until ...`
+/// - `Object 'x' is subject to bucket's retention policy or object hold and cannot be deleted or overwritten until ...`
+/// - `Object 'x' is under active Temporary hold and cannot be deleted, overwritten or archived until hold is removed.`
+/// - `Object 'x' is under active Event-Based hold and cannot be deleted, overwritten or archived until hold is removed.`
+const OBJECT_LOCKED_MESSAGES: [&str; 4] = [
+    "is subject to object retention",
+    "is subject to bucket's retention policy",
+    "is under active temporary hold",
+    "is under active event-based hold",
+];
+
+/// Check if this error is caused by object retention or holds.
+fn is_object_locked(err: &GcsError) -> bool {
+    let is_locked_message = |msg: &str| {
+        let msg = msg.to_lowercase();
+        OBJECT_LOCKED_MESSAGES.iter().any(|v| msg.contains(v))
+    };
+
+    is_locked_message(&err.message)
+        || err
+            .errors
+            .iter()
+            .any(|v| v.reason == "retentionPolicyNotMet" || is_locked_message(&v.message))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,4 +150,60 @@ mod tests {
         assert_eq!(out.error.errors[0].location_type, "header");
         assert_eq!(out.error.errors[0].location, "Authorization");
     }
+
+    #[test]
+    fn test_parse_error_object_locked() {
+        let bs = bytes::Bytes::from(
+            r#"
+{
+"error": {
+ "errors": [
+  {
+   "domain": "global",
+   "reason": "forbidden",
+   "message": "Object 'bucket/audit.log' is subject to object retention and cannot be deleted, overwritten or archived until 2030-01-01T00:00:00Z"
+  }
+ ],
+ "code": 403,
+ "message": "Object 'bucket/audit.log' is subject to object retention and cannot be deleted, overwritten or archived until 2030-01-01T00:00:00Z"
+}
+}
+"#,
+        );
+
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Buffer::from(bs))
+            .expect("must success");
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::ObjectLocked);
+    }
+
+    #[test]
+    fn test_parse_error_permission_denied() {
+        let bs = bytes::Bytes::from(
+            r#"
+{
+"error": {
+ "errors": [
+  {
+   "domain": "global",
+   "reason": "forbidden",
+   "message": "user@example.com does not have storage.objects.setRetention access to the Google Cloud Storage object. Permission 'storage.objects.setRetention' denied on resource (or it may not exist)."
+  }
+ ],
+ "code": 403,
+ "message": "user@example.com does not have storage.objects.setRetention access to the Google Cloud Storage object. Permission 'storage.objects.setRetention' denied on resource (or it may not exist)."
+}
+}
+"#,
+        );
+
+        let resp = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Buffer::from(bs))
+            .expect("must success");
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    }
 }
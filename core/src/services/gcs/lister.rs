@@ -125,6 +125,9 @@ impl oio::PageList for GcsLister {
             }
 
             meta.set_last_modified(parse_datetime_from_rfc3339(object.updated.as_str())?);
+            if !object.storage_class.is_empty() {
+                meta.set_storage_class(&object.storage_class);
+            }
 
             let de = oio::Entry::with(path, meta);
 
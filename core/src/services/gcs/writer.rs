@@ -20,9 +20,11 @@ use std::sync::Arc;
 use bytes::Buf;
 use http::StatusCode;
 
+use super::core::format_object_retention;
 use super::core::CompleteMultipartUploadRequestPart;
 use super::core::GcsCore;
 use super::core::InitiateMultipartUploadResult;
+use super::core::ListPartsResult;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
@@ -70,7 +72,7 @@ impl oio::MultipartWrite for GcsWriter {
     async fn initiate_part(&self) -> Result<String> {
         let resp = self
             .core
-            .gcs_initiate_multipart_upload(&percent_encode_path(&self.path))
+            .gcs_initiate_multipart_upload(&percent_encode_path(&self.path), &self.op)
             .await?;
 
         if !resp.status().is_success() {
@@ -113,6 +115,7 @@ impl oio::MultipartWrite for GcsWriter {
 
         Ok(oio::MultipartPart {
             part_number,
+            size,
             etag,
             checksum: None,
         })
@@ -135,6 +138,29 @@ impl oio::MultipartWrite for GcsWriter {
         if !resp.status().is_success() {
             return Err(parse_error(resp));
         }
+
+        // XML multipart upload can't carry retention and holds, set them after
+        // the object has been created.
+        let mut metadata = serde_json::Map::new();
+        if let Some((mode, retain_until)) = self.op.retention() {
+            metadata.insert(
+                "retention".to_string(),
+                format_object_retention(mode, retain_until),
+            );
+        }
+        if let Some(hold) = self.op.legal_hold() {
+            metadata.insert("temporaryHold".to_string(), hold.into());
+        }
+        if !metadata.is_empty() {
+            let resp = self
+                .core
+                .gcs_patch_object(&self.path, metadata.into(), false)
+                .await?;
+            if !resp.status().is_success() {
+                return Err(parse_error(resp));
+            }
+        }
+
         Ok(())
     }
 
@@ -149,4 +175,40 @@ impl oio::MultipartWrite for GcsWriter {
             _ => Err(parse_error(resp)),
         }
     }
+
+    async fn list_parts(&self, upload_id: &str) -> Result<Vec<oio::MultipartPart>> {
+        let mut parts = Vec::new();
+        let mut marker = None;
+        loop {
+            let resp = self
+                .core
+                .gcs_list_parts(&self.path, upload_id, marker)
+                .await?;
+            if !resp.status().is_success() {
+                return Err(parse_error(resp));
+            }
+
+            let result: ListPartsResult = quick_xml::de::from_reader(resp.into_body().reader())
+                .map_err(new_xml_deserialize_error)?;
+            parts.extend(result.part);
+
+            match result.next_part_number_marker {
+                Some(v) if result.is_truncated => marker = Some(v),
+                _ => break,
+            }
+        }
+
+        // Only keep the continuous parts from the first one, the rest will be uploaded again.
+        Ok(parts
+            .into_iter()
+            .enumerate()
+            .take_while(|(idx, p)| p.part_number == idx + 1)
+            .map(|(_, p)| oio::MultipartPart {
+                part_number: p.part_number,
+                size: p.size,
+                etag: p.etag,
+                checksum: None,
+            })
+            .collect())
+    }
 }
@@ -298,6 +298,10 @@ impl Access for HdfsBackend {
         let mut m = Metadata::new(mode);
         m.set_content_length(meta.len());
         m.set_last_modified(meta.modified().into());
+        m.set_last_accessed(meta.accessed().into());
+        m.set_unix_mode(meta.permissions() as u32);
+        m.set_owner(meta.owner());
+        m.set_group(meta.group());
 
         Ok(RpStat::new(m))
     }
@@ -499,6 +503,10 @@ impl Access for HdfsBackend {
         let mut m = Metadata::new(mode);
         m.set_content_length(meta.len());
         m.set_last_modified(meta.modified().into());
+        m.set_last_accessed(meta.accessed().into());
+        m.set_unix_mode(meta.permissions() as u32);
+        m.set_owner(meta.owner());
+        m.set_group(meta.group());
 
         Ok(RpStat::new(m))
     }
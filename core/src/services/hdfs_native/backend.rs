@@ -186,6 +186,13 @@ impl Access for HdfsNativeBackend {
                 rename: true,
                 blocking: true,
 
+                summary: true,
+
+                set_attr: true,
+                set_attr_with_mode: true,
+                set_attr_with_owner: true,
+                set_attr_with_times: true,
+
                 ..Default::default()
             });
 
@@ -258,11 +265,87 @@ impl Access for HdfsNativeBackend {
             .set_last_modified(parse_datetime_from_from_timestamp_millis(
                 status.modification_time as i64,
             )?)
-            .set_content_length(status.length as u64);
+            .set_last_accessed(parse_datetime_from_from_timestamp_millis(
+                status.access_time as i64,
+            )?)
+            .set_content_length(status.length as u64)
+            .set_unix_mode(status.permission as u32)
+            .set_owner(&status.owner)
+            .set_group(&status.group);
 
         Ok(RpStat::new(metadata))
     }
 
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        if args.uid().is_some() || args.gid().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "hdfs only supports set owner by name",
+            ));
+        }
+
+        let p = build_rooted_abs_path(&self.root, path);
+
+        if let Some(mode) = args.mode() {
+            self.client
+                .set_permission(&p, mode)
+                .await
+                .map_err(parse_hdfs_error)?;
+        }
+
+        if args.owner().is_some() || args.group().is_some() {
+            self.client
+                .set_owner(&p, args.owner(), args.group())
+                .await
+                .map_err(parse_hdfs_error)?;
+        }
+
+        if args.has_times() {
+            // hdfs requires both mtime and atime, keep the missing one as is.
+            let (mut mtime, mut atime) = (None, None);
+            if args.last_modified().is_none() || args.last_accessed().is_none() {
+                let status = self
+                    .client
+                    .get_file_info(&p)
+                    .await
+                    .map_err(parse_hdfs_error)?;
+                mtime = Some(status.modification_time);
+                atime = Some(status.access_time);
+            }
+            if let Some(v) = args.last_modified() {
+                mtime = Some(v.timestamp_millis() as u64);
+            }
+            if let Some(v) = args.last_accessed() {
+                atime = Some(v.timestamp_millis() as u64);
+            }
+
+            self.client
+                .set_times(&p, mtime.unwrap_or_default(), atime.unwrap_or_default())
+                .await
+                .map_err(parse_hdfs_error)?;
+        }
+
+        Ok(RpSetAttr::default())
+    }
+
+    async fn summary(&self, path: &str, _args: OpSummary) -> Result<RpSummary> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        let cs = self
+            .client
+            .get_content_summary(&p)
+            .await
+            .map_err(parse_hdfs_error)?;
+
+        Ok(RpSummary::new(
+            Summary::new()
+                .with_file_count(cs.file_count)
+                // The dir itself is counted by hdfs.
+                .with_dir_count(cs.directory_count.saturating_sub(1))
+                .with_total_bytes(cs.length),
+        ))
+    }
+
     async fn delete(&self, path: &str, _args: OpDelete) -> Result<RpDelete> {
         let p = build_rooted_abs_path(&self.root, path);
 
@@ -304,7 +304,7 @@ impl Access for HttpBackend {
             _ => {
                 return Err(Error::new(
                     ErrorKind::Unsupported,
-                    "Http doesn't support presigned write",
+                    "Http only supports presigned stat and read",
                 ))
             }
         };
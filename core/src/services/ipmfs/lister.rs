@@ -63,7 +63,7 @@ impl oio::PageList for IpmfsLister {
             let path = match object.mode() {
                 EntryMode::FILE => format!("{}{}", &self.path, object.name),
                 EntryMode::DIR => format!("{}{}/", &self.path, object.name),
-                EntryMode::Unknown => unreachable!(),
+                EntryMode::Symlink | EntryMode::Unknown => unreachable!(),
             };
 
             let path = build_rel_path(&self.root, &path);
@@ -24,6 +24,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::core::MonoiofsCore;
+use super::writer::MonoiofsWriter;
 use crate::raw::*;
 use crate::*;
 
@@ -111,7 +112,7 @@ pub struct MonoiofsBackend {
 
 impl Access for MonoiofsBackend {
     type Reader = ();
-    type Writer = ();
+    type Writer = MonoiofsWriter;
     type Lister = ();
     type BlockingReader = ();
     type BlockingWriter = ();
@@ -122,8 +123,103 @@ impl Access for MonoiofsBackend {
         am.set_scheme(Scheme::Monoiofs)
             .set_root(&self.core.root().to_string_lossy())
             .set_native_capability(Capability {
+                stat: true,
+
+                write: true,
+                write_can_empty: true,
+                write_can_multi: true,
+                write_with_content_encoding: true,
+                write_with_user_metadata: true,
+
                 ..Default::default()
             });
         am.into()
     }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        let path = self.core.prepare_path(path);
+
+        let (meta, fs_meta) = self
+            .core
+            .dispatch_blocking(move || -> Result<_> {
+                let meta = std::fs::metadata(&path).map_err(new_std_io_error)?;
+                Ok((meta, FsMeta::load(&path, true)?))
+            })
+            .await??;
+        let mode = if meta.is_dir() {
+            EntryMode::DIR
+        } else if meta.is_file() {
+            EntryMode::FILE
+        } else {
+            EntryMode::Unknown
+        };
+        let last_mod = meta.modified().map_err(new_std_io_error)?.into();
+        let mut m = Metadata::new(mode)
+            .with_content_length(meta.len())
+            .with_last_modified(last_mod);
+        fs_meta.apply(&mut m);
+
+        Ok(RpStat::new(m))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let path = self.core.prepare_path(path);
+        let meta = FsMeta::from_op_write(&args);
+
+        let p = path.clone();
+        let file = self
+            .core
+            .dispatch_blocking(move || -> Result<_> {
+                if let Some(parent) = p.parent() {
+                    std::fs::create_dir_all(parent).map_err(new_std_io_error)?;
+                }
+                std::fs::OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .truncate(true)
+                    .open(&p)
+                    .map_err(new_std_io_error)
+            })
+            .await??;
+
+        let w = MonoiofsWriter::new(self.core.clone(), file, path, meta);
+        Ok((RpWrite::new(), w))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_with_user_metadata() -> Result<()> {
+        let root = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        let op = Operator::new(MonoiofsBuilder::default().root(&root.to_string_lossy()))?.finish();
+
+        let user_metadata = HashMap::from([("location".to_string(), "everywhere".to_string())]);
+        op.write_with("dir/file", "Hello, World!")
+            .content_encoding("identity")
+            .user_metadata(user_metadata.clone())
+            .await?;
+
+        let meta = op.stat("dir/file").await?;
+        assert_eq!(meta.content_length(), 13);
+        assert_eq!(meta.content_encoding(), Some("identity"));
+        assert_eq!(meta.user_metadata(), Some(&user_metadata));
+
+        // Metadata is replaced only after the writer is closed.
+        let mut w = op.writer_with("dir/file").content_encoding("gzip").await?;
+        w.write("Hello").await?;
+        let meta = op.stat("dir/file").await?;
+        assert_eq!(meta.content_encoding(), Some("identity"));
+        w.close().await?;
+        let meta = op.stat("dir/file").await?;
+        assert_eq!(meta.content_encoding(), Some("gzip"));
+        assert_eq!(meta.user_metadata(), None);
+
+        std::fs::remove_dir_all(root).unwrap();
+        Ok(())
+    }
 }
@@ -24,9 +24,12 @@ use flume::Receiver;
 use flume::Sender;
 use futures::channel::oneshot;
 use futures::Future;
+use monoio::blocking::DefaultThreadPool;
 use monoio::FusionDriver;
 use monoio::RuntimeBuilder;
 
+use crate::*;
+
 /// a boxed function that spawns task in current monoio runtime
 type TaskSpawner = Box<dyn FnOnce() + Send>;
 
@@ -66,11 +69,17 @@ impl MonoiofsCore {
         &self.root
     }
 
+    pub fn prepare_path(&self, path: &str) -> PathBuf {
+        self.root.join(path.trim_end_matches('/'))
+    }
+
     /// entrypoint of each worker thread, sets up monoio runtimes and channels
     fn worker_entrypoint(rx: Receiver<TaskSpawner>, io_uring_entries: u32) {
         let mut rt = RuntimeBuilder::<FusionDriver>::new()
             .enable_all()
             .with_entries(io_uring_entries)
+            // blocking syscalls like xattr are executed in this pool
+            .attach_thread_pool(Box::new(DefaultThreadPool::new(1)))
             .build()
             .expect("monoio runtime initialize should success");
         // run a infinite loop that receives TaskSpawner and calls
@@ -113,6 +122,18 @@ impl MonoiofsCore {
         }
     }
 
+    /// dispatch a blocking function to the thread pool of monoio runtime
+    /// and wait for its result
+    pub async fn dispatch_blocking<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> T + 'static + Send,
+        T: 'static + Send,
+    {
+        self.dispatch(move || monoio::spawn_blocking(f))
+            .await
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "monoio blocking task cancelled"))
+    }
+
     /// This method always panics. It is called only when at least a
     /// worker thread has panicked or meet a broken rx, which is
     /// unrecoverable. It propagates worker thread's panic if there
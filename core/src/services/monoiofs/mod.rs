@@ -20,3 +20,4 @@ pub use backend::MonoiofsBuilder as Monoiofs;
 pub use backend::MonoiofsConfig;
 
 mod core;
+mod writer;
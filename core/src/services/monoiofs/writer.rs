@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use super::core::MonoiofsCore;
+use crate::raw::*;
+use crate::*;
+
+pub struct MonoiofsWriter {
+    core: Arc<MonoiofsCore>,
+    file: File,
+    pos: u64,
+    /// Path and FsMeta of the file, FsMeta will be stored after the file has
+    /// been written.
+    path: PathBuf,
+    meta: FsMeta,
+}
+
+impl MonoiofsWriter {
+    pub fn new(core: Arc<MonoiofsCore>, file: File, path: PathBuf, meta: FsMeta) -> Self {
+        Self {
+            core,
+            file,
+            pos: 0,
+            path,
+            meta,
+        }
+    }
+}
+
+impl oio::Write for MonoiofsWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let f = self.file.try_clone().map_err(new_std_io_error)?;
+        let bs = bs.to_bytes();
+        let (pos, size) = (self.pos, bs.len() as u64);
+
+        self.core
+            .dispatch(move || async move {
+                let f = monoio::fs::File::from_std(f)?;
+                let (res, _) = f.write_all_at(bs, pos).await;
+                f.close().await?;
+                res
+            })
+            .await
+            .map_err(new_std_io_error)?;
+        self.pos += size;
+
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        let f = self.file.try_clone().map_err(new_std_io_error)?;
+
+        self.core
+            .dispatch(move || async move {
+                let f = monoio::fs::File::from_std(f)?;
+                let res = f.sync_all().await;
+                f.close().await?;
+                res
+            })
+            .await
+            .map_err(new_std_io_error)?;
+
+        let (path, meta) = (self.path.clone(), self.meta.clone());
+        self.core
+            .dispatch_blocking(move || meta.store(&path))
+            .await?
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "Monoiofs doesn't support abort",
+        ))
+    }
+}
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use bytes::Buf;
 use http::Response;
 use http::StatusCode;
 use http::Uri;
@@ -29,6 +30,7 @@ use reqsign::HuaweicloudObsSigner;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::core::ListMultipartUploadsOutput;
 use super::core::ObsCore;
 use super::error::parse_error;
 use super::lister::ObsLister;
@@ -295,6 +297,7 @@ impl Access for ObsBackend {
                 write_can_multi: true,
                 write_with_content_type: true,
                 write_with_cache_control: true,
+                write_with_tags: true,
                 // The min multipart size of OBS is 5 MiB.
                 //
                 // ref: <https://support.huaweicloud.com/intl/en-us/ugobs-obs/obs_41_0021.html>
@@ -314,6 +317,12 @@ impl Access for ObsBackend {
                 list: true,
                 list_with_recursive: true,
 
+                list_multipart_uploads: true,
+                abort_multipart_upload: true,
+
+                get_tags: true,
+                set_tags: true,
+
                 presign: true,
                 presign_stat: true,
                 presign_read: true,
@@ -391,6 +400,28 @@ impl Access for ObsBackend {
         Ok((RpList::default(), oio::PageLister::new(l)))
     }
 
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.obs_get_object_tagging(path).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let tagging: Tagging = quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+                Ok(RpGetTags::new(tagging.into_tags()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self.core.obs_put_object_tagging(path, args.tags()).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(RpSetTags::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
         let resp = self.core.obs_copy_object(from, to).await?;
 
@@ -402,6 +433,71 @@ impl Access for ObsBackend {
         }
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        _: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let mut uploads = Vec::new();
+        let (mut key_marker, mut upload_id_marker) = (None, None);
+        loop {
+            let resp = self
+                .core
+                .obs_list_multipart_uploads(
+                    path,
+                    key_marker.as_deref(),
+                    upload_id_marker.as_deref(),
+                )
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let output: ListMultipartUploadsOutput =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+            for upload in output.upload {
+                let mut v = MultipartUpload::new(&build_rel_path(&self.core.root, &upload.key))
+                    .with_upload_id(&upload.upload_id);
+                if let Ok(initiated) = parse_datetime_from_rfc3339(&upload.initiated) {
+                    v = v.with_initiated(initiated);
+                }
+                uploads.push(v);
+            }
+
+            if !output.is_truncated {
+                break;
+            }
+            key_marker = output.next_key_marker;
+            upload_id_marker = output.next_upload_id_marker;
+        }
+
+        Ok(RpListMultipartUploads::new(uploads))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let Some(upload_id) = args.upload_id() else {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "upload id is required to abort multipart upload",
+            ));
+        };
+
+        let resp = self
+            .core
+            .obs_abort_multipart_upload(path, upload_id)
+            .await?;
+        match resp.status() {
+            // Upload that has been aborted or completed will return 404.
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(RpAbortMultipartUpload::new()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let mut req = match args.operation() {
             PresignOperation::Stat(v) => self.core.obs_head_object_request(path, v)?,
@@ -413,6 +509,12 @@ impl Access for ObsBackend {
                 self.core
                     .obs_put_object_request(path, None, v, Buffer::new())?
             }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "obs doesn't support this presign operation",
+                ))
+            }
         };
         self.core.sign_query(&mut req, args.expire()).await?;
 
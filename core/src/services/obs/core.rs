@@ -15,8 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::fmt::Write;
 use std::time::Duration;
 
 use bytes::Bytes;
@@ -162,6 +164,9 @@ impl ObsCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control)
         }
+        if let Some(tags) = args.tags() {
+            req = req.header("x-obs-tagging", format_tagging_query(tags));
+        }
 
         if let Some(mime) = args.content_type() {
             req = req.header(CONTENT_TYPE, mime)
@@ -248,6 +253,9 @@ impl ObsCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control)
         }
+        if let Some(tags) = args.tags() {
+            req = req.header("x-obs-tagging", format_tagging_query(tags));
+        }
 
         let req = req.body(body).map_err(new_request_build_error)?;
         Ok(req)
@@ -270,6 +278,47 @@ impl ObsCore {
         self.send(req).await
     }
 
+    pub async fn obs_get_object_tagging(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    pub async fn obs_put_object_tagging(
+        &self,
+        path: &str,
+        tags: &HashMap<String, String>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let content = quick_xml::se::to_string(&Tagging::from_tags(tags))
+            .map_err(new_xml_deserialize_error)?;
+
+        let req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            // Set content-md5 as required by API.
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        let mut req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn obs_list_objects(
         &self,
         path: &str,
@@ -311,6 +360,7 @@ impl ObsCore {
         &self,
         path: &str,
         content_type: Option<&str>,
+        tags: Option<&HashMap<String, String>>,
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
@@ -320,6 +370,9 @@ impl ObsCore {
         if let Some(mime) = content_type {
             req = req.header(CONTENT_TYPE, mime)
         }
+        if let Some(tags) = tags {
+            req = req.header("x-obs-tagging", format_tagging_query(tags));
+        }
         let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
 
         self.sign(&mut req).await?;
@@ -391,6 +444,36 @@ impl ObsCore {
         self.send(req).await
     }
 
+    /// List the incomplete multipart uploads under given prefix.
+    pub async fn obs_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: Option<&str>,
+        upload_id_marker: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/?uploads&prefix={}",
+            self.endpoint,
+            percent_encode_path(&p)
+        );
+        if let Some(marker) = key_marker {
+            write!(url, "&key-marker={}", percent_encode_path(marker))
+                .expect("write into string must succeed");
+        }
+        if let Some(marker) = upload_id_marker {
+            write!(url, "&upload-id-marker={}", percent_encode_path(marker))
+                .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     /// Abort an on-going multipart upload.
     pub async fn obs_abort_multipart_upload(
         &self,
@@ -490,6 +573,24 @@ pub struct ListObjectsOutputContent {
     pub size: u64,
 }
 
+/// Output of ListMultipartUploads.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutput {
+    pub is_truncated: bool,
+    pub next_key_marker: Option<String>,
+    pub next_upload_id_marker: Option<String>,
+    pub upload: Vec<ListMultipartUploadsOutputUpload>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutputUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: String,
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
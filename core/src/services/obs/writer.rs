@@ -65,7 +65,7 @@ impl oio::MultipartWrite for ObsWriter {
     async fn initiate_part(&self) -> Result<String> {
         let resp = self
             .core
-            .obs_initiate_multipart_upload(&self.path, self.op.content_type())
+            .obs_initiate_multipart_upload(&self.path, self.op.content_type(), self.op.tags())
             .await?;
 
         let status = resp.status();
@@ -114,6 +114,7 @@ impl oio::MultipartWrite for ObsWriter {
 
                 Ok(MultipartPart {
                     part_number,
+                    size,
                     etag,
                     checksum: None,
                 })
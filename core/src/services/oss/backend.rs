@@ -32,6 +32,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::core::*;
+use super::error::is_restore_in_progress;
 use super::error::parse_error;
 use super::lister::OssLister;
 use super::writer::OssWriter;
@@ -419,7 +420,10 @@ impl Access for OssBackend {
                 write_can_append: true,
                 write_can_multi: true,
                 write_with_cache_control: true,
+                write_with_tags: true,
+                write_with_storage_class: true,
                 write_with_content_type: true,
+                write_with_content_encoding: true,
                 write_with_content_disposition: true,
                 // The min multipart size of OSS is 100 KiB.
                 //
@@ -443,12 +447,25 @@ impl Access for OssBackend {
                 list_with_start_after: true,
                 list_with_recursive: true,
 
+                list_multipart_uploads: true,
+                abort_multipart_upload: true,
+
+                get_tags: true,
+                set_tags: true,
+
+                restore: true,
+
                 presign: true,
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_delete: true,
+                presign_list: true,
+                presign_multipart: true,
+                presign_post: true,
 
                 batch: true,
+                batch_delete: true,
                 batch_max_operations: Some(self.core.batch_max_operations),
 
                 ..Default::default()
@@ -532,6 +549,42 @@ impl Access for OssBackend {
         Ok((RpList::default(), oio::PageLister::new(l)))
     }
 
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.oss_get_object_tagging(path).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let tagging: Tagging = quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+                Ok(RpGetTags::new(tagging.into_tags()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self.core.oss_put_object_tagging(path, args.tags()).await?;
+
+        match resp.status() {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(RpSetTags::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let resp = self.core.oss_restore_object(path, &args).await?;
+
+        match resp.status() {
+            // 202 means the restore has been accepted, 200 means the object has
+            // been restored already.
+            StatusCode::ACCEPTED | StatusCode::OK => Ok(RpRestore::default()),
+            // 409 could also be returned for other conflicts, only a restore
+            // already in progress is fine.
+            StatusCode::CONFLICT if is_restore_in_progress(resp.body()) => Ok(RpRestore::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
         let resp = self.core.oss_copy_object(from, to).await?;
         let status = resp.status();
@@ -542,6 +595,71 @@ impl Access for OssBackend {
         }
     }
 
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        _: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let mut uploads = Vec::new();
+        let (mut key_marker, mut upload_id_marker) = (None, None);
+        loop {
+            let resp = self
+                .core
+                .oss_list_multipart_uploads(
+                    path,
+                    key_marker.as_deref(),
+                    upload_id_marker.as_deref(),
+                )
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let output: ListMultipartUploadsOutput =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+            for upload in output.upload {
+                let mut v = MultipartUpload::new(&build_rel_path(&self.core.root, &upload.key))
+                    .with_upload_id(&upload.upload_id);
+                if let Ok(initiated) = parse_datetime_from_rfc3339(&upload.initiated) {
+                    v = v.with_initiated(initiated);
+                }
+                uploads.push(v);
+            }
+
+            if !output.is_truncated {
+                break;
+            }
+            key_marker = output.next_key_marker;
+            upload_id_marker = output.next_upload_id_marker;
+        }
+
+        Ok(RpListMultipartUploads::new(uploads))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let Some(upload_id) = args.upload_id() else {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "upload id is required to abort multipart upload",
+            ));
+        };
+
+        let resp = self
+            .core
+            .oss_abort_multipart_upload(path, upload_id)
+            .await?;
+        match resp.status() {
+            // Upload that has been aborted or completed will return 404.
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(RpAbortMultipartUpload::new()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
@@ -551,6 +669,34 @@ impl Access for OssBackend {
                 self.core
                     .oss_put_object_request(path, None, v, Buffer::new(), true)?
             }
+            PresignOperation::Delete(v) => self.core.oss_delete_object_request(path, true, v)?,
+            PresignOperation::List(v) => {
+                let delimiter = if v.recursive() { "" } else { "/" };
+                self.core.oss_list_object_request(
+                    path,
+                    true,
+                    "",
+                    delimiter,
+                    v.limit(),
+                    v.start_after().map(String::from),
+                )?
+            }
+            PresignOperation::InitiateMultipartUpload(v) => {
+                self.core.oss_initiate_upload_request(path, v, true)?
+            }
+            PresignOperation::UploadPart(v) => self.core.oss_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number(),
+                true,
+                None,
+                Buffer::new(),
+            )?,
+            PresignOperation::CompleteMultipartUpload(v) => self
+                .core
+                .oss_complete_multipart_upload_request(path, v.upload_id(), true)
+                .body(Buffer::new())
+                .map_err(new_request_build_error)?,
         };
 
         self.core.sign_query(&mut req, args.expire()).await?;
@@ -565,6 +711,12 @@ impl Access for OssBackend {
         )))
     }
 
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.oss_presign_post(path, &args).await?;
+
+        Ok(RpPresignPost::new(post))
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let ops = args.into_operation();
         // Sadly, OSS will not return failed keys, so we will build
@@ -21,9 +21,15 @@ use std::fmt::Formatter;
 use std::fmt::Write;
 use std::time::Duration;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use bytes::Bytes;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
@@ -39,6 +45,7 @@ use reqsign::AliyunLoader;
 use reqsign::AliyunOssSigner;
 use serde::Deserialize;
 use serde::Serialize;
+use sha1::Sha1;
 
 use crate::raw::*;
 use crate::*;
@@ -53,6 +60,12 @@ pub mod constants {
     pub const OSS_QUERY_VERSION_ID: &str = "versionId";
 
     pub const X_OSS_META_PREFIX: &str = "x-oss-meta-";
+
+    pub const X_OSS_TAGGING: &str = "x-oss-tagging";
+
+    pub const X_OSS_STORAGE_CLASS: &str = "x-oss-storage-class";
+
+    pub const X_OSS_RESTORE: &str = "x-oss-restore";
 }
 
 pub struct OssCore {
@@ -177,6 +190,10 @@ impl OssCore {
             req = req.header(CONTENT_DISPOSITION, pos);
         }
 
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, encoding);
+        }
+
         if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control);
         }
@@ -194,6 +211,14 @@ impl OssCore {
             }
         }
 
+        if let Some(tags) = args.tags() {
+            req = req.header(constants::X_OSS_TAGGING, format_tagging_query(tags));
+        }
+
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header(constants::X_OSS_STORAGE_CLASS, storage_class);
+        }
+
         Ok(req)
     }
 
@@ -221,6 +246,16 @@ impl OssCore {
     ) -> Result<Metadata> {
         let mut m = parse_into_metadata(path, headers)?;
 
+        if let Some(v) = parse_header_to_str(headers, constants::X_OSS_STORAGE_CLASS)? {
+            m.set_storage_class(v);
+        }
+
+        if let Some(v) = parse_header_to_str(headers, constants::X_OSS_RESTORE)? {
+            if let Some(status) = parse_restore_status(v)? {
+                m.set_restore_status(status);
+            }
+        }
+
         let data: HashMap<String, String> = headers
             .iter()
             .filter_map(|(key, _)| {
@@ -351,9 +386,14 @@ impl OssCore {
         Ok(req)
     }
 
-    fn oss_delete_object_request(&self, path: &str, args: &OpDelete) -> Result<Request<Buffer>> {
+    pub fn oss_delete_object_request(
+        &self,
+        path: &str,
+        is_presign: bool,
+        args: &OpDelete,
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
-        let endpoint = self.get_endpoint(false);
+        let endpoint = self.get_endpoint(is_presign);
         let mut url = format!("{}/{}", endpoint, percent_encode_path(&p));
 
         let mut query_args = Vec::new();
@@ -416,6 +456,7 @@ impl OssCore {
     pub fn oss_list_object_request(
         &self,
         path: &str,
+        is_presign: bool,
         token: &str,
         delimiter: &str,
         limit: Option<usize>,
@@ -423,7 +464,7 @@ impl OssCore {
     ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
-        let endpoint = self.get_endpoint(false);
+        let endpoint = self.get_endpoint(is_presign);
         let mut url = format!("{}/?list-type=2", endpoint);
 
         write!(url, "&delimiter={delimiter}").expect("write into string must succeed");
@@ -501,14 +542,15 @@ impl OssCore {
         limit: Option<usize>,
         start_after: Option<String>,
     ) -> Result<Response<Buffer>> {
-        let mut req = self.oss_list_object_request(path, token, delimiter, limit, start_after)?;
+        let mut req =
+            self.oss_list_object_request(path, false, token, delimiter, limit, start_after)?;
 
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
     pub async fn oss_delete_object(&self, path: &str, args: &OpDelete) -> Result<Response<Buffer>> {
-        let mut req = self.oss_delete_object_request(path, args)?;
+        let mut req = self.oss_delete_object_request(path, false, args)?;
         self.sign(&mut req).await?;
         self.send(req).await
     }
@@ -544,6 +586,99 @@ impl OssCore {
         self.send(req).await
     }
 
+    /// Generate a POST policy signed with OSS signature version 1.
+    ///
+    /// Reference: <https://help.aliyun.com/zh/oss/developer-reference/postobject>
+    pub async fn oss_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+    ) -> Result<PresignedPost> {
+        let Some(cred) = self.load_credential().await? else {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "presign post requires credential but anonymous access is used",
+            ));
+        };
+
+        let p = build_abs_path(&self.root, path);
+        let policy = PostPolicy::new(Utc::now(), &p, args).with_condition("bucket", &self.bucket);
+        let fields = sign_post_policy_v1(policy, &cred);
+
+        let uri = self.get_endpoint(true).parse().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "endpoint is not a valid uri").set_source(err)
+        })?;
+
+        Ok(PresignedPost::new(uri, fields))
+    }
+
+    pub async fn oss_get_object_tagging(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn oss_put_object_tagging(
+        &self,
+        path: &str,
+        tags: &HashMap<String, String>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?tagging", self.endpoint, percent_encode_path(&p));
+
+        let content = quick_xml::se::to_string(&Tagging::from_tags(tags))
+            .map_err(new_xml_deserialize_error)?;
+
+        let req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        let mut req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    pub async fn oss_restore_object(
+        &self,
+        path: &str,
+        args: &OpRestore,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let url = format!("{}/{}?restore", self.endpoint, percent_encode_path(&p));
+
+        // JobParameters is only accepted by cold archive objects, and the default
+        // tier is `Standard`, so we only send it when another tier is required.
+        let content = quick_xml::se::to_string(&RestoreRequest {
+            days: args.days(),
+            job_parameters: match args.tier() {
+                RestoreTier::Standard => None,
+                tier => Some(RestoreRequestJobParameters {
+                    tier: tier.to_string(),
+                }),
+            },
+        })
+        .map_err(new_xml_deserialize_error)?;
+
+        let req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .header("CONTENT-MD5", format_content_md5(content.as_bytes()));
+
+        let mut req = req
+            .body(Buffer::from(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     fn get_endpoint(&self, is_presign: bool) -> &str {
         if is_presign {
             &self.presign_endpoint
@@ -552,43 +687,59 @@ impl OssCore {
         }
     }
 
-    pub async fn oss_initiate_upload(
+    pub fn oss_initiate_upload_request(
         &self,
         path: &str,
-        content_type: Option<&str>,
-        content_disposition: Option<&str>,
-        cache_control: Option<&str>,
+        args: &OpWrite,
         is_presign: bool,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let path = build_abs_path(&self.root, path);
         let endpoint = self.get_endpoint(is_presign);
         let url = format!("{}/{}?uploads", endpoint, percent_encode_path(&path));
         let mut req = Request::post(&url);
-        if let Some(mime) = content_type {
+        if let Some(mime) = args.content_type() {
             req = req.header(CONTENT_TYPE, mime);
         }
-        if let Some(disposition) = content_disposition {
+        if let Some(disposition) = args.content_disposition() {
             req = req.header(CONTENT_DISPOSITION, disposition);
         }
-        if let Some(cache_control) = cache_control {
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, encoding);
+        }
+        if let Some(cache_control) = args.cache_control() {
             req = req.header(CACHE_CONTROL, cache_control);
         }
+        if let Some(tags) = args.tags() {
+            req = req.header(constants::X_OSS_TAGGING, format_tagging_query(tags));
+        }
+        if let Some(storage_class) = args.storage_class() {
+            req = req.header(constants::X_OSS_STORAGE_CLASS, storage_class);
+        }
         req = self.insert_sse_headers(req);
-        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+        req.body(Buffer::new()).map_err(new_request_build_error)
+    }
+
+    pub async fn oss_initiate_upload(
+        &self,
+        path: &str,
+        args: &OpWrite,
+        is_presign: bool,
+    ) -> Result<Response<Buffer>> {
+        let mut req = self.oss_initiate_upload_request(path, args, is_presign)?;
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
     /// Creates a request to upload a part
-    pub async fn oss_upload_part_request(
+    pub fn oss_upload_part_request(
         &self,
         path: &str,
         upload_id: &str,
         part_number: usize,
         is_presign: bool,
-        size: u64,
+        size: Option<u64>,
         body: Buffer,
-    ) -> Result<Response<Buffer>> {
+    ) -> Result<Request<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let endpoint = self.get_endpoint(is_presign);
 
@@ -601,19 +752,35 @@ impl OssCore {
         );
 
         let mut req = Request::put(&url);
-        req = req.header(CONTENT_LENGTH, size);
-        let mut req = req.body(body).map_err(new_request_build_error)?;
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+        req.body(body).map_err(new_request_build_error)
+    }
+
+    pub async fn oss_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: u64,
+        body: Buffer,
+    ) -> Result<Response<Buffer>> {
+        let mut req =
+            self.oss_upload_part_request(path, upload_id, part_number, false, Some(size), body)?;
         self.sign(&mut req).await?;
         self.send(req).await
     }
 
-    pub async fn oss_complete_multipart_upload_request(
+    /// Build the complete multipart upload request without body.
+    ///
+    /// Presigned requests will leave the body to users.
+    pub fn oss_complete_multipart_upload_request(
         &self,
         path: &str,
         upload_id: &str,
         is_presign: bool,
-        parts: Vec<MultipartUploadPart>,
-    ) -> Result<Response<Buffer>> {
+    ) -> http::request::Builder {
         let p = build_abs_path(&self.root, path);
         let endpoint = self.get_endpoint(is_presign);
         let url = format!(
@@ -623,7 +790,16 @@ impl OssCore {
             percent_encode_path(upload_id)
         );
 
-        let req = Request::post(&url);
+        Request::post(&url)
+    }
+
+    pub async fn oss_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<MultipartUploadPart>,
+    ) -> Result<Response<Buffer>> {
+        let req = self.oss_complete_multipart_upload_request(path, upload_id, false);
 
         let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest {
             part: parts.to_vec(),
@@ -642,6 +818,36 @@ impl OssCore {
         self.send(req).await
     }
 
+    /// List the incomplete multipart uploads under given prefix.
+    pub async fn oss_list_multipart_uploads(
+        &self,
+        path: &str,
+        key_marker: Option<&str>,
+        upload_id_marker: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut url = format!(
+            "{}/?uploads&prefix={}",
+            self.endpoint,
+            percent_encode_path(&p)
+        );
+        if let Some(marker) = key_marker {
+            write!(url, "&key-marker={}", percent_encode_path(marker))
+                .expect("write into string must succeed");
+        }
+        if let Some(marker) = upload_id_marker {
+            write!(url, "&upload-id-marker={}", percent_encode_path(marker))
+                .expect("write into string must succeed");
+        }
+
+        let mut req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     /// Abort an ongoing multipart upload.
     /// reference docs https://www.alibabacloud.com/help/zh/oss/developer-reference/abortmultipartupload
     pub async fn oss_abort_multipart_upload(
@@ -666,6 +872,21 @@ impl OssCore {
     }
 }
 
+/// Request of RestoreObject.
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "RestoreRequest", rename_all = "PascalCase")]
+pub struct RestoreRequest {
+    pub days: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_parameters: Option<RestoreRequestJobParameters>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct RestoreRequestJobParameters {
+    pub tier: String,
+}
+
 /// Request of DeleteObjects.
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "Delete", rename_all = "PascalCase")]
@@ -747,6 +968,7 @@ pub struct ListObjectsOutputContent {
     #[serde(rename = "ETag")]
     pub etag: String,
     pub size: u64,
+    pub storage_class: String,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -755,13 +977,72 @@ pub struct CommonPrefix {
     pub prefix: String,
 }
 
+/// Output of ListMultipartUploads.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutput {
+    pub is_truncated: bool,
+    pub next_key_marker: Option<String>,
+    pub next_upload_id_marker: Option<String>,
+    pub upload: Vec<ListMultipartUploadsOutputUpload>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutputUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: String,
+}
+
+/// Add signing fields into the POST policy and sign it, return all form fields.
+pub fn sign_post_policy_v1(policy: PostPolicy, cred: &AliyunCredential) -> Vec<(String, String)> {
+    let encoded = policy.encode();
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(cred.access_key_secret.as_bytes())
+        .expect("hmac accepts key of any size");
+    mac.update(encoded.as_bytes());
+    let signature = BASE64_STANDARD.encode(mac.finalize().into_bytes());
+
+    let mut fields = policy.into_fields();
+    fields.push(("OSSAccessKeyId".to_string(), cred.access_key_id.clone()));
+    fields.push(("policy".to_string(), encoded));
+    fields.push(("Signature".to_string(), signature));
+    if let Some(token) = &cred.security_token {
+        fields.push(("x-oss-security-token".to_string(), token.clone()));
+    }
+    fields
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use bytes::Buf;
     use bytes::Bytes;
+    use chrono::DateTime;
 
     use super::*;
 
+    #[test]
+    fn test_sign_post_policy_v1() {
+        let now = DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let cred = AliyunCredential {
+            access_key_id: "access_key_id".to_string(),
+            access_key_secret: "access_key_secret".to_string(),
+            ..Default::default()
+        };
+        let args = OpPresignPost::new(Duration::from_secs(3600));
+        let policy = PostPolicy::new(now, "a.png", &args).with_condition("bucket", "test");
+
+        let fields = sign_post_policy_v1(policy, &cred);
+        let names = fields.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["key", "OSSAccessKeyId", "policy", "Signature"]);
+        assert_eq!(fields[3].1, "Mvkg9iDpLO7YAflR+l30oHGxn98=");
+    }
+
     /// This example is from https://www.alibabacloud.com/help/zh/object-storage-service/latest/deletemultipleobjects
     #[test]
     fn test_serialize_delete_objects_request() {
@@ -950,20 +1231,45 @@ mod tests {
                     last_modified: "2020-05-18T05:45:54.000Z".to_string(),
                     etag: "\"35A27C2B9EAEEB6F48FD7FB5861D****\"".to_string(),
                     size: 25,
+                    storage_class: "STANDARD".to_string(),
                 },
                 ListObjectsOutputContent {
                     key: "ba".to_string(),
                     last_modified: "2020-05-18T11:17:58.000Z".to_string(),
                     etag: "\"35A27C2B9EAEEB6F48FD7FB5861D****\"".to_string(),
                     size: 25,
+                    storage_class: "STANDARD".to_string(),
                 },
                 ListObjectsOutputContent {
                     key: "bc".to_string(),
                     last_modified: "2020-05-18T05:45:59.000Z".to_string(),
                     etag: "\"35A27C2B9EAEEB6F48FD7FB5861D****\"".to_string(),
                     size: 25,
+                    storage_class: "STANDARD".to_string(),
                 }
             ]
         )
     }
+
+    #[test]
+    fn test_serialize_restore_request() {
+        let content = quick_xml::se::to_string(&RestoreRequest {
+            days: 2,
+            job_parameters: None,
+        })
+        .expect("must success");
+        assert_eq!(content, "<RestoreRequest><Days>2</Days></RestoreRequest>");
+
+        let content = quick_xml::se::to_string(&RestoreRequest {
+            days: 2,
+            job_parameters: Some(RestoreRequestJobParameters {
+                tier: RestoreTier::Expedited.to_string(),
+            }),
+        })
+        .expect("must success");
+        assert_eq!(
+            content,
+            "<RestoreRequest><Days>2</Days><JobParameters><Tier>Expedited</Tier></JobParameters></RestoreRequest>"
+        );
+    }
 }
@@ -68,6 +68,14 @@ pub async fn parse_error(resp: Response<Buffer>) -> Result<Error> {
     Ok(err)
 }
 
+/// Check if the error body of restore means a restore is already in progress.
+///
+/// OSS returns `409 Conflict` with code `RestoreAlreadyInProgress` in this case.
+pub(crate) fn is_restore_in_progress(body: &Buffer) -> bool {
+    de::from_reader::<_, OssError>(body.clone().reader())
+        .is_ok_and(|v| v.code == "RestoreAlreadyInProgress")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,6 +106,9 @@ impl oio::PageList for OssLister {
             meta.set_content_md5(object.etag.trim_matches('"'));
             meta.set_content_length(object.size);
             meta.set_last_modified(parse_datetime_from_rfc3339(object.last_modified.as_str())?);
+            if !object.storage_class.is_empty() {
+                meta.set_storage_class(&object.storage_class);
+            }
 
             let de = oio::Entry::with(path, meta);
             ctx.entries.push_back(de);
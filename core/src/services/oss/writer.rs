@@ -64,13 +64,7 @@ impl oio::MultipartWrite for OssWriter {
     async fn initiate_part(&self) -> Result<String> {
         let resp = self
             .core
-            .oss_initiate_upload(
-                &self.path,
-                self.op.content_type(),
-                self.op.content_disposition(),
-                self.op.cache_control(),
-                false,
-            )
+            .oss_initiate_upload(&self.path, &self.op, false)
             .await?;
 
         let status = resp.status();
@@ -101,7 +95,7 @@ impl oio::MultipartWrite for OssWriter {
 
         let resp = self
             .core
-            .oss_upload_part_request(&self.path, upload_id, part_number, false, size, body)
+            .oss_upload_part(&self.path, upload_id, part_number, size, body)
             .await?;
 
         let status = resp.status();
@@ -119,6 +113,7 @@ impl oio::MultipartWrite for OssWriter {
 
                 Ok(oio::MultipartPart {
                     part_number,
+                    size,
                     etag,
                     checksum: None,
                 })
@@ -138,7 +133,7 @@ impl oio::MultipartWrite for OssWriter {
 
         let resp = self
             .core
-            .oss_complete_multipart_upload_request(&self.path, upload_id, false, parts)
+            .oss_complete_multipart_upload(&self.path, upload_id, parts)
             .await?;
 
         let status = resp.status();
@@ -383,6 +383,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                batch_read: true,
 
                 ..Default::default()
             },
@@ -398,6 +399,22 @@ impl kv::Adapter for Adapter {
         Ok(result.map(Buffer::from))
     }
 
+    async fn get_many(&self, keys: &[String]) -> Result<Vec<Option<Buffer>>> {
+        let conn = self.conn().await?;
+        // Use `MGET` directly, `conn.get` will return a single value if only one key is given.
+        let mut cmd = redis::cmd("MGET");
+        cmd.arg(keys);
+        let result: Vec<Option<bytes::Bytes>> = match conn {
+            RedisConnection::Normal(mut conn) => {
+                cmd.query_async(&mut conn).await.map_err(format_redis_error)
+            }
+            RedisConnection::Cluster(mut conn) => {
+                cmd.query_async(&mut conn).await.map_err(format_redis_error)
+            }
+        }?;
+        Ok(result.into_iter().map(|v| v.map(Buffer::from)).collect())
+    }
+
     async fn set(&self, key: &str, value: Buffer) -> Result<()> {
         let conn = self.conn().await?;
         let value = value.to_vec();
@@ -43,6 +43,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::core::*;
+use super::error::is_restore_in_progress;
 use super::error::parse_error;
 use super::error::parse_s3_error_code;
 use super::lister::S3Lister;
@@ -1011,6 +1012,7 @@ impl Builder for S3Builder {
             core: Arc::new(S3Core {
                 bucket: bucket.to_string(),
                 endpoint,
+                region: region.clone(),
                 root,
                 server_side_encryption,
                 server_side_encryption_aws_kms_key_id,
@@ -1069,8 +1071,14 @@ impl Access for S3Backend {
                 write: true,
                 write_can_empty: true,
                 write_can_multi: true,
+                write_can_resume: true,
                 write_with_cache_control: true,
                 write_with_content_type: true,
+                write_with_content_encoding: true,
+                write_with_tags: true,
+                write_with_retention: true,
+                write_with_legal_hold: true,
+                write_with_storage_class: true,
                 // The min multipart size of S3 is 5 MiB.
                 //
                 // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
@@ -1092,12 +1100,28 @@ impl Access for S3Backend {
                 list_with_start_after: true,
                 list_with_recursive: true,
 
+                list_multipart_uploads: true,
+                abort_multipart_upload: true,
+
+                get_tags: true,
+                set_tags: true,
+
+                set_retention: true,
+                set_legal_hold: true,
+
+                restore: true,
+
                 presign: true,
                 presign_stat: true,
                 presign_read: true,
                 presign_write: true,
+                presign_delete: true,
+                presign_list: true,
+                presign_multipart: true,
+                presign_post: true,
 
                 batch: true,
+                batch_delete: true,
                 batch_max_operations: Some(self.core.batch_max_operations),
 
                 ..Default::default()
@@ -1120,6 +1144,9 @@ impl Access for S3Backend {
                     meta.set_version(v);
                 }
 
+                parse_object_lock_metadata(headers, &mut meta)?;
+                parse_storage_class_metadata(headers, &mut meta)?;
+
                 Ok(RpStat::new(meta))
             }
             _ => Err(parse_error(resp)),
@@ -1145,9 +1172,11 @@ impl Access for S3Backend {
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let concurrent = args.concurrent();
         let executor = args.executor().cloned();
-        let writer = S3Writer::new(self.core.clone(), path, args);
+        let writer = S3Writer::new(self.core.clone(), path, args.clone());
 
-        let w = oio::MultipartWriter::new(writer, executor, concurrent);
+        let w = oio::MultipartWriter::new(writer, executor, concurrent)
+            .with_checkpoint(&args)
+            .await?;
 
         Ok((RpWrite::default(), w))
     }
@@ -1194,6 +1223,121 @@ impl Access for S3Backend {
         }
     }
 
+    async fn get_tags(&self, path: &str, _: OpGetTags) -> Result<RpGetTags> {
+        let resp = self.core.s3_get_object_tagging(path).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let tagging: Tagging = quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+                Ok(RpGetTags::new(tagging.into_tags()))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn set_tags(&self, path: &str, args: OpSetTags) -> Result<RpSetTags> {
+        let resp = self.core.s3_put_object_tagging(path, args.tags()).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetTags::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn set_retention(&self, path: &str, args: OpSetRetention) -> Result<RpSetRetention> {
+        let resp = self.core.s3_put_object_retention(path, &args).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetRetention::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn set_legal_hold(&self, path: &str, args: OpSetLegalHold) -> Result<RpSetLegalHold> {
+        let resp = self
+            .core
+            .s3_put_object_legal_hold(path, args.hold())
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetLegalHold::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn restore(&self, path: &str, args: OpRestore) -> Result<RpRestore> {
+        let resp = self.core.s3_restore_object(path, &args).await?;
+
+        match resp.status() {
+            // 202 means the restore has been accepted, 200 means the object has
+            // been restored already.
+            StatusCode::ACCEPTED | StatusCode::OK => Ok(RpRestore::default()),
+            // 409 could also be returned for other conflicts, only a restore
+            // already in progress is fine.
+            StatusCode::CONFLICT if is_restore_in_progress(resp.body()) => Ok(RpRestore::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn list_multipart_uploads(
+        &self,
+        path: &str,
+        _: OpListMultipartUploads,
+    ) -> Result<RpListMultipartUploads> {
+        let mut uploads = Vec::new();
+        let (mut key_marker, mut upload_id_marker) = (None, None);
+        loop {
+            let resp = self
+                .core
+                .s3_list_multipart_uploads(path, key_marker.as_deref(), upload_id_marker.as_deref())
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListMultipartUploadsOutput =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+            for upload in output.upload {
+                let mut v = MultipartUpload::new(&build_rel_path(&self.core.root, &upload.key))
+                    .with_upload_id(&upload.upload_id);
+                if let Ok(initiated) = parse_datetime_from_rfc3339(&upload.initiated) {
+                    v = v.with_initiated(initiated);
+                }
+                uploads.push(v);
+            }
+
+            if !output.is_truncated {
+                break;
+            }
+            key_marker = output.next_key_marker;
+            upload_id_marker = output.next_upload_id_marker;
+        }
+
+        Ok(RpListMultipartUploads::new(uploads))
+    }
+
+    async fn abort_multipart_upload(
+        &self,
+        path: &str,
+        args: OpAbortMultipartUpload,
+    ) -> Result<RpAbortMultipartUpload> {
+        let Some(upload_id) = args.upload_id() else {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "upload id is required to abort multipart upload",
+            ));
+        };
+
+        let resp = self.core.s3_abort_multipart_upload(path, upload_id).await?;
+        match resp.status() {
+            // Upload that has been aborted or completed will return 404.
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(RpAbortMultipartUpload::new()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let (expire, op) = args.into_parts();
 
@@ -1208,6 +1352,34 @@ impl Access for S3Backend {
                 self.core
                     .s3_put_object_request(path, None, &OpWrite::default(), Buffer::new())?
             }
+            PresignOperation::Delete(v) => self.core.s3_delete_object_request(path, &v)?,
+            PresignOperation::List(v) => {
+                let delimiter = if v.recursive() { "" } else { "/" };
+                self.core.s3_list_objects_request(
+                    path,
+                    "",
+                    delimiter,
+                    v.limit(),
+                    v.start_after().map(String::from),
+                )?
+            }
+            PresignOperation::InitiateMultipartUpload(v) => {
+                self.core.s3_initiate_multipart_upload_request(path, &v)?
+            }
+            PresignOperation::UploadPart(v) => self.core.s3_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number(),
+                None,
+                &OpWrite::default(),
+                Buffer::new(),
+                None,
+            )?,
+            PresignOperation::CompleteMultipartUpload(v) => self
+                .core
+                .s3_complete_multipart_upload_request(path, v.upload_id())
+                .body(Buffer::new())
+                .map_err(new_request_build_error)?,
         };
 
         self.core.sign_query(&mut req, expire).await?;
@@ -1222,6 +1394,12 @@ impl Access for S3Backend {
         )))
     }
 
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.s3_presign_post(path, &args).await?;
+
+        Ok(RpPresignPost::new(post))
+    }
+
     async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
         let ops = args.into_operation();
         if ops.len() > 1000 {
@@ -55,6 +55,9 @@ pub fn parse_error(resp: Response<Buffer>) -> Error {
 
     if let Some(s3_err) = s3_err {
         (kind, retryable) = parse_s3_error_code(s3_err.code.as_str()).unwrap_or((kind, retryable));
+        if is_object_locked(&s3_err) {
+            kind = ErrorKind::ObjectLocked;
+        }
     }
 
     let mut err = Error::new(kind, message);
@@ -70,8 +73,11 @@ pub fn parse_error(resp: Response<Buffer>) -> Error {
 
 /// Util function to build [`Error`] from a [`S3Error`] object.
 pub(crate) fn from_s3_error(s3_error: S3Error, parts: Parts) -> Error {
-    let (kind, retryable) =
+    let (mut kind, retryable) =
         parse_s3_error_code(s3_error.code.as_str()).unwrap_or((ErrorKind::Unexpected, false));
+    if is_object_locked(&s3_error) {
+        kind = ErrorKind::ObjectLocked;
+    }
     let mut err = Error::new(kind, format!("{s3_error:?}"));
 
     err = with_error_response_context(err, parts);
@@ -112,10 +118,31 @@ pub fn parse_s3_error_code(code: &str) -> Option<(ErrorKind, bool)> {
         // indicates a temporary issue with the service or server, such as high load,
         // maintenance, or an internal problem.
         "ServiceUnavailable" => Some((ErrorKind::Unexpected, true)),
+        // > The operation is not valid for the current state of the object.
+        //
+        // Returned while reading objects in `GLACIER` or `DEEP_ARCHIVE` that
+        // haven't been restored.
+        "InvalidObjectState" => Some((ErrorKind::ObjectArchived, false)),
         _ => None,
     }
 }
 
+/// Check if this error is caused by object lock.
+///
+/// S3 doesn't have a dedicated error code for locked objects, instead it returns
+/// `AccessDenied` with a message like `Access Denied because object protected by object lock.`
+fn is_object_locked(s3_err: &S3Error) -> bool {
+    s3_err.code == "AccessDenied" && s3_err.message.to_lowercase().contains("object lock")
+}
+
+/// Check if the error body of restore means a restore is already in progress.
+///
+/// S3 returns `409 Conflict` with code `RestoreAlreadyInProgress` in this case.
+pub(crate) fn is_restore_in_progress(body: &Buffer) -> bool {
+    de::from_reader::<_, S3Error>(body.clone().reader())
+        .is_ok_and(|v| v.code == "RestoreAlreadyInProgress")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +188,70 @@ mod tests {
         let out: S3Error = de::from_reader(bs.reader()).expect("must success");
         assert_eq!(out, S3Error::default());
     }
+
+    #[test]
+    fn test_parse_error_object_locked() {
+        let bs = bytes::Bytes::from(
+            r#"
+<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>AccessDenied</Code>
+  <Message>Access Denied because object protected by object lock.</Message>
+  <RequestId>4442587FB7D0A2F9</RequestId>
+</Error>
+"#,
+        );
+
+        let resp = Response::builder()
+            .status(403)
+            .body(Buffer::from(bs))
+            .expect("must success");
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::ObjectLocked);
+    }
+
+    #[test]
+    fn test_parse_error_object_archived() {
+        let bs = bytes::Bytes::from(
+            r#"
+<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>InvalidObjectState</Code>
+  <Message>The operation is not valid for the object's storage class</Message>
+  <StorageClass>GLACIER</StorageClass>
+  <RequestId>4442587FB7D0A2F9</RequestId>
+</Error>
+"#,
+        );
+
+        let resp = Response::builder()
+            .status(403)
+            .body(Buffer::from(bs))
+            .expect("must success");
+        let err = parse_error(resp);
+        assert_eq!(err.kind(), ErrorKind::ObjectArchived);
+    }
+
+    #[test]
+    fn test_is_restore_in_progress() {
+        let cases = [
+            ("RestoreAlreadyInProgress", true),
+            ("OperationAborted", false),
+        ];
+
+        for (code, expected) in cases {
+            let bs = format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>{code}</Code>
+  <Message>conflict</Message>
+</Error>"#
+            );
+            assert_eq!(
+                is_restore_in_progress(&Buffer::from(bs)),
+                expected,
+                "code {code}"
+            );
+        }
+    }
 }
@@ -128,6 +128,9 @@ impl oio::PageList for S3Lister {
             // object.last_modified provides more precious time that contains
             // nanosecond, let's trim them.
             meta.set_last_modified(parse_datetime_from_rfc3339(object.last_modified.as_str())?);
+            if let Some(storage_class) = &object.storage_class {
+                meta.set_storage_class(storage_class);
+            }
 
             let de = oio::Entry::with(path, meta);
             ctx.entries.push_back(de);
@@ -101,7 +101,8 @@ impl oio::MultipartWrite for S3Writer {
             &self.path,
             upload_id,
             part_number,
-            size,
+            Some(size),
+            &self.op,
             body,
             checksum.clone(),
         )?;
@@ -125,6 +126,7 @@ impl oio::MultipartWrite for S3Writer {
 
                 Ok(oio::MultipartPart {
                     part_number,
+                    size,
                     etag,
                     checksum,
                 })
@@ -187,4 +189,40 @@ impl oio::MultipartWrite for S3Writer {
             _ => Err(parse_error(resp)),
         }
     }
+
+    async fn list_parts(&self, upload_id: &str) -> Result<Vec<oio::MultipartPart>> {
+        let mut parts = Vec::new();
+        let mut marker = None;
+        loop {
+            let resp = self
+                .core
+                .s3_list_parts(&self.path, upload_id, marker)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListPartsOutput = quick_xml::de::from_reader(resp.into_body().reader())
+                .map_err(new_xml_deserialize_error)?;
+            parts.extend(output.part);
+
+            match output.next_part_number_marker {
+                Some(v) if output.is_truncated => marker = Some(v),
+                _ => break,
+            }
+        }
+
+        // Only keep the continuous parts from the first one, the rest will be uploaded again.
+        Ok(parts
+            .into_iter()
+            .enumerate()
+            .take_while(|(idx, p)| p.part_number == idx + 1)
+            .map(|(_, p)| oio::MultipartPart {
+                part_number: p.part_number,
+                size: p.size,
+                etag: p.etag,
+                checksum: p.checksum_crc32c,
+            })
+            .collect())
+    }
 }
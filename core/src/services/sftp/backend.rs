@@ -27,6 +27,8 @@ use bb8::RunError;
 use log::debug;
 use openssh::KnownHosts;
 use openssh::SessionBuilder;
+use openssh_sftp_client::metadata::MetaDataBuilder;
+use openssh_sftp_client::metadata::Permissions;
 use openssh_sftp_client::Sftp;
 use openssh_sftp_client::SftpOptions;
 use serde::Deserialize;
@@ -40,6 +42,7 @@ use super::error::parse_sftp_error;
 use super::error::parse_ssh_error;
 use super::lister::SftpLister;
 use super::reader::SftpReader;
+use super::utils::to_unix_timestamp;
 use super::writer::SftpWriter;
 use crate::raw::*;
 use crate::*;
@@ -363,6 +366,7 @@ impl Access for SftpBackend {
 
                 write: true,
                 write_can_multi: true,
+                write_can_random: true,
 
                 create_dir: true,
                 delete: true,
@@ -373,6 +377,15 @@ impl Access for SftpBackend {
                 copy: self.copyable,
                 rename: true,
 
+                create_symlink: true,
+                read_link: true,
+
+                set_attr: true,
+                set_attr_with_mode: true,
+                set_attr_with_owner: true,
+                set_attr_with_times: true,
+                set_attr_with_size: true,
+
                 ..Default::default()
             });
 
@@ -402,12 +415,18 @@ impl Access for SftpBackend {
         Ok(RpCreateDir::default())
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let client = self.connect().await?;
         let mut fs = client.fs();
         fs.set_cwd(&self.root);
 
-        let meta: Metadata = fs.metadata(path).await.map_err(parse_sftp_error)?.into();
+        let meta: Metadata = if args.no_follow() {
+            fs.symlink_metadata(path).await
+        } else {
+            fs.metadata(path).await
+        }
+        .map_err(parse_sftp_error)?
+        .into();
 
         Ok(RpStat::new(meta))
     }
@@ -452,13 +471,128 @@ impl Access for SftpBackend {
         option.create(true);
         if op.append() {
             option.append(true);
+        } else if op.offset().is_some() {
+            option.write(true);
         } else {
             option.write(true).truncate(true);
         }
 
         let file = option.open(path).await.map_err(parse_sftp_error)?;
+        let mut w = SftpWriter::new(file);
+        if let Some(offset) = op.offset() {
+            w.seek(offset).await?;
+        }
+
+        Ok((RpWrite::new(), w))
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        if let Some((dir, _)) = path.rsplit_once('/') {
+            self.create_dir(dir, OpCreateDir::default()).await?;
+        }
+
+        let client = self.connect().await?;
+        let mut fs = client.fs();
+        fs.set_cwd(&self.root);
+
+        fs.symlink(args.target(), path)
+            .await
+            .map_err(parse_sftp_error)?;
+
+        Ok(RpCreateSymlink::default())
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        if args.owner().is_some() || args.group().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "sftp only supports set owner by numeric uid and gid",
+            ));
+        }
+
+        let client = self.connect().await?;
+        let mut fs = client.fs();
+        fs.set_cwd(&self.root);
+
+        // sftp requires uid and gid, atime and mtime to be set together, so
+        // we need to fill the missing one from current metadata.
+        let current = if args.uid().is_some() != args.gid().is_some()
+            || args.last_modified().is_some() != args.last_accessed().is_some()
+        {
+            let meta: Metadata = fs.metadata(path).await.map_err(parse_sftp_error)?.into();
+            Some(meta)
+        } else {
+            None
+        };
+        let missing = |field: &'static str| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "sftp server doesn't return current attribute",
+            )
+            .with_context("attribute", field)
+        };
+
+        let mut builder = MetaDataBuilder::new();
+        if let Some(mode) = args.mode() {
+            builder.permissions(Permissions::from((mode & 0o7777) as u16));
+        }
+        if args.uid().is_some() || args.gid().is_some() {
+            let uid = match args.uid() {
+                Some(v) => v,
+                None => current
+                    .as_ref()
+                    .and_then(|m| m.uid())
+                    .ok_or_else(|| missing("uid"))?,
+            };
+            let gid = match args.gid() {
+                Some(v) => v,
+                None => current
+                    .as_ref()
+                    .and_then(|m| m.gid())
+                    .ok_or_else(|| missing("gid"))?,
+            };
+            builder.id((uid, gid));
+        }
+        if args.has_times() {
+            let modified = match args.last_modified() {
+                Some(v) => v,
+                None => current
+                    .as_ref()
+                    .and_then(|m| m.last_modified())
+                    .ok_or_else(|| missing("last_modified"))?,
+            };
+            let accessed = match args.last_accessed() {
+                Some(v) => v,
+                None => current
+                    .as_ref()
+                    .and_then(|m| m.last_accessed())
+                    .ok_or_else(|| missing("last_accessed"))?,
+            };
+            builder.time(to_unix_timestamp(accessed)?, to_unix_timestamp(modified)?);
+        }
+        if let Some(size) = args.size() {
+            builder.len(size);
+        }
+
+        fs.set_metadata(path, builder.create())
+            .await
+            .map_err(parse_sftp_error)?;
+
+        Ok(RpSetAttr::default())
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let client = self.connect().await?;
+        let mut fs = client.fs();
+        fs.set_cwd(&self.root);
+
+        let target = fs.read_link(path).await.map_err(parse_sftp_error)?;
+        let target = target.to_str().ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "symlink target is not valid utf-8")
+                .with_context("target", target.to_string_lossy())
+        })?;
 
-        Ok((RpWrite::new(), SftpWriter::new(file)))
+        Ok(RpReadLink::new(target))
     }
 
     async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
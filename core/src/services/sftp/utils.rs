@@ -15,10 +15,19 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::time::SystemTime;
+
+use chrono::DateTime;
+use chrono::Utc;
 use openssh_sftp_client::metadata::MetaData as SftpMeta;
+use openssh_sftp_client::metadata::Permissions;
+use openssh_sftp_client::UnixTimeStamp;
 
 use crate::EntryMode;
+use crate::Error;
+use crate::ErrorKind;
 use crate::Metadata;
+use crate::Result;
 
 /// REMOVE ME: we should not implement `From<SftpMeta> for Metadata`.
 impl From<SftpMeta> for Metadata {
@@ -30,6 +39,8 @@ impl From<SftpMeta> for Metadata {
                     EntryMode::FILE
                 } else if filetype.is_dir() {
                     EntryMode::DIR
+                } else if filetype.is_symlink() {
+                    EntryMode::Symlink
                 } else {
                     EntryMode::Unknown
                 }
@@ -46,6 +57,53 @@ impl From<SftpMeta> for Metadata {
             metadata.set_last_modified(modified.as_system_time().into());
         }
 
+        if let Some(accessed) = meta.accessed() {
+            metadata.set_last_accessed(accessed.as_system_time().into());
+        }
+
+        if let Some(perm) = meta.permissions() {
+            metadata.set_unix_mode(permissions_to_mode(perm));
+        }
+
+        if let Some(uid) = meta.uid() {
+            metadata.set_uid(uid);
+        }
+
+        if let Some(gid) = meta.gid() {
+            metadata.set_gid(gid);
+        }
+
         metadata
     }
 }
+
+/// Convert sftp permissions into unix permission bits.
+pub fn permissions_to_mode(perm: Permissions) -> u32 {
+    [
+        (perm.suid(), 0o4000),
+        (perm.sgid(), 0o2000),
+        (perm.svtx(), 0o1000),
+        (perm.read_by_owner(), 0o400),
+        (perm.write_by_owner(), 0o200),
+        (perm.execute_by_owner(), 0o100),
+        (perm.read_by_group(), 0o40),
+        (perm.write_by_group(), 0o20),
+        (perm.execute_by_group(), 0o10),
+        (perm.read_by_other(), 0o4),
+        (perm.write_by_other(), 0o2),
+        (perm.execute_by_other(), 0o1),
+    ]
+    .into_iter()
+    .filter(|(set, _)| *set)
+    .fold(0, |mode, (_, bit)| mode | bit)
+}
+
+/// Convert datetime into sftp timestamp which only supports seconds since
+/// unix epoch in u32.
+pub fn to_unix_timestamp(v: DateTime<Utc>) -> Result<UnixTimeStamp> {
+    UnixTimeStamp::new(SystemTime::from(v)).map_err(|e| {
+        Error::new(ErrorKind::Unsupported, "time is out of range of sftp")
+            .with_context("time", v.to_rfc3339())
+            .set_source(e)
+    })
+}
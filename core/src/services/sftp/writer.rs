@@ -15,11 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::io::SeekFrom;
 use std::pin::Pin;
 
 use bytes::Buf;
 use openssh_sftp_client::file::File;
 use openssh_sftp_client::file::TokioCompatFile;
+use tokio::io::AsyncSeekExt;
 use tokio::io::AsyncWriteExt;
 
 use crate::raw::*;
@@ -36,6 +38,15 @@ impl SftpWriter {
             file: Box::pin(TokioCompatFile::new(file)),
         }
     }
+
+    /// Seek to the given offset so that following writes start from there.
+    pub async fn seek(&mut self, offset: u64) -> Result<()> {
+        self.file
+            .seek(SeekFrom::Start(offset))
+            .await
+            .map_err(new_std_io_error)?;
+        Ok(())
+    }
 }
 
 impl oio::Write for SftpWriter {
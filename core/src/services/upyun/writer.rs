@@ -97,6 +97,7 @@ impl oio::MultipartWrite for UpyunWriter {
         match status {
             StatusCode::NO_CONTENT | StatusCode::CREATED => Ok(oio::MultipartPart {
                 part_number,
+                size,
                 etag: "".to_string(),
                 checksum: None,
             }),
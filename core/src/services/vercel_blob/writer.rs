@@ -105,6 +105,7 @@ impl oio::MultipartWrite for VercelBlobWriter {
 
                 Ok(oio::MultipartPart {
                     part_number,
+                    size,
                     etag: resp.etag,
                     checksum: None,
                 })
@@ -33,6 +33,7 @@ use tokio::sync::OnceCell;
 use super::error::parse_error;
 use super::lister::WebhdfsLister;
 use super::message::BooleanResp;
+use super::message::ContentSummaryWrapper;
 use super::message::FileStatusType;
 use super::message::FileStatusWrapper;
 use super::writer::WebhdfsWriter;
@@ -498,6 +499,100 @@ impl WebhdfsBackend {
         self.client.send(req).await
     }
 
+    /// Get the status of the symlink itself instead of its target.
+    pub(super) async fn webhdfs_get_file_link_status(
+        &self,
+        path: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=GETFILELINKSTATUS",
+            self.endpoint,
+            percent_encode_path(&p),
+        );
+
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    pub(super) async fn webhdfs_create_symlink(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=CREATESYMLINK&destination={}&createParent=true",
+            self.endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(target),
+        );
+
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::put(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    /// Send attribute changing ops like `SETPERMISSION`, `SETOWNER`, `SETTIMES`
+    /// and `TRUNCATE` with given query params.
+    pub(super) async fn webhdfs_set_attr(
+        &self,
+        path: &str,
+        method: http::Method,
+        params: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?{params}",
+            self.endpoint,
+            percent_encode_path(&p),
+        );
+
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::builder()
+            .method(method)
+            .uri(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
+    pub(super) async fn webhdfs_get_content_summary(&self, path: &str) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=GETCONTENTSUMMARY",
+            self.endpoint,
+            percent_encode_path(&p),
+        );
+
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::get(&url)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.client.send(req).await
+    }
+
     pub async fn webhdfs_delete(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
@@ -568,6 +663,17 @@ impl Access for WebhdfsBackend {
 
                 list: true,
 
+                create_symlink: true,
+                read_link: true,
+
+                summary: true,
+
+                set_attr: true,
+                set_attr_with_mode: true,
+                set_attr_with_owner: true,
+                set_attr_with_times: true,
+                set_attr_with_size: true,
+
                 ..Default::default()
             });
         am.into()
@@ -605,13 +711,17 @@ impl Access for WebhdfsBackend {
         }
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         // if root exists and is a directory, stat will be ok
         self.root_checker
             .get_or_try_init(|| async { self.check_root().await })
             .await?;
 
-        let resp = self.webhdfs_get_file_status(path).await?;
+        let resp = if args.no_follow() {
+            self.webhdfs_get_file_link_status(path).await?
+        } else {
+            self.webhdfs_get_file_status(path).await?
+        };
         let status = resp.status();
         match status {
             StatusCode::OK => {
@@ -621,14 +731,32 @@ impl Access for WebhdfsBackend {
                     .map_err(new_json_deserialize_error)?
                     .file_status;
 
-                let meta = match file_status.ty {
+                let mut meta = match file_status.ty {
                     FileStatusType::Directory => Metadata::new(EntryMode::DIR),
                     FileStatusType::File => Metadata::new(EntryMode::FILE)
                         .with_content_length(file_status.length)
                         .with_last_modified(parse_datetime_from_from_timestamp_millis(
                             file_status.modification_time,
                         )?),
+                    FileStatusType::Symlink => Metadata::new(EntryMode::Symlink)
+                        .with_last_modified(parse_datetime_from_from_timestamp_millis(
+                            file_status.modification_time,
+                        )?),
                 };
+                if file_status.access_time > 0 {
+                    meta.set_last_accessed(parse_datetime_from_from_timestamp_millis(
+                        file_status.access_time,
+                    )?);
+                }
+                if let Ok(mode) = u32::from_str_radix(&file_status.permission, 8) {
+                    meta.set_unix_mode(mode);
+                }
+                if !file_status.owner.is_empty() {
+                    meta.set_owner(&file_status.owner);
+                }
+                if !file_status.group.is_empty() {
+                    meta.set_group(&file_status.group);
+                }
 
                 Ok(RpStat::new(meta))
             }
@@ -637,6 +765,103 @@ impl Access for WebhdfsBackend {
         }
     }
 
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        let resp = self.webhdfs_create_symlink(path, args.target()).await?;
+        match resp.status() {
+            StatusCode::OK => Ok(RpCreateSymlink::default()),
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let resp = self.webhdfs_get_file_link_status(path).await?;
+        match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body();
+
+                let file_status = serde_json::from_reader::<_, FileStatusWrapper>(bs.reader())
+                    .map_err(new_json_deserialize_error)?
+                    .file_status;
+
+                match (file_status.ty, file_status.symlink) {
+                    (FileStatusType::Symlink, Some(target)) => Ok(RpReadLink::new(&target)),
+                    _ => Err(Error::new(ErrorKind::Unexpected, "path is not a symlink")),
+                }
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn set_attr(&self, path: &str, args: OpSetAttr) -> Result<RpSetAttr> {
+        if args.uid().is_some() || args.gid().is_some() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "webhdfs only supports set owner by name",
+            ));
+        }
+
+        let mut ops = vec![];
+        if let Some(size) = args.size() {
+            ops.push((http::Method::POST, format!("op=TRUNCATE&newlength={size}")));
+        }
+        if args.has_times() {
+            // `-1` means keep the time unchanged.
+            let mtime = args.last_modified().map_or(-1, |v| v.timestamp_millis());
+            let atime = args.last_accessed().map_or(-1, |v| v.timestamp_millis());
+            ops.push((
+                http::Method::PUT,
+                format!("op=SETTIMES&modificationtime={mtime}&accesstime={atime}"),
+            ));
+        }
+        if args.owner().is_some() || args.group().is_some() {
+            let mut params = "op=SETOWNER".to_string();
+            if let Some(owner) = args.owner() {
+                params += &format!("&owner={}", percent_encode_path(owner));
+            }
+            if let Some(group) = args.group() {
+                params += &format!("&group={}", percent_encode_path(group));
+            }
+            ops.push((http::Method::PUT, params));
+        }
+        if let Some(mode) = args.mode() {
+            ops.push((
+                http::Method::PUT,
+                format!("op=SETPERMISSION&permission={:o}", mode & 0o7777),
+            ));
+        }
+
+        for (method, params) in ops {
+            let resp = self.webhdfs_set_attr(path, method, &params).await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+        }
+
+        Ok(RpSetAttr::default())
+    }
+
+    async fn summary(&self, path: &str, _: OpSummary) -> Result<RpSummary> {
+        let resp = self.webhdfs_get_content_summary(path).await?;
+        match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body();
+
+                let cs = serde_json::from_reader::<_, ContentSummaryWrapper>(bs.reader())
+                    .map_err(new_json_deserialize_error)?
+                    .content_summary;
+
+                Ok(RpSummary::new(
+                    Summary::new()
+                        .with_file_count(cs.file_count)
+                        // The dir itself is counted by webhdfs.
+                        .with_dir_count(cs.directory_count.saturating_sub(1))
+                        .with_total_bytes(cs.length),
+                ))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let resp = self.webhdfs_read_file(path, args.range()).await?;
 
@@ -102,9 +102,12 @@ impl oio::PageList for WebhdfsLister {
                     .with_last_modified(parse_datetime_from_from_timestamp_millis(
                         status.modification_time,
                     )?),
+                FileStatusType::Symlink => Metadata::new(EntryMode::Symlink).with_last_modified(
+                    parse_datetime_from_from_timestamp_millis(status.modification_time)?,
+                ),
             };
 
-            if meta.mode().is_file() {
+            if !meta.mode().is_dir() {
                 path = path.trim_end_matches('/').to_string();
             }
             if meta.mode().is_dir() {
@@ -66,10 +66,21 @@ pub(super) struct FileStatuses {
 pub struct FileStatus {
     pub length: u64,
     pub modification_time: i64,
+    #[serde(default)]
+    pub access_time: i64,
+    /// Permission in octal string like `755`.
+    #[serde(default)]
+    pub permission: String,
+    #[serde(default)]
+    pub owner: String,
+    #[serde(default)]
+    pub group: String,
 
     pub path_suffix: String,
     #[serde(rename = "type")]
     pub ty: FileStatusType,
+    /// The target of the symlink, only set if `ty` is `Symlink`.
+    pub symlink: Option<String>,
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq, Eq)]
@@ -78,6 +89,21 @@ pub enum FileStatusType {
     Directory,
     #[default]
     File,
+    Symlink,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct ContentSummaryWrapper {
+    pub content_summary: ContentSummary,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ContentSummary {
+    pub directory_count: u64,
+    pub file_count: u64,
+    pub length: u64,
 }
 
 #[cfg(test)]
@@ -108,6 +134,9 @@ mod test {
         assert_eq!(status.file_status.modification_time, 1320173277227);
         assert_eq!(status.file_status.path_suffix, "");
         assert_eq!(status.file_status.ty, FileStatusType::Directory);
+        assert_eq!(status.file_status.permission, "777");
+        assert_eq!(status.file_status.owner, "webuser");
+        assert_eq!(status.file_status.group, "supergroup");
     }
 
     #[tokio::test]
@@ -246,4 +275,55 @@ mod test {
             "bazfile"
         );
     }
+
+    #[test]
+    fn test_content_summary() {
+        let json = r#"
+{
+  "ContentSummary":
+  {
+    "directoryCount": 2,
+    "fileCount"     : 1,
+    "length"        : 24930,
+    "quota"         : -1,
+    "spaceConsumed" : 24930,
+    "spaceQuota"    : -1
+  }
+}
+"#;
+        let summary = serde_json::from_str::<ContentSummaryWrapper>(json)
+            .expect("must success")
+            .content_summary;
+        assert_eq!(summary.directory_count, 2);
+        assert_eq!(summary.file_count, 1);
+        assert_eq!(summary.length, 24930);
+    }
+
+    #[test]
+    fn test_file_link_status() {
+        let json = r#"
+{
+  "FileStatus":
+  {
+    "accessTime"      : 0,
+    "blockSize"       : 0,
+    "group"           : "supergroup",
+    "length"          : 0,
+    "modificationTime": 1320173277227,
+    "owner"           : "webuser",
+    "pathSuffix"      : "",
+    "permission"      : "777",
+    "replication"     : 0,
+    "symlink"         : "/path/to/target",
+    "type"            : "SYMLINK"
+  }
+}
+"#;
+        let status: FileStatusWrapper = serde_json::from_str(json).expect("must success");
+        assert_eq!(status.file_status.ty, FileStatusType::Symlink);
+        assert_eq!(
+            status.file_status.symlink.as_deref(),
+            Some("/path/to/target")
+        );
+    }
 }
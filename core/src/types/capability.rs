@@ -89,14 +89,32 @@ pub struct Capability {
     pub write_can_empty: bool,
     /// If operator supports write by append.
     pub write_can_append: bool,
+    /// If operator supports resuming write from a checkpoint.
+    pub write_can_resume: bool,
+    /// If operator supports write at any offset of an existing file natively.
+    ///
+    /// Services without it (like hdfs) fall back to a local staging file in
+    /// [`Operator::random_writer`][crate::Operator::random_writer], which downloads
+    /// the whole file first.
+    pub write_can_random: bool,
     /// If operator supports write with content type.
     pub write_with_content_type: bool,
     /// If operator supports write with content disposition.
     pub write_with_content_disposition: bool,
     /// If operator supports write with cache control.
     pub write_with_cache_control: bool,
+    /// If operator supports write with content encoding.
+    pub write_with_content_encoding: bool,
     /// If operator supports write with user defined metadata
     pub write_with_user_metadata: bool,
+    /// If operator supports write with tags.
+    pub write_with_tags: bool,
+    /// If operator supports write with retention mode and retain until date.
+    pub write_with_retention: bool,
+    /// If operator supports write with legal hold.
+    pub write_with_legal_hold: bool,
+    /// If operator supports write with storage class.
+    pub write_with_storage_class: bool,
     /// write_multi_max_size is the max size that services support in write_multi.
     ///
     /// For example, AWS S3 supports 5GiB as max in write_multi.
@@ -135,6 +153,47 @@ pub struct Capability {
     /// If backend supports list with recursive.
     pub list_with_recursive: bool,
 
+    /// If operator supports listing incomplete multipart uploads.
+    pub list_multipart_uploads: bool,
+    /// If operator supports aborting incomplete multipart uploads.
+    pub abort_multipart_upload: bool,
+
+    /// If operator supports create symlink.
+    pub create_symlink: bool,
+    /// If operator supports read the target of symlink.
+    pub read_link: bool,
+
+    /// If operator supports get the tags of object.
+    pub get_tags: bool,
+    /// If operator supports set the tags of object.
+    pub set_tags: bool,
+
+    /// If operator supports set the retention of object.
+    pub set_retention: bool,
+    /// If operator supports set the legal hold of object.
+    pub set_legal_hold: bool,
+
+    /// If operator supports restore archived object.
+    pub restore: bool,
+
+    /// If operator supports set attributes.
+    pub set_attr: bool,
+    /// If operator supports set attributes with unix permission bits.
+    pub set_attr_with_mode: bool,
+    /// If operator supports set attributes with owner and group.
+    pub set_attr_with_owner: bool,
+    /// If operator supports set attributes with last modified and last accessed.
+    pub set_attr_with_times: bool,
+    /// If operator supports set attributes with size (truncate).
+    pub set_attr_with_size: bool,
+
+    /// If operator can compute the summary of a dir natively without listing.
+    ///
+    /// Only webhdfs and hdfs-native support it for now. hdfs and dbfs don't
+    /// expose directory sizes via libhdfs or the DBFS API, so they fall back
+    /// to listing.
+    pub summary: bool,
+
     /// If operator supports presign.
     pub presign: bool,
     /// If operator supports presign read.
@@ -143,11 +202,23 @@ pub struct Capability {
     pub presign_stat: bool,
     /// If operator supports presign write.
     pub presign_write: bool,
+    /// If operator supports presign delete.
+    pub presign_delete: bool,
+    /// If operator supports presign list.
+    pub presign_list: bool,
+    /// If operator supports presign multipart upload initiate, upload part and complete.
+    pub presign_multipart: bool,
+    /// If operator supports presign post with POST policy for browser based uploads.
+    pub presign_post: bool,
 
     /// If operator supports batch.
     pub batch: bool,
     /// If operator supports batch delete.
     pub batch_delete: bool,
+    /// If operator supports batch stat.
+    pub batch_stat: bool,
+    /// If operator supports batch read of small objects.
+    pub batch_read: bool,
     /// The max operations that operator supports in batch.
     pub batch_max_operations: Option<usize>,
 
@@ -180,6 +251,12 @@ impl Debug for Capability {
         if self.rename {
             s.push("Rename");
         }
+        if self.create_symlink {
+            s.push("CreateSymlink");
+        }
+        if self.set_attr {
+            s.push("SetAttr");
+        }
         if self.list {
             s.push("List");
         }
@@ -37,6 +37,12 @@ impl WriteContext {
     /// Create a new WriteContext.
     #[inline]
     pub fn new(acc: Accessor, path: String, args: OpWrite, options: OpWriter) -> Self {
+        // Make sure writers always have a place to report checkpoint.
+        let args = match args.checkpoint_recorder() {
+            Some(_) => args,
+            None => args.with_checkpoint_recorder(oio::CheckpointRecorder::new()),
+        };
+
         Self {
             acc,
             path,
@@ -69,6 +75,11 @@ impl WriteContext {
         &self.options
     }
 
+    /// Get the latest checkpoint reported by the underlying writer.
+    pub fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.args.checkpoint_recorder().and_then(|v| v.get())
+    }
+
     /// Calculate the chunk size for this write process.
     ///
     /// Returns the chunk size and if the chunk size is exact.
@@ -51,7 +51,7 @@ use crate::*;
 ///         EntryMode::DIR => {
 ///             println!("Handling dir {}", entry.path())
 ///         }
-///         EntryMode::Unknown => continue,
+///         EntryMode::Symlink | EntryMode::Unknown => continue,
 ///     }
 /// }
 /// # Ok(())
@@ -133,7 +133,7 @@ impl Entry {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -177,7 +177,7 @@ impl Entry {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", path)
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -86,6 +86,18 @@ pub enum ErrorKind {
     ///
     /// OpenDAL returns this error to indicate that the range of the read request is not satisfied.
     RangeNotSatisfied,
+    /// The given path is protected by a retention period or legal hold.
+    ///
+    /// Services with object lock (for example, S3 Object Lock, Azblob immutability
+    /// policies and GCS object retention) reject deleting or overwriting a locked
+    /// object until the retention expires and the legal hold is released.
+    ObjectLocked,
+    /// The given path is archived and must be restored before reading.
+    ///
+    /// Services with archive storage classes (for example, S3 `GLACIER` and
+    /// `DEEP_ARCHIVE`) reject reading an archived object until it has been
+    /// restored via [`Operator::restore`](crate::Operator::restore).
+    ObjectArchived,
 }
 
 impl ErrorKind {
@@ -116,6 +128,8 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::IsSameFile => "IsSameFile",
             ErrorKind::ConditionNotMatch => "ConditionNotMatch",
             ErrorKind::RangeNotSatisfied => "RangeNotSatisfied",
+            ErrorKind::ObjectLocked => "ObjectLocked",
+            ErrorKind::ObjectArchived => "ObjectArchived",
         }
     }
 }
@@ -130,7 +130,10 @@ impl Lister {
         let required_metakey = args.metakey();
         let concurrent = cmp::max(1, args.concurrent());
 
-        let (_, lister) = acc.list(path, args).await?;
+        let lister: oio::Lister = match args.glob() {
+            Some(pattern) => Box::new(oio::GlobLister::new(acc.clone(), pattern, args.clone())?),
+            None => acc.list(path, args).await?.1,
+        };
 
         Ok(Self {
             acc,
@@ -235,7 +238,10 @@ impl BlockingLister {
     /// Create a new lister.
     pub(crate) fn create(acc: Accessor, path: &str, args: OpList) -> Result<Self> {
         let required_metakey = args.metakey();
-        let (_, lister) = acc.blocking_list(path, args)?;
+        let lister: oio::BlockingLister = match args.glob() {
+            Some(pattern) => Box::new(oio::GlobLister::new(acc.clone(), pattern, args.clone())?),
+            None => acc.blocking_list(path, args)?.1,
+        };
 
         Ok(Self {
             acc,
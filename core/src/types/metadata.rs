@@ -40,6 +40,7 @@ pub struct Metadata {
 
     cache_control: Option<String>,
     content_disposition: Option<String>,
+    content_encoding: Option<String>,
     content_length: Option<u64>,
     content_md5: Option<String>,
     content_range: Option<BytesContentRange>,
@@ -48,6 +49,20 @@ pub struct Metadata {
     last_modified: Option<DateTime<Utc>>,
     version: Option<String>,
     user_metadata: Option<HashMap<String, String>>,
+
+    unix_mode: Option<u32>,
+    uid: Option<u32>,
+    gid: Option<u32>,
+    owner: Option<String>,
+    group: Option<String>,
+    last_accessed: Option<DateTime<Utc>>,
+
+    retention_mode: Option<RetentionMode>,
+    retain_until: Option<DateTime<Utc>>,
+    legal_hold: Option<bool>,
+
+    storage_class: Option<String>,
+    restore_status: Option<RestoreStatus>,
 }
 
 impl Metadata {
@@ -73,8 +88,23 @@ impl Metadata {
             last_modified: None,
             etag: None,
             content_disposition: None,
+            content_encoding: None,
             version: None,
             user_metadata: None,
+
+            unix_mode: None,
+            uid: None,
+            gid: None,
+            owner: None,
+            group: None,
+            last_accessed: None,
+
+            retention_mode: None,
+            retain_until: None,
+            legal_hold: None,
+
+            storage_class: None,
+            restore_status: None,
         }
     }
 
@@ -123,6 +153,11 @@ impl Metadata {
         matches!(self.mode, EntryMode::DIR)
     }
 
+    /// Returns `true` if this metadata is for a symbolic link.
+    pub fn is_symlink(&self) -> bool {
+        matches!(self.mode, EntryMode::Symlink)
+    }
+
     /// Set mode for entry.
     pub fn set_mode(&mut self, v: EntryMode) -> &mut Self {
         self.mode = v;
@@ -280,6 +315,41 @@ impl Metadata {
         self
     }
 
+    /// Content Encoding of this entry.
+    ///
+    /// Content Encoding is defined by [RFC 9110](https://httpwg.org/specs/rfc9110.html#field.content-encoding).
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::ContentEncoding`], otherwise this method returns `None`.
+    pub fn content_encoding(&self) -> Option<&str> {
+        #[cfg(feature = "tests")]
+        debug_assert!(
+            self.metakey.contains(Metakey::ContentEncoding)
+                || self.metakey.contains(Metakey::Complete),
+            "visiting not set metadata: content_encoding, maybe a bug"
+        );
+
+        self.content_encoding.as_deref()
+    }
+
+    /// Set Content Encoding of this entry.
+    ///
+    /// Content Encoding is defined by [RFC 9110](https://httpwg.org/specs/rfc9110.html#field.content-encoding).
+    pub fn set_content_encoding(&mut self, v: &str) -> &mut Self {
+        self.content_encoding = Some(v.to_string());
+        self.metakey |= Metakey::ContentEncoding;
+        self
+    }
+
+    /// Set Content Encoding of this entry.
+    ///
+    /// Content Encoding is defined by [RFC 9110](https://httpwg.org/specs/rfc9110.html#field.content-encoding).
+    pub fn with_content_encoding(mut self, v: String) -> Self {
+        self.content_encoding = Some(v);
+        self.metakey |= Metakey::ContentEncoding;
+        self
+    }
+
     /// Content Range of this entry.
     ///
     /// Content Range is defined by [RFC 9110](https://httpwg.org/specs/rfc9110.html#field.content-range).
@@ -526,6 +596,260 @@ impl Metadata {
         self.metakey |= Metakey::UserMetaData;
         self
     }
+
+    /// Unix permission bits of this entry, like `0o644`.
+    ///
+    /// Only the permission bits (including setuid, setgid and sticky) are
+    /// kept, the file type bits are exposed via [`Metadata::mode`] instead.
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::UnixMode`] on services that have posix like permissions,
+    /// otherwise this method returns `None`.
+    pub fn unix_mode(&self) -> Option<u32> {
+        self.unix_mode
+    }
+
+    /// Set unix permission bits of this entry.
+    pub fn set_unix_mode(&mut self, v: u32) -> &mut Self {
+        self.unix_mode = Some(v & 0o7777);
+        self.metakey |= Metakey::UnixMode;
+        self
+    }
+
+    /// Set unix permission bits of this entry.
+    pub fn with_unix_mode(mut self, v: u32) -> Self {
+        self.set_unix_mode(v);
+        self
+    }
+
+    /// Numeric user id of the owner of this entry.
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::Owner`] on services that expose numeric ids, otherwise this method
+    /// returns `None`.
+    pub fn uid(&self) -> Option<u32> {
+        self.uid
+    }
+
+    /// Set numeric user id of the owner of this entry.
+    pub fn set_uid(&mut self, v: u32) -> &mut Self {
+        self.uid = Some(v);
+        self.metakey |= Metakey::Owner;
+        self
+    }
+
+    /// Set numeric user id of the owner of this entry.
+    pub fn with_uid(mut self, v: u32) -> Self {
+        self.set_uid(v);
+        self
+    }
+
+    /// Numeric group id of this entry.
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::Owner`] on services that expose numeric ids, otherwise this method
+    /// returns `None`.
+    pub fn gid(&self) -> Option<u32> {
+        self.gid
+    }
+
+    /// Set numeric group id of this entry.
+    pub fn set_gid(&mut self, v: u32) -> &mut Self {
+        self.gid = Some(v);
+        self.metakey |= Metakey::Owner;
+        self
+    }
+
+    /// Set numeric group id of this entry.
+    pub fn with_gid(mut self, v: u32) -> Self {
+        self.set_gid(v);
+        self
+    }
+
+    /// Name of the owner of this entry.
+    ///
+    /// Services like hdfs identify owners by name instead of numeric id.
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::Owner`], otherwise this method returns `None`.
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Set name of the owner of this entry.
+    pub fn set_owner(&mut self, v: &str) -> &mut Self {
+        self.owner = Some(v.to_string());
+        self.metakey |= Metakey::Owner;
+        self
+    }
+
+    /// Set name of the owner of this entry.
+    pub fn with_owner(mut self, v: String) -> Self {
+        self.owner = Some(v);
+        self.metakey |= Metakey::Owner;
+        self
+    }
+
+    /// Name of the group of this entry.
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::Owner`], otherwise this method returns `None`.
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// Set name of the group of this entry.
+    pub fn set_group(&mut self, v: &str) -> &mut Self {
+        self.group = Some(v.to_string());
+        self.metakey |= Metakey::Owner;
+        self
+    }
+
+    /// Set name of the group of this entry.
+    pub fn with_group(mut self, v: String) -> Self {
+        self.group = Some(v);
+        self.metakey |= Metakey::Owner;
+        self
+    }
+
+    /// Last accessed time of this entry.
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::LastAccessed`], otherwise this method returns `None`.
+    pub fn last_accessed(&self) -> Option<DateTime<Utc>> {
+        self.last_accessed
+    }
+
+    /// Set last accessed time of this entry.
+    pub fn set_last_accessed(&mut self, v: DateTime<Utc>) -> &mut Self {
+        self.last_accessed = Some(v);
+        self.metakey |= Metakey::LastAccessed;
+        self
+    }
+
+    /// Set last accessed time of this entry.
+    pub fn with_last_accessed(mut self, v: DateTime<Utc>) -> Self {
+        self.set_last_accessed(v);
+        self
+    }
+
+    /// Retention mode of this entry.
+    ///
+    /// This value is only available when calling on result of `stat` with
+    /// [`Metakey::Retention`] on services that support object lock and the object
+    /// has a retention set, otherwise this method returns `None`.
+    pub fn retention_mode(&self) -> Option<RetentionMode> {
+        self.retention_mode
+    }
+
+    /// Set retention mode of this entry.
+    pub fn set_retention_mode(&mut self, v: RetentionMode) -> &mut Self {
+        self.retention_mode = Some(v);
+        self.metakey |= Metakey::Retention;
+        self
+    }
+
+    /// Set retention mode of this entry.
+    pub fn with_retention_mode(mut self, v: RetentionMode) -> Self {
+        self.set_retention_mode(v);
+        self
+    }
+
+    /// The time until which this entry is protected from deletion and overwrite.
+    ///
+    /// This value is only available when calling on result of `stat` with
+    /// [`Metakey::Retention`] on services that support object lock and the object
+    /// has a retention set, otherwise this method returns `None`.
+    pub fn retain_until(&self) -> Option<DateTime<Utc>> {
+        self.retain_until
+    }
+
+    /// Set the time until which this entry is retained.
+    pub fn set_retain_until(&mut self, v: DateTime<Utc>) -> &mut Self {
+        self.retain_until = Some(v);
+        self.metakey |= Metakey::Retention;
+        self
+    }
+
+    /// Set the time until which this entry is retained.
+    pub fn with_retain_until(mut self, v: DateTime<Utc>) -> Self {
+        self.set_retain_until(v);
+        self
+    }
+
+    /// Legal hold status of this entry.
+    ///
+    /// An entry under legal hold can't be deleted or overwritten until the hold
+    /// is released, regardless of its retention.
+    ///
+    /// This value is only available when calling on result of `stat` with
+    /// [`Metakey::LegalHold`] on services that support object lock, otherwise
+    /// this method returns `None`.
+    pub fn legal_hold(&self) -> Option<bool> {
+        self.legal_hold
+    }
+
+    /// Set legal hold status of this entry.
+    pub fn set_legal_hold(&mut self, v: bool) -> &mut Self {
+        self.legal_hold = Some(v);
+        self.metakey |= Metakey::LegalHold;
+        self
+    }
+
+    /// Set legal hold status of this entry.
+    pub fn with_legal_hold(mut self, v: bool) -> Self {
+        self.set_legal_hold(v);
+        self
+    }
+
+    /// Storage class of this entry, like `STANDARD`, `GLACIER` or `Archive`.
+    ///
+    /// The value is service specific and returned as is.
+    ///
+    /// This value is only available when calling on result of `stat` or `list` with
+    /// [`Metakey::StorageClass`], otherwise this method returns `None`.
+    pub fn storage_class(&self) -> Option<&str> {
+        self.storage_class.as_deref()
+    }
+
+    /// Set storage class of this entry.
+    pub fn set_storage_class(&mut self, v: &str) -> &mut Self {
+        self.storage_class = Some(v.to_string());
+        self.metakey |= Metakey::StorageClass;
+        self
+    }
+
+    /// Set storage class of this entry.
+    pub fn with_storage_class(mut self, v: String) -> Self {
+        self.storage_class = Some(v);
+        self.metakey |= Metakey::StorageClass;
+        self
+    }
+
+    /// Restore status of this entry.
+    ///
+    /// Archived entries must be restored by [`Operator::restore`] before reading,
+    /// this value tells whether the restore is still in progress.
+    ///
+    /// This value is only available when calling on result of `stat` with
+    /// [`Metakey::RestoreStatus`] on an entry that has been requested to restore,
+    /// otherwise this method returns `None`.
+    pub fn restore_status(&self) -> Option<RestoreStatus> {
+        self.restore_status
+    }
+
+    /// Set restore status of this entry.
+    pub fn set_restore_status(&mut self, v: RestoreStatus) -> &mut Self {
+        self.restore_status = Some(v);
+        self.metakey |= Metakey::RestoreStatus;
+        self
+    }
+
+    /// Set restore status of this entry.
+    pub fn with_restore_status(mut self, v: RestoreStatus) -> Self {
+        self.set_restore_status(v);
+        self
+    }
 }
 
 flags! {
@@ -569,5 +893,21 @@ flags! {
         Version,
         /// Key for user metadata
         UserMetaData,
+        /// Key for unix permission bits.
+        UnixMode,
+        /// Key for owner and group, both numeric ids and names.
+        Owner,
+        /// Key for last accessed.
+        LastAccessed,
+        /// Key for retention mode and retain until date.
+        Retention,
+        /// Key for legal hold.
+        LegalHold,
+        /// Key for storage class.
+        StorageClass,
+        /// Key for restore status.
+        RestoreStatus,
+        /// Key for content encoding.
+        ContentEncoding,
     }
 }
@@ -18,6 +18,13 @@
 mod mode;
 pub use mode::EntryMode;
 
+mod retention;
+pub use retention::RetentionMode;
+
+mod restore;
+pub use restore::RestoreStatus;
+pub use restore::RestoreTier;
+
 mod buffer;
 pub use buffer::Buffer;
 
@@ -44,9 +51,22 @@ mod list;
 pub use list::BlockingLister;
 pub use list::Lister;
 
+mod multipart_upload;
+pub use multipart_upload::MultipartUpload;
+
+mod summary;
+pub use summary::DepthSummary;
+pub use summary::Summary;
+
 mod execute;
 pub use execute::*;
 
+mod transfer;
+pub(crate) use transfer::transfer;
+pub use transfer::TransferCheckpoint;
+pub use transfer::TransferProgress;
+pub(crate) use transfer::TransferProgressFn;
+
 mod operator;
 pub use operator::operator_functions;
 pub use operator::operator_futures;
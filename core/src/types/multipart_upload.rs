@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::prelude::*;
+
+/// MultipartUpload is an incomplete upload that has been started but
+/// not completed or aborted yet.
+///
+/// Incomplete uploads are invisible to `list` but still cost storage, users
+/// can fetch them via [`Operator::list_multipart_uploads`] and clean them up
+/// via [`Operator::abort_multipart_upload`].
+///
+/// [`Operator::list_multipart_uploads`]: crate::Operator::list_multipart_uploads
+/// [`Operator::abort_multipart_upload`]: crate::Operator::abort_multipart_upload
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultipartUpload {
+    path: String,
+    upload_id: Option<String>,
+    initiated: Option<DateTime<Utc>>,
+}
+
+impl MultipartUpload {
+    /// Create a new upload of given path.
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Path of this upload, relative to the operator's root.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Upload id of this upload.
+    ///
+    /// Services that upload by blocks (like azblob) don't have an upload id.
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload_id.as_deref()
+    }
+
+    /// Set the upload id of this upload.
+    pub fn with_upload_id(mut self, upload_id: &str) -> Self {
+        self.upload_id = Some(upload_id.to_string());
+        self
+    }
+
+    /// The time this upload was initiated.
+    pub fn initiated(&self) -> Option<DateTime<Utc>> {
+        self.initiated
+    }
+
+    /// Set the time this upload was initiated.
+    pub fn with_initiated(mut self, initiated: DateTime<Utc>) -> Self {
+        self.initiated = Some(initiated);
+        self
+    }
+}
@@ -910,7 +910,7 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -959,7 +959,7 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -991,7 +991,7 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1050,7 +1050,7 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1092,7 +1092,7 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1119,7 +1119,7 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1154,7 +1154,7 @@ impl BlockingOperator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1170,6 +1170,27 @@ impl BlockingOperator {
             |inner, path, args| BlockingLister::create(inner, &path, args),
         ))
     }
+
+    /// List entries that match given glob pattern.
+    ///
+    /// Refer to [`Operator::glob`] for the supported syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::BlockingOperator;
+    /// # fn test(op: BlockingOperator) -> Result<()> {
+    /// let entries = op.glob("logs/2024-*/*.json")?;
+    /// for entry in entries {
+    ///     println!("Handling file {}", entry.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn glob(&self, pattern: &str) -> Result<Vec<Entry>> {
+        self.list_with("/").filter(&normalize_path(pattern)).call()
+    }
 }
 
 impl From<BlockingOperator> for Operator {
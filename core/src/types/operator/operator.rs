@@ -15,9 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::future::Future;
+use std::path::Path;
 use std::time::Duration;
 
+use chrono::DateTime;
+use chrono::Utc;
 use futures::stream;
 use futures::Stream;
 use futures::StreamExt;
@@ -531,6 +536,20 @@ impl Operator {
     /// # }
     /// ```
     ///
+    /// ## `decode`
+    ///
+    /// Decode the content by its `Content-Encoding` like `gzip` transparently. OpenDAL will
+    /// stat the file first to get the content encoding, and `range` is not supported.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = op.read_with("path/to/file.js").decode(true).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// # Examples
     ///
     /// Read the whole path into a bytes.
@@ -565,6 +584,39 @@ impl Operator {
                     );
                 }
 
+                if options.decode() {
+                    if !args.range().is_full() {
+                        return Err(Error::new(
+                            ErrorKind::Unsupported,
+                            "read with decode doesn't support range",
+                        )
+                        .with_operation("read")
+                        .with_context("service", inner.info().scheme())
+                        .with_context("path", &path));
+                    }
+
+                    let mut op = OpStat::new();
+                    if let Some(v) = args.version() {
+                        op = op.with_version(v);
+                    }
+                    let meta = inner.stat(&path, op).await?.into_metadata();
+
+                    let context = ReadContext::new(inner, path, args, options);
+                    let buf = Reader::new(context).read(..).await?;
+                    return match meta.content_encoding() {
+                        #[cfg(feature = "decode-content")]
+                        Some(v) => decode_content(v, buf),
+                        #[cfg(not(feature = "decode-content"))]
+                        Some(v) if !v.trim().eq_ignore_ascii_case("identity") => Err(Error::new(
+                            ErrorKind::Unsupported,
+                            "read with decode requires feature decode-content",
+                        )
+                        .with_operation("read")
+                        .with_context("content_encoding", v)),
+                        _ => Ok(buf),
+                    };
+                }
+
                 let range = args.range();
                 let context = ReadContext::new(inner, path, args, options);
                 let r = Reader::new(context);
@@ -842,278 +894,712 @@ impl Operator {
         Ok(())
     }
 
-    /// Write multiple bytes into path.
+    /// Create a symlink at `path` that points to `target`.
     ///
     /// # Notes
     ///
-    /// ## Extra Options
-    ///
-    /// [`Operator::writer`] is a wrapper of [`Operator::writer_with`] without any options. To use
-    /// extra options like `content_type` and `cache_control`, please use [`Operator::writer_with`]
-    /// instead.
+    /// - `path` must not end with `/`.
+    /// - `target` is stored as is, OpenDAL will not normalize or resolve it.
+    ///   Relative targets are resolved by the service against the dir of `path`.
     ///
-    /// ## Chunk
+    /// # Examples
     ///
-    /// Some storage services have a minimum chunk size requirement. For example, `s3` could return
-    /// hard errors like `EntityTooSmall` if the chunk size is too small. Some services like `gcs`
-    /// also return errors if the chunk size is not aligned. Besides, cloud storage services will cost
-    /// more money if we write data in small chunks.
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
     ///
-    /// OpenDAL sets the chunk size automatically based on the [Capability](crate::types::Capability)
-    /// of the service if users don't set it. Users can set `chunk` to control the exact size to send
-    /// to the storage service.
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.create_symlink("../data/file", "path/to/link").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_symlink(&self, target: &str, path: &str) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::Symlink) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "symlink path is a directory")
+                    .with_operation("Operator::create_symlink")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", path),
+            );
+        }
+
+        self.inner()
+            .create_symlink(&path, OpCreateSymlink::new(target))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Read the target of the symlink at `path`.
     ///
-    /// Users can use [`Operator::writer_with`] to set a good chunk size might improve the performance,
+    /// The target is returned as is, use `stat_with(path).no_follow(true)` to
+    /// check if a path is a symlink.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// use bytes::Bytes;
     ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut w = op.writer("path/to/file").await?;
-    /// w.write(vec![0; 4096]).await?;
-    /// w.write(vec![1; 4096]).await?;
-    /// w.close().await?;
+    /// let target = op.read_link("path/to/link").await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn writer(&self, path: &str) -> Result<Writer> {
-        self.writer_with(path).await
+    pub async fn read_link(&self, path: &str) -> Result<String> {
+        let path = normalize_path(path);
+
+        let rp = self.inner().read_link(&path, OpReadLink::new()).await?;
+
+        Ok(rp.into_target())
     }
 
-    /// Write multiple bytes into path with extra options.
-    ///
-    /// # Options
-    ///
-    /// ## `append`
-    ///
-    /// Set `append` for this `write` request.
+    /// Get the tags of the object at `path`.
     ///
-    /// `write` by default to overwrite existing files. To append to the end of file instead,
-    /// please set `append` to true.
+    /// Tags are key/value pairs separated from user metadata, services like
+    /// s3 use them for lifecycle and cost allocation policies.
     ///
-    /// The following example will append data to existing file instead.
+    /// # Examples
     ///
     /// ```no_run
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// # use futures::StreamExt;
-    /// # use futures::SinkExt;
-    /// use bytes::Bytes;
     ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut w = op.writer_with("path/to/file").append(true).await?;
-    /// w.write(vec![0; 4096]).await?;
-    /// w.write(vec![1; 4096]).await?;
-    /// w.close().await?;
+    /// let tags = op.get_tags("path/to/file").await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub async fn get_tags(&self, path: &str) -> Result<HashMap<String, String>> {
+        let path = normalize_path(path);
+
+        let rp = self.inner().get_tags(&path, OpGetTags::new()).await?;
+
+        Ok(rp.into_tags())
+    }
+
+    /// Set the tags of the object at `path`.
     ///
-    /// ## `chunk`
-    ///
-    /// Set `chunk` for the writer.
-    ///
-    /// Some storage services have a minimum chunk size requirement. For example, `s3` could return
-    /// hard errors like `EntityTooSmall` if the chunk size is too small. Some services like `gcs`
-    /// also return errors if the chunk size is not aligned. Besides, cloud storage services will cost
-    /// more money if we write data in small chunks.
-    ///
-    /// OpenDAL sets the chunk size automatically based on the [Capability](crate::types::Capability)
-    /// of the service if users don't set it. Users can set `chunk` to control the exact size to send
-    /// to the storage service.
+    /// # Notes
     ///
-    /// Set a good chunk size might improve the performance, reduce the API calls and save money.
+    /// The given tags will replace all existing tags of the object, pass an
+    /// empty iterator to remove all tags.
     ///
-    /// The following example will set the writer chunk to 8MiB. Only one API call will be sent at
-    /// `close` instead.
+    /// # Examples
     ///
     /// ```no_run
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// # use futures::StreamExt;
-    /// # use futures::SinkExt;
-    /// use bytes::Bytes;
     ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut w = op
-    ///     .writer_with("path/to/file")
-    ///     .chunk(8 * 1024 * 1024)
-    ///     .await?;
-    /// w.write(vec![0; 4096]).await?;
-    /// w.write(vec![1; 4096]).await?;
-    /// w.close().await?;
+    /// op.set_tags(
+    ///     "path/to/file",
+    ///     [("team".to_string(), "data".to_string())],
+    /// )
+    /// .await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub async fn set_tags(
+        &self,
+        path: &str,
+        tags: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner()
+            .set_tags(&path, OpSetTags::new(HashMap::from_iter(tags)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Set the retention of the object at `path`.
     ///
-    /// ## `concurrent`
-    ///
-    /// Set `concurrent` for the writer.
+    /// The object can't be deleted or overwritten until `retain_until`, deleting
+    /// it before that returns [`ErrorKind::ObjectLocked`].
     ///
-    /// OpenDAL by default to write file without concurrent. This is not efficient for cases when users
-    /// write large chunks of data. By setting `concurrent`, opendal will writing files concurrently
-    /// on support storage services.
+    /// # Notes
     ///
-    /// The following example will set the writer concurrent to 8.
+    /// Most services only allow extending a retention. Use [`Operator::set_retention_with`]
+    /// with `bypass_governance` to shorten a governance mode retention.
     ///
-    /// - The first write will start and return immediately.
-    /// - The second write will start and return immediately.
-    /// - The close will make sure all writes are done in order and return result.
+    /// # Examples
     ///
     /// ```no_run
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// # use futures::StreamExt;
-    /// # use futures::SinkExt;
-    /// use bytes::Bytes;
+    /// use chrono::Duration;
+    /// use chrono::Utc;
+    /// use opendal::RetentionMode;
     ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut w = op.writer_with("path/to/file").concurrent(8).await?;
-    /// w.write(vec![0; 4096]).await?; // Start the first write
-    /// w.write(vec![1; 4096]).await?; // Second write will be concurrent without wait
-    /// w.close().await?; // Close will make sure all writes are done and success
+    /// op.set_retention(
+    ///     "path/to/file",
+    ///     RetentionMode::Compliance,
+    ///     Utc::now() + Duration::days(365),
+    /// )
+    /// .await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub async fn set_retention(
+        &self,
+        path: &str,
+        mode: RetentionMode,
+        retain_until: DateTime<Utc>,
+    ) -> Result<()> {
+        self.set_retention_with(path, mode, retain_until).await
+    }
+
+    /// Set the retention of the object at `path` with extra options.
     ///
-    /// ## `cache_control`
-    ///
-    /// Set the `cache_control` for this `write` request.
-    ///
-    /// Some storage services support setting `cache_control` as system metadata.
+    /// # Examples
     ///
     /// ```no_run
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// # use futures::StreamExt;
-    /// # use futures::SinkExt;
-    /// use bytes::Bytes;
+    /// use chrono::Utc;
+    /// use opendal::RetentionMode;
     ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut w = op
-    ///     .writer_with("path/to/file")
-    ///     .cache_control("max-age=604800")
+    /// op.set_retention_with("path/to/file", RetentionMode::Governance, Utc::now())
+    ///     .bypass_governance(true)
     ///     .await?;
-    /// w.write(vec![0; 4096]).await?;
-    /// w.write(vec![1; 4096]).await?;
-    /// w.close().await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub fn set_retention_with(
+        &self,
+        path: &str,
+        mode: RetentionMode,
+        retain_until: DateTime<Utc>,
+    ) -> FutureSetRetention<impl Future<Output = Result<()>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpSetRetention::new(mode, retain_until),
+            |inner, path, args| async move {
+                inner.set_retention(&path, args).await?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Place or release the legal hold of the object at `path`.
     ///
-    /// ## `content_type`
-    ///
-    /// Set the `content_type` for this `write` request.
+    /// An object under legal hold can't be deleted or overwritten regardless of
+    /// its retention, deleting it returns [`ErrorKind::ObjectLocked`].
     ///
-    /// Some storage services support setting `content_type` as system metadata.
+    /// # Examples
     ///
     /// ```no_run
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// use bytes::Bytes;
     ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut w = op
-    ///     .writer_with("path/to/file")
-    ///     .content_type("text/plain")
-    ///     .await?;
-    /// w.write(vec![0; 4096]).await?;
-    /// w.write(vec![1; 4096]).await?;
-    /// w.close().await?;
+    /// op.set_legal_hold("path/to/file", true).await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub async fn set_legal_hold(&self, path: &str, hold: bool) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner()
+            .set_legal_hold(&path, OpSetLegalHold::new(hold))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Restore the archived object at `path` so that it can be read.
     ///
-    /// ## `content_disposition`
+    /// `days` is how long the restored copy will be kept, services like azblob
+    /// move the object out of the archive tier permanently and ignore it.
     ///
-    /// Set the `content_disposition` for this `write` request.
+    /// # Notes
     ///
-    /// Some storage services support setting `content_disposition` as system metadata.
+    /// Restore is asynchronous on most services, this function returns once the
+    /// request has been accepted. Use [`Metadata::restore_status`] to check the progress.
     ///
-    /// ```
+    /// # Examples
+    ///
+    /// ```no_run
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// # use futures::StreamExt;
-    /// # use futures::SinkExt;
-    /// use bytes::Bytes;
+    /// use opendal::RestoreTier;
     ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut w = op
-    ///     .writer_with("path/to/file")
-    ///     .content_disposition("attachment; filename=\"filename.jpg\"")
-    ///     .await?;
-    /// w.write(vec![0; 4096]).await?;
-    /// w.write(vec![1; 4096]).await?;
-    /// w.close().await?;
+    /// op.restore("path/to/file", 7, RestoreTier::Standard).await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub async fn restore(&self, path: &str, days: u32, tier: RestoreTier) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner()
+            .restore(&path, OpRestore::new(days, tier))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Set attributes of the entry at `path`, like `chmod`, `chown`, `touch` and `truncate`.
+    ///
+    /// Only the attributes set on the returned future will be changed.
+    ///
+    /// # Notes
+    ///
+    /// Every attribute has its own capability, check [`Capability::set_attr_with_mode`],
+    /// [`Capability::set_attr_with_owner`], [`Capability::set_attr_with_times`] and
+    /// [`Capability::set_attr_with_size`] before using them.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// # use futures::StreamExt;
-    /// # use futures::SinkExt;
-    /// use bytes::Bytes;
+    /// use chrono::Utc;
     ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut w = op
-    ///     .writer_with("path/to/file")
-    ///     .content_type("application/octet-stream")
+    /// op.set_attr("path/to/file")
+    ///     .mode(0o644)
+    ///     .last_modified(Utc::now())
     ///     .await?;
-    /// w.write(vec![0; 4096]).await?;
-    /// w.write(vec![1; 4096]).await?;
-    /// w.close().await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn writer_with(&self, path: &str) -> FutureWriter<impl Future<Output = Result<Writer>>> {
+    pub fn set_attr(&self, path: &str) -> FutureSetAttr<impl Future<Output = Result<()>>> {
         let path = normalize_path(path);
 
         OperatorFuture::new(
             self.inner().clone(),
             path,
-            (
-                OpWrite::default().merge_executor(self.default_executor.clone()),
-                OpWriter::default(),
-            ),
-            |inner, path, (args, options)| async move {
-                if !validate_path(&path, EntryMode::FILE) {
-                    return Err(
-                        Error::new(ErrorKind::IsADirectory, "write path is a directory")
-                            .with_operation("Operator::writer")
-                            .with_context("service", inner.info().scheme().into_static())
-                            .with_context("path", &path),
-                    );
-                }
-
-                let context = WriteContext::new(inner, path, args, options);
-                let w = Writer::new(context).await?;
-                Ok(w)
+            OpSetAttr::default(),
+            |inner, path, args| async move {
+                inner.set_attr(&path, args).await?;
+                Ok(())
             },
         )
     }
 
-    /// Write data with extra options.
+    /// Transfer a file from `from` in current operator to `to` in another operator.
     ///
     /// # Notes
     ///
-    /// ## Streaming Write
-    ///
-    /// This function will write all bytes at once. For more precise memory control or
-    /// writing data lazily, please use [`Operator::writer_with`].
+    /// [`Operator::transfer`] is a wrapper of [`Operator::transfer_with`] without any options. To
+    /// use extra options like `concurrent` and `progress`, please use [`Operator::transfer_with`]
+    /// instead.
     ///
-    /// ## Multipart Uploads Write
+    /// - `from` and `to` must be a file.
+    /// - `to` will be overwritten if it exists.
+    /// - `dst` could be the same operator as current one, but [`Operator::copy`] is preferred
+    ///   in this case since it could be done server side.
     ///
-    /// OpenDAL abstracts the multipart uploads into [`Writer`]. It will automatically
-    /// handle the multipart uploads for you. You can control the behavior of multipart uploads
-    /// by setting `chunk`, `concurrent` via [`Operator::writer_with`]
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator, dst: Operator) -> Result<()> {
+    /// op.transfer("path/to/file", &dst, "path/to/file2").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transfer(&self, from: &str, dst: &Operator, to: &str) -> Result<()> {
+        self.transfer_with(from, dst, to).await
+    }
+
+    /// Transfer a file from `from` in current operator to `to` in another operator with
+    /// extra options.
+    ///
+    /// Data will be read by concurrent ranged reads and sent to the destination via
+    /// [`Writer`], so that services with multipart upload support will be written
+    /// concurrently too.
+    ///
+    /// After all data has been sent, the size of destination will be verified.
+    ///
+    /// # Options
+    ///
+    /// ## `concurrent` and `chunk`
+    ///
+    /// Set the concurrent and chunk size of both reads and writes.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator, dst: Operator) -> Result<()> {
+    /// op.transfer_with("path/to/file", &dst, "path/to/file")
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .concurrent(8)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `checksum`
+    ///
+    /// Compute the MD5 of transferred data and compare it with the content of destination
+    /// after transfer. Please note that this will read the destination again.
+    ///
+    /// ## `progress` and `checkpoint`
+    ///
+    /// Progress callback will be called every time data has been sent. The
+    /// [`TransferCheckpoint`] carried by [`TransferProgress`] can be persisted and used
+    /// to resume the transfer after failure.
+    ///
+    /// If the transfer fails with a checkpoint of multipart upload reported or given,
+    /// the upload will be kept for resuming. Use [`Operator::abort_multipart_upload`]
+    /// to purge it if the transfer won't be resumed.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use opendal::TransferCheckpoint;
+    /// # async fn test(op: Operator, dst: Operator, cp: TransferCheckpoint) -> Result<()> {
+    /// op.transfer_with("path/to/file", &dst, "path/to/file")
+    ///     .checkpoint(cp)
+    ///     .progress(|p| println!("{}/{}", p.transferred(), p.total()))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transfer_with(
+        &self,
+        from: &str,
+        dst: &Operator,
+        to: &str,
+    ) -> FutureTransfer<impl Future<Output = Result<()>>> {
+        let from = normalize_path(from);
+        let to = normalize_path(to);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            from,
+            (OpTransfer::default(), dst.inner().clone(), to),
+            |inner, from, (args, dst, to)| async move {
+                if !validate_path(&from, EntryMode::FILE) {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "from path is a directory")
+                            .with_operation("Operator::transfer")
+                            .with_context("service", inner.info().scheme())
+                            .with_context("from", from),
+                    );
+                }
+
+                if !validate_path(&to, EntryMode::FILE) {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "to path is a directory")
+                            .with_operation("Operator::transfer")
+                            .with_context("service", dst.info().scheme())
+                            .with_context("to", to),
+                    );
+                }
+
+                transfer(inner, from, dst, to, args).await
+            },
+        )
+    }
+
+    /// Write multiple bytes into path.
+    ///
+    /// # Notes
+    ///
+    /// ## Extra Options
+    ///
+    /// [`Operator::writer`] is a wrapper of [`Operator::writer_with`] without any options. To use
+    /// extra options like `content_type` and `cache_control`, please use [`Operator::writer_with`]
+    /// instead.
+    ///
+    /// ## Chunk
+    ///
+    /// Some storage services have a minimum chunk size requirement. For example, `s3` could return
+    /// hard errors like `EntityTooSmall` if the chunk size is too small. Some services like `gcs`
+    /// also return errors if the chunk size is not aligned. Besides, cloud storage services will cost
+    /// more money if we write data in small chunks.
+    ///
+    /// OpenDAL sets the chunk size automatically based on the [Capability](crate::types::Capability)
+    /// of the service if users don't set it. Users can set `chunk` to control the exact size to send
+    /// to the storage service.
+    ///
+    /// Users can use [`Operator::writer_with`] to set a good chunk size might improve the performance,
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op.writer("path/to/file").await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.write(vec![1; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn writer(&self, path: &str) -> Result<Writer> {
+        self.writer_with(path).await
+    }
+
+    /// Write multiple bytes into path with extra options.
+    ///
+    /// # Options
+    ///
+    /// ## `append`
+    ///
+    /// Set `append` for this `write` request.
+    ///
+    /// `write` by default to overwrite existing files. To append to the end of file instead,
+    /// please set `append` to true.
+    ///
+    /// The following example will append data to existing file instead.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use futures::StreamExt;
+    /// # use futures::SinkExt;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op.writer_with("path/to/file").append(true).await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.write(vec![1; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `chunk`
+    ///
+    /// Set `chunk` for the writer.
+    ///
+    /// Some storage services have a minimum chunk size requirement. For example, `s3` could return
+    /// hard errors like `EntityTooSmall` if the chunk size is too small. Some services like `gcs`
+    /// also return errors if the chunk size is not aligned. Besides, cloud storage services will cost
+    /// more money if we write data in small chunks.
+    ///
+    /// OpenDAL sets the chunk size automatically based on the [Capability](crate::types::Capability)
+    /// of the service if users don't set it. Users can set `chunk` to control the exact size to send
+    /// to the storage service.
+    ///
+    /// Set a good chunk size might improve the performance, reduce the API calls and save money.
+    ///
+    /// The following example will set the writer chunk to 8MiB. Only one API call will be sent at
+    /// `close` instead.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use futures::StreamExt;
+    /// # use futures::SinkExt;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .chunk(8 * 1024 * 1024)
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.write(vec![1; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `concurrent`
+    ///
+    /// Set `concurrent` for the writer.
+    ///
+    /// OpenDAL by default to write file without concurrent. This is not efficient for cases when users
+    /// write large chunks of data. By setting `concurrent`, opendal will writing files concurrently
+    /// on support storage services.
+    ///
+    /// The following example will set the writer concurrent to 8.
+    ///
+    /// - The first write will start and return immediately.
+    /// - The second write will start and return immediately.
+    /// - The close will make sure all writes are done in order and return result.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use futures::StreamExt;
+    /// # use futures::SinkExt;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op.writer_with("path/to/file").concurrent(8).await?;
+    /// w.write(vec![0; 4096]).await?; // Start the first write
+    /// w.write(vec![1; 4096]).await?; // Second write will be concurrent without wait
+    /// w.close().await?; // Close will make sure all writes are done and success
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `cache_control`
+    ///
+    /// Set the `cache_control` for this `write` request.
+    ///
+    /// Some storage services support setting `cache_control` as system metadata.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use futures::StreamExt;
+    /// # use futures::SinkExt;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .cache_control("max-age=604800")
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.write(vec![1; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `content_type`
+    ///
+    /// Set the `content_type` for this `write` request.
+    ///
+    /// Some storage services support setting `content_type` as system metadata.
+    ///
+    /// ```no_run
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .content_type("text/plain")
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.write(vec![1; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `content_disposition`
+    ///
+    /// Set the `content_disposition` for this `write` request.
+    ///
+    /// Some storage services support setting `content_disposition` as system metadata.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use futures::StreamExt;
+    /// # use futures::SinkExt;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .content_disposition("attachment; filename=\"filename.jpg\"")
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.write(vec![1; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `content_encoding`
+    ///
+    /// Set the `content_encoding` for this `write` request.
+    ///
+    /// Data is written as is, users should encode it before writing. Readers could
+    /// use [`Operator::read_with`] with `decode` to decompress it transparently.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator, gzipped: Vec<u8>) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file.js")
+    ///     .content_type("text/javascript")
+    ///     .content_encoding("gzip")
+    ///     .await?;
+    /// w.write(gzipped).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use futures::StreamExt;
+    /// # use futures::SinkExt;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("path/to/file")
+    ///     .content_type("application/octet-stream")
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.write(vec![1; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn writer_with(&self, path: &str) -> FutureWriter<impl Future<Output = Result<Writer>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (
+                OpWrite::default().merge_executor(self.default_executor.clone()),
+                OpWriter::default(),
+            ),
+            |inner, path, (args, options)| async move {
+                if !validate_path(&path, EntryMode::FILE) {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                            .with_operation("Operator::writer")
+                            .with_context("service", inner.info().scheme().into_static())
+                            .with_context("path", &path),
+                    );
+                }
+
+                let context = WriteContext::new(inner, path, args, options);
+                let w = Writer::new(context).await?;
+                Ok(w)
+            },
+        )
+    }
+
+    /// Write data with extra options.
+    ///
+    /// # Notes
+    ///
+    /// ## Streaming Write
+    ///
+    /// This function will write all bytes at once. For more precise memory control or
+    /// writing data lazily, please use [`Operator::writer_with`].
+    ///
+    /// ## Multipart Uploads Write
+    ///
+    /// OpenDAL abstracts the multipart uploads into [`Writer`]. It will automatically
+    /// handle the multipart uploads for you. You can control the behavior of multipart uploads
+    /// by setting `chunk`, `concurrent` via [`Operator::writer_with`]
     ///
     /// # Options
     ///
@@ -1138,6 +1624,24 @@ impl Operator {
     /// # }
     /// ```
     ///
+    /// ## `offset`
+    ///
+    /// Set `offset` for this `write` request.
+    ///
+    /// Data will be written starting at `offset` of the existing file, and the content
+    /// outside the written range will be kept. Require [`Capability::write_can_random`],
+    /// use [`Operator::write_at`] instead which will fallback to a staging file for
+    /// services that don't support random write.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op.write_with("path/to/file", vec![0; 4096]).offset(1024).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// ## `cache_control`
     ///
     /// Set the `cache_control` for this `write` request.
@@ -1182,74 +1686,222 @@ impl Operator {
     ///
     /// ## `content_disposition`
     ///
-    /// Set the `content_disposition` for this `write` request.
+    /// Set the `content_disposition` for this `write` request.
+    ///
+    /// Some storage services support setting `content_disposition` as system metadata.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = b"hello, world!".to_vec();
+    /// let _ = op
+    ///     .write_with("path/to/file", bs)
+    ///     .content_disposition("attachment; filename=\"filename.jpg\"")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## `content_encoding`
+    ///
+    /// Set the `content_encoding` for this `write` request.
+    ///
+    /// Data is written as is, users should encode it before writing. Readers could
+    /// use [`Operator::read_with`] with `decode` to decompress it transparently.
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator, gzipped: Vec<u8>) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("path/to/file.js", gzipped)
+    ///     .content_encoding("gzip")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use bytes::Bytes;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let bs = b"hello, world!".to_vec();
+    /// let _ = op
+    ///     .write_with("path/to/file", bs)
+    ///     .content_type("text/plain")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_with(
+        &self,
+        path: &str,
+        bs: impl Into<Buffer>,
+    ) -> FutureWrite<impl Future<Output = Result<()>>> {
+        let path = normalize_path(path);
+        let bs = bs.into();
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (
+                OpWrite::default().merge_executor(self.default_executor.clone()),
+                OpWriter::default(),
+                bs,
+            ),
+            |inner, path, (args, options, bs)| async move {
+                if !validate_path(&path, EntryMode::FILE) {
+                    return Err(
+                        Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                            .with_operation("Operator::write_with")
+                            .with_context("service", inner.info().scheme().into_static())
+                            .with_context("path", &path),
+                    );
+                }
+
+                let context = WriteContext::new(inner, path, args, options);
+                let mut w = Writer::new(context).await?;
+                w.write(bs).await?;
+                w.close().await?;
+                Ok(())
+            },
+        )
+    }
+
+    /// Write data starting at `offset` of the file at `path`.
+    ///
+    /// Content outside the written range will be kept, and the gap between the end
+    /// of file and `offset` will be filled with zeros. The file will be created if
+    /// it doesn't exist.
+    ///
+    /// # Notes
+    ///
+    /// Services with [`Capability::write_can_random`] will be written directly, others
+    /// will download the whole file into a local staging file, apply the write and
+    /// upload it back. Use [`Operator::random_writer`] to apply multiple writes with
+    /// only one round trip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.write_at("path/to/file", 1024, vec![0; 4096]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_at(&self, path: &str, offset: u64, bs: impl Into<Buffer>) -> Result<()> {
+        let mut w = self.random_writer(path).await?;
+        w.write_at(offset, bs).await?;
+        w.close().await
+    }
+
+    /// Create a new [`RandomWriter`] to write data at any offset of the file at `path`
+    /// and truncate it.
+    ///
+    /// Refer to [`RandomWriter`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op.random_writer("path/to/file").await?;
+    /// w.write_at(4096, vec![1; 4096]).await?;
+    /// w.write_at(0, vec![0; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn random_writer(&self, path: &str) -> Result<RandomWriter> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                    .with_operation("Operator::random_writer")
+                    .with_context("service", self.info().scheme().into_static())
+                    .with_context("path", &path),
+            );
+        }
+
+        RandomWriter::new(self.clone(), path).await
+    }
+
+    /// Create a new [`RandomWriter`] that always writes via a local staging file
+    /// created in `dir`, even if the service supports random write natively.
+    ///
+    /// It's useful to batch changes locally, like the write-back cache of a filesystem.
+    /// Changes will be uploaded while [`RandomWriter::close`] is called.
     ///
-    /// Some storage services support setting `content_disposition` as system metadata.
+    /// # Examples
     ///
     /// ```
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// use bytes::Bytes;
-    ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let bs = b"hello, world!".to_vec();
-    /// let _ = op
-    ///     .write_with("path/to/file", bs)
-    ///     .content_disposition("attachment; filename=\"filename.jpg\"")
-    ///     .await?;
+    /// let mut w = op.staging_writer("path/to/file", "/tmp").await?;
+    /// w.write_at(0, vec![0; 4096]).await?;
+    /// let bs = w.read_at(0, 1024).await?;
+    /// w.close().await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub async fn staging_writer(&self, path: &str, dir: impl AsRef<Path>) -> Result<RandomWriter> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                    .with_operation("Operator::staging_writer")
+                    .with_context("service", self.info().scheme().into_static())
+                    .with_context("path", &path),
+            );
+        }
+
+        RandomWriter::new_staging(self.clone(), path, dir.as_ref()).await
+    }
+
+    /// Truncate or extend the file at `path` to `size`, the extended part will be
+    /// filled with zeros.
+    ///
+    /// # Notes
+    ///
+    /// Services with [`Capability::set_attr_with_size`] will be truncated directly,
+    /// others will be rewritten via a local staging file.
     ///
     /// # Examples
     ///
     /// ```
     /// # use opendal::Result;
     /// # use opendal::Operator;
-    /// use bytes::Bytes;
-    ///
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let bs = b"hello, world!".to_vec();
-    /// let _ = op
-    ///     .write_with("path/to/file", bs)
-    ///     .content_type("text/plain")
-    ///     .await?;
+    /// op.truncate("path/to/file", 1024).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn write_with(
-        &self,
-        path: &str,
-        bs: impl Into<Buffer>,
-    ) -> FutureWrite<impl Future<Output = Result<()>>> {
+    pub async fn truncate(&self, path: &str, size: u64) -> Result<()> {
         let path = normalize_path(path);
-        let bs = bs.into();
 
-        OperatorFuture::new(
-            self.inner().clone(),
-            path,
-            (
-                OpWrite::default().merge_executor(self.default_executor.clone()),
-                OpWriter::default(),
-                bs,
-            ),
-            |inner, path, (args, options, bs)| async move {
-                if !validate_path(&path, EntryMode::FILE) {
-                    return Err(
-                        Error::new(ErrorKind::IsADirectory, "write path is a directory")
-                            .with_operation("Operator::write_with")
-                            .with_context("service", inner.info().scheme().into_static())
-                            .with_context("path", &path),
-                    );
-                }
+        if self.info().full_capability().set_attr_with_size {
+            return self.set_attr(&path).size(size).await;
+        }
 
-                let context = WriteContext::new(inner, path, args, options);
-                let mut w = Writer::new(context).await?;
-                w.write(bs).await?;
-                w.close().await?;
-                Ok(())
-            },
-        )
+        // Make sure the file exists, otherwise staging writer will create it.
+        self.stat(&path).await?;
+
+        let mut w = RandomWriter::new_staging(self.clone(), path, &std::env::temp_dir()).await?;
+        w.truncate(size).await?;
+        w.close().await
     }
 
     /// Delete the given path.
@@ -1356,7 +2008,7 @@ impl Operator {
     pub async fn remove_via(&self, input: impl Stream<Item = String> + Unpin) -> Result<()> {
         let input = input.map(|v| normalize_path(&v));
 
-        if self.info().full_capability().batch {
+        if self.info().full_capability().batch_delete {
             let mut input = input
                 .map(|v| (v, OpDelete::default().into()))
                 .chunks(self.limit());
@@ -1426,7 +2078,7 @@ impl Operator {
 
         let obs = self.lister_with(path).recursive(true).await?;
 
-        if self.info().full_capability().batch {
+        if self.info().full_capability().batch_delete {
             let mut obs = obs.try_chunks(self.limit());
 
             while let Some(batches) = obs.next().await {
@@ -1458,6 +2110,301 @@ impl Operator {
         Ok(())
     }
 
+    /// Stat multiple paths at once.
+    ///
+    /// Every path has its own result, so one missing file won't fail the
+    /// whole call. Results are returned in the same order as input paths.
+    ///
+    /// # Notes
+    ///
+    /// If underlying services support stat in batch, we will use batch
+    /// stat instead. Otherwise, stat requests will be sent concurrently
+    /// and bounded by [`Operator::limit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let results = op
+    ///     .stat_many(vec!["abc".to_string(), "def".to_string()])
+    ///     .await?;
+    /// for (path, meta) in results {
+    ///     println!("{path}: {:?}", meta.map(|v| v.content_length()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat_many(&self, paths: Vec<String>) -> Result<Vec<(String, Result<Metadata>)>> {
+        let paths: Vec<String> = paths.iter().map(|v| normalize_path(v)).collect();
+
+        if self.info().full_capability().batch_stat {
+            return self
+                .batch_many(
+                    paths,
+                    |_| Some(OpStat::default().into()),
+                    |rp| match rp {
+                        BatchedReply::Stat(rp) => Ok(rp.into_metadata()),
+                        _ => Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "batch stat returns unexpected reply",
+                        )),
+                    },
+                )
+                .await;
+        }
+
+        Ok(stream::iter(paths)
+            .map(|path| async move {
+                let result = self.stat(&path).await;
+                (path, result)
+            })
+            .buffered(self.limit())
+            .collect()
+            .await)
+    }
+
+    /// Read the whole content of multiple small files at once.
+    ///
+    /// Every path has its own result, so one missing file won't fail the
+    /// whole call. Results are returned in the same order as input paths.
+    ///
+    /// # Notes
+    ///
+    /// All content will be loaded into memory, please use [`Operator::reader`]
+    /// for large files.
+    ///
+    /// If underlying services support read in batch, we will use batch
+    /// read instead. Otherwise, read requests will be sent concurrently
+    /// and bounded by [`Operator::limit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let results = op
+    ///     .read_many(vec!["abc".to_string(), "def".to_string()])
+    ///     .await?;
+    /// for (path, bs) in results {
+    ///     println!("{path}: {:?}", bs.map(|v| v.len()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_many(&self, paths: Vec<String>) -> Result<Vec<(String, Result<Buffer>)>> {
+        let paths: Vec<String> = paths.iter().map(|v| normalize_path(v)).collect();
+
+        if self.info().full_capability().batch_read {
+            return self
+                .batch_many(
+                    paths,
+                    |path| {
+                        if validate_path(path, EntryMode::FILE) {
+                            Some(OpRead::default().into())
+                        } else {
+                            None
+                        }
+                    },
+                    |rp| match rp {
+                        BatchedReply::Read(_, bs) => Ok(bs),
+                        _ => Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "batch read returns unexpected reply",
+                        )),
+                    },
+                )
+                .await;
+        }
+
+        Ok(stream::iter(paths)
+            .map(|path| async move {
+                let result = self.read(&path).await;
+                (path, result)
+            })
+            .buffered(self.limit())
+            .collect()
+            .await)
+    }
+
+    /// Send given paths to services' batch API by chunks and collect results
+    /// in the same order as input paths.
+    ///
+    /// Paths that `build_op` returns `None` are treated as directories and
+    /// won't be sent to services.
+    async fn batch_many<T>(
+        &self,
+        paths: Vec<String>,
+        build_op: impl Fn(&str) -> Option<BatchOperation>,
+        parse_reply: impl Fn(BatchedReply) -> Result<T>,
+    ) -> Result<Vec<(String, Result<T>)>> {
+        let mut results = Vec::with_capacity(paths.len());
+
+        for chunk in paths.chunks(self.limit()) {
+            let mut chunk_results: Vec<Option<Result<T>>> = chunk.iter().map(|_| None).collect();
+
+            // Replies are matched by path, so every round only contains unique
+            // paths and duplicated ones are sent in the following rounds.
+            let mut pending: Vec<usize> = (0..chunk.len()).collect();
+            while !pending.is_empty() {
+                let mut seen = HashSet::new();
+                let (round, rest): (Vec<_>, Vec<_>) = pending
+                    .into_iter()
+                    .partition(|&idx| seen.insert(chunk[idx].as_str()));
+                pending = rest;
+
+                let batches: Vec<_> = round
+                    .iter()
+                    .filter_map(|&idx| build_op(&chunk[idx]).map(|op| (chunk[idx].clone(), op)))
+                    .collect();
+
+                let mut replies: HashMap<String, Result<BatchedReply>> = if batches.is_empty() {
+                    HashMap::new()
+                } else {
+                    self.inner()
+                        .batch(OpBatch::new(batches))
+                        .await?
+                        .into_results()
+                        .into_iter()
+                        .collect()
+                };
+
+                for idx in round {
+                    let path = &chunk[idx];
+                    let result = match replies.remove(path) {
+                        Some(reply) => reply.and_then(&parse_reply),
+                        None if !validate_path(path, EntryMode::FILE) => Err(Error::new(
+                            ErrorKind::IsADirectory,
+                            "batch path is a directory",
+                        )
+                        .with_context("path", path)),
+                        None => Err(Error::new(
+                            ErrorKind::Unexpected,
+                            "batch result of path is missing",
+                        )
+                        .with_context("path", path)),
+                    };
+                    chunk_results[idx] = Some(result);
+                }
+            }
+
+            for (path, result) in chunk.iter().zip(chunk_results) {
+                let result = result.expect("every path in batch must have a result");
+                results.push((path.clone(), result));
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// List incomplete multipart uploads that starts with given `path`.
+    ///
+    /// Uploads that are interrupted by crash or never completed will be
+    /// kept by services and still cost storage, this API can be used to
+    /// find them out.
+    ///
+    /// # Notes
+    ///
+    /// Require [`Capability::list_multipart_uploads`], otherwise an
+    /// [`ErrorKind::Unsupported`] error will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let uploads = op.list_multipart_uploads("path/to/dir/").await?;
+    /// for upload in uploads {
+    ///     println!("{} initiated at {:?}", upload.path(), upload.initiated());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_multipart_uploads(&self, path: &str) -> Result<Vec<MultipartUpload>> {
+        let path = normalize_path(path);
+
+        let rp = self
+            .inner()
+            .list_multipart_uploads(&path, OpListMultipartUploads::new())
+            .await?;
+        Ok(rp.into_uploads())
+    }
+
+    /// Abort the given incomplete multipart upload and purge all its data.
+    ///
+    /// # Notes
+    ///
+    /// Require [`Capability::abort_multipart_upload`], otherwise an
+    /// [`ErrorKind::Unsupported`] error will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for upload in op.list_multipart_uploads("path/to/dir/").await? {
+    ///     op.abort_multipart_upload(&upload).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn abort_multipart_upload(&self, upload: &MultipartUpload) -> Result<()> {
+        let mut args = OpAbortMultipartUpload::new();
+        if let Some(upload_id) = upload.upload_id() {
+            args = args.with_upload_id(upload_id);
+        }
+
+        self.inner()
+            .abort_multipart_upload(upload.path(), args)
+            .await?;
+        Ok(())
+    }
+
+    /// Abort all incomplete multipart uploads that starts with given `path`.
+    ///
+    /// Returns the number of aborted uploads.
+    ///
+    /// # Notes
+    ///
+    /// Require [`Capability::list_multipart_uploads`] and
+    /// [`Capability::abort_multipart_upload`], otherwise an
+    /// [`ErrorKind::Unsupported`] error will be returned.
+    ///
+    /// Please make sure there are no on-going writes under `path`, they
+    /// will be aborted too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// #
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let aborted = op.abort_multipart_uploads("path/to/dir/").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn abort_multipart_uploads(&self, path: &str) -> Result<usize> {
+        let uploads = self.list_multipart_uploads(path).await?;
+        let count = uploads.len();
+
+        futures::stream::iter(uploads)
+            .map(Ok)
+            .try_for_each_concurrent(self.limit, |upload| async move {
+                self.abort_multipart_upload(&upload).await
+            })
+            .await?;
+        Ok(count)
+    }
+
     /// List entries that starts with given `path` in parent dir.
     ///
     /// # Notes
@@ -1503,7 +2450,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1533,7 +2480,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1632,7 +2579,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1660,7 +2607,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1686,7 +2633,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1744,7 +2691,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1836,7 +2783,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1863,7 +2810,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1880,37 +2827,163 @@ impl Operator {
     /// use opendal::Metakey;
     /// use opendal::Operator;
     /// # async fn test(op: Operator) -> Result<()> {
-    /// let mut ds = op
-    ///     .lister_with("path/to/dir/")
-    ///     .metakey(Metakey::ContentLength | Metakey::LastModified)
-    ///     .await?;
-    /// while let Some(mut entry) = ds.try_next().await? {
-    ///     let meta = entry.metadata();
-    ///     match meta.mode() {
-    ///         EntryMode::FILE => {
-    ///             println!(
-    ///                 "Handling file {} with size {}",
-    ///                 entry.path(),
-    ///                 meta.content_length()
-    ///             )
-    ///         }
-    ///         EntryMode::DIR => {
-    ///             println!("Handling dir {}", entry.path())
-    ///         }
-    ///         EntryMode::Unknown => continue,
-    ///     }
+    /// let mut ds = op
+    ///     .lister_with("path/to/dir/")
+    ///     .metakey(Metakey::ContentLength | Metakey::LastModified)
+    ///     .await?;
+    /// while let Some(mut entry) = ds.try_next().await? {
+    ///     let meta = entry.metadata();
+    ///     match meta.mode() {
+    ///         EntryMode::FILE => {
+    ///             println!(
+    ///                 "Handling file {} with size {}",
+    ///                 entry.path(),
+    ///                 meta.content_length()
+    ///             )
+    ///         }
+    ///         EntryMode::DIR => {
+    ///             println!("Handling dir {}", entry.path())
+    ///         }
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lister_with(&self, path: &str) -> FutureLister<impl Future<Output = Result<Lister>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpList::default(),
+            |inner, path, args| async move { Lister::create(inner, &path, args).await },
+        )
+    }
+
+    /// List entries that match given glob pattern.
+    ///
+    /// The following syntax is supported:
+    ///
+    /// - `?` matches any single character except `/`.
+    /// - `*` matches any sequence of characters except `/`.
+    /// - `**` as a whole segment matches zero or more dirs.
+    /// - `[abc]` and `[a-z]` match one character in the set, `[!abc]` negates it.
+    /// - `\` escapes the next character.
+    ///
+    /// Patterns ending with `/` only match dirs.
+    ///
+    /// # Notes
+    ///
+    /// The longest literal prefix of the pattern will be pushed down to the
+    /// service, and only dirs that could contain matched entries will be
+    /// visited. So `logs/2024-*/*.json` will list `logs/2024-` instead of
+    /// the whole `logs/`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let entries = op.glob("logs/2024-*/**/*.json").await?;
+    /// for entry in entries {
+    ///     println!("Handling file {}", entry.path())
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn glob(&self, pattern: &str) -> Result<Vec<Entry>> {
+        self.list_with("/").filter(&normalize_path(pattern)).await
+    }
+
+    /// Get the summary of all entries under given path.
+    ///
+    /// Refer to [`Summary`] for the details of the returned value.
+    ///
+    /// # Notes
+    ///
+    /// If the service can't compute summary natively (check
+    /// [`Capability::summary`]), all entries under the path will be listed
+    /// recursively. Use [`Operator::summary_with`] to control the concurrent
+    /// of stat requests while listing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let summary = op.summary("path/to/dir/").await?;
+    /// println!(
+    ///     "{} files, {} bytes",
+    ///     summary.file_count(),
+    ///     summary.total_bytes()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn summary(&self, path: &str) -> Result<Summary> {
+        self.summary_with(path).await
+    }
+
+    /// Get the summary of all entries under given path with extra options.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let summary = op.summary_with("path/to/dir/").concurrent(16).await?;
+    /// for (depth, ds) in summary.depths() {
+    ///     println!("depth {depth}: {} files", ds.file_count());
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub fn lister_with(&self, path: &str) -> FutureLister<impl Future<Output = Result<Lister>>> {
+    pub fn summary_with(&self, path: &str) -> FutureSummary<impl Future<Output = Result<Summary>>> {
         let path = normalize_path(path);
 
         OperatorFuture::new(
             self.inner().clone(),
             path,
             OpList::default(),
-            |inner, path, args| async move { Lister::create(inner, &path, args).await },
+            |inner, path, args| async move {
+                if path.ends_with('/') && inner.info().full_capability().summary {
+                    let rp = inner.summary(&path, OpSummary::new()).await?;
+                    return Ok(rp.into_summary());
+                }
+
+                let base = match path.as_str() {
+                    "/" => "",
+                    p if p.ends_with('/') => p,
+                    p => get_parent(p).trim_start_matches('/'),
+                }
+                .to_string();
+
+                let args = args
+                    .with_recursive(true)
+                    .with_metakey(Metakey::Mode | Metakey::ContentLength | Metakey::LastModified);
+                let mut lister = Lister::create(inner, &path, args).await?;
+
+                let mut summary = Summary::new();
+                while let Some(entry) = lister.try_next().await? {
+                    // Some services will return the listed dir itself, skip it.
+                    if entry.path() == path {
+                        continue;
+                    }
+                    let depth = entry
+                        .path()
+                        .strip_prefix(&base)
+                        .unwrap_or(entry.path())
+                        .trim_end_matches('/')
+                        .matches('/')
+                        .count();
+                    summary.add(depth, entry.metadata());
+                }
+                Ok(summary)
+            },
         )
     }
 }
@@ -2233,4 +3306,321 @@ impl Operator {
             },
         )
     }
+
+    /// Presign an operation for delete.
+    ///
+    /// Require [`Capability::presign_delete`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_delete("test.txt", Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `DELETE`
+    pub async fn presign_delete(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpDelete::new(), expire);
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation for list.
+    ///
+    /// Require [`Capability::presign_list`].
+    ///
+    /// # Notes
+    ///
+    /// The response of the presigned request is the raw list response of
+    /// the service, users need to parse it by themselves.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_list("path/to/dir/", Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn presign_list(&self, path: &str, expire: Duration) -> Result<PresignedRequest> {
+        self.presign_list_with(path, expire).await
+    }
+
+    /// Presign an operation for list with extra options.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_list_with("path/to/dir/", Duration::from_secs(3600))
+    ///         .recursive(true)
+    ///         .limit(100)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn presign_list_with(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> FuturePresignList<impl Future<Output = Result<PresignedRequest>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (OpList::default(), expire),
+            |inner, path, (args, dur)| async move {
+                let op = OpPresign::new(PresignOperation::List(args), dur);
+                let rp = inner.presign(&path, op).await?;
+                Ok(rp.into_presigned_request())
+            },
+        )
+    }
+
+    /// Presign an operation to initiate a multipart upload.
+    ///
+    /// Require [`Capability::presign_multipart`].
+    ///
+    /// Together with [`Operator::presign_upload_part`] and
+    /// [`Operator::presign_complete_multipart_upload`], users can hand out
+    /// presigned requests so that clients (like browsers) can upload large
+    /// files in parts without credentials.
+    ///
+    /// # Notes
+    ///
+    /// The upload id must be extracted from the response of the presigned
+    /// request by users, for example, `UploadId` in `InitiateMultipartUploadResult`
+    /// for s3.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_initiate_multipart_upload_with("test.mp4", Duration::from_secs(3600))
+    ///         .content_type("video/mp4")
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn presign_initiate_multipart_upload(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        self.presign_initiate_multipart_upload_with(path, expire)
+            .await
+    }
+
+    /// Presign an operation to initiate a multipart upload with extra options.
+    ///
+    /// The options are the same as [`Operator::presign_write_with`].
+    pub fn presign_initiate_multipart_upload_with(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> FuturePresignWrite<impl Future<Output = Result<PresignedRequest>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            (OpWrite::default(), expire),
+            |inner, path, (args, dur)| async move {
+                let op = OpPresign::new(PresignOperation::InitiateMultipartUpload(args), dur);
+                let rp = inner.presign(&path, op).await?;
+                Ok(rp.into_presigned_request())
+            },
+        )
+    }
+
+    /// Presign an operation to upload a part of the given multipart upload.
+    ///
+    /// Require [`Capability::presign_multipart`].
+    ///
+    /// `part_number` starts from 1.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator, upload_id: &str) -> Result<()> {
+    ///     let mut reqs = Vec::new();
+    ///     for part_number in 1..=10 {
+    ///         reqs.push(
+    ///             op.presign_upload_part("test.mp4", upload_id, part_number, Duration::from_secs(3600))
+    ///                 .await?,
+    ///         );
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn presign_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpUploadPart::new(upload_id, part_number), expire);
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign an operation to complete the given multipart upload.
+    ///
+    /// Require [`Capability::presign_multipart`].
+    ///
+    /// # Notes
+    ///
+    /// Users must send the parts list in the service specific format as the
+    /// body, for example, `CompleteMultipartUpload` xml for s3.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator, upload_id: &str) -> Result<()> {
+    ///     let signed_req = op
+    ///         .presign_complete_multipart_upload("test.mp4", upload_id, Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn presign_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpCompleteMultipartUpload::new(upload_id), expire);
+
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Presign a POST policy for browser based form uploads.
+    ///
+    /// Require [`Capability::presign_post`].
+    ///
+    /// # Notes
+    ///
+    /// `presign_post` is a wrapper of [`Self::presign_post_with`] without any options. To use
+    /// extra options like `content_length_range`, please use [`Self::presign_post_with`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let post = op.presign_post("test.png", Duration::from_secs(3600)).await?;
+    ///     // Build a `multipart/form-data` form with all fields and the `file` at last.
+    ///     for (name, value) in post.fields() {
+    ///         println!("{name}: {value}");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn presign_post(&self, path: &str, expire: Duration) -> Result<PresignedPost> {
+        self.presign_post_with(path, expire).await
+    }
+
+    /// Presign a POST policy for browser based form uploads with extra options.
+    ///
+    /// # Options
+    ///
+    /// ## `content_length_range`
+    ///
+    /// Limit the size of uploaded object in `[min, max]` bytes.
+    ///
+    /// ## `key_prefix`
+    ///
+    /// Treat path as a key prefix, browsers can upload to any key that starts with it.
+    ///
+    /// ## `content_type`
+    ///
+    /// Require the uploaded object to have the given content type.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let post = op
+    ///         .presign_post_with("uploads/", Duration::from_secs(3600))
+    ///         .key_prefix(true)
+    ///         .content_length_range(1, 10 * 1024 * 1024)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn presign_post_with(
+        &self,
+        path: &str,
+        expire: Duration,
+    ) -> FuturePresignPost<impl Future<Output = Result<PresignedPost>>> {
+        let path = normalize_path(path);
+
+        OperatorFuture::new(
+            self.inner().clone(),
+            path,
+            OpPresignPost::new(expire),
+            |inner, path, args| async move {
+                let rp = inner.presign_post(&path, args).await?;
+                Ok(rp.into_presigned_post())
+            },
+        )
+    }
 }
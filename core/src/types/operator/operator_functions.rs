@@ -290,6 +290,20 @@ impl FunctionList {
         self
     }
 
+    /// Filter entries by given glob pattern.
+    ///
+    /// The pattern is appended to the list path, so `list_with("dir/").filter("*.txt")`
+    /// matches the same entries as `glob("dir/*.txt")`. Only dirs that could contain
+    /// matched entries will be visited, use `**` to match across dirs.
+    ///
+    /// `recursive` will be ignored if filter is set, and `start_after` is not
+    /// supported together with filter.
+    pub fn filter(mut self, pattern: &str) -> Self {
+        let pattern = oio::join_glob(&self.0.path, pattern);
+        self.0 = self.0.map_args(|args| args.with_glob(&pattern));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Vec<Entry>> {
@@ -343,6 +357,20 @@ impl FunctionLister {
         self
     }
 
+    /// Filter entries by given glob pattern.
+    ///
+    /// The pattern is appended to the list path, so `list_with("dir/").filter("*.txt")`
+    /// matches the same entries as `glob("dir/*.txt")`. Only dirs that could contain
+    /// matched entries will be visited, use `**` to match across dirs.
+    ///
+    /// `recursive` will be ignored if filter is set, and `start_after` is not
+    /// supported together with filter.
+    pub fn filter(mut self, pattern: &str) -> Self {
+        let pattern = oio::join_glob(&self.0.path, pattern);
+        self.0 = self.0.map_args(|args| args.with_glob(&pattern));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<BlockingLister> {
@@ -448,6 +476,14 @@ impl FunctionStat {
         self
     }
 
+    /// Return the metadata of the symlink itself instead of the path it points to.
+    ///
+    /// Services that don't support symlink will ignore this option.
+    pub fn no_follow(mut self, v: bool) -> Self {
+        self.0 = self.0.map_args(|args| args.with_no_follow(v));
+        self
+    }
+
     /// Call the function to consume all the input and generate a
     /// result.
     pub fn call(self) -> Result<Metadata> {
@@ -24,6 +24,8 @@ use std::future::IntoFuture;
 use std::ops::RangeBounds;
 use std::time::Duration;
 
+use chrono::DateTime;
+use chrono::Utc;
 use flagset::FlagSet;
 use futures::Future;
 
@@ -109,6 +111,13 @@ impl<F: Future<Output = Result<Metadata>>> FutureStat<F> {
     pub fn version(self, v: &str) -> Self {
         self.map(|args| args.with_version(v))
     }
+
+    /// Return the metadata of the symlink itself instead of the path it points to.
+    ///
+    /// Services that don't support symlink will ignore this option.
+    pub fn no_follow(self, v: bool) -> Self {
+        self.map(|args| args.with_no_follow(v))
+    }
 }
 
 /// Future that generated by [`Operator::presign_stat_with`].
@@ -191,12 +200,62 @@ impl<F: Future<Output = Result<PresignedRequest>>> FuturePresignWrite<F> {
         self.map(|(args, dur)| (args.with_content_disposition(v), dur))
     }
 
+    /// Set the content encoding of option
+    pub fn content_encoding(self, v: &str) -> Self {
+        self.map(|(args, dur)| (args.with_content_encoding(v), dur))
+    }
+
     /// Set the content type of option
     pub fn cache_control(self, v: &str) -> Self {
         self.map(|(args, dur)| (args.with_cache_control(v), dur))
     }
 }
 
+/// Future that generated by [`Operator::presign_list_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FuturePresignList<F> = OperatorFuture<(OpList, Duration), PresignedRequest, F>;
+
+impl<F: Future<Output = Result<PresignedRequest>>> FuturePresignList<F> {
+    /// Change the limit of this list operation.
+    pub fn limit(self, v: usize) -> Self {
+        self.map(|(args, dur)| (args.with_limit(v), dur))
+    }
+
+    /// Change the start_after of this list operation.
+    pub fn start_after(self, v: &str) -> Self {
+        self.map(|(args, dur)| (args.with_start_after(v), dur))
+    }
+
+    /// Change the recursive flag of this list operation.
+    pub fn recursive(self, v: bool) -> Self {
+        self.map(|(args, dur)| (args.with_recursive(v), dur))
+    }
+}
+
+/// Future that generated by [`Operator::presign_post_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FuturePresignPost<F> = OperatorFuture<OpPresignPost, PresignedPost, F>;
+
+impl<F: Future<Output = Result<PresignedPost>>> FuturePresignPost<F> {
+    /// Require the uploaded object to have the given content type.
+    pub fn content_type(self, v: &str) -> Self {
+        self.map(|args| args.with_content_type(v))
+    }
+
+    /// Require the uploaded object size to be in `[min, max]` bytes.
+    pub fn content_length_range(self, min: u64, max: u64) -> Self {
+        self.map(|args| args.with_content_length_range(min, max))
+    }
+
+    /// Treat the path as a key prefix so that browsers can upload any key
+    /// under it. The generated `key` field will be `{path}${filename}`.
+    pub fn key_prefix(self, v: bool) -> Self {
+        self.map(|args| args.with_key_prefix(v))
+    }
+}
+
 /// Future that generated by [`Operator::read_with`] or [`Operator::reader_with`].
 ///
 /// Users can add more options by public functions provided by this struct.
@@ -237,6 +296,14 @@ impl<F: Future<Output = Result<Buffer>>> FutureRead<F> {
     pub fn chunk(self, chunk_size: usize) -> Self {
         self.map(|(args, op_reader)| (args, op_reader.with_chunk(chunk_size)))
     }
+
+    /// Decode the content by its content encoding like `gzip`.
+    ///
+    /// Decoding requires feature `decode-content`, otherwise an `Unsupported`
+    /// error will be returned for encoded content.
+    pub fn decode(self, v: bool) -> Self {
+        self.map(|(args, op_reader)| (args, op_reader.with_decode(v)))
+    }
 }
 
 /// Future that generated by [`Operator::read_with`] or [`Operator::reader_with`].
@@ -287,6 +354,19 @@ impl<F: Future<Output = Result<()>>> FutureWrite<F> {
         self.map(|(args, options, bs)| (args.with_append(v), options, bs))
     }
 
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the data will be written starting at the offset of the
+    /// existing file, and the content outside the written range will be kept.
+    ///
+    /// # Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support
+    /// random write, use [`Operator::write_at`] for a fallback.
+    pub fn offset(self, v: u64) -> Self {
+        self.map(|(args, options, bs)| (args.with_offset(v), options, bs))
+    }
+
     /// Set the buffer size of op.
     ///
     /// If buffer size is set, the data will be buffered by the underlying writer.
@@ -319,6 +399,11 @@ impl<F: Future<Output = Result<()>>> FutureWrite<F> {
         self.map(|(args, options, bs)| (args.with_content_disposition(v), options, bs))
     }
 
+    /// Set the content encoding of option
+    pub fn content_encoding(self, v: &str) -> Self {
+        self.map(|(args, options, bs)| (args.with_content_encoding(v), options, bs))
+    }
+
     /// Set the executor for this operation.
     pub fn executor(self, executor: Executor) -> Self {
         self.map(|(args, options, bs)| (args.with_executor(executor), options, bs))
@@ -339,6 +424,37 @@ impl<F: Future<Output = Result<()>>> FutureWrite<F> {
             )
         })
     }
+
+    /// Set the tags of the object to write.
+    ///
+    /// Tags are key/value pairs separated from user metadata, services like
+    /// s3 use them for lifecycle and cost allocation policies.
+    pub fn tags(self, tags: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.map(|(args, options, bs)| (args.with_tags(HashMap::from_iter(tags)), options, bs))
+    }
+
+    /// Set the retention of the object to write.
+    ///
+    /// The object can't be deleted or overwritten until `retain_until`. Check
+    /// [`Capability::write_with_retention`] before using it.
+    pub fn retention(self, mode: RetentionMode, retain_until: DateTime<Utc>) -> Self {
+        self.map(|(args, options, bs)| (args.with_retention(mode, retain_until), options, bs))
+    }
+
+    /// Set the legal hold of the object to write.
+    ///
+    /// Check [`Capability::write_with_legal_hold`] before using it.
+    pub fn legal_hold(self, hold: bool) -> Self {
+        self.map(|(args, options, bs)| (args.with_legal_hold(hold), options, bs))
+    }
+
+    /// Set the storage class of the object to write, like `STANDARD_IA` or `GLACIER`.
+    ///
+    /// The value is service specific and overrides the `default_storage_class`
+    /// of the service.
+    pub fn storage_class(self, v: &str) -> Self {
+        self.map(|(args, options, bs)| (args.with_storage_class(v), options, bs))
+    }
 }
 
 /// Future that generated by [`Operator::writer_with`].
@@ -358,6 +474,19 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self.map(|(args, options)| (args.with_append(v), options))
     }
 
+    /// Set the offset of op.
+    ///
+    /// If the offset is set, the data will be written starting at the offset of the
+    /// existing file, and the content outside the written range will be kept.
+    ///
+    /// ## Notes
+    ///
+    /// Service could return `Unsupported` if the underlying storage does not support
+    /// random write, use [`Operator::random_writer`] for a fallback.
+    pub fn offset(self, v: u64) -> Self {
+        self.map(|(args, options)| (args.with_offset(v), options))
+    }
+
     /// Set the chunk size of op.
     ///
     /// If chunk size is set, the data will be chunked by the underlying writer.
@@ -397,6 +526,11 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self.map(|(args, options)| (args.with_content_disposition(v), options))
     }
 
+    /// Set the content encoding of option
+    pub fn content_encoding(self, v: &str) -> Self {
+        self.map(|(args, options)| (args.with_content_encoding(v), options))
+    }
+
     /// Set the executor for this operation.
     pub fn executor(self, executor: Executor) -> Self {
         self.map(|(args, options)| (args.with_executor(executor), options))
@@ -411,6 +545,83 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
     pub fn user_metadata(self, data: impl IntoIterator<Item = (String, String)>) -> Self {
         self.map(|(args, options)| (args.with_user_metadata(HashMap::from_iter(data)), options))
     }
+
+    /// Set the tags of the object to write.
+    ///
+    /// Tags are key/value pairs separated from user metadata, services like
+    /// s3 use them for lifecycle and cost allocation policies.
+    pub fn tags(self, tags: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.map(|(args, options)| (args.with_tags(HashMap::from_iter(tags)), options))
+    }
+
+    /// Set the retention of the object to write.
+    ///
+    /// The object can't be deleted or overwritten until `retain_until`. Check
+    /// [`Capability::write_with_retention`] before using it.
+    pub fn retention(self, mode: RetentionMode, retain_until: DateTime<Utc>) -> Self {
+        self.map(|(args, options)| (args.with_retention(mode, retain_until), options))
+    }
+
+    /// Set the legal hold of the object to write.
+    ///
+    /// Check [`Capability::write_with_legal_hold`] before using it.
+    pub fn legal_hold(self, hold: bool) -> Self {
+        self.map(|(args, options)| (args.with_legal_hold(hold), options))
+    }
+
+    /// Set the storage class of the object to write, like `STANDARD_IA` or `GLACIER`.
+    ///
+    /// The value is service specific and overrides the `default_storage_class`
+    /// of the service.
+    pub fn storage_class(self, v: &str) -> Self {
+        self.map(|(args, options)| (args.with_storage_class(v), options))
+    }
+
+    /// Resume a previous upload from the given checkpoint.
+    ///
+    /// The checkpoint can be fetched via [`Writer::checkpoint`]. Parts that have
+    /// been uploaded will be listed from service, use [`WriteCheckpoint::offset`]
+    /// of the new writer's checkpoint to know where to continue from.
+    ///
+    /// ## Notes
+    ///
+    /// - Service could return `Unsupported` if the underlying storage does not support resume.
+    /// - Users should use the same `chunk` as the previous upload.
+    pub fn resume(self, checkpoint: WriteCheckpoint) -> Self {
+        self.map(|(args, options)| (args.with_resume(checkpoint), options))
+    }
+}
+
+/// Future that generated by [`Operator::transfer_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureTransfer<F> = OperatorFuture<(OpTransfer, Accessor, String), (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureTransfer<F> {
+    /// Set the concurrent of both reads and writes.
+    pub fn concurrent(self, v: usize) -> Self {
+        self.map(|(args, dst, to)| (args.with_concurrent(v), dst, to))
+    }
+
+    /// Set the chunk size of both reads and writes.
+    pub fn chunk(self, v: usize) -> Self {
+        self.map(|(args, dst, to)| (args.with_chunk(v), dst, to))
+    }
+
+    /// Verify the checksum of destination after transfer.
+    pub fn checksum(self, v: bool) -> Self {
+        self.map(|(args, dst, to)| (args.with_checksum(v), dst, to))
+    }
+
+    /// Resume the transfer from given checkpoint.
+    pub fn checkpoint(self, v: TransferCheckpoint) -> Self {
+        self.map(|(args, dst, to)| (args.with_checkpoint(v), dst, to))
+    }
+
+    /// Set the callback that will be called every time data has been sent.
+    pub fn progress(self, f: impl Fn(&TransferProgress) + Send + Sync + 'static) -> Self {
+        self.map(|(args, dst, to)| (args.with_progress(f), dst, to))
+    }
 }
 
 /// Future that generated by [`Operator::delete_with`].
@@ -475,6 +686,19 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
     pub fn concurrent(self, v: usize) -> Self {
         self.map(|args| args.with_concurrent(v))
     }
+
+    /// Filter entries by given glob pattern.
+    ///
+    /// The pattern is appended to the list path, so `list_with("dir/").filter("*.txt")`
+    /// matches the same entries as `glob("dir/*.txt")`. Only dirs that could contain
+    /// matched entries will be visited, use `**` to match across dirs.
+    ///
+    /// `recursive` will be ignored if filter is set, and `start_after` is not
+    /// supported together with filter.
+    pub fn filter(self, pattern: &str) -> Self {
+        let pattern = oio::join_glob(&self.path, pattern);
+        self.map(|args| args.with_glob(&pattern))
+    }
 }
 
 /// Future that generated by [`Operator::list_with`] or [`Operator::lister_with`].
@@ -527,4 +751,100 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
     pub fn concurrent(self, v: usize) -> Self {
         self.map(|args| args.with_concurrent(v))
     }
+
+    /// Filter entries by given glob pattern.
+    ///
+    /// The pattern is appended to the list path, so `list_with("dir/").filter("*.txt")`
+    /// matches the same entries as `glob("dir/*.txt")`. Only dirs that could contain
+    /// matched entries will be visited, use `**` to match across dirs.
+    ///
+    /// `recursive` will be ignored if filter is set, and `start_after` is not
+    /// supported together with filter.
+    pub fn filter(self, pattern: &str) -> Self {
+        let pattern = oio::join_glob(&self.path, pattern);
+        self.map(|args| args.with_glob(&pattern))
+    }
+}
+
+/// Future that generated by [`Operator::set_retention_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureSetRetention<F> = OperatorFuture<OpSetRetention, (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureSetRetention<F> {
+    /// Bypass an existing governance mode retention.
+    ///
+    /// Shortening or removing a governance mode retention requires this flag
+    /// and the related permission on services like s3 and gcs.
+    pub fn bypass_governance(self, v: bool) -> Self {
+        self.map(|args| args.with_bypass_governance(v))
+    }
+}
+
+/// Future that generated by [`Operator::set_attr`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureSetAttr<F> = OperatorFuture<OpSetAttr, (), F>;
+
+impl<F: Future<Output = Result<()>>> FutureSetAttr<F> {
+    /// Set the unix permission bits, like `chmod`.
+    pub fn mode(self, v: u32) -> Self {
+        self.map(|args| args.with_mode(v))
+    }
+
+    /// Set the numeric user id of owner, like `chown`.
+    pub fn uid(self, v: u32) -> Self {
+        self.map(|args| args.with_uid(v))
+    }
+
+    /// Set the numeric group id, like `chgrp`.
+    pub fn gid(self, v: u32) -> Self {
+        self.map(|args| args.with_gid(v))
+    }
+
+    /// Set the owner by name.
+    ///
+    /// Services like hdfs identify owners by name instead of numeric id.
+    pub fn owner(self, v: &str) -> Self {
+        self.map(|args| args.with_owner(v))
+    }
+
+    /// Set the group by name.
+    pub fn group(self, v: &str) -> Self {
+        self.map(|args| args.with_group(v))
+    }
+
+    /// Set the last modified time, like `touch -m`.
+    pub fn last_modified(self, v: DateTime<Utc>) -> Self {
+        self.map(|args| args.with_last_modified(v))
+    }
+
+    /// Set the last accessed time, like `touch -a`.
+    pub fn last_accessed(self, v: DateTime<Utc>) -> Self {
+        self.map(|args| args.with_last_accessed(v))
+    }
+
+    /// Set the size of file, like `truncate`.
+    ///
+    /// The file will be extended with zeros if `v` is larger than current size.
+    pub fn size(self, v: u64) -> Self {
+        self.map(|args| args.with_size(v))
+    }
+}
+
+/// Future that generated by [`Operator::summary_with`].
+///
+/// Users can add more options by public functions provided by this struct.
+pub type FutureSummary<F> = OperatorFuture<OpList, Summary, F>;
+
+impl<F: Future<Output = Result<Summary>>> FutureSummary<F> {
+    /// Concurrent is used to control the number of concurrent stat requests
+    /// while listing.
+    ///
+    /// If concurrent is set to <=1, the stat requests will be performed sequentially.
+    ///
+    /// The default concurrent is 1.
+    pub fn concurrent(self, v: usize) -> Self {
+        self.map(|args| args.with_concurrent(v))
+    }
 }
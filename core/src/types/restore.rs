@@ -0,0 +1,77 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// RestoreTier is the speed tier used to restore an archived object.
+///
+/// Faster tiers usually cost more. Services map them as:
+///
+/// | OpenDAL     | S3          | OSS         | Azblob rehydrate priority |
+/// |-------------|-------------|-------------|---------------------------|
+/// | `Expedited` | `Expedited` | `Expedited` | `High`                    |
+/// | `Standard`  | `Standard`  | `Standard`  | `Standard`                |
+/// | `Bulk`      | `Bulk`      | `Bulk`      | `Standard`                |
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum RestoreTier {
+    /// Restore in minutes.
+    Expedited,
+    /// Restore in hours.
+    #[default]
+    Standard,
+    /// Restore in bulk, the cheapest and slowest tier.
+    Bulk,
+}
+
+impl Display for RestoreTier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreTier::Expedited => write!(f, "Expedited"),
+            RestoreTier::Standard => write!(f, "Standard"),
+            RestoreTier::Bulk => write!(f, "Bulk"),
+        }
+    }
+}
+
+/// RestoreStatus is the status of restoring an archived object.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RestoreStatus {
+    /// The object is being restored and can't be read yet.
+    InProgress,
+    /// The object has been restored and can be read.
+    Completed {
+        /// The time when the restored copy expires, `None` means the object
+        /// has been moved out of the archive tier permanently.
+        expiry: Option<DateTime<Utc>>,
+    },
+}
+
+impl RestoreStatus {
+    /// Check if the restore is still in progress.
+    pub fn is_in_progress(&self) -> bool {
+        matches!(self, RestoreStatus::InProgress)
+    }
+
+    /// Check if the restore has been completed.
+    pub fn is_completed(&self) -> bool {
+        matches!(self, RestoreStatus::Completed { .. })
+    }
+}
@@ -0,0 +1,56 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// RetentionMode is the mode of a retention period set on an object.
+///
+/// Services name these modes differently:
+///
+/// | OpenDAL      | S3           | Azblob     | GCS        |
+/// |--------------|--------------|------------|------------|
+/// | `Governance` | `GOVERNANCE` | `Unlocked` | `Unlocked` |
+/// | `Compliance` | `COMPLIANCE` | `Locked`   | `Locked`   |
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RetentionMode {
+    /// The retention can be shortened or removed by users with special permissions.
+    Governance,
+    /// The retention can't be shortened or removed by anyone, including the root user.
+    Compliance,
+}
+
+impl RetentionMode {
+    /// Check if this mode is Governance.
+    pub fn is_governance(self) -> bool {
+        self == RetentionMode::Governance
+    }
+
+    /// Check if this mode is Compliance.
+    pub fn is_compliance(self) -> bool {
+        self == RetentionMode::Compliance
+    }
+}
+
+impl Display for RetentionMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetentionMode::Governance => write!(f, "governance"),
+            RetentionMode::Compliance => write!(f, "compliance"),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::collections::BTreeMap;
+
+use chrono::prelude::*;
+
+use crate::*;
+
+/// Summary is the disk usage of all entries under a given path.
+///
+/// Users can get summary via [`Operator::summary`].
+///
+/// Summary will be computed natively if the service supports it (like
+/// `GETCONTENTSUMMARY` of webhdfs), in which case only the counts and bytes
+/// are known. Otherwise, summary will be computed by listing all entries
+/// recursively.
+///
+/// [`Operator::summary`]: crate::Operator::summary
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    file_count: u64,
+    dir_count: u64,
+    total_bytes: u64,
+    newest_modified: Option<DateTime<Utc>>,
+    oldest_modified: Option<DateTime<Utc>>,
+    depths: BTreeMap<usize, DepthSummary>,
+}
+
+/// DepthSummary is the disk usage of entries at the same depth.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DepthSummary {
+    file_count: u64,
+    dir_count: u64,
+    total_bytes: u64,
+}
+
+impl DepthSummary {
+    /// Count of files at this depth.
+    pub fn file_count(&self) -> u64 {
+        self.file_count
+    }
+
+    /// Count of dirs at this depth.
+    pub fn dir_count(&self) -> u64 {
+        self.dir_count
+    }
+
+    /// Total bytes of files at this depth.
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+}
+
+impl Summary {
+    /// Create a new summary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count of files under the path.
+    pub fn file_count(&self) -> u64 {
+        self.file_count
+    }
+
+    /// Set the count of files.
+    pub fn with_file_count(mut self, v: u64) -> Self {
+        self.file_count = v;
+        self
+    }
+
+    /// Count of dirs under the path, the path itself is not included.
+    pub fn dir_count(&self) -> u64 {
+        self.dir_count
+    }
+
+    /// Set the count of dirs.
+    pub fn with_dir_count(mut self, v: u64) -> Self {
+        self.dir_count = v;
+        self
+    }
+
+    /// Total bytes of files under the path.
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// Set the total bytes.
+    pub fn with_total_bytes(mut self, v: u64) -> Self {
+        self.total_bytes = v;
+        self
+    }
+
+    /// The newest last modified time of files under the path.
+    ///
+    /// Returns `None` if there are no files or the time is unknown.
+    pub fn newest_modified(&self) -> Option<DateTime<Utc>> {
+        self.newest_modified
+    }
+
+    /// The oldest last modified time of files under the path.
+    ///
+    /// Returns `None` if there are no files or the time is unknown.
+    pub fn oldest_modified(&self) -> Option<DateTime<Utc>> {
+        self.oldest_modified
+    }
+
+    /// The summary of each depth.
+    ///
+    /// Entries directly under the path are at depth `0`, entries in their
+    /// sub dirs are at depth `1` and so on.
+    ///
+    /// This will be empty if the summary is computed natively by service.
+    pub fn depths(&self) -> &BTreeMap<usize, DepthSummary> {
+        &self.depths
+    }
+
+    /// Add an entry at given depth into this summary.
+    pub(crate) fn add(&mut self, depth: usize, meta: &Metadata) {
+        let ds = self.depths.entry(depth).or_default();
+
+        if meta.is_dir() {
+            self.dir_count += 1;
+            ds.dir_count += 1;
+            return;
+        }
+
+        self.file_count += 1;
+        self.total_bytes += meta.content_length();
+        ds.file_count += 1;
+        ds.total_bytes += meta.content_length();
+
+        if let Some(t) = meta.last_modified() {
+            self.newest_modified = Some(self.newest_modified.map_or(t, |v| v.max(t)));
+            self.oldest_modified = Some(self.oldest_modified.map_or(t, |v| v.min(t)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_add() {
+        let t1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+
+        let mut s = Summary::new();
+        s.add(0, &Metadata::new(EntryMode::DIR));
+        s.add(
+            0,
+            &Metadata::new(EntryMode::FILE)
+                .with_content_length(1)
+                .with_last_modified(t2),
+        );
+        s.add(
+            1,
+            &Metadata::new(EntryMode::FILE)
+                .with_content_length(2)
+                .with_last_modified(t1),
+        );
+
+        assert_eq!(s.file_count(), 2);
+        assert_eq!(s.dir_count(), 1);
+        assert_eq!(s.total_bytes(), 3);
+        assert_eq!(s.newest_modified(), Some(t2));
+        assert_eq!(s.oldest_modified(), Some(t1));
+        assert_eq!(s.depths()[&0].file_count(), 1);
+        assert_eq!(s.depths()[&0].dir_count(), 1);
+        assert_eq!(s.depths()[&1].total_bytes(), 2);
+    }
+}
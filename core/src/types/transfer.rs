@@ -0,0 +1,430 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use md5::Digest;
+use md5::Md5;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// TransferCheckpoint records the state of a transfer so that it can be
+/// resumed after failure.
+///
+/// Checkpoint is serializable, users can persist it anywhere they like and
+/// pass it back via `transfer_with(..).checkpoint(..)`.
+///
+/// # Notes
+///
+/// A transfer can only be resumed if:
+///
+/// - The source has not been changed, which is checked via its size and etag.
+/// - The destination supports `write_can_append`, so that committed bytes
+///   are durable before the transfer finishes, or `write_can_resume`, so that
+///   the uploaded parts of the multipart upload can be reused.
+///
+/// Otherwise, the transfer will restart from the beginning.
+///
+/// The multipart upload recorded in checkpoint won't be aborted if the transfer
+/// fails, users should abort it via [`Operator::abort_multipart_upload`] if they
+/// decide not to resume.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferCheckpoint {
+    size: u64,
+    etag: Option<String>,
+    offset: u64,
+    #[serde(default)]
+    write: Option<WriteCheckpoint>,
+}
+
+impl TransferCheckpoint {
+    /// Create a new checkpoint of source with given content length.
+    pub fn new(size: u64) -> Self {
+        Self {
+            size,
+            ..Default::default()
+        }
+    }
+
+    /// Set the ETag of the source while this checkpoint is taken.
+    pub fn with_etag(mut self, etag: &str) -> Self {
+        self.etag = Some(etag.to_string());
+        self
+    }
+
+    /// Set the bytes that have been committed to the destination.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the checkpoint of the destination's multipart upload.
+    pub fn with_write(mut self, write: WriteCheckpoint) -> Self {
+        self.write = Some(write);
+        self
+    }
+
+    /// Content length of the source while this checkpoint is taken.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// ETag of the source while this checkpoint is taken.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// The bytes that have been committed to the destination.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The checkpoint of the destination's multipart upload.
+    ///
+    /// Only set if the destination supports `write_can_resume`.
+    pub fn write(&self) -> Option<&WriteCheckpoint> {
+        self.write.as_ref()
+    }
+
+    /// Check if this checkpoint is taken from the given source metadata.
+    fn matches(&self, meta: &Metadata) -> bool {
+        self.size == meta.content_length() && self.etag.as_deref() == meta.etag()
+    }
+}
+
+/// TransferProgress is reported to the progress callback during a transfer.
+#[derive(Debug, Clone)]
+pub struct TransferProgress {
+    transferred: u64,
+    total: u64,
+    checkpoint: TransferCheckpoint,
+}
+
+impl TransferProgress {
+    /// The bytes that have been sent to the destination, including the
+    /// bytes resumed from checkpoint.
+    pub fn transferred(&self) -> u64 {
+        self.transferred
+    }
+
+    /// The total bytes of the source.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The checkpoint that can be used to resume this transfer.
+    pub fn checkpoint(&self) -> &TransferCheckpoint {
+        &self.checkpoint
+    }
+}
+
+/// Callback that will be called every time data has been sent to the destination.
+pub(crate) type TransferProgressFn = Arc<dyn Fn(&TransferProgress) + Send + Sync>;
+
+/// Transfer the content of `src_path` in `src` into `dst_path` in `dst`.
+///
+/// Reads are issued as concurrent ranged requests via [`BufferStream`] and writes
+/// are sent by [`Writer`] so that services with multipart support can upload
+/// concurrently.
+pub(crate) async fn transfer(
+    src: Accessor,
+    src_path: String,
+    dst: Accessor,
+    dst_path: String,
+    args: OpTransfer,
+) -> Result<()> {
+    let meta = src.stat(&src_path, OpStat::new()).await?.into_metadata();
+    if meta.is_dir() {
+        return Err(
+            Error::new(ErrorKind::IsADirectory, "transfer source is a directory")
+                .with_operation("Operator::transfer")
+                .with_context("service", src.info().scheme())
+                .with_context("path", &src_path),
+        );
+    }
+
+    let total = meta.content_length();
+
+    let cap = dst.info().full_capability();
+    let can_append = cap.write_can_append;
+    let can_resume = !can_append && cap.write_can_resume;
+    let accepted = args.checkpoint().filter(|cp| cp.matches(&meta));
+
+    let mut offset = 0;
+    let mut append = false;
+    if can_append {
+        if let Some(cp) = accepted {
+            offset = resume_offset(&dst, &dst_path, cp.offset(), total).await?;
+        }
+        if offset > 0 {
+            append = true;
+        } else if args.checkpoint().is_some() {
+            // The given checkpoint is rejected, make sure we are not
+            // appending to stale content.
+            dst.delete(&dst_path, OpDelete::new()).await?;
+            append = true;
+        } else {
+            // Only append to a fresh destination, existing content will be
+            // replaced by a normal write instead.
+            append = match dst.stat(&dst_path, OpStat::new()).await {
+                Ok(_) => false,
+                Err(err) if err.kind() == ErrorKind::NotFound => true,
+                Err(err) => return Err(err),
+            };
+        }
+    }
+
+    let op_read = OpRead::new();
+    let mut op_reader = OpReader::new().with_concurrent(args.concurrent());
+    if let Some(chunk) = args.chunk() {
+        op_reader = op_reader.with_chunk(chunk);
+    }
+
+    // Appendable destinations commit every write, which makes checkpoint meaningful.
+    let mut op_write = OpWrite::new().with_append(append);
+    let mut op_writer = OpWriter::new();
+    if !append {
+        op_write = op_write.with_concurrent(args.concurrent());
+        if let Some(chunk) = args.chunk() {
+            op_writer = op_writer.with_chunk(chunk);
+        }
+    }
+    let mut w = None;
+    let mut resumed = false;
+    // Multipart destinations can continue the upload recorded in checkpoint.
+    if let Some(wcp) = accepted
+        .and_then(|cp| cp.write.clone())
+        .filter(|_| can_resume)
+    {
+        let ctx = WriteContext::new(
+            dst.clone(),
+            dst_path.clone(),
+            op_write.clone().with_resume(wcp),
+            op_writer.clone(),
+        );
+        match Writer::new(ctx).await {
+            Ok(v) => {
+                offset = v.checkpoint().map(|v| v.offset()).unwrap_or_default();
+                w = Some(v);
+                resumed = true;
+            }
+            // The upload has been completed or aborted, start over.
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+    }
+    let mut w = match w {
+        Some(w) => w,
+        None => {
+            let ctx = WriteContext::new(dst.clone(), dst_path.clone(), op_write, op_writer);
+            Writer::new(ctx).await?
+        }
+    };
+
+    let mut checkpoint = TransferCheckpoint {
+        size: total,
+        etag: meta.etag().map(|v| v.to_string()),
+        offset,
+        write: w.checkpoint().filter(|_| can_resume),
+    };
+
+    let read_ctx = Arc::new(ReadContext::new(
+        src.clone(),
+        src_path.clone(),
+        op_read,
+        op_reader.clone(),
+    ));
+
+    let mut hasher = args.checksum().then(Md5::new);
+    // The bytes already committed must be hashed too.
+    if let Some(hasher) = hasher.as_mut() {
+        if offset > 0 {
+            update_hasher(hasher, BufferStream::new(read_ctx.clone(), 0..offset)).await?;
+        }
+    }
+
+    let mut stream = BufferStream::new(read_ctx, offset..total);
+    let mut transferred = offset;
+    let res: Result<()> = async {
+        while let Some(buf) = stream.try_next().await? {
+            if let Some(hasher) = hasher.as_mut() {
+                buf.clone().for_each(|bs| hasher.update(&bs));
+            }
+            transferred += buf.len() as u64;
+            w.write(buf).await?;
+
+            if append {
+                checkpoint.offset = transferred;
+            } else if can_resume {
+                checkpoint.write = w.checkpoint();
+                checkpoint.offset = checkpoint.write.as_ref().map_or(0, |v| v.offset());
+            }
+            if let Some(f) = args.progress() {
+                f(&TransferProgress {
+                    transferred,
+                    total,
+                    checkpoint: checkpoint.clone(),
+                });
+            }
+        }
+        w.close().await
+    }
+    .await;
+    if let Err(err) = res {
+        // Keep the upload that users hold a checkpoint of, so that it can be resumed.
+        let resumable = checkpoint.write.is_some() && (resumed || args.progress().is_some());
+        if !resumable {
+            // Abort is a best effort operation, the original error matters more.
+            let _ = w.abort().await;
+        }
+        return Err(err
+            .with_operation("Operator::transfer")
+            .with_context("from", &src_path)
+            .with_context("to", &dst_path));
+    }
+
+    let written = dst
+        .stat(&dst_path, OpStat::new())
+        .await?
+        .into_metadata()
+        .content_length();
+    if written != total {
+        return Err(
+            Error::new(ErrorKind::Unexpected, "transferred size mismatch")
+                .with_operation("Operator::transfer")
+                .with_context("to", &dst_path)
+                .with_context("expect", total)
+                .with_context("actual", written),
+        );
+    }
+
+    if let Some(hasher) = hasher {
+        let expect = hasher.finalize();
+
+        let mut dst_hasher = Md5::new();
+        let ctx = Arc::new(ReadContext::new(
+            dst,
+            dst_path.clone(),
+            OpRead::new(),
+            op_reader,
+        ));
+        update_hasher(&mut dst_hasher, BufferStream::new(ctx, 0..total)).await?;
+        let actual = dst_hasher.finalize();
+
+        if expect != actual {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "transferred checksum mismatch")
+                    .with_operation("Operator::transfer")
+                    .with_context("to", &dst_path)
+                    .with_context("expect", format!("{expect:x}"))
+                    .with_context("actual", format!("{actual:x}")),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Decide where to resume the transfer from.
+///
+/// The destination could contain more data than the checkpoint says if
+/// the process exits before the checkpoint is persisted, we trust the
+/// destination as long as it doesn't exceed the source.
+async fn resume_offset(dst: &Accessor, path: &str, offset: u64, total: u64) -> Result<u64> {
+    let size = match dst.stat(path, OpStat::new()).await {
+        Ok(rp) => rp.into_metadata().content_length(),
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err),
+    };
+
+    if size < offset || size > total {
+        return Ok(0);
+    }
+    Ok(size)
+}
+
+async fn update_hasher(hasher: &mut Md5, mut stream: BufferStream) -> Result<()> {
+    while let Some(buf) = stream.try_next().await? {
+        buf.for_each(|bs| hasher.update(&bs));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::services;
+
+    #[tokio::test]
+    async fn test_transfer_between_operators() -> Result<()> {
+        let src = Operator::new(services::Memory::default())?.finish();
+        let dst = Operator::new(services::Memory::default())?.finish();
+
+        let content = vec![7; 4096 + 17];
+        src.write("src", content.clone()).await?;
+
+        let progress = Arc::new(Mutex::new(vec![]));
+        let p = progress.clone();
+        src.transfer_with("src", &dst, "dst")
+            .chunk(1024)
+            .concurrent(4)
+            .checksum(true)
+            .progress(move |v| p.lock().unwrap().push(v.transferred()))
+            .await?;
+
+        assert_eq!(dst.read("dst").await?.to_vec(), content);
+        let progress = progress.lock().unwrap();
+        assert_eq!(progress.len(), 5);
+        assert_eq!(progress.last().copied(), Some(content.len() as u64));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transfer_source_dir() -> Result<()> {
+        let src = Operator::new(services::Memory::default())?.finish();
+        let dst = Operator::new(services::Memory::default())?.finish();
+
+        let err = src
+            .transfer("src/", &dst, "dst")
+            .await
+            .expect_err("transfer dir must fail");
+        assert_eq!(err.kind(), ErrorKind::IsADirectory);
+        Ok(())
+    }
+
+    #[test]
+    fn test_checkpoint_serde() {
+        let cp = TransferCheckpoint::new(1024)
+            .with_etag("\"abc\"")
+            .with_offset(512)
+            .with_write(
+                WriteCheckpoint::new()
+                    .with_upload_id("upload")
+                    .with_offset(512),
+            );
+
+        let s = serde_json::to_string(&cp).unwrap();
+        let actual: TransferCheckpoint = serde_json::from_str(&s).unwrap();
+        assert_eq!(actual, cp);
+    }
+}
@@ -18,6 +18,12 @@
 mod writer;
 pub use writer::Writer;
 
+mod checkpoint;
+pub use checkpoint::WriteCheckpoint;
+
+mod random_writer;
+pub use random_writer::RandomWriter;
+
 mod buffer_sink;
 pub use buffer_sink::BufferSink;
 mod futures_async_writer;
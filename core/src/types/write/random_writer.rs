@@ -0,0 +1,472 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use futures::channel::oneshot;
+use futures::TryStreamExt;
+use uuid::Uuid;
+
+use crate::raw::*;
+use crate::*;
+
+/// The size of chunks that staging file will be uploaded in.
+const STAGING_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// RandomWriter is used to write data at any offset of a file and truncate it.
+///
+/// Services that support random write natively (check [`Capability::write_can_random`])
+/// will be written directly. Other services will be written via a local staging file:
+/// the file will be downloaded into the staging file once its content is needed, and
+/// uploaded back while [`RandomWriter::close`] is called.
+///
+/// # Notes
+///
+/// - Changes written via the staging file are invisible until [`RandomWriter::close`] returns.
+/// - Content type, content disposition, cache control, content encoding and user metadata
+///   of the existing file will be kept while uploading the staging file, as long as the
+///   service supports writing them.
+/// - The staging file is accessed in a separate thread, and will be removed after dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use opendal::Result;
+/// # use opendal::Operator;
+/// # async fn test(op: Operator) -> Result<()> {
+/// let mut w = op.random_writer("path/to/file").await?;
+/// w.write_at(1024, vec![0; 4096]).await?;
+/// w.truncate(2048).await?;
+/// w.close().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RandomWriter {
+    op: Operator,
+    path: String,
+
+    staging: Option<Staging>,
+}
+
+struct Staging {
+    path: PathBuf,
+    file: Arc<File>,
+    dirty: bool,
+    /// Whether the existing file has been downloaded into the staging file.
+    loaded: bool,
+    /// Metadata of the existing file that should be kept after uploaded.
+    meta: Option<Metadata>,
+}
+
+impl Drop for Staging {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+impl Staging {
+    /// Run blocking IO on the staging file.
+    async fn io<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&File) -> std::io::Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let file = self.file.clone();
+        unblock(move || f(&file)).await.map_err(new_std_io_error)
+    }
+
+    /// Materialize the existing file into the staging file if not loaded yet.
+    async fn load(&mut self, op: &Operator, path: &str) -> Result<()> {
+        if self.loaded {
+            return Ok(());
+        }
+
+        let mut s = op.reader(path).await?.into_bytes_stream(..).await?;
+        while let Some(bs) = s.try_next().await.map_err(new_std_io_error)? {
+            self.io(move |mut f| f.write_all(&bs)).await?;
+        }
+        self.loaded = true;
+        Ok(())
+    }
+}
+
+/// Run blocking IO in a separate thread, so that it won't block the async
+/// runtime whichever it is.
+async fn unblock<T: Send + 'static>(
+    f: impl FnOnce() -> std::io::Result<T> + Send + 'static,
+) -> std::io::Result<T> {
+    let (tx, rx) = oneshot::channel();
+    std::thread::spawn(move || {
+        let _ = tx.send(f());
+    });
+    rx.await
+        .unwrap_or_else(|_| Err(std::io::Error::other("staging io thread panicked")))
+}
+
+impl RandomWriter {
+    /// Create a new random writer, staging file will be used if the service
+    /// doesn't support random write natively.
+    pub(crate) async fn new(op: Operator, path: String) -> Result<Self> {
+        if op.info().full_capability().write_can_random {
+            return Ok(Self {
+                op,
+                path,
+                staging: None,
+            });
+        }
+
+        Self::new_staging(op, path, &std::env::temp_dir()).await
+    }
+
+    /// Create a new random writer that always writes via a staging file in given dir.
+    pub(crate) async fn new_staging(op: Operator, path: String, dir: &Path) -> Result<Self> {
+        let staging_path = dir.join(format!("opendal-staging-{}", Uuid::new_v4()));
+        let p = staging_path.clone();
+        let file = unblock(move || {
+            OpenOptions::new()
+                .create_new(true)
+                .read(true)
+                .write(true)
+                .open(p)
+        })
+        .await
+        .map_err(|err| {
+            new_std_io_error(err)
+                .with_operation("RandomWriter::new")
+                .with_context("staging_path", staging_path.to_string_lossy())
+        })?;
+
+        let mut staging = Staging {
+            path: staging_path,
+            file: Arc::new(file),
+            dirty: false,
+            loaded: false,
+            meta: None,
+        };
+
+        // The existing file won't be downloaded until its content is needed, so
+        // truncating it to zero at first won't download it at all.
+        match op.stat(&path).await {
+            Ok(meta) if meta.is_dir() => {
+                return Err(
+                    Error::new(ErrorKind::IsADirectory, "write path is a directory")
+                        .with_operation("RandomWriter::new")
+                        .with_context("path", &path),
+                );
+            }
+            Ok(meta) => staging.meta = Some(meta),
+            // The file will be created while closing.
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                staging.loaded = true;
+                staging.dirty = true;
+            }
+            Err(err) => return Err(err),
+        }
+
+        Ok(Self {
+            op,
+            path,
+            staging: Some(staging),
+        })
+    }
+
+    /// Write data starting at given offset of the file.
+    ///
+    /// Content outside the written range will be kept, and the gap between the end
+    /// of file and the offset will be filled with zeros.
+    pub async fn write_at(&mut self, offset: u64, bs: impl Into<Buffer>) -> Result<()> {
+        let bs = bs.into();
+
+        let Some(staging) = &mut self.staging else {
+            return self.op.write_with(&self.path, bs).offset(offset).await;
+        };
+        staging.load(&self.op, &self.path).await?;
+
+        staging
+            .io(move |mut f| {
+                f.seek(SeekFrom::Start(offset))?;
+                for chunk in bs {
+                    f.write_all(&chunk)?;
+                }
+                Ok(())
+            })
+            .await?;
+        staging.dirty = true;
+        Ok(())
+    }
+
+    /// Truncate or extend the file to given size, the extended part will be
+    /// filled with zeros.
+    pub async fn truncate(&mut self, size: u64) -> Result<()> {
+        if self.staging.is_none() {
+            if self.op.info().full_capability().set_attr_with_size {
+                return self.op.set_attr(&self.path).size(size).await;
+            }
+
+            // Fallback to staging file since the service can't truncate directly.
+            self.staging =
+                Self::new_staging(self.op.clone(), self.path.clone(), &std::env::temp_dir())
+                    .await?
+                    .staging
+                    .take();
+        }
+        let staging = self.staging.as_mut().expect("staging must be initialized");
+        if size == 0 {
+            staging.loaded = true;
+        }
+        staging.load(&self.op, &self.path).await?;
+
+        staging.io(move |f| f.set_len(size)).await?;
+        staging.dirty = true;
+        Ok(())
+    }
+
+    /// Read at most `size` bytes starting at given offset of the file, changes that
+    /// haven't been closed are included.
+    pub async fn read_at(&mut self, offset: u64, size: u64) -> Result<Buffer> {
+        let end = self
+            .content_length()
+            .await?
+            .min(offset.saturating_add(size));
+        if offset >= end {
+            return Ok(Buffer::new());
+        }
+
+        let Some(staging) = &mut self.staging else {
+            return self.op.read_with(&self.path).range(offset..end).await;
+        };
+        staging.load(&self.op, &self.path).await?;
+
+        let buf = staging
+            .io(move |mut f| {
+                f.seek(SeekFrom::Start(offset))?;
+                let mut buf = vec![0; (end - offset) as usize];
+                f.read_exact(&mut buf)?;
+                Ok(buf)
+            })
+            .await?;
+        Ok(buf.into())
+    }
+
+    /// Get the current length of the file, changes that haven't been closed are included.
+    pub async fn content_length(&self) -> Result<u64> {
+        match &self.staging {
+            Some(Staging {
+                loaded: false,
+                meta: Some(meta),
+                ..
+            }) => Ok(meta.content_length()),
+            Some(staging) => staging.io(|f| Ok(f.metadata()?.len())).await,
+            None => Ok(self.op.stat(&self.path).await?.content_length()),
+        }
+    }
+
+    /// Close the writer and make sure all changes have been persisted.
+    ///
+    /// The staging file will be uploaded only if it has been changed, so it's
+    /// fine to call `close` multiple times.
+    pub async fn close(&mut self) -> Result<()> {
+        let Some(staging) = &mut self.staging else {
+            return Ok(());
+        };
+        if !staging.dirty {
+            return Ok(());
+        }
+
+        let cap = self.op.info().full_capability();
+        let mut fut = self.op.writer_with(&self.path);
+        if let Some(meta) = &staging.meta {
+            if let Some(v) = meta.content_type().filter(|_| cap.write_with_content_type) {
+                fut = fut.content_type(v);
+            }
+            if let Some(v) = meta
+                .content_disposition()
+                .filter(|_| cap.write_with_content_disposition)
+            {
+                fut = fut.content_disposition(v);
+            }
+            if let Some(v) = meta
+                .cache_control()
+                .filter(|_| cap.write_with_cache_control)
+            {
+                fut = fut.cache_control(v);
+            }
+            if let Some(v) = meta
+                .content_encoding()
+                .filter(|_| cap.write_with_content_encoding)
+            {
+                fut = fut.content_encoding(v);
+            }
+            if let Some(v) = meta
+                .user_metadata()
+                .filter(|_| cap.write_with_user_metadata)
+            {
+                fut = fut.user_metadata(v.clone());
+            }
+        }
+        let mut w = fut.await?;
+        let mut offset = 0;
+        loop {
+            let buf = staging
+                .io(move |mut f| {
+                    f.seek(SeekFrom::Start(offset))?;
+                    let mut buf = Vec::with_capacity(STAGING_CHUNK_SIZE);
+                    f.take(STAGING_CHUNK_SIZE as u64).read_to_end(&mut buf)?;
+                    Ok(buf)
+                })
+                .await?;
+            if buf.is_empty() {
+                break;
+            }
+            offset += buf.len() as u64;
+            w.write(buf).await?;
+        }
+        w.close().await?;
+
+        staging.dirty = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "services-fs")]
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::services;
+
+    #[tokio::test]
+    async fn test_staging_write_at() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("test_file", "Hello, World!").await?;
+
+        let mut w =
+            RandomWriter::new_staging(op.clone(), "test_file".to_string(), &std::env::temp_dir())
+                .await?;
+        w.write_at(7, "Rust!").await?;
+        w.write_at(15, "!").await?;
+
+        // Changes are invisible before closed.
+        assert_eq!(op.read("test_file").await?.to_vec(), b"Hello, World!");
+
+        w.close().await?;
+        assert_eq!(op.read("test_file").await?.to_vec(), b"Hello, Rust!!\0\0!");
+        Ok(())
+    }
+
+    #[cfg(feature = "services-fs")]
+    #[tokio::test]
+    async fn test_staging_keep_metadata() -> Result<()> {
+        let root = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        let op = Operator::new(services::Fs::default().root(&root.to_string_lossy()))?.finish();
+        let user_metadata = HashMap::from([("location".to_string(), "everywhere".to_string())]);
+        op.write_with("test_file", "Hello, World!")
+            .content_encoding("identity")
+            .user_metadata(user_metadata.clone())
+            .await?;
+
+        let mut w =
+            RandomWriter::new_staging(op.clone(), "test_file".to_string(), &std::env::temp_dir())
+                .await?;
+        w.write_at(7, "Rust!").await?;
+        w.close().await?;
+
+        let meta = op.stat("test_file").await?;
+        assert_eq!(meta.content_encoding(), Some("identity"));
+        assert_eq!(meta.user_metadata(), Some(&user_metadata));
+        assert_eq!(op.read("test_file").await?.to_vec(), b"Hello, Rust!!");
+
+        std::fs::remove_dir_all(root).unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_staging_truncate() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("test_file", "Hello, World!").await?;
+
+        let mut w =
+            RandomWriter::new_staging(op.clone(), "test_file".to_string(), &std::env::temp_dir())
+                .await?;
+        w.truncate(5).await?;
+        w.close().await?;
+        assert_eq!(op.read("test_file").await?.to_vec(), b"Hello");
+
+        w.truncate(7).await?;
+        w.close().await?;
+        assert_eq!(op.read("test_file").await?.to_vec(), b"Hello\0\0");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_staging_truncate_without_download() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("test_file", "Hello, World!").await?;
+
+        let mut w = op.staging_writer("test_file", std::env::temp_dir()).await?;
+        assert_eq!(w.content_length().await?, 13);
+
+        // The existing file is not needed anymore after truncated to zero.
+        op.delete("test_file").await?;
+        w.truncate(0).await?;
+        w.write_at(0, "Hello").await?;
+        w.close().await?;
+        assert_eq!(op.read("test_file").await?.to_vec(), b"Hello");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_staging_read_at() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+        op.write("test_file", "Hello, World!").await?;
+
+        let mut w = op.staging_writer("test_file", std::env::temp_dir()).await?;
+        w.write_at(7, "Rust!").await?;
+        w.truncate(12).await?;
+
+        // Changes are visible to the writer itself before closed.
+        assert_eq!(w.content_length().await?, 12);
+        assert_eq!(w.read_at(0, 100).await?.to_vec(), b"Hello, Rust!");
+        assert_eq!(w.read_at(7, 4).await?.to_vec(), b"Rust");
+        assert!(w.read_at(12, 4).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_staging_create() -> Result<()> {
+        let op = Operator::new(services::Memory::default())?.finish();
+
+        let mut w =
+            RandomWriter::new_staging(op.clone(), "test_file".to_string(), &std::env::temp_dir())
+                .await?;
+        w.close().await?;
+        assert!(op.read("test_file").await?.is_empty());
+
+        let staging_path = w.staging.as_ref().unwrap().path.clone();
+        drop(w);
+        assert!(!staging_path.exists());
+        Ok(())
+    }
+}
@@ -99,7 +99,7 @@ use crate::*;
 ///   creating writer with `append` enabled.
 pub struct Writer {
     /// Keep a reference to write context in writer.
-    _ctx: Arc<WriteContext>,
+    ctx: Arc<WriteContext>,
     inner: WriteGenerator<oio::Writer>,
 }
 
@@ -109,7 +109,7 @@ impl Writer {
         let ctx = Arc::new(ctx);
         let inner = WriteGenerator::create(ctx.clone()).await?;
 
-        Ok(Self { _ctx: ctx, inner })
+        Ok(Self { ctx, inner })
     }
 
     /// Write [`Buffer`] into writer.
@@ -159,6 +159,36 @@ impl Writer {
         self.write(bs).await
     }
 
+    /// Get the checkpoint of current write.
+    ///
+    /// Returns `None` if the underlying service doesn't support resume or
+    /// the upload has not been started yet.
+    ///
+    /// The checkpoint can be persisted and passed back via
+    /// `writer_with(path).resume(checkpoint)` to continue this upload after
+    /// failure. After resuming, [`WriteCheckpoint::offset`] tells the bytes
+    /// that have been confirmed by the service, users should continue writing
+    /// from there.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use opendal::Operator;
+    /// use opendal::Result;
+    /// use opendal::WriteCheckpoint;
+    ///
+    /// async fn test(op: Operator, cp: WriteCheckpoint, content: Vec<u8>) -> Result<()> {
+    ///     let mut w = op.writer_with("hello.txt").resume(cp).await?;
+    ///     let offset = w.checkpoint().map(|v| v.offset()).unwrap_or_default();
+    ///     w.write(content[offset as usize..].to_vec()).await?;
+    ///     w.close().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn checkpoint(&self) -> Option<WriteCheckpoint> {
+        self.ctx.checkpoint()
+    }
+
     /// Abort the writer and clean up all written data.
     ///
     /// ## Notes
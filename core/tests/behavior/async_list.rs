@@ -48,6 +48,9 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_list_dir_with_recursive_no_trailing_slash,
             test_list_file_with_recursive,
             test_list_root_with_recursive,
+            test_list_with_filter,
+            test_glob,
+            test_summary,
             test_remove_all
         ))
     }
@@ -483,6 +486,107 @@ pub async fn test_list_root_with_recursive(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List with filter should only return matched entries.
+pub async fn test_list_with_filter(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = ["a.json", "b.txt", "x/c.json", "y/d.json"];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_filter").await?;
+    }
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .filter("*.json")
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<HashSet<_>>();
+    let expected = HashSet::from([format!("{parent}/a.json")]);
+    assert_eq!(actual, expected);
+
+    let actual = op
+        .list_with(&format!("{parent}/"))
+        .filter("*/")
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<HashSet<_>>();
+    let expected = HashSet::from([format!("{parent}/x/"), format!("{parent}/y/")]);
+    assert_eq!(actual, expected);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// Glob should only return matched entries across dirs.
+pub async fn test_glob(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = [
+        "logs/2023-12/a.json",
+        "logs/2024-01/a.json",
+        "logs/2024-01/b.txt",
+        "logs/2024-02/x/c.json",
+    ];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_glob").await?;
+    }
+
+    let actual = op
+        .glob(&format!("{parent}/logs/2024-*/*.json"))
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<HashSet<_>>();
+    let expected = HashSet::from([format!("{parent}/logs/2024-01/a.json")]);
+    assert_eq!(actual, expected);
+
+    let actual = op
+        .glob(&format!("{parent}/logs/2024-*/**/*.json"))
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<HashSet<_>>();
+    let expected = HashSet::from([
+        format!("{parent}/logs/2024-01/a.json"),
+        format!("{parent}/logs/2024-02/x/c.json"),
+    ]);
+    assert_eq!(actual, expected);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// Summary should count all files and bytes under the dir.
+pub async fn test_summary(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    // Services with dir markers will return the listed dir itself.
+    if op.info().full_capability().create_dir {
+        op.create_dir(&format!("{parent}/")).await?;
+    }
+
+    let paths = [("a", 1), ("b", 2), ("x/c", 3), ("x/y/d", 4)];
+    for (path, size) in paths.iter() {
+        op.write(&format!("{parent}/{path}"), vec![0; *size])
+            .await?;
+    }
+
+    let summary = op.summary(&format!("{parent}/")).await?;
+    assert_eq!(summary.file_count(), 4);
+    assert_eq!(summary.total_bytes(), 10);
+    // Only `x/` and `x/y/` could be counted, depending on whether dirs are listed.
+    assert!(summary.dir_count() <= 2);
+    if !summary.depths().is_empty() {
+        assert_eq!(summary.depths()[&0].file_count(), 2);
+        assert_eq!(summary.depths()[&1].total_bytes(), 3);
+        assert_eq!(summary.depths()[&2].total_bytes(), 4);
+    }
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
 // Walk top down should output as expected
 pub async fn test_list_dir_with_recursive(op: Operator) -> Result<()> {
     let parent = uuid::Uuid::new_v4().to_string();
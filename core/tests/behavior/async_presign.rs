@@ -39,6 +39,10 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_presign_stat
         ))
     }
+
+    if cap.write && cap.presign_delete {
+        tests.extend(async_trials!(op, test_presign_delete))
+    }
 }
 
 /// Presign write should succeed.
@@ -138,3 +142,30 @@ pub async fn test_presign_read(op: Operator) -> Result<()> {
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Presign delete should delete the file.
+pub async fn test_presign_delete(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await.expect("write must succeed");
+
+    let signed_req = op.presign_delete(&path, Duration::from_secs(3600)).await?;
+    debug!("Generated request: {signed_req:?}");
+
+    let client = reqwest::Client::new();
+    let mut req = client.request(
+        signed_req.method().clone(),
+        Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+    );
+    for (k, v) in signed_req.header() {
+        req = req.header(k, v);
+    }
+
+    let resp = req.send().await.expect("send request must succeed");
+    assert!(resp.status().is_success(), "status: {}", resp.status());
+
+    assert!(!op.is_exist(&path).await?, "file must be deleted");
+    Ok(())
+}
@@ -38,6 +38,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_read_range,
             test_reader,
             test_read_not_exist,
+            test_read_many,
             test_read_with_if_match,
             test_read_with_if_none_match,
             test_read_with_dir_path,
@@ -172,6 +173,39 @@ pub async fn test_read_not_exist(op: Operator) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Read many should return content of every path in order.
+pub async fn test_read_many(op: Operator) -> anyhow::Result<()> {
+    let (path_a, content_a, _) = TEST_FIXTURE.new_file(op.clone());
+    let (path_b, content_b, _) = TEST_FIXTURE.new_file(op.clone());
+    let not_exist = uuid::Uuid::new_v4().to_string();
+
+    op.write(&path_a, content_a.clone())
+        .await
+        .expect("write must succeed");
+    op.write(&path_b, content_b.clone())
+        .await
+        .expect("write must succeed");
+
+    let results = op
+        .read_many(vec![path_a.clone(), not_exist.clone(), path_b.clone()])
+        .await?;
+    assert_eq!(results.len(), 3);
+
+    let (path, bs) = &results[0];
+    assert_eq!(path, &path_a);
+    assert_eq!(bs.as_ref().unwrap().to_vec(), content_a);
+
+    let (path, bs) = &results[1];
+    assert_eq!(path, &not_exist);
+    assert_eq!(bs.as_ref().unwrap_err().kind(), ErrorKind::NotFound);
+
+    let (path, bs) = &results[2];
+    assert_eq!(path, &path_b);
+    assert_eq!(bs.as_ref().unwrap().to_vec(), content_b);
+
+    Ok(())
+}
+
 /// Read with if_match should match, else get a ConditionNotMatch error.
 pub async fn test_read_with_if_match(op: Operator) -> anyhow::Result<()> {
     if !op.info().full_capability().read_with_if_match {
@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use anyhow::Result;
+use chrono::DateTime;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if !(cap.stat && cap.write && cap.set_attr) {
+        return;
+    }
+
+    if cap.set_attr_with_mode {
+        tests.extend(async_trials!(op, test_set_attr_mode))
+    }
+    if cap.set_attr_with_times {
+        tests.extend(async_trials!(op, test_set_attr_last_modified))
+    }
+    if cap.read && cap.set_attr_with_size {
+        tests.extend(async_trials!(op, test_set_attr_size))
+    }
+}
+
+/// Set mode should be visible in stat.
+pub async fn test_set_attr_mode(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await?;
+    op.set_attr(&path).mode(0o600).await?;
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.unix_mode(), Some(0o600));
+
+    op.delete(&path).await?;
+    Ok(())
+}
+
+/// Set last modified should be visible in stat.
+pub async fn test_set_attr_last_modified(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await?;
+
+    let mtime = DateTime::from_timestamp(1577836800, 0).unwrap();
+    op.set_attr(&path).last_modified(mtime).await?;
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.last_modified(), Some(mtime));
+
+    op.delete(&path).await?;
+    Ok(())
+}
+
+/// Set size should truncate the file.
+pub async fn test_set_attr_size(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content.clone()).await?;
+
+    let new_size = size as u64 / 2;
+    op.set_attr(&path).size(new_size).await?;
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.content_length(), new_size);
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs.to_vec(), content[..new_size as usize]);
+
+    op.delete(&path).await?;
+    Ok(())
+}
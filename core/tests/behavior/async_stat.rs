@@ -37,6 +37,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_stat_with_special_chars,
             test_stat_not_cleaned_path,
             test_stat_not_exist,
+            test_stat_many,
             test_stat_with_if_match,
             test_stat_with_if_none_match,
             test_stat_with_override_cache_control,
@@ -181,6 +182,40 @@ pub async fn test_stat_not_exist(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Stat many should return results of every path in order.
+pub async fn test_stat_many(op: Operator) -> Result<()> {
+    let (path_a, content_a, size_a) = TEST_FIXTURE.new_file(op.clone());
+    let (path_b, content_b, size_b) = TEST_FIXTURE.new_file(op.clone());
+    let not_exist = uuid::Uuid::new_v4().to_string();
+
+    op.write(&path_a, content_a)
+        .await
+        .expect("write must succeed");
+    op.write(&path_b, content_b)
+        .await
+        .expect("write must succeed");
+
+    let results = op
+        .stat_many(vec![path_a.clone(), not_exist.clone(), path_b.clone()])
+        .await?;
+    assert_eq!(results.len(), 3);
+
+    let (path, meta) = &results[0];
+    assert_eq!(path, &path_a);
+    assert_eq!(meta.as_ref().unwrap().mode(), EntryMode::FILE);
+    assert_eq!(meta.as_ref().unwrap().content_length(), size_a as u64);
+
+    let (path, meta) = &results[1];
+    assert_eq!(path, &not_exist);
+    assert_eq!(meta.as_ref().unwrap_err().kind(), ErrorKind::NotFound);
+
+    let (path, meta) = &results[2];
+    assert_eq!(path, &path_b);
+    assert_eq!(meta.as_ref().unwrap().content_length(), size_b as u64);
+
+    Ok(())
+}
+
 /// Stat with if_match should succeed, else get a ConditionNotMatch error.
 pub async fn test_stat_with_if_match(op: Operator) -> Result<()> {
     if !op.info().full_capability().stat_with_if_match {
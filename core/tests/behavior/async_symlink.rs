@@ -0,0 +1,101 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use anyhow::Result;
+use futures::TryStreamExt;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.create_symlink && cap.read_link {
+        tests.extend(async_trials!(
+            op,
+            test_create_symlink,
+            test_stat_symlink_no_follow,
+            test_list_symlink
+        ))
+    }
+}
+
+/// Create symlink and read its target back.
+pub async fn test_create_symlink(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&format!("{parent}/target"), content.clone())
+        .await
+        .expect("write must succeed");
+    op.create_symlink("target", &format!("{parent}/link"))
+        .await
+        .expect("create symlink must succeed");
+
+    let target = op.read_link(&format!("{parent}/link")).await?;
+    assert_eq!(target, "target");
+
+    let bs = op.read(&format!("{parent}/link")).await?;
+    assert_eq!(bs.to_vec(), content, "read through symlink");
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// Stat with no_follow should return the symlink itself.
+pub async fn test_stat_symlink_no_follow(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&format!("{parent}/target"), content).await?;
+    op.create_symlink("target", &format!("{parent}/link"))
+        .await?;
+
+    let meta = op.stat(&format!("{parent}/link")).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE);
+
+    let meta = op
+        .stat_with(&format!("{parent}/link"))
+        .no_follow(true)
+        .await?;
+    assert_eq!(meta.mode(), EntryMode::Symlink);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+/// List should return symlink as it is.
+pub async fn test_list_symlink(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&format!("{parent}/target"), content).await?;
+    op.create_symlink("target", &format!("{parent}/link"))
+        .await?;
+
+    let entries: Vec<_> = op
+        .lister(&format!("{parent}/"))
+        .await?
+        .try_collect()
+        .await?;
+    let link = entries
+        .iter()
+        .find(|e| e.path() == format!("{parent}/link"))
+        .expect("symlink must be listed");
+    assert_eq!(link.metadata().mode(), EntryMode::Symlink);
+
+    op.remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
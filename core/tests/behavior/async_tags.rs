@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if !(cap.write && cap.get_tags) {
+        return;
+    }
+
+    if cap.write_with_tags {
+        tests.extend(async_trials!(op, test_write_with_tags))
+    }
+    if cap.set_tags {
+        tests.extend(async_trials!(op, test_set_tags))
+    }
+}
+
+/// Write with tags should be visible in get_tags.
+pub async fn test_write_with_tags(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    let tags = HashMap::from([
+        ("project".to_string(), "opendal".to_string()),
+        ("cost-center".to_string(), "a b/c".to_string()),
+    ]);
+    op.write_with(&path, content).tags(tags.clone()).await?;
+
+    assert_eq!(op.get_tags(&path).await?, tags);
+
+    op.delete(&path).await?;
+    Ok(())
+}
+
+/// Set tags should replace all existing tags.
+pub async fn test_set_tags(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&path, content).await?;
+    assert!(op.get_tags(&path).await?.is_empty());
+
+    op.set_tags(&path, [("a".to_string(), "1".to_string())])
+        .await?;
+    op.set_tags(&path, [("b".to_string(), "2".to_string())])
+        .await?;
+
+    let tags = op.get_tags(&path).await?;
+    assert_eq!(tags, HashMap::from([("b".to_string(), "2".to_string())]));
+
+    op.delete(&path).await?;
+    Ok(())
+}
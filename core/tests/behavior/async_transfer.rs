@@ -0,0 +1,234 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use anyhow::Result;
+use opendal::TransferCheckpoint;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.stat {
+        tests.extend(async_trials!(
+            op,
+            test_transfer_file,
+            test_transfer_with_progress,
+            test_transfer_with_checksum,
+            test_transfer_non_existing_source,
+            test_transfer_overwrite
+        ))
+    }
+
+    if cap.read && cap.write && cap.stat && cap.write_can_append {
+        tests.extend(async_trials!(op, test_transfer_resume_from_checkpoint))
+    }
+
+    if cap.read && cap.write && cap.stat && cap.write_can_resume && !cap.write_can_append {
+        tests.extend(async_trials!(
+            op,
+            test_transfer_resume_multipart_from_checkpoint
+        ))
+    }
+}
+
+/// Transfer a file and test contents.
+pub async fn test_transfer_file(op: Operator) -> Result<()> {
+    let (source_path, source_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = TEST_FIXTURE.new_file_path();
+    op.transfer(&source_path, &op, &target_path).await?;
+
+    let target_content = op
+        .read(&target_path)
+        .await
+        .expect("read must succeed")
+        .to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+    Ok(())
+}
+
+/// Transfer a file with progress callback.
+pub async fn test_transfer_with_progress(op: Operator) -> Result<()> {
+    let (source_path, source_content, size) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = TEST_FIXTURE.new_file_path();
+    let progress = Arc::new(Mutex::new(vec![]));
+    let p = progress.clone();
+    op.transfer_with(&source_path, &op, &target_path)
+        .chunk(1024 * 1024)
+        .concurrent(4)
+        .progress(move |v| p.lock().unwrap().push((v.transferred(), v.total())))
+        .await?;
+
+    let progress = progress.lock().unwrap();
+    assert!(!progress.is_empty());
+    assert!(progress.windows(2).all(|v| v[0].0 < v[1].0));
+    assert_eq!(progress.last().copied(), Some((size as u64, size as u64)));
+    Ok(())
+}
+
+/// Transfer a file with checksum verification.
+pub async fn test_transfer_with_checksum(op: Operator) -> Result<()> {
+    let (source_path, source_content, size) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = TEST_FIXTURE.new_file_path();
+    op.transfer_with(&source_path, &op, &target_path)
+        .checksum(true)
+        .await?;
+
+    let meta = op.stat(&target_path).await?;
+    assert_eq!(meta.content_length(), size as u64);
+    Ok(())
+}
+
+/// Transfer a nonexistent source should return an error.
+pub async fn test_transfer_non_existing_source(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let target_path = uuid::Uuid::new_v4().to_string();
+
+    let err = op
+        .transfer(&source_path, &op, &target_path)
+        .await
+        .expect_err("transfer must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    Ok(())
+}
+
+/// Transfer to an existing file should overwrite it.
+pub async fn test_transfer_overwrite(op: Operator) -> Result<()> {
+    let (source_path, source_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&source_path, source_content.clone()).await?;
+
+    let (target_path, target_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&target_path, target_content).await?;
+
+    op.transfer(&source_path, &op, &target_path).await?;
+
+    let actual = op.read(&target_path).await?.to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(actual)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+    Ok(())
+}
+
+/// Transfer should continue from checkpoint instead of starting over.
+pub async fn test_transfer_resume_from_checkpoint(op: Operator) -> Result<()> {
+    let source_content = gen_fixed_bytes(4 * 1024 * 1024);
+    let source_path = TEST_FIXTURE.new_file_path();
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = TEST_FIXTURE.new_file_path();
+    let checkpoint = Arc::new(Mutex::new(None));
+    let cp = checkpoint.clone();
+    op.transfer_with(&source_path, &op, &target_path)
+        .chunk(1024 * 1024)
+        .progress(move |v| {
+            cp.lock().unwrap().get_or_insert(v.checkpoint().clone());
+        })
+        .await?;
+
+    // Simulate a failure after the first chunk has been committed.
+    let checkpoint = checkpoint
+        .lock()
+        .unwrap()
+        .take()
+        .expect("checkpoint must exist");
+    assert_eq!(checkpoint.offset(), 1024 * 1024);
+    op.write(
+        &target_path,
+        source_content[..checkpoint.offset() as usize].to_vec(),
+    )
+    .await?;
+
+    let resumed = Arc::new(Mutex::new(vec![]));
+    let r = resumed.clone();
+    op.transfer_with(&source_path, &op, &target_path)
+        .chunk(1024 * 1024)
+        .checksum(true)
+        .checkpoint(checkpoint)
+        .progress(move |v| r.lock().unwrap().push(v.transferred()))
+        .await?;
+
+    // Only the remaining 3 chunks should be transferred.
+    assert_eq!(
+        resumed.lock().unwrap().clone(),
+        vec![2 * 1024 * 1024, 3 * 1024 * 1024, 4 * 1024 * 1024]
+    );
+    let actual = op.read(&target_path).await?.to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(actual)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+    Ok(())
+}
+
+/// Transfer should continue the multipart upload recorded in checkpoint.
+pub async fn test_transfer_resume_multipart_from_checkpoint(op: Operator) -> Result<()> {
+    let chunk = 5 * 1024 * 1024;
+    let source_content = gen_fixed_bytes(3 * chunk);
+    let source_path = TEST_FIXTURE.new_file_path();
+    op.write(&source_path, source_content.clone()).await?;
+    let meta = op.stat(&source_path).await?;
+
+    // Simulate a failure after the first two parts have been uploaded.
+    let target_path = TEST_FIXTURE.new_file_path();
+    let mut w = op.writer_with(&target_path).chunk(chunk).await?;
+    w.write(source_content[..chunk].to_vec()).await?;
+    w.write(source_content[chunk..2 * chunk].to_vec()).await?;
+    w.write(source_content[2 * chunk..].to_vec()).await?;
+    let write_checkpoint = w.checkpoint().expect("checkpoint must exist");
+    drop(w);
+    assert_eq!(write_checkpoint.offset(), 2 * chunk as u64);
+
+    let mut checkpoint = TransferCheckpoint::new(meta.content_length())
+        .with_offset(write_checkpoint.offset())
+        .with_write(write_checkpoint);
+    if let Some(etag) = meta.etag() {
+        checkpoint = checkpoint.with_etag(etag);
+    }
+
+    let resumed = Arc::new(Mutex::new(vec![]));
+    let r = resumed.clone();
+    op.transfer_with(&source_path, &op, &target_path)
+        .chunk(chunk)
+        .checksum(true)
+        .checkpoint(checkpoint)
+        .progress(move |v| r.lock().unwrap().push(v.transferred()))
+        .await?;
+
+    // Only the last part should be transferred.
+    assert_eq!(resumed.lock().unwrap().clone(), vec![3 * chunk as u64]);
+    let actual = op.read(&target_path).await?.to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(actual)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+    Ok(())
+}
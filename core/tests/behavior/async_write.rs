@@ -16,9 +16,12 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::io::Write;
 
 use anyhow::Result;
 use bytes::Bytes;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use futures::io::BufReader;
 use futures::io::Cursor;
 use futures::stream;
@@ -44,6 +47,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_write_with_cache_control,
             test_write_with_content_type,
             test_write_with_content_disposition,
+            test_write_with_content_encoding,
             test_write_with_user_metadata,
             test_writer_write,
             test_writer_write_with_overwrite,
@@ -53,10 +57,18 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_writer_abort,
             test_writer_abort_with_concurrent,
             test_writer_futures_copy,
-            test_writer_futures_copy_with_concurrent
+            test_writer_futures_copy_with_concurrent,
+            test_write_at,
+            test_write_at_with_gap,
+            test_truncate,
+            test_random_writer
         ))
     }
 
+    if cap.read && cap.write && cap.write_can_random && cap.stat {
+        tests.extend(async_trials!(op, test_write_with_offset))
+    }
+
     if cap.read && cap.write && cap.write_can_append && cap.stat {
         tests.extend(async_trials!(
             op,
@@ -64,6 +76,14 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_writer_with_append
         ))
     }
+
+    if cap.read && cap.write && cap.write_can_resume && cap.stat {
+        tests.extend(async_trials!(op, test_writer_resume))
+    }
+
+    if cap.write && cap.write_can_multi && cap.list_multipart_uploads {
+        tests.extend(async_trials!(op, test_list_and_abort_multipart_uploads))
+    }
 }
 
 /// Write a single file and test with stat.
@@ -208,6 +228,59 @@ pub async fn test_write_with_content_disposition(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a single file with content encoding should keep content as is and
+/// could be decoded while reading.
+pub async fn test_write_with_content_encoding(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_encoding {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+
+    let mut w = GzEncoder::new(Vec::new(), Compression::default());
+    w.write_all(&content)?;
+    let gzipped = w.finish()?;
+
+    op.write_with(&path, gzipped.clone())
+        .content_encoding("gzip")
+        .await?;
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(
+        meta.content_encoding()
+            .expect("content encoding must exist"),
+        "gzip"
+    );
+
+    let bs = op.read(&path).await.expect("read must succeed");
+    assert_eq!(bs.to_vec(), gzipped, "read must return stored content");
+
+    let bs = op
+        .read_with(&path)
+        .decode(true)
+        .await
+        .expect("read with decode must succeed");
+    assert_eq!(
+        bs.to_vec(),
+        content,
+        "read with decode must return decoded content"
+    );
+
+    // Overwrite without content encoding should clear it.
+    op.write(&path, content.clone()).await?;
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_encoding(), None);
+
+    let bs = op
+        .read_with(&path)
+        .decode(true)
+        .await
+        .expect("read with decode must succeed");
+    assert_eq!(bs.to_vec(), content);
+
+    Ok(())
+}
+
 /// write a single file with user defined metadata should succeed.
 pub async fn test_write_with_user_metadata(op: Operator) -> Result<()> {
     if !op.info().full_capability().write_with_user_metadata {
@@ -525,6 +598,105 @@ pub async fn test_writer_futures_copy_with_concurrent(op: Operator) -> Result<()
     Ok(())
 }
 
+/// Write at an offset of an existing file should keep the rest content.
+pub async fn test_write_at(op: Operator) -> Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content.clone()).await?;
+
+    let offset = size / 2;
+    let patch = vec![0xaa; size / 4 + 1];
+    op.write_at(&path, offset as u64, patch.clone())
+        .await
+        .expect("write at must succeed");
+
+    let mut expected = content;
+    expected[offset..offset + patch.len()].copy_from_slice(&patch);
+
+    let bs = op.read(&path).await?;
+    assert_eq!(bs.len(), size, "size must be kept");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(bs.to_vec())),
+        format!("{:x}", Sha256::digest(&expected)),
+        "read content"
+    );
+
+    Ok(())
+}
+
+/// Write at an offset beyond the end of file should fill the gap with zeros.
+pub async fn test_write_at_with_gap(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    op.write(&path, "Hello").await?;
+
+    op.write_at(&path, 8, "World")
+        .await
+        .expect("write at must succeed");
+    assert_eq!(op.read(&path).await?.to_vec(), b"Hello\0\0\0World");
+
+    // Write at a not exist file should create it.
+    let path = TEST_FIXTURE.new_file_path();
+    op.write_at(&path, 2, "Hello")
+        .await
+        .expect("write at must succeed");
+    assert_eq!(op.read(&path).await?.to_vec(), b"\0\0Hello");
+
+    Ok(())
+}
+
+/// Truncate and extend an existing file.
+pub async fn test_truncate(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    op.write(&path, "Hello, World!").await?;
+
+    op.truncate(&path, 5).await.expect("truncate must succeed");
+    assert_eq!(op.read(&path).await?.to_vec(), b"Hello");
+
+    op.truncate(&path, 7).await.expect("extend must succeed");
+    assert_eq!(op.read(&path).await?.to_vec(), b"Hello\0\0");
+
+    let res = op.truncate(&TEST_FIXTURE.new_file_path(), 5).await;
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::NotFound);
+
+    Ok(())
+}
+
+/// Random writer should apply all writes in order.
+pub async fn test_random_writer(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    op.write(&path, "Hello, World!").await?;
+
+    let mut w = op.random_writer(&path).await?;
+    w.write_at(7, "Rust").await?;
+    w.write_at(0, "h").await?;
+    w.truncate(12).await?;
+    w.write_at(11, "?").await?;
+    w.close().await.expect("close must succeed");
+
+    assert_eq!(op.read(&path).await?.to_vec(), b"hello, Rust?");
+
+    Ok(())
+}
+
+/// Write with offset natively should keep the rest content.
+pub async fn test_write_with_offset(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    op.write(&path, "Hello, World!").await?;
+
+    op.write_with(&path, "Rust!")
+        .offset(7)
+        .await
+        .expect("write with offset must succeed");
+    assert_eq!(op.read(&path).await?.to_vec(), b"Hello, Rust!!");
+
+    let mut w = op.writer_with(&path).offset(5).await?;
+    w.write("; ").await?;
+    w.write("Hi").await?;
+    w.close().await?;
+    assert_eq!(op.read(&path).await?.to_vec(), b"Hello; Hist!!");
+
+    Ok(())
+}
+
 /// Test append to a file must success.
 pub async fn test_write_with_append(op: Operator) -> Result<()> {
     let path = TEST_FIXTURE.new_file_path();
@@ -624,3 +796,64 @@ pub async fn test_writer_write_with_overwrite(op: Operator) -> Result<()> {
     op.delete(&path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Resume an unfinished upload from its checkpoint.
+pub async fn test_writer_resume(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let chunk = 5 * 1024 * 1024;
+    let content = gen_fixed_bytes(4 * chunk);
+
+    let mut w = op.writer_with(&path).chunk(chunk).await?;
+    w.write(content[..3 * chunk].to_vec()).await?;
+    let checkpoint = w.checkpoint().expect("checkpoint must exist");
+    // Simulate a crash by dropping the writer without close.
+    drop(w);
+
+    let mut w = op
+        .writer_with(&path)
+        .chunk(chunk)
+        .resume(checkpoint)
+        .await?;
+    let offset = w.checkpoint().expect("checkpoint must exist").offset() as usize;
+    assert!(offset > 0, "uploaded parts must be resumed");
+    assert!(offset <= 3 * chunk);
+    w.write(content[offset..].to_vec()).await?;
+    w.close().await?;
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs.len(), content.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+    Ok(())
+}
+
+/// Incomplete uploads should be listed and can be aborted.
+pub async fn test_list_and_abort_multipart_uploads(op: Operator) -> Result<()> {
+    let dir = TEST_FIXTURE.new_dir_path();
+    let path = format!("{dir}{}", uuid::Uuid::new_v4());
+    let chunk = 5 * 1024 * 1024;
+
+    let mut w = op.writer_with(&path).chunk(chunk).await?;
+    w.write(gen_fixed_bytes(3 * chunk)).await?;
+    // Leave the upload incomplete.
+    drop(w);
+
+    let uploads = op.list_multipart_uploads(&dir).await?;
+    assert!(
+        uploads.iter().any(|v| v.path() == path),
+        "incomplete upload must be listed"
+    );
+
+    let aborted = op.abort_multipart_uploads(&dir).await?;
+    assert!(aborted >= 1);
+
+    let uploads = op.list_multipart_uploads(&dir).await?;
+    assert!(
+        uploads.iter().all(|v| v.path() != path),
+        "aborted upload must not be listed"
+    );
+    Ok(())
+}
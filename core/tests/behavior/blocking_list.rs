@@ -16,6 +16,7 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use anyhow::Result;
 use log::debug;
@@ -35,6 +36,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_blocking_list_dir_with_recursive,
             test_blocking_list_dir_with_recursive_no_trailing_slash,
             test_blocking_list_file_with_recursive,
+            test_blocking_glob,
             test_blocking_remove_all
         ))
     }
@@ -310,3 +312,24 @@ pub fn test_blocking_list_file_with_recursive(op: BlockingOperator) -> Result<()
     assert_eq!(actual, expected);
     Ok(())
 }
+
+/// Glob should only return matched entries across dirs.
+pub fn test_blocking_glob(op: BlockingOperator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let paths = ["a/x.json", "a/y.txt", "b/z.json"];
+    for path in paths.iter() {
+        op.write(&format!("{parent}/{path}"), "test_glob")?;
+    }
+
+    let actual = op
+        .glob(&format!("{parent}/*/*.json"))?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<HashSet<_>>();
+    let expected = HashSet::from([format!("{parent}/a/x.json"), format!("{parent}/b/z.json")]);
+    assert_eq!(actual, expected);
+
+    op.remove_all(&format!("{parent}/"))?;
+    Ok(())
+}
@@ -29,7 +29,11 @@ mod async_list;
 mod async_presign;
 mod async_read;
 mod async_rename;
+mod async_set_attr;
 mod async_stat;
+mod async_symlink;
+mod async_tags;
+mod async_transfer;
 mod async_write;
 
 // Blocking test cases
@@ -70,6 +74,10 @@ fn main() -> anyhow::Result<()> {
     async_read::tests(&op, &mut tests);
     async_rename::tests(&op, &mut tests);
     async_stat::tests(&op, &mut tests);
+    async_set_attr::tests(&op, &mut tests);
+    async_symlink::tests(&op, &mut tests);
+    async_tags::tests(&op, &mut tests);
+    async_transfer::tests(&op, &mut tests);
     async_write::tests(&op, &mut tests);
 
     blocking_copy::tests(&op, &mut tests);
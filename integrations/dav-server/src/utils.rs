@@ -21,6 +21,7 @@ pub fn convert_error(opendal_error: opendal::Error) -> dav_server::fs::FsError {
             dav_server::fs::FsError::Exists
         }
         opendal::ErrorKind::NotFound => dav_server::fs::FsError::NotFound,
+        opendal::ErrorKind::PermissionDenied => dav_server::fs::FsError::Forbidden,
         _ => dav_server::fs::FsError::GeneralFailure,
     }
 }
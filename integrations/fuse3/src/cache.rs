@@ -0,0 +1,383 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::os::unix::fs::DirBuilderExt;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use bytes::Bytes;
+use bytes::BytesMut;
+use fuse3::Result;
+use opendal::Metadata;
+use opendal::Operator;
+use opendal::RandomWriter;
+
+use super::file_system::opendal_error2errno;
+use super::stats::Stats;
+
+/// Sequence of caches created by this process, used to name their dirs.
+static CACHE_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// `Cache` is the local disk cache used by [`Filesystem`](crate::Filesystem).
+///
+/// It contains two parts:
+///
+/// - a block cache for reads: files are fetched in fixed size blocks which are kept on
+///   local disk and evicted in LRU order once `capacity` is exceeded. Sequential reads
+///   trigger readahead of the following blocks in background.
+/// - staging files for writes (write-back): every handle opened for writing gets its own
+///   staging [`RandomWriter`] that receives all writes and truncates, and is uploaded on flush.
+///
+/// All files are stored in a private directory under the given dir, which is removed
+/// while dropping the cache.
+pub struct Cache {
+    dir: PathBuf,
+    capacity: u64,
+    block_size: u64,
+    readahead: u64,
+    write_back: bool,
+
+    next_id: AtomicU64,
+    blocks: Mutex<Blocks>,
+    pub(crate) stats: Arc<Stats>,
+}
+
+#[derive(Default)]
+struct Blocks {
+    entries: HashMap<BlockKey, Block>,
+    loading: HashSet<BlockKey>,
+    used: u64,
+    tick: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct BlockKey {
+    path: String,
+    version: String,
+    index: u64,
+}
+
+struct Block {
+    id: u64,
+    size: u64,
+    last_used: u64,
+}
+
+impl Cache {
+    /// Create a new cache that stores its files under the given dir.
+    ///
+    /// By default, the cache uses 1 GiB of disk for 1 MiB blocks, reads ahead 4 blocks
+    /// and enables write-back.
+    pub fn new(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().join(format!(
+            "ofs-{}-{}",
+            std::process::id(),
+            CACHE_SEQ.fetch_add(1, Ordering::Relaxed)
+        ));
+        // The dir could only be left by a dead process with the same pid.
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        // Staging files contain data of all opened files, keep them private.
+        fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(&dir)?;
+
+        Ok(Self {
+            dir,
+            capacity: 1024 * 1024 * 1024,
+            block_size: 1024 * 1024,
+            readahead: 4,
+            write_back: true,
+            next_id: AtomicU64::new(0),
+            blocks: Mutex::new(Blocks::default()),
+            stats: Arc::default(),
+        })
+    }
+
+    /// Set the max disk space used by cached blocks, `0` disables the block cache.
+    pub fn capacity(mut self, capacity: u64) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Set the size of cached blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is `0`.
+    pub fn block_size(mut self, block_size: u64) -> Self {
+        assert!(block_size > 0, "block size must be greater than 0");
+        self.block_size = block_size;
+        self
+    }
+
+    /// Set the number of blocks to read ahead for sequential reads, `0` disables readahead.
+    pub fn readahead(mut self, blocks: u64) -> Self {
+        self.readahead = blocks;
+        self
+    }
+
+    /// Set whether writes go through local staging files.
+    pub fn write_back(mut self, enabled: bool) -> Self {
+        self.write_back = enabled;
+        self
+    }
+
+    pub(crate) fn is_read_cache_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    pub(crate) fn is_write_back_enabled(&self) -> bool {
+        self.write_back
+    }
+
+    /// Create a new staging writer of given path, whose staging file is stored in
+    /// the dir of cache.
+    pub(crate) async fn staging_writer(&self, op: &Operator, path: &str) -> Result<RandomWriter> {
+        op.staging_writer(path, &self.dir)
+            .await
+            .map_err(opendal_error2errno)
+    }
+
+    /// Read `size` bytes at `offset` of a file with given length and version through
+    /// the block cache.
+    pub(crate) async fn read(
+        &self,
+        op: &Operator,
+        path: &str,
+        version: &str,
+        length: u64,
+        offset: u64,
+        size: u64,
+    ) -> Result<Bytes> {
+        let end = length.min(offset.saturating_add(size));
+        if offset >= end {
+            return Ok(Bytes::new());
+        }
+
+        let mut buf = BytesMut::with_capacity((end - offset) as usize);
+        for index in offset / self.block_size..=(end - 1) / self.block_size {
+            let key = BlockKey {
+                path: path.to_string(),
+                version: version.to_string(),
+                index,
+            };
+            let data = match self.get(&key) {
+                Some(data) => {
+                    self.stats.cache_hit();
+                    data
+                }
+                None => {
+                    self.stats.cache_miss();
+                    self.load(op, key, length).await?
+                }
+            };
+
+            let block_start = index * self.block_size;
+            let start = offset.max(block_start) - block_start;
+            let stop = (end - block_start).min(data.len() as u64);
+            if start >= stop {
+                break;
+            }
+            buf.extend_from_slice(&data[start as usize..stop as usize]);
+        }
+
+        Ok(buf.freeze())
+    }
+
+    /// Start loading the blocks following `offset` in background.
+    pub(crate) fn read_ahead(
+        self: &Arc<Self>,
+        op: &Operator,
+        path: &str,
+        version: &str,
+        length: u64,
+        offset: u64,
+    ) {
+        let first = offset.div_ceil(self.block_size);
+        for index in first..first + self.readahead {
+            if index * self.block_size >= length {
+                break;
+            }
+
+            let key = BlockKey {
+                path: path.to_string(),
+                version: version.to_string(),
+                index,
+            };
+            {
+                let mut blocks = self.blocks.lock().unwrap();
+                if blocks.entries.contains_key(&key) || !blocks.loading.insert(key.clone()) {
+                    continue;
+                }
+            }
+
+            let cache = self.clone();
+            let op = op.clone();
+            tokio::spawn(async move {
+                if let Err(err) = cache.load(&op, key.clone(), length).await {
+                    log::debug!("read_ahead: failed to load block {:?}: {}", key, err);
+                }
+                cache.blocks.lock().unwrap().loading.remove(&key);
+            });
+        }
+    }
+
+    /// Drop all cached blocks of given path.
+    pub(crate) fn invalidate(&self, path: &str) {
+        let mut blocks = self.blocks.lock().unwrap();
+        let keys: Vec<_> = blocks
+            .entries
+            .keys()
+            .filter(|key| key.path == path)
+            .cloned()
+            .collect();
+        for key in keys {
+            self.remove(&mut blocks, &key);
+        }
+        self.stats.set_cache_used(blocks.used);
+    }
+
+    fn next_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn block_path(&self, id: u64) -> PathBuf {
+        self.dir.join(format!("block-{id}"))
+    }
+
+    fn get(&self, key: &BlockKey) -> Option<Bytes> {
+        let id = {
+            let mut blocks = self.blocks.lock().unwrap();
+            blocks.tick += 1;
+            let tick = blocks.tick;
+            let block = blocks.entries.get_mut(key)?;
+            block.last_used = tick;
+            block.id
+        };
+
+        // The block could be evicted after we released the lock, treat it as a miss.
+        fs::read(self.block_path(id)).ok().map(Bytes::from)
+    }
+
+    async fn load(&self, op: &Operator, key: BlockKey, length: u64) -> Result<Bytes> {
+        let start = key.index * self.block_size;
+        let end = length.min(start + self.block_size);
+        let data = op
+            .read_with(&key.path)
+            .range(start..end)
+            .await
+            .map_err(opendal_error2errno)?
+            .to_bytes();
+
+        if self.is_read_cache_enabled() {
+            if let Err(err) = self.insert(key, &data) {
+                log::warn!("failed to write block cache: {}", err);
+            }
+        }
+        Ok(data)
+    }
+
+    fn insert(&self, key: BlockKey, data: &[u8]) -> io::Result<()> {
+        let id = self.next_id();
+        fs::write(self.block_path(id), data)?;
+
+        let mut blocks = self.blocks.lock().unwrap();
+        if blocks.entries.contains_key(&key) {
+            drop(blocks);
+            return fs::remove_file(self.block_path(id));
+        }
+
+        blocks.tick += 1;
+        let block = Block {
+            id,
+            size: data.len() as u64,
+            last_used: blocks.tick,
+        };
+        blocks.used += block.size;
+        blocks.entries.insert(key, block);
+
+        while blocks.used > self.capacity {
+            let Some(key) = blocks
+                .entries
+                .iter()
+                .min_by_key(|(_, block)| block.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.remove(&mut blocks, &key);
+        }
+        self.stats.set_cache_used(blocks.used);
+        Ok(())
+    }
+
+    fn remove(&self, blocks: &mut Blocks, key: &BlockKey) {
+        if let Some(block) = blocks.entries.remove(key) {
+            blocks.used -= block.size;
+            let _ = fs::remove_file(self.block_path(block.id));
+        }
+    }
+}
+
+impl Drop for Cache {
+    fn drop(&mut self) {
+        if let Err(err) = fs::remove_dir_all(&self.dir) {
+            log::warn!("failed to remove cache dir {:?}: {}", self.dir, err);
+        }
+    }
+}
+
+/// Returns the version of a file used to address cached blocks, blocks cached for
+/// other versions of the same path will never be read.
+pub(crate) fn version(metadata: &Metadata) -> String {
+    match metadata.etag() {
+        Some(etag) => etag.to_string(),
+        None => format!(
+            "{}-{}",
+            metadata.content_length(),
+            metadata
+                .last_modified()
+                .map(|t| t.timestamp_nanos_opt().unwrap_or_default())
+                .unwrap_or_default()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::*;
+
+    #[test]
+    fn test_cache_dir_is_private() {
+        let cache = Cache::new(std::env::temp_dir()).unwrap();
+        let mode = fs::metadata(&cache.dir).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+    }
+}
@@ -16,9 +16,11 @@
 // under the License.
 
 use std::ffi::OsString;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
 use fuse3::Errno;
+use opendal::RandomWriter;
 use opendal::Writer;
 use tokio::sync::Mutex;
 
@@ -31,14 +33,30 @@ pub struct OpenedFile {
     pub path: OsString,
     pub is_read: bool,
     pub inner_writer: Option<Arc<Mutex<InnerWriter>>>,
+    pub read_state: Option<Arc<ReadState>>,
+}
+
+/// Read state of files opened for reading.
+pub struct ReadState {
+    /// Length of the file while opening.
+    pub length: u64,
+    /// Version of the file while opening, used to address cached blocks.
+    pub version: String,
+    /// End of the last read, used to detect sequential reads.
+    pub last_end: AtomicU64,
 }
 
 /// # FIXME
 ///
 /// We need better naming and API for this struct.
-pub struct InnerWriter {
-    pub writer: Writer,
-    pub written: u64,
+pub enum InnerWriter {
+    /// Writer for files opened with truncate or append, which can only be
+    /// written sequentially.
+    Sequential { writer: Writer, written: u64 },
+    /// Writer that can be written at any offset.
+    Random(RandomWriter),
+    /// Writer with local staging file that will be uploaded while flushing (write-back).
+    Staging(RandomWriter),
 }
 
 /// File key is the key of opened file.
@@ -18,11 +18,15 @@
 use std::ffi::OsStr;
 use std::num::NonZeroU32;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::SystemTime;
 
 use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
 use fuse3::path::prelude::*;
 use fuse3::Errno;
 use fuse3::Result;
@@ -34,14 +38,19 @@ use opendal::ErrorKind;
 use opendal::Metadata;
 use opendal::Metakey;
 use opendal::Operator;
+use opendal::RandomWriter;
 use sharded_slab::Slab;
 use tokio::sync::Mutex;
 
+use super::cache;
+use super::cache::Cache;
 use super::file::FileKey;
 use super::file::InnerWriter;
 use super::file::OpenedFile;
+use super::file::ReadState;
+use super::stats::Stats;
 
-const TTL: Duration = Duration::from_secs(1); // 1 second
+const DEFAULT_TTL: Duration = Duration::from_secs(1); // 1 second
 
 /// `Filesystem` represents the filesystem that implements [`PathFilesystem`] by opendal.
 ///
@@ -86,6 +95,10 @@ pub struct Filesystem {
     op: Operator,
     gid: u32,
     uid: u32,
+    attr_ttl: Duration,
+    entry_ttl: Duration,
+    cache: Option<Arc<Cache>>,
+    stats: Arc<Stats>,
 
     opened_files: Slab<OpenedFile>,
 }
@@ -97,12 +110,67 @@ impl Filesystem {
             op,
             uid,
             gid,
+            attr_ttl: DEFAULT_TTL,
+            entry_ttl: DEFAULT_TTL,
+            cache: None,
+            stats: Arc::default(),
             opened_files: Slab::new(),
         }
     }
 
-    fn check_flags(&self, flags: u32) -> Result<(bool, bool, bool)> {
-        let is_trunc = flags & libc::O_TRUNC as u32 != 0 || flags & libc::O_CREAT as u32 != 0;
+    /// Set how long the kernel could cache file attributes, defaults to 1 second.
+    pub fn attr_ttl(mut self, ttl: Duration) -> Self {
+        self.attr_ttl = ttl;
+        self
+    }
+
+    /// Set how long the kernel could cache directory entries, defaults to 1 second.
+    pub fn entry_ttl(mut self, ttl: Duration) -> Self {
+        self.entry_ttl = ttl;
+        self
+    }
+
+    /// Enable the local disk [`Cache`] for reads and writes.
+    ///
+    /// Without cache, every read is sent to the backend and writes are streamed to it
+    /// directly, which requires the backend to support appending or random writing
+    /// for anything other than sequential writes.
+    pub fn cache(mut self, mut cache: Cache) -> Self {
+        cache.stats = self.stats.clone();
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Get the [`Stats`] of this filesystem, which keeps updating after mounted.
+    pub fn stats(&self) -> Arc<Stats> {
+        self.stats.clone()
+    }
+
+    fn read_cache(&self) -> Option<&Arc<Cache>> {
+        self.cache.as_ref().filter(|c| c.is_read_cache_enabled())
+    }
+
+    fn write_back_cache(&self) -> Option<&Arc<Cache>> {
+        self.cache.as_ref().filter(|c| c.is_write_back_enabled())
+    }
+
+    // Drop cached blocks of the path after it's changed by us.
+    fn invalidate(&self, path: &str) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate(path);
+        }
+    }
+
+    // Upload the staging file if it has been changed.
+    async fn upload(&self, path: &str, staging: &mut RandomWriter) -> Result<()> {
+        staging.close().await.map_err(opendal_error2errno)?;
+        self.invalidate(path);
+        Ok(())
+    }
+
+    // Files that don't exist are treated as truncated, since they will be created empty.
+    fn check_flags(&self, flags: u32, exists: bool) -> Result<(bool, bool, bool, bool)> {
+        let is_trunc = flags & libc::O_TRUNC as u32 != 0 || !exists;
         let is_append = flags & libc::O_APPEND as u32 != 0;
 
         let mode = flags & libc::O_ACCMODE as u32;
@@ -111,9 +179,7 @@ impl Filesystem {
         if !is_read && !is_write {
             Err(Errno::from(libc::EINVAL))?;
         }
-        // OpenDAL only supports truncate write and append write,
-        // so O_TRUNC or O_APPEND needs to be specified explicitly
-        if (is_write && !is_trunc && !is_append) || is_trunc && !is_write {
+        if is_trunc && !is_write {
             Err(Errno::from(libc::EINVAL))?;
         }
 
@@ -121,11 +187,27 @@ impl Filesystem {
         if is_read && !capability.read {
             Err(Errno::from(libc::EACCES))?;
         }
-        if is_trunc && !capability.write {
+        if is_write && !capability.write {
             Err(Errno::from(libc::EACCES))?;
         }
-        if is_append && !capability.write_can_append {
-            Err(Errno::from(libc::EACCES))?;
+        if self.write_back_cache().is_some() {
+            // Staging file is filled with current content unless truncated.
+            if is_write && !is_trunc && !capability.read {
+                Err(Errno::from(libc::EACCES))?;
+            }
+        } else {
+            // Random write will fallback to staging file which requires read.
+            if is_write
+                && !is_trunc
+                && !is_append
+                && !capability.write_can_random
+                && !capability.read
+            {
+                Err(Errno::from(libc::EACCES))?;
+            }
+            if is_append && !capability.write_can_append {
+                Err(Errno::from(libc::EACCES))?;
+            }
         }
 
         log::trace!(
@@ -135,7 +217,44 @@ impl Filesystem {
             is_trunc,
             is_append
         );
-        Ok((is_read, is_trunc, is_append))
+        Ok((is_read, is_write, is_trunc, is_append))
+    }
+
+    // Switch the opened writer to random writer so that it can be written at any offset.
+    async fn random_writer<'a>(
+        &self,
+        path: &str,
+        inner: &'a mut InnerWriter,
+    ) -> Result<&'a mut RandomWriter> {
+        if let InnerWriter::Sequential { writer, .. } = inner {
+            writer.close().await.map_err(opendal_error2errno)?;
+            let w = self
+                .op
+                .random_writer(path)
+                .await
+                .map_err(opendal_error2errno)?;
+            *inner = InnerWriter::Random(w);
+        }
+
+        match inner {
+            InnerWriter::Random(w) => Ok(w),
+            _ => unreachable!("writer must be random"),
+        }
+    }
+
+    fn insert_opened_file(&self, file: OpenedFile) -> Result<FileKey> {
+        let key = self
+            .opened_files
+            .insert(file)
+            .ok_or(Errno::from(libc::EBUSY))?;
+        self.stats.file_opened();
+        Ok(FileKey(key))
+    }
+
+    fn take_opened_file(&self, key: FileKey) -> Option<OpenedFile> {
+        let file = self.opened_files.take(key.0)?;
+        self.stats.file_closed();
+        Some(file)
     }
 
     // Get opened file and check given path
@@ -175,18 +294,23 @@ impl PathFilesystem for Filesystem {
 
     async fn lookup(&self, _req: Request, parent: &OsStr, name: &OsStr) -> Result<ReplyEntry> {
         log::debug!("lookup(parent={:?}, name={:?})", parent, name);
+        let _op = self.stats.start_op();
 
         let path = PathBuf::from(parent).join(name);
         let metadata = self
             .op
-            .stat(&path.to_string_lossy())
+            .stat_with(&path.to_string_lossy())
+            .no_follow(true)
             .await
             .map_err(opendal_error2errno)?;
 
         let now = SystemTime::now();
         let attr = metadata2file_attr(&metadata, now, self.uid, self.gid);
 
-        Ok(ReplyEntry { ttl: TTL, attr })
+        Ok(ReplyEntry {
+            ttl: self.entry_ttl,
+            attr,
+        })
     }
 
     async fn getattr(
@@ -197,6 +321,7 @@ impl PathFilesystem for Filesystem {
         flags: u32,
     ) -> Result<ReplyAttr> {
         log::debug!("getattr(path={:?}, fh={:?}, flags={:?})", path, fh, flags);
+        let _op = self.stats.start_op();
 
         let fh_path = fh.and_then(|fh| {
             self.opened_files
@@ -214,16 +339,46 @@ impl PathFilesystem for Filesystem {
             (a, b) => a.or(b),
         };
 
-        let metadata = self
-            .op
-            .stat(&file_path.unwrap_or_default().to_string_lossy())
-            .await
-            .map_err(opendal_error2errno)?;
+        // Files opened with write-back are not uploaded yet, use the size of staging file.
+        let inner_writer = fh.and_then(|fh| {
+            self.opened_files
+                .get(FileKey::try_from(fh).ok()?.0)
+                .and_then(|f| f.inner_writer.clone())
+        });
+        let staging_size = match inner_writer {
+            Some(inner_writer) => match &*inner_writer.lock().await {
+                InnerWriter::Staging(staging) => Some(
+                    staging
+                        .content_length()
+                        .await
+                        .map_err(opendal_error2errno)?,
+                ),
+                _ => None,
+            },
+            None => None,
+        };
 
         let now = SystemTime::now();
-        let attr = metadata2file_attr(&metadata, now, self.uid, self.gid);
+        let metadata = self
+            .op
+            .stat_with(&file_path.unwrap_or_default().to_string_lossy())
+            .no_follow(true)
+            .await;
+        let mut attr = match metadata {
+            Ok(metadata) => metadata2file_attr(&metadata, now, self.uid, self.gid),
+            Err(err) if err.kind() == ErrorKind::NotFound && staging_size.is_some() => {
+                dummy_file_attr(FileType::RegularFile, now, self.uid, self.gid)
+            }
+            Err(err) => Err(opendal_error2errno(err))?,
+        };
+        if let Some(size) = staging_size {
+            attr.size = size;
+        }
 
-        Ok(ReplyAttr { ttl: TTL, attr })
+        Ok(ReplyAttr {
+            ttl: self.attr_ttl,
+            attr,
+        })
     }
 
     async fn setattr(
@@ -239,6 +394,89 @@ impl PathFilesystem for Filesystem {
             fh,
             set_attr
         );
+        let _op = self.stats.start_op();
+
+        let file_path = match (path, fh) {
+            (Some(path), _) => path.to_string_lossy().to_string(),
+            (None, Some(fh)) => self
+                .get_opened_file(FileKey::try_from(fh)?, None)?
+                .path
+                .to_string_lossy()
+                .to_string(),
+            (None, None) => Err(Errno::from(libc::EINVAL))?,
+        };
+
+        if let Some(size) = set_attr.size {
+            // Truncate via the opened writer so that it won't be overwritten while flushing.
+            let inner_writer = match fh {
+                Some(fh) => self
+                    .get_opened_file(FileKey::try_from(fh)?, None)?
+                    .inner_writer
+                    .clone(),
+                None => None,
+            };
+
+            match inner_writer {
+                Some(inner_writer) => {
+                    let mut inner = inner_writer.lock().await;
+                    match &mut *inner {
+                        InnerWriter::Staging(staging) => {
+                            staging.truncate(size).await.map_err(opendal_error2errno)?
+                        }
+                        InnerWriter::Sequential { written, .. } if *written == size => {}
+                        _ => {
+                            self.random_writer(&file_path, &mut inner)
+                                .await?
+                                .truncate(size)
+                                .await
+                                .map_err(opendal_error2errno)?;
+                        }
+                    }
+                }
+                None => {
+                    self.op
+                        .truncate(&file_path, size)
+                        .await
+                        .map_err(opendal_error2errno)?;
+                    self.invalidate(&file_path);
+                }
+            }
+        }
+
+        // Attributes not supported by the service (like any of them on s3) are
+        // ignored, so that tools like `touch` still work on them.
+        let cap = self.op.info().full_capability();
+        let mut fut = self.op.set_attr(&file_path);
+        let mut changed = false;
+        if cap.set_attr_with_mode {
+            if let Some(mode) = set_attr.mode {
+                fut = fut.mode(mode & 0o7777);
+                changed = true;
+            }
+        }
+        if cap.set_attr_with_owner {
+            if let Some(uid) = set_attr.uid {
+                fut = fut.uid(uid);
+                changed = true;
+            }
+            if let Some(gid) = set_attr.gid {
+                fut = fut.gid(gid);
+                changed = true;
+            }
+        }
+        if cap.set_attr_with_times {
+            if let Some(t) = set_attr.mtime.and_then(timestamp2datetime) {
+                fut = fut.last_modified(t);
+                changed = true;
+            }
+            if let Some(t) = set_attr.atime.and_then(timestamp2datetime) {
+                fut = fut.last_accessed(t);
+                changed = true;
+            }
+        }
+        if changed {
+            fut.await.map_err(opendal_error2errno)?;
+        }
 
         self.getattr(_req, path, fh, 0).await
     }
@@ -256,7 +494,43 @@ impl PathFilesystem for Filesystem {
             name,
             link_path
         );
-        Err(libc::EOPNOTSUPP.into())
+        let _op = self.stats.start_op();
+
+        let path = PathBuf::from(parent).join(name);
+        self.op
+            .create_symlink(&link_path.to_string_lossy(), &path.to_string_lossy())
+            .await
+            .map_err(opendal_error2errno)?;
+
+        let metadata = self
+            .op
+            .stat_with(&path.to_string_lossy())
+            .no_follow(true)
+            .await
+            .map_err(opendal_error2errno)?;
+
+        let now = SystemTime::now();
+        let attr = metadata2file_attr(&metadata, now, self.uid, self.gid);
+
+        Ok(ReplyEntry {
+            ttl: self.entry_ttl,
+            attr,
+        })
+    }
+
+    async fn readlink(&self, _req: Request, path: &OsStr) -> Result<ReplyData> {
+        log::debug!("readlink(path={:?})", path);
+        let _op = self.stats.start_op();
+
+        let target = self
+            .op
+            .read_link(&path.to_string_lossy())
+            .await
+            .map_err(opendal_error2errno)?;
+
+        Ok(ReplyData {
+            data: Bytes::from(target),
+        })
     }
 
     async fn mknod(
@@ -290,6 +564,7 @@ impl PathFilesystem for Filesystem {
             name,
             mode
         );
+        let _op = self.stats.start_op();
 
         let mut path = PathBuf::from(parent).join(name);
         path.push(""); // ref https://users.rust-lang.org/t/trailing-in-paths/43166
@@ -301,23 +576,29 @@ impl PathFilesystem for Filesystem {
         let now = SystemTime::now();
         let attr = dummy_file_attr(FileType::Directory, now, self.uid, self.gid);
 
-        Ok(ReplyEntry { ttl: TTL, attr })
+        Ok(ReplyEntry {
+            ttl: self.entry_ttl,
+            attr,
+        })
     }
 
     async fn unlink(&self, _req: Request, parent: &OsStr, name: &OsStr) -> Result<()> {
         log::debug!("unlink(parent={:?}, name={:?})", parent, name);
+        let _op = self.stats.start_op();
 
         let path = PathBuf::from(parent).join(name);
         self.op
             .delete(&path.to_string_lossy())
             .await
             .map_err(opendal_error2errno)?;
+        self.invalidate(&path.to_string_lossy());
 
         Ok(())
     }
 
     async fn rmdir(&self, _req: Request, parent: &OsStr, name: &OsStr) -> Result<()> {
         log::debug!("rmdir(parent={:?}, name={:?})", parent, name);
+        let _op = self.stats.start_op();
 
         let path = PathBuf::from(parent).join(name);
         self.op
@@ -343,6 +624,7 @@ impl PathFilesystem for Filesystem {
             parent,
             name
         );
+        let _op = self.stats.start_op();
 
         if !self.op.info().full_capability().rename {
             return Err(Errno::from(libc::ENOTSUP))?;
@@ -355,6 +637,8 @@ impl PathFilesystem for Filesystem {
             .rename(&origin_path.to_string_lossy(), &path.to_string_lossy())
             .await
             .map_err(opendal_error2errno)?;
+        self.invalidate(&origin_path.to_string_lossy());
+        self.invalidate(&path.to_string_lossy());
 
         Ok(())
     }
@@ -377,16 +661,34 @@ impl PathFilesystem for Filesystem {
 
     async fn open(&self, _req: Request, path: &OsStr, flags: u32) -> Result<ReplyOpen> {
         log::debug!("open(path={:?}, flags=0x{:x})", path, flags);
-
-        let (is_read, is_trunc, is_append) = self.check_flags(flags)?;
-        if flags & libc::O_CREAT as u32 != 0 {
+        let _op = self.stats.start_op();
+
+        // Only create the file if it doesn't exist, existing content must be kept.
+        let exists = match flags & libc::O_CREAT as u32 {
+            0 => true,
+            _ => match self.op.stat(&path.to_string_lossy()).await {
+                Ok(_) => true,
+                Err(err) if err.kind() == ErrorKind::NotFound => false,
+                Err(err) => Err(opendal_error2errno(err))?,
+            },
+        };
+        let (is_read, is_write, is_trunc, is_append) = self.check_flags(flags, exists)?;
+        if !exists {
             self.op
                 .write(&path.to_string_lossy(), Bytes::new())
                 .await
                 .map_err(opendal_error2errno)?;
         }
 
-        let inner_writer = if is_trunc || is_append {
+        let inner_writer = if let Some(cache) = self.write_back_cache().filter(|_| is_write) {
+            let mut staging = cache
+                .staging_writer(&self.op, &path.to_string_lossy())
+                .await?;
+            if is_trunc {
+                staging.truncate(0).await.map_err(opendal_error2errno)?;
+            }
+            Some(Arc::new(Mutex::new(InnerWriter::Staging(staging))))
+        } else if is_trunc || is_append {
             let writer = self
                 .op
                 .writer_with(&path.to_string_lossy())
@@ -402,23 +704,49 @@ impl PathFilesystem for Filesystem {
             } else {
                 0
             };
-            Some(Arc::new(Mutex::new(InnerWriter { writer, written })))
+            Some(Arc::new(Mutex::new(InnerWriter::Sequential {
+                writer,
+                written,
+            })))
+        } else if is_write {
+            let writer = self
+                .op
+                .random_writer(&path.to_string_lossy())
+                .await
+                .map_err(opendal_error2errno)?;
+            Some(Arc::new(Mutex::new(InnerWriter::Random(writer))))
         } else {
             None
         };
 
-        let key = self
-            .opened_files
-            .insert(OpenedFile {
-                path: path.into(),
-                is_read,
-                inner_writer,
-            })
-            .ok_or(Errno::from(libc::EBUSY))?;
+        // Files opened for writing are changing, they are read without the block cache.
+        let read_state = match is_read && inner_writer.is_none() {
+            true => {
+                let metadata = self
+                    .op
+                    .stat(&path.to_string_lossy())
+                    .await
+                    .map_err(opendal_error2errno)?;
+                Some(Arc::new(ReadState {
+                    length: metadata.content_length(),
+                    version: cache::version(&metadata),
+                    last_end: AtomicU64::new(0),
+                }))
+            }
+            false => None,
+        };
+
+        let key = self.insert_opened_file(OpenedFile {
+            path: path.into(),
+            is_read,
+            inner_writer,
+            read_state,
+        })?;
 
         Ok(ReplyOpen {
-            fh: FileKey(key).to_fh(),
-            flags,
+            fh: key.to_fh(),
+            // The reply flags are `FOPEN_*` flags instead of open flags.
+            flags: 0,
         })
     }
 
@@ -437,25 +765,83 @@ impl PathFilesystem for Filesystem {
             offset,
             size
         );
+        let _op = self.stats.start_op();
 
-        let file_path = {
+        let (file_path, inner_writer, read_state) = {
             let file = self.get_opened_file(FileKey::try_from(fh)?, path)?;
             if !file.is_read {
                 Err(Errno::from(libc::EACCES))?;
             }
-            file.path.to_string_lossy().to_string()
+            (
+                file.path.to_string_lossy().to_string(),
+                file.inner_writer.clone(),
+                file.read_state.clone(),
+            )
         };
 
-        let data = self
-            .op
-            .read_with(&file_path)
-            .range(offset..)
-            .await
-            .map_err(opendal_error2errno)?;
+        if let Some(inner_writer) = inner_writer {
+            if let InnerWriter::Staging(staging) = &mut *inner_writer.lock().await {
+                let data = staging
+                    .read_at(offset, size as u64)
+                    .await
+                    .map_err(opendal_error2errno)?;
+                return Ok(ReplyData {
+                    data: data.to_bytes(),
+                });
+            }
+        }
 
-        Ok(ReplyData {
-            data: data.to_bytes(),
-        })
+        // Files opened for writing are changing, read them with the latest length.
+        let Some(state) = read_state else {
+            let length = self
+                .op
+                .stat(&file_path)
+                .await
+                .map_err(opendal_error2errno)?
+                .content_length();
+            let end = length.min(offset + size as u64);
+            if offset >= end {
+                return Ok(ReplyData { data: Bytes::new() });
+            }
+            let data = self
+                .op
+                .read_with(&file_path)
+                .range(offset..end)
+                .await
+                .map_err(opendal_error2errno)?;
+            return Ok(ReplyData {
+                data: data.to_bytes(),
+            });
+        };
+
+        let end = state.length.min(offset + size as u64);
+        let data = match self.read_cache() {
+            Some(cache) => {
+                if state.last_end.swap(end, Ordering::Relaxed) == offset {
+                    cache.read_ahead(&self.op, &file_path, &state.version, state.length, end);
+                }
+                cache
+                    .read(
+                        &self.op,
+                        &file_path,
+                        &state.version,
+                        state.length,
+                        offset,
+                        size as u64,
+                    )
+                    .await?
+            }
+            None if offset >= end => Bytes::new(),
+            None => self
+                .op
+                .read_with(&file_path)
+                .range(offset..end)
+                .await
+                .map_err(opendal_error2errno)?
+                .to_bytes(),
+        };
+
+        Ok(ReplyData { data })
     }
 
     async fn write(
@@ -476,28 +862,35 @@ impl PathFilesystem for Filesystem {
             data.len(),
             flags
         );
+        let _op = self.stats.start_op();
 
-        let Some(inner_writer) = ({
-            self.get_opened_file(FileKey::try_from(fh)?, path)?
-                .inner_writer
-                .clone()
-        }) else {
-            Err(Errno::from(libc::EACCES))?
+        let (file_path, inner_writer) = {
+            let file = self.get_opened_file(FileKey::try_from(fh)?, path)?;
+            let Some(inner_writer) = file.inner_writer.clone() else {
+                Err(Errno::from(libc::EACCES))?
+            };
+            (file.path.to_string_lossy().to_string(), inner_writer)
         };
 
         let mut inner = inner_writer.lock().await;
-        // OpenDAL doesn't support random write
-        if offset != inner.written {
-            Err(Errno::from(libc::EINVAL))?;
+        match &mut *inner {
+            InnerWriter::Staging(staging) => staging
+                .write_at(offset, data.to_vec())
+                .await
+                .map_err(opendal_error2errno)?,
+            InnerWriter::Sequential { writer, written } if offset == *written => {
+                writer.write_from(data).await.map_err(opendal_error2errno)?;
+                *written += data.len() as u64;
+            }
+            _ => {
+                self.random_writer(&file_path, &mut inner)
+                    .await?
+                    .write_at(offset, data.to_vec())
+                    .await
+                    .map_err(opendal_error2errno)?;
+            }
         }
 
-        inner
-            .writer
-            .write_from(data)
-            .await
-            .map_err(opendal_error2errno)?;
-        inner.written += data.len() as u64;
-
         Ok(ReplyWrite {
             written: data.len() as _,
         })
@@ -520,16 +913,26 @@ impl PathFilesystem for Filesystem {
             lock_owner,
             flush
         );
+        let _op = self.stats.start_op();
+
+        let Some(file) = self.take_opened_file(FileKey::try_from(fh)?) else {
+            return Ok(());
+        };
 
-        // Just take and forget it.
-        let _ = self.opened_files.take(FileKey::try_from(fh)?.0);
+        // Upload the staging file in case it's changed after the last flush.
+        if let Some(inner_writer) = file.inner_writer {
+            if let InnerWriter::Staging(staging) = &mut *inner_writer.lock().await {
+                self.upload(&file.path.to_string_lossy(), staging).await?;
+            }
+        }
         Ok(())
     }
 
     /// In design, flush could be called multiple times for a single open. But there is the only
     /// place that we can handle the write operations.
     ///
-    /// So we only support the use case that flush only be called once.
+    /// Staging files are uploaded on every flush, other writers only support the use case that
+    /// flush only be called once.
     async fn flush(
         &self,
         _req: Request,
@@ -543,23 +946,35 @@ impl PathFilesystem for Filesystem {
             fh,
             lock_owner,
         );
+        let _op = self.stats.start_op();
+
+        let key = FileKey::try_from(fh)?;
+        let (file_path, inner_writer) = {
+            let file = self.get_opened_file(key, path)?;
+            (
+                file.path.to_string_lossy().to_string(),
+                file.inner_writer.clone(),
+            )
+        };
+        // Files opened for reading only will be kept until released.
+        let Some(inner_writer) = inner_writer else {
+            return Ok(());
+        };
 
-        let file = self
-            .opened_files
-            .take(FileKey::try_from(fh)?.0)
-            .ok_or(Errno::from(libc::EBADF))?;
-
-        if let Some(inner_writer) = file.inner_writer {
-            let mut lock = inner_writer.lock().await;
-            let res = lock.writer.close().await.map_err(opendal_error2errno);
-            return res;
-        }
-
-        if matches!(path, Some(ref p) if p != &file.path) {
-            Err(Errno::from(libc::EBADF))?;
+        let mut inner = inner_writer.lock().await;
+        if let InnerWriter::Staging(staging) = &mut *inner {
+            return self.upload(&file_path, staging).await;
         }
 
-        Ok(())
+        // Other writers can't be used after closing, so take the file on the first flush.
+        let _ = self.take_opened_file(key);
+        let res = match &mut *inner {
+            InnerWriter::Sequential { writer, .. } => writer.close().await,
+            InnerWriter::Random(writer) => writer.close().await,
+            InnerWriter::Staging(_) => unreachable!("staging file has been uploaded"),
+        };
+        self.invalidate(&file_path);
+        res.map_err(opendal_error2errno)
     }
 
     type DirEntryStream<'a> = BoxStream<'a, Result<DirectoryEntry>>;
@@ -572,6 +987,7 @@ impl PathFilesystem for Filesystem {
         offset: i64,
     ) -> Result<ReplyDirectory<Self::DirEntryStream<'a>>> {
         log::debug!("readdir(path={:?}, fh={}, offset={})", path, fh, offset);
+        let _op = self.stats.start_op();
 
         let mut current_dir = PathBuf::from(path);
         current_dir.push(""); // ref https://users.rust-lang.org/t/trailing-in-paths/43166
@@ -611,6 +1027,7 @@ impl PathFilesystem for Filesystem {
 
     async fn access(&self, _req: Request, path: &OsStr, mask: u32) -> Result<()> {
         log::debug!("access(path={:?}, mask=0x{:x})", path, mask);
+        let _op = self.stats.start_op();
 
         self.op
             .stat(&path.to_string_lossy())
@@ -635,12 +1052,20 @@ impl PathFilesystem for Filesystem {
             mode,
             flags
         );
+        let _op = self.stats.start_op();
 
-        let (is_read, is_trunc, is_append) = self.check_flags(flags | libc::O_CREAT as u32)?;
+        let (is_read, _, is_trunc, is_append) =
+            self.check_flags(flags | libc::O_CREAT as u32, false)?;
 
         let path = PathBuf::from(parent).join(name);
 
-        let inner_writer = if is_trunc || is_append {
+        let inner_writer = if let Some(cache) = self.write_back_cache() {
+            let mut staging = cache
+                .staging_writer(&self.op, &path.to_string_lossy())
+                .await?;
+            staging.truncate(0).await.map_err(opendal_error2errno)?;
+            Some(Arc::new(Mutex::new(InnerWriter::Staging(staging))))
+        } else if is_trunc || is_append {
             let writer = self
                 .op
                 .writer_with(&path.to_string_lossy())
@@ -648,7 +1073,10 @@ impl PathFilesystem for Filesystem {
                 .append(is_append)
                 .await
                 .map_err(opendal_error2errno)?;
-            Some(Arc::new(Mutex::new(InnerWriter { writer, written: 0 })))
+            Some(Arc::new(Mutex::new(InnerWriter::Sequential {
+                writer,
+                written: 0,
+            })))
         } else {
             None
         };
@@ -656,21 +1084,20 @@ impl PathFilesystem for Filesystem {
         let now = SystemTime::now();
         let attr = dummy_file_attr(FileType::RegularFile, now, self.uid, self.gid);
 
-        let key = self
-            .opened_files
-            .insert(OpenedFile {
-                path: path.into(),
-                is_read,
-                inner_writer,
-            })
-            .ok_or(Errno::from(libc::EBUSY))?;
+        let key = self.insert_opened_file(OpenedFile {
+            path: path.into(),
+            is_read,
+            inner_writer,
+            read_state: None,
+        })?;
 
         Ok(ReplyCreated {
-            ttl: TTL,
+            ttl: self.entry_ttl,
             attr,
             generation: 0,
-            fh: FileKey(key).to_fh(),
-            flags,
+            fh: key.to_fh(),
+            // The reply flags are `FOPEN_*` flags instead of open flags.
+            flags: 0,
         })
     }
 
@@ -690,12 +1117,15 @@ impl PathFilesystem for Filesystem {
             fh,
             offset
         );
+        let _op = self.stats.start_op();
 
         let now = SystemTime::now();
         let mut current_dir = PathBuf::from(parent);
         current_dir.push(""); // ref https://users.rust-lang.org/t/trailing-in-paths/43166
         let uid = self.uid;
         let gid = self.gid;
+        let entry_ttl = self.entry_ttl;
+        let attr_ttl = self.attr_ttl;
 
         let children = self
             .op
@@ -713,8 +1143,8 @@ impl PathFilesystem for Filesystem {
                             name: e.name().trim_matches('/').into(),
                             offset: (i + 3) as i64,
                             attr: metadata2file_attr(metadata, now, uid, gid),
-                            entry_ttl: TTL,
-                            attr_ttl: TTL,
+                            entry_ttl,
+                            attr_ttl,
                         }
                     })
                     .map_err(opendal_error2errno)
@@ -727,16 +1157,16 @@ impl PathFilesystem for Filesystem {
                 name: ".".into(),
                 offset: 1,
                 attr: relative_path_attr,
-                entry_ttl: TTL,
-                attr_ttl: TTL,
+                entry_ttl,
+                attr_ttl,
             }),
             Result::Ok(DirectoryEntryPlus {
                 kind: FileType::Directory,
                 name: "..".into(),
                 offset: 2,
                 attr: relative_path_attr,
-                entry_ttl: TTL,
-                attr_ttl: TTL,
+                entry_ttl,
+                attr_ttl,
             }),
         ]);
 
@@ -805,19 +1235,32 @@ impl PathFilesystem for Filesystem {
 const fn entry_mode2file_type(mode: EntryMode) -> FileType {
     match mode {
         EntryMode::DIR => FileType::Directory,
+        EntryMode::Symlink => FileType::Symlink,
         _ => FileType::RegularFile,
     }
 }
 
 fn metadata2file_attr(metadata: &Metadata, atime: SystemTime, uid: u32, gid: u32) -> FileAttr {
     let last_modified = metadata.last_modified().map(|t| t.into()).unwrap_or(atime);
+    let last_accessed = metadata.last_accessed().map(|t| t.into()).unwrap_or(atime);
     let kind = entry_mode2file_type(metadata.mode());
-    FileAttr {
+    let mut attr = FileAttr {
         size: metadata.content_length(),
+        atime: last_accessed,
         mtime: last_modified,
         ctime: last_modified,
+        uid: metadata.uid().unwrap_or(uid),
+        gid: metadata.gid().unwrap_or(gid),
         ..dummy_file_attr(kind, atime, uid, gid)
+    };
+    if let Some(mode) = metadata.unix_mode() {
+        attr.perm = fuse3::perm_from_mode_and_kind(kind, mode);
     }
+    attr
+}
+
+fn timestamp2datetime(ts: fuse3::Timestamp) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(ts.sec, ts.nsec)
 }
 
 const fn dummy_file_attr(kind: FileType, now: SystemTime, uid: u32, gid: u32) -> FileAttr {
@@ -837,13 +1280,15 @@ const fn dummy_file_attr(kind: FileType, now: SystemTime, uid: u32, gid: u32) ->
     }
 }
 
-fn opendal_error2errno(err: opendal::Error) -> fuse3::Errno {
+pub(crate) fn opendal_error2errno(err: opendal::Error) -> fuse3::Errno {
     log::trace!("opendal_error2errno: {:?}", err);
     match err.kind() {
         ErrorKind::Unsupported => Errno::from(libc::EOPNOTSUPP),
         ErrorKind::IsADirectory => Errno::from(libc::EISDIR),
         ErrorKind::NotFound => Errno::from(libc::ENOENT),
         ErrorKind::PermissionDenied => Errno::from(libc::EACCES),
+        ErrorKind::ObjectLocked => Errno::from(libc::EPERM),
+        ErrorKind::ObjectArchived => Errno::from(libc::EIO),
         ErrorKind::AlreadyExists => Errno::from(libc::EEXIST),
         ErrorKind::NotADirectory => Errno::from(libc::ENOTDIR),
         ErrorKind::RangeNotSatisfied => Errno::from(libc::EINVAL),
@@ -851,3 +1296,64 @@ fn opendal_error2errno(err: opendal::Error) -> fuse3::Errno {
         _ => Errno::from(libc::ENOENT),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use opendal::services;
+
+    use super::*;
+
+    async fn reopen_with_create(fs: Filesystem) -> Result<()> {
+        fs.op.write("test_file", "Hello, World!").await.unwrap();
+
+        let flags = (libc::O_RDWR | libc::O_CREAT) as u32;
+        let path = OsStr::new("test_file");
+        let reply = fs.open(Request::default(), path, flags).await?;
+        fs.write(Request::default(), Some(path), reply.fh, 0, b"J", 0, flags)
+            .await?;
+        fs.flush(Request::default(), Some(path), reply.fh, 0)
+            .await?;
+        fs.release(Request::default(), Some(path), reply.fh, flags, 0, false)
+            .await?;
+
+        let content = fs.op.read("test_file").await.unwrap();
+        assert_eq!(content.to_vec(), b"Jello, World!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reopen_with_create_keeps_content() -> Result<()> {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        reopen_with_create(Filesystem::new(op, 1000, 1000)).await
+    }
+
+    #[tokio::test]
+    async fn test_reopen_with_create_keeps_content_write_back() -> Result<()> {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        let cache = Cache::new(std::env::temp_dir()).unwrap();
+        reopen_with_create(Filesystem::new(op, 1000, 1000).cache(cache)).await
+    }
+
+    #[tokio::test]
+    async fn test_setattr_ignores_unsupported_attrs() -> Result<()> {
+        let op = Operator::new(services::Memory::default()).unwrap().finish();
+        op.write("test_file", "Hello, World!").await.unwrap();
+        let fs = Filesystem::new(op, 1000, 1000);
+
+        let set_attr = SetAttr {
+            mode: Some(0o600),
+            uid: Some(0),
+            size: Some(5),
+            ..Default::default()
+        };
+        let path = OsStr::new("test_file");
+        let reply = fs
+            .setattr(Request::default(), Some(path), None, set_attr)
+            .await?;
+        assert_eq!(reply.attr.size, 5);
+
+        let content = fs.op.read("test_file").await.unwrap();
+        assert_eq!(content.to_vec(), b"Hello");
+        Ok(())
+    }
+}
@@ -55,6 +55,10 @@
 //! }
 //! ```
 
+mod cache;
+pub use cache::Cache;
 mod file;
 mod file_system;
 pub use file_system::Filesystem;
+mod stats;
+pub use stats::Stats;
@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// Runtime statistics of a [`Filesystem`](crate::Filesystem), which could be fetched by
+/// [`Filesystem::stats`](crate::Filesystem::stats) before mounting.
+#[derive(Debug, Default)]
+pub struct Stats {
+    in_flight: AtomicU64,
+    opened_files: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    cache_used: AtomicU64,
+}
+
+impl Stats {
+    /// Number of operations being handled.
+    pub fn in_flight(&self) -> u64 {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    /// Number of opened files.
+    pub fn opened_files(&self) -> u64 {
+        self.opened_files.load(Ordering::Relaxed)
+    }
+
+    /// Number of blocks read from the cache.
+    pub fn cache_hits(&self) -> u64 {
+        self.cache_hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of blocks read from the backend because they are not cached.
+    pub fn cache_misses(&self) -> u64 {
+        self.cache_misses.load(Ordering::Relaxed)
+    }
+
+    /// Ratio of blocks read from the cache, `0` if no block has been read.
+    pub fn cache_hit_ratio(&self) -> f64 {
+        let hits = self.cache_hits();
+        match hits + self.cache_misses() {
+            0 => 0.0,
+            total => hits as f64 / total as f64,
+        }
+    }
+
+    /// Disk space used by cached blocks.
+    pub fn cache_used(&self) -> u64 {
+        self.cache_used.load(Ordering::Relaxed)
+    }
+
+    /// Start an operation which is in flight until the returned guard is dropped.
+    pub(crate) fn start_op(&self) -> OpGuard<'_> {
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+        OpGuard(self)
+    }
+
+    pub(crate) fn file_opened(&self) {
+        self.opened_files.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn file_closed(&self) {
+        self.opened_files.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn cache_miss(&self) {
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_cache_used(&self, used: u64) {
+        self.cache_used.store(used, Ordering::Relaxed);
+    }
+}
+
+pub(crate) struct OpGuard<'a>(&'a Stats);
+
+impl Drop for OpGuard<'_> {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+}
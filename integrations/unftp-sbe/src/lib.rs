@@ -63,8 +63,10 @@ use std::path::{Path, PathBuf};
 
 use libunftp::auth::UserDetail;
 use libunftp::storage::{self, StorageBackend};
+use opendal::Metakey;
 use opendal::Operator;
 
+use tokio::io::AsyncWriteExt;
 use tokio_util::compat::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
 
 #[derive(Debug, Clone)]
@@ -96,7 +98,7 @@ impl storage::Metadata for OpendalMetadata {
     }
 
     fn is_symlink(&self) -> bool {
-        false
+        self.0.is_symlink()
     }
 
     fn modified(&self) -> storage::Result<std::time::SystemTime> {
@@ -158,12 +160,17 @@ impl<User: UserDetail> StorageBackend<User> for OpendalStorage {
     where
         Self::Metadata: storage::Metadata,
     {
+        let path = convert_path(path.as_ref())?;
+        let dir = format!("{}/", path.trim_end_matches('/'));
         let ret = self
             .op
-            .list(convert_path(path.as_ref())?)
+            .list_with(&dir)
+            .metakey(Metakey::Mode | Metakey::ContentLength | Metakey::LastModified)
             .await
             .map_err(convert_err)?
             .into_iter()
+            // Some services will return the directory itself.
+            .filter(|x| x.path().trim_end_matches('/') != dir.trim_matches('/'))
             .map(|x| {
                 let (path, metadata) = x.into_parts();
                 storage::Fileinfo {
@@ -211,6 +218,8 @@ impl<User: UserDetail> StorageBackend<User> for OpendalStorage {
             .into_futures_async_write()
             .compat_write();
         let len = tokio::io::copy(&mut input, &mut w).await?;
+        // The writer must be shut down to commit the written data.
+        w.shutdown().await?;
         Ok(len)
     }
 
@@ -222,10 +231,9 @@ impl<User: UserDetail> StorageBackend<User> for OpendalStorage {
     }
 
     async fn mkd<P: AsRef<Path> + Send + Debug>(&self, _: &User, path: P) -> storage::Result<()> {
-        self.op
-            .create_dir(convert_path(path.as_ref())?)
-            .await
-            .map_err(convert_err)
+        // `create_dir` requires the path to end with `/`.
+        let path = format!("{}/", convert_path(path.as_ref())?.trim_end_matches('/'));
+        self.op.create_dir(&path).await.map_err(convert_err)
     }
 
     async fn rename<P: AsRef<Path> + Send + Debug>(
@@ -23,10 +23,13 @@ use std::mem::size_of;
 use std::sync::Mutex;
 use std::time::Duration;
 
+use chrono::DateTime;
+use chrono::Utc;
 use log::debug;
 use opendal::Buffer;
 use opendal::ErrorKind;
 use opendal::Operator;
+use opendal::RandomWriter;
 use sharded_slab::Slab;
 use tokio::runtime::Builder;
 use tokio::runtime::Runtime;
@@ -58,9 +61,15 @@ enum FileType {
     File,
 }
 
-struct InnerWriter {
-    writer: opendal::Writer,
-    written: u64,
+enum InnerWriter {
+    /// Writer for files opened with truncate or append, which can only be
+    /// written sequentially.
+    Sequential {
+        writer: opendal::Writer,
+        written: u64,
+    },
+    /// Writer that can be written at any offset.
+    Random(RandomWriter),
 }
 
 #[derive(Clone)]
@@ -97,6 +106,8 @@ fn opendal_error2error(error: opendal::Error) -> Error {
         ErrorKind::IsADirectory => Error::from(libc::EISDIR),
         ErrorKind::NotFound => Error::from(libc::ENOENT),
         ErrorKind::PermissionDenied => Error::from(libc::EACCES),
+        ErrorKind::ObjectLocked => Error::from(libc::EPERM),
+        ErrorKind::ObjectArchived => Error::from(libc::EIO),
         ErrorKind::AlreadyExists => Error::from(libc::EEXIST),
         ErrorKind::NotADirectory => Error::from(libc::ENOTDIR),
         ErrorKind::RangeNotSatisfied => Error::from(libc::EINVAL),
@@ -115,7 +126,24 @@ fn opendal_metadata2opened_file(
         opendal::EntryMode::DIR => FileType::Dir,
         _ => FileType::File,
     };
-    OpenedFile::new(file_type, path, uid, gid)
+    let mut file = OpenedFile::new(
+        file_type,
+        path,
+        metadata.uid().unwrap_or(uid),
+        metadata.gid().unwrap_or(gid),
+    );
+    if let Some(mode) = metadata.unix_mode() {
+        file.metadata.mode = (file.metadata.mode & libc::S_IFMT) | mode;
+    }
+    if let Some(t) = metadata.last_modified() {
+        file.metadata.mtime = t.timestamp() as u64;
+        file.metadata.mtimensec = t.timestamp_subsec_nanos();
+    }
+    if let Some(t) = metadata.last_accessed() {
+        file.metadata.atime = t.timestamp() as u64;
+        file.metadata.atimensec = t.timestamp_subsec_nanos();
+    }
+    file
 }
 
 /// Filesystem is a filesystem implementation with opendal backend,
@@ -241,8 +269,9 @@ impl Filesystem {
         })
     }
 
-    fn check_flags(&self, flags: u32) -> Result<(bool, bool)> {
-        let is_trunc = flags & libc::O_TRUNC as u32 != 0 || flags & libc::O_CREAT as u32 != 0;
+    // Files that don't exist are treated as truncated, since they will be created empty.
+    fn check_flags(&self, flags: u32, exists: bool) -> Result<(bool, bool, bool)> {
+        let is_trunc = flags & libc::O_TRUNC as u32 != 0 || !exists;
         let is_append = flags & libc::O_APPEND as u32 != 0;
 
         let mode = flags & libc::O_ACCMODE as u32;
@@ -255,8 +284,16 @@ impl Filesystem {
         if is_append && !capability.write_can_append {
             Err(Error::from(libc::EACCES))?;
         }
+        // Random write will fallback to staging file which requires read.
+        if is_write
+            && !is_trunc
+            && !is_append
+            && !(capability.write && (capability.write_can_random || capability.read))
+        {
+            Err(Error::from(libc::EACCES))?;
+        }
 
-        Ok((is_write, is_append))
+        Ok((is_write, is_trunc, is_append))
     }
 }
 
@@ -373,11 +410,34 @@ impl Filesystem {
         Filesystem::reply_ok(Some(out), None, in_header.unique, w)
     }
 
-    fn setattr(&self, in_header: InHeader, _r: Reader, w: Writer) -> Result<usize> {
-        debug!("setattr: inode={}", in_header.nodeid);
+    fn setattr(&self, in_header: InHeader, mut r: Reader, w: Writer) -> Result<usize> {
+        let set_attr_in: SetattrIn = r.read_obj().map_err(|e| {
+            new_vhost_user_fs_error("failed to decode protocol messages", Some(e.into()))
+        })?;
+
+        debug!(
+            "setattr: inode={} valid={:#x}",
+            in_header.nodeid, set_attr_in.valid
+        );
+
+        let path = match self
+            .opened_files
+            .get(in_header.nodeid as usize)
+            .map(|f| f.path.clone())
+        {
+            Some(path) => path,
+            None => return Filesystem::reply_error(in_header.unique, w),
+        };
+
+        if self
+            .rt
+            .block_on(self.do_set_attr(&path, &set_attr_in))
+            .is_err()
+        {
+            return Filesystem::reply_error(in_header.unique, w);
+        }
 
-        // do nothing for setattr.
-        self.getattr(in_header, _r, w)
+        self.getattr(in_header, r, w)
     }
 
     fn create(&self, in_header: InHeader, mut r: Reader, w: Writer) -> Result<usize> {
@@ -617,12 +677,84 @@ impl Filesystem {
         Ok(attr)
     }
 
+    async fn do_set_attr(&self, path: &str, set_attr_in: &SetattrIn) -> Result<()> {
+        let valid = set_attr_in.valid;
+        let to_datetime = |sec: u64, nsec: u32| {
+            DateTime::from_timestamp(sec as i64, nsec).ok_or(Error::from(libc::EINVAL))
+        };
+
+        if valid & FATTR_SIZE != 0 {
+            self.do_truncate(path, set_attr_in.size).await?;
+        }
+
+        // Attributes not supported by the service (like any of them on s3) are
+        // ignored, so that tools like `touch` still work on them.
+        let cap = self.core.info().full_capability();
+        let mut fut = self.core.set_attr(path);
+        let mut changed = false;
+        if cap.set_attr_with_mode && valid & FATTR_MODE != 0 {
+            fut = fut.mode(set_attr_in.mode & 0o7777);
+            changed = true;
+        }
+        if cap.set_attr_with_owner {
+            if valid & FATTR_UID != 0 {
+                fut = fut.uid(set_attr_in.uid);
+                changed = true;
+            }
+            if valid & FATTR_GID != 0 {
+                fut = fut.gid(set_attr_in.gid);
+                changed = true;
+            }
+        }
+        if cap.set_attr_with_times {
+            if valid & FATTR_ATIME_NOW != 0 {
+                fut = fut.last_accessed(Utc::now());
+                changed = true;
+            } else if valid & FATTR_ATIME != 0 {
+                fut = fut.last_accessed(to_datetime(set_attr_in.atime, set_attr_in.atimensec)?);
+                changed = true;
+            }
+            if valid & FATTR_MTIME_NOW != 0 {
+                fut = fut.last_modified(Utc::now());
+                changed = true;
+            } else if valid & FATTR_MTIME != 0 {
+                fut = fut.last_modified(to_datetime(set_attr_in.mtime, set_attr_in.mtimensec)?);
+                changed = true;
+            }
+        }
+        if !changed {
+            return Ok(());
+        }
+
+        fut.await.map_err(opendal_error2error)
+    }
+
     async fn do_set_writer(&self, path: &str, flags: u32) -> Result<()> {
-        let (is_write, is_append) = self.check_flags(flags)?;
+        // Only create the file if it doesn't exist, existing content must be kept.
+        let exists = match flags & libc::O_CREAT as u32 {
+            0 => true,
+            _ => match self.core.stat(path).await {
+                Ok(_) => true,
+                Err(err) if err.kind() == ErrorKind::NotFound => false,
+                Err(err) => return Err(opendal_error2error(err)),
+            },
+        };
+        let (is_write, is_trunc, is_append) = self.check_flags(flags, exists)?;
         if !is_write {
             return Ok(());
         }
 
+        if !is_trunc && !is_append {
+            let writer = self
+                .core
+                .random_writer(path)
+                .await
+                .map_err(opendal_error2error)?;
+            let mut opened_file_writer = self.opened_files_writer.lock().await;
+            opened_file_writer.insert(path.to_string(), InnerWriter::Random(writer));
+            return Ok(());
+        }
+
         let writer = self
             .core
             .writer_with(path)
@@ -639,7 +771,7 @@ impl Filesystem {
             0
         };
 
-        let inner_writer = InnerWriter { writer, written };
+        let inner_writer = InnerWriter::Sequential { writer, written };
         let mut opened_file_writer = self.opened_files_writer.lock().await;
         opened_file_writer.insert(path.to_string(), inner_writer);
 
@@ -651,11 +783,11 @@ impl Filesystem {
         let inner_writer = opened_file_writer
             .get_mut(path)
             .ok_or(Error::from(libc::EINVAL))?;
-        inner_writer
-            .writer
-            .close()
-            .await
-            .map_err(opendal_error2error)?;
+        let res = match inner_writer {
+            InnerWriter::Sequential { writer, .. } => writer.close().await,
+            InnerWriter::Random(writer) => writer.close().await,
+        };
+        res.map_err(opendal_error2error)?;
         opened_file_writer.remove(path);
 
         Ok(())
@@ -684,16 +816,62 @@ impl Filesystem {
         let inner_writer = opened_file_writer
             .get_mut(path)
             .ok_or(Error::from(libc::EINVAL))?;
-        if offset != inner_writer.written {
-            return Err(Error::from(libc::EINVAL));
+        match inner_writer {
+            InnerWriter::Sequential { writer, written } if offset == *written => {
+                writer.write_from(data).await.map_err(opendal_error2error)?;
+                *written += len as u64;
+            }
+            _ => {
+                self.do_switch_random_writer(path, inner_writer)
+                    .await?
+                    .write_at(offset, data)
+                    .await
+                    .map_err(opendal_error2error)?;
+            }
         }
-        inner_writer
-            .writer
-            .write_from(data)
-            .await
-            .map_err(opendal_error2error)?;
-        inner_writer.written += len as u64;
 
         Ok(len)
     }
+
+    async fn do_truncate(&self, path: &str, size: u64) -> Result<()> {
+        let mut opened_file_writer = self.opened_files_writer.lock().await;
+        // Truncate via the opened writer so that it won't be overwritten while releasing.
+        let Some(inner_writer) = opened_file_writer.get_mut(path) else {
+            return self
+                .core
+                .truncate(path, size)
+                .await
+                .map_err(opendal_error2error);
+        };
+        if matches!(inner_writer, InnerWriter::Sequential { written, .. } if *written == size) {
+            return Ok(());
+        }
+
+        self.do_switch_random_writer(path, inner_writer)
+            .await?
+            .truncate(size)
+            .await
+            .map_err(opendal_error2error)
+    }
+
+    async fn do_switch_random_writer<'a>(
+        &self,
+        path: &str,
+        inner_writer: &'a mut InnerWriter,
+    ) -> Result<&'a mut RandomWriter> {
+        if let InnerWriter::Sequential { writer, .. } = inner_writer {
+            writer.close().await.map_err(opendal_error2error)?;
+            let writer = self
+                .core
+                .random_writer(path)
+                .await
+                .map_err(opendal_error2error)?;
+            *inner_writer = InnerWriter::Random(writer);
+        }
+
+        match inner_writer {
+            InnerWriter::Random(writer) => Ok(writer),
+            InnerWriter::Sequential { .. } => unreachable!("writer must be random"),
+        }
+    }
 }
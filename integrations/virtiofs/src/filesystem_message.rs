@@ -259,6 +259,42 @@ pub struct WriteIn {
     pub padding: u32,
 }
 
+/// SetattrIn is used to parse the parameters passed in the Setattr filesystem call.
+///
+/// The fields of the struct need to conform to the specific format of the virtiofs message.
+/// Currently, we only need to align them exactly with virtiofsd.
+/// Reference: https://gitlab.com/virtio-fs/virtiofsd/-/blob/main/src/fuse.rs?ref_type=heads#L796
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SetattrIn {
+    pub valid: u32,
+    pub padding: u32,
+    pub fh: u64,
+    pub size: u64,
+    pub lock_owner: u64,
+    pub atime: u64,
+    pub mtime: u64,
+    pub ctime: u64,
+    pub atimensec: u32,
+    pub mtimensec: u32,
+    pub ctimensec: u32,
+    pub mode: u32,
+    pub unused4: u32,
+    pub uid: u32,
+    pub gid: u32,
+    pub unused5: u32,
+}
+
+/// Bits of `SetattrIn::valid` that mark which attributes should be changed.
+pub const FATTR_MODE: u32 = 1 << 0;
+pub const FATTR_UID: u32 = 1 << 1;
+pub const FATTR_GID: u32 = 1 << 2;
+pub const FATTR_SIZE: u32 = 1 << 3;
+pub const FATTR_ATIME: u32 = 1 << 4;
+pub const FATTR_MTIME: u32 = 1 << 5;
+pub const FATTR_ATIME_NOW: u32 = 1 << 7;
+pub const FATTR_MTIME_NOW: u32 = 1 << 8;
+
 /// WriteOut is used to return the number of bytes written in the Write filesystem call.
 ///
 /// The fields of the struct need to conform to the specific format of the virtiofs message.
@@ -286,3 +322,4 @@ unsafe impl ByteValued for OpenOut {}
 unsafe impl ByteValued for ReadIn {}
 unsafe impl ByteValued for WriteIn {}
 unsafe impl ByteValued for WriteOut {}
+unsafe impl ByteValued for SetattrIn {}